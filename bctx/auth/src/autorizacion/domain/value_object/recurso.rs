@@ -11,24 +11,34 @@ pub enum RecursoError {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Recurso {
     Admin,
+    Baremo,
+    Bloqueo,
+    Cuenta,
     Examen,
     Evaluacion,
     Postulante,
+    Pregunta,
     Psicologo,
     Respuesta,
     Revision,
+    Sesion,
 }
 
 impl fmt::Display for Recurso {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Recurso::Admin => write!(f, "admin"),
+            Recurso::Baremo => write!(f, "baremo"),
+            Recurso::Bloqueo => write!(f, "bloqueo"),
+            Recurso::Cuenta => write!(f, "cuenta"),
             Recurso::Examen => write!(f, "examen"),
             Recurso::Evaluacion => write!(f, "evaluacion"),
             Recurso::Postulante => write!(f, "postulante"),
+            Recurso::Pregunta => write!(f, "pregunta"),
             Recurso::Psicologo => write!(f, "psicologo"),
             Recurso::Respuesta => write!(f, "respuesta"),
             Recurso::Revision => write!(f, "revision"),
+            Recurso::Sesion => write!(f, "sesion"),
         }
     }
 }
@@ -39,12 +49,17 @@ impl FromStr for Recurso {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "admin" | "admins" => Ok(Recurso::Admin),
+            "baremo" | "baremos" => Ok(Recurso::Baremo),
+            "bloqueo" | "bloqueos" => Ok(Recurso::Bloqueo),
+            "cuenta" | "cuentas" => Ok(Recurso::Cuenta),
             "examen" | "examenes" => Ok(Recurso::Examen),
             "evaluacion" | "evaluaciones" => Ok(Recurso::Evaluacion),
             "postulante" | "postulantes" => Ok(Recurso::Postulante),
+            "pregunta" | "preguntas" => Ok(Recurso::Pregunta),
             "psicologo" | "psicologos" => Ok(Recurso::Psicologo),
             "respuesta" | "respuestas" => Ok(Recurso::Respuesta),
             "revision" | "revisiones" => Ok(Recurso::Revision),
+            "sesion" | "sesiones" => Ok(Recurso::Sesion),
             _ => Err(RecursoError::NoValido(s.to_string())),
         }
     }
@@ -65,6 +80,9 @@ mod tests {
     #[test]
     fn test_recurso_from_str_singular() {
         assert_eq!("admin".parse::<Recurso>().unwrap(), Recurso::Admin);
+        assert_eq!("baremo".parse::<Recurso>().unwrap(), Recurso::Baremo);
+        assert_eq!("bloqueo".parse::<Recurso>().unwrap(), Recurso::Bloqueo);
+        assert_eq!("cuenta".parse::<Recurso>().unwrap(), Recurso::Cuenta);
         assert_eq!("examen".parse::<Recurso>().unwrap(), Recurso::Examen);
         assert_eq!(
             "evaluacion".parse::<Recurso>().unwrap(),
@@ -74,14 +92,19 @@ mod tests {
             "postulante".parse::<Recurso>().unwrap(),
             Recurso::Postulante
         );
+        assert_eq!("pregunta".parse::<Recurso>().unwrap(), Recurso::Pregunta);
         assert_eq!("psicologo".parse::<Recurso>().unwrap(), Recurso::Psicologo);
         assert_eq!("respuesta".parse::<Recurso>().unwrap(), Recurso::Respuesta);
         assert_eq!("revision".parse::<Recurso>().unwrap(), Recurso::Revision);
+        assert_eq!("sesion".parse::<Recurso>().unwrap(), Recurso::Sesion);
     }
 
     #[test]
     fn test_recurso_from_str_plural() {
         assert_eq!("admins".parse::<Recurso>().unwrap(), Recurso::Admin);
+        assert_eq!("baremos".parse::<Recurso>().unwrap(), Recurso::Baremo);
+        assert_eq!("bloqueos".parse::<Recurso>().unwrap(), Recurso::Bloqueo);
+        assert_eq!("cuentas".parse::<Recurso>().unwrap(), Recurso::Cuenta);
         assert_eq!("examenes".parse::<Recurso>().unwrap(), Recurso::Examen);
         assert_eq!(
             "evaluaciones".parse::<Recurso>().unwrap(),
@@ -91,9 +114,11 @@ mod tests {
             "postulantes".parse::<Recurso>().unwrap(),
             Recurso::Postulante
         );
+        assert_eq!("preguntas".parse::<Recurso>().unwrap(), Recurso::Pregunta);
         assert_eq!("psicologos".parse::<Recurso>().unwrap(), Recurso::Psicologo);
         assert_eq!("respuestas".parse::<Recurso>().unwrap(), Recurso::Respuesta);
         assert_eq!("revisiones".parse::<Recurso>().unwrap(), Recurso::Revision);
+        assert_eq!("sesiones".parse::<Recurso>().unwrap(), Recurso::Sesion);
     }
 
     #[test]
@@ -110,6 +135,10 @@ mod tests {
             Recurso::desde_ruta("/revisiones").unwrap(),
             Recurso::Revision
         );
+        assert_eq!(
+            Recurso::desde_ruta("/preguntas/789").unwrap(),
+            Recurso::Pregunta
+        );
     }
 
     #[test]
@@ -1,2 +1,6 @@
+pub mod acceso_respuesta;
+pub mod bloqueo_login;
+pub mod credencial;
 pub mod error;
+pub mod sesion_refresh;
 pub mod usuario_login;
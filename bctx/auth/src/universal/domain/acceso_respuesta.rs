@@ -0,0 +1,75 @@
+use uuid::Uuid;
+
+/// Sin 0, 1, I, L ni O para que el codigo se pueda dictar o copiar a mano
+const ALFABETO_CODIGO: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+pub const CODIGO_ACCESO_LONGITUD: usize = 8;
+const SESION_RESPUESTA_PREFIX: &str = "respuesta:";
+
+/// Codigo corto de un solo uso con el que un postulante entra a una respuesta
+pub fn generar_codigo_acceso() -> String {
+    let base = ALFABETO_CODIGO.len() as u128;
+    let mut aleatorio = Uuid::new_v4().as_u128();
+    (0..CODIGO_ACCESO_LONGITUD)
+        .map(|_| {
+            let indice = (aleatorio % base) as usize;
+            aleatorio /= base;
+            ALFABETO_CODIGO[indice] as char
+        })
+        .collect()
+}
+
+/// Los codigos se comparan sin distinguir mayusculas ni espacios alrededor
+pub fn normalizar_codigo_acceso(codigo: &str) -> String {
+    codigo.trim().to_uppercase()
+}
+
+/// Clave de la sesion activa. Un token limitado a una respuesta tiene su
+/// propia sesion, separada de la del sujeto.
+pub fn clave_sesion(sujeto_id: &str, respuesta_id: Option<&str>) -> String {
+    match respuesta_id {
+        Some(respuesta_id) => format!("{}{}", SESION_RESPUESTA_PREFIX, respuesta_id),
+        None => sujeto_id.to_string(),
+    }
+}
+
+/// Un token limitado a una respuesta solo alcanza `/respuestas/{id}` y sus
+/// subrutas.
+pub fn ruta_permitida(respuesta_id: &str, ruta: &str) -> bool {
+    ruta.strip_prefix("/respuestas/")
+        .and_then(|resto| resto.strip_prefix(respuesta_id))
+        .is_some_and(|resto| resto.is_empty() || resto.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPUESTA_ID: &str = "3f2b8c1a-9d4e-4a7b-b6c5-2e1f0a9d8c7b";
+
+    #[test]
+    fn test_codigo_acceso() {
+        let codigo = generar_codigo_acceso();
+        assert_eq!(codigo.len(), CODIGO_ACCESO_LONGITUD);
+        assert!(codigo.bytes().all(|c| ALFABETO_CODIGO.contains(&c)));
+        assert_ne!(codigo, generar_codigo_acceso());
+        assert_eq!(normalizar_codigo_acceso(" k7m2p9qx "), "K7M2P9QX");
+    }
+
+    #[test]
+    fn test_ruta_permitida_solo_bajo_la_respuesta() {
+        assert!(ruta_permitida(
+            RESPUESTA_ID,
+            &format!("/respuestas/{}", RESPUESTA_ID)
+        ));
+        assert!(ruta_permitida(
+            RESPUESTA_ID,
+            &format!("/respuestas/{}/empezar", RESPUESTA_ID)
+        ));
+        assert!(!ruta_permitida(
+            RESPUESTA_ID,
+            &format!("/respuestas/{}0/empezar", RESPUESTA_ID)
+        ));
+        assert!(!ruta_permitida(RESPUESTA_ID, "/respuestas"));
+        assert!(!ruta_permitida(RESPUESTA_ID, "/postulantes/me"));
+    }
+}
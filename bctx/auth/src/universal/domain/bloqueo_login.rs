@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Contra que se cuentan los intentos fallidos de login
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClaveIntento {
+    Documento(String),
+    Ip(String),
+    /// Fallos contra el codigo de restablecimiento de password del documento
+    CodigoReset(String),
+}
+
+impl fmt::Display for ClaveIntento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClaveIntento::Documento(documento) => write!(f, "documento:{}", documento),
+            ClaveIntento::Ip(ip) => write!(f, "ip:{}", ip),
+            ClaveIntento::CodigoReset(documento) => write!(f, "codigo_reset:{}", documento),
+        }
+    }
+}
+
+/// Al llegar a `max_intentos_*` fallos la clave se bloquea `bloqueo_base_segundos`,
+/// y cada fallo adicional duplica el bloqueo hasta `bloqueo_max_segundos`. Los
+/// fallos se olvidan tras `ventana_segundos` sin intentos nuevos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoliticaBloqueo {
+    pub max_intentos_documento: u32,
+    pub max_intentos_ip: u32,
+    pub bloqueo_base_segundos: u64,
+    pub bloqueo_max_segundos: u64,
+    pub ventana_segundos: u64,
+}
+
+impl Default for PoliticaBloqueo {
+    fn default() -> Self {
+        Self {
+            max_intentos_documento: 5,
+            max_intentos_ip: 20,
+            bloqueo_base_segundos: 60,
+            bloqueo_max_segundos: 3600,
+            ventana_segundos: 86400,
+        }
+    }
+}
+
+impl PoliticaBloqueo {
+    /// Segundos de bloqueo que corresponden tras `fallos` intentos fallidos
+    pub fn duracion_bloqueo(&self, clave: &ClaveIntento, fallos: u32) -> Option<u64> {
+        let max_intentos = match clave {
+            ClaveIntento::Documento(_) | ClaveIntento::CodigoReset(_) => {
+                self.max_intentos_documento
+            }
+            ClaveIntento::Ip(_) => self.max_intentos_ip,
+        };
+        if fallos < max_intentos.max(1) {
+            return None;
+        }
+
+        let exponente = (fallos - max_intentos.max(1)).min(32);
+        let duracion = self
+            .bloqueo_base_segundos
+            .saturating_mul(1u64 << exponente)
+            .min(self.bloqueo_max_segundos);
+        Some(duracion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_el_bloqueo_crece_exponencialmente_hasta_el_maximo() {
+        let politica = PoliticaBloqueo::default();
+        let documento = ClaveIntento::Documento("12345678".to_string());
+
+        assert_eq!(politica.duracion_bloqueo(&documento, 4), None);
+        assert_eq!(politica.duracion_bloqueo(&documento, 5), Some(60));
+        assert_eq!(politica.duracion_bloqueo(&documento, 6), Some(120));
+        assert_eq!(politica.duracion_bloqueo(&documento, 7), Some(240));
+        assert_eq!(politica.duracion_bloqueo(&documento, 100), Some(3600));
+    }
+
+    #[test]
+    fn test_la_ip_tolera_mas_intentos() {
+        let politica = PoliticaBloqueo::default();
+        let ip = ClaveIntento::Ip("10.0.0.1".to_string());
+
+        assert_eq!(politica.duracion_bloqueo(&ip, 19), None);
+        assert_eq!(politica.duracion_bloqueo(&ip, 20), Some(60));
+        assert_eq!(ip.to_string(), "ip:10.0.0.1");
+    }
+}
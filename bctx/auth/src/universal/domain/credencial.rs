@@ -0,0 +1,48 @@
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use uuid::Uuid;
+
+pub const PASSWORD_LONGITUD_MINIMA: usize = 8;
+const CODIGO_RESET_LONGITUD: usize = 10;
+
+/// Reglas para un password elegido por el usuario
+pub fn validar_password_nuevo(password: &str) -> Result<(), LoginUniversalError> {
+    if password.trim().is_empty() {
+        return Err(LoginUniversalError::PasswordNoValido(
+            "el password no puede estar vacio".to_string(),
+        ));
+    }
+    if password.chars().count() < PASSWORD_LONGITUD_MINIMA {
+        return Err(LoginUniversalError::PasswordNoValido(format!(
+            "el password debe tener al menos {} caracteres",
+            PASSWORD_LONGITUD_MINIMA
+        )));
+    }
+    Ok(())
+}
+
+/// Codigo de un solo uso para restablecer el password, en mayusculas hexadecimales
+pub fn generar_codigo_reset() -> String {
+    Uuid::new_v4().simple().to_string()[..CODIGO_RESET_LONGITUD].to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validar_password_nuevo() {
+        assert!(validar_password_nuevo("clave-segura").is_ok());
+        assert!(matches!(
+            validar_password_nuevo("corta"),
+            Err(LoginUniversalError::PasswordNoValido(_))
+        ));
+        assert!(validar_password_nuevo("        ").is_err());
+    }
+
+    #[test]
+    fn test_codigo_reset() {
+        let codigo = generar_codigo_reset();
+        assert_eq!(codigo.len(), CODIGO_RESET_LONGITUD);
+        assert_ne!(codigo, generar_codigo_reset());
+    }
+}
@@ -19,4 +19,34 @@ pub enum LoginUniversalError {
 
     #[error("Cifrado no valido")]
     CifradoNoValido,
+
+    #[error("Token revocado")]
+    SesionRevocada,
+
+    #[error("Refresh token no valido o expirado")]
+    RefreshTokenNoValido,
+
+    #[error("Refresh token reutilizado, se revocaron las sesiones")]
+    RefreshTokenReutilizado,
+
+    #[error("Demasiados intentos fallidos, intente nuevamente en {segundos} segundos")]
+    LoginBloqueado { segundos: u64 },
+
+    #[error("Password no valido: {0}")]
+    PasswordNoValido(String),
+
+    #[error("Codigo de restablecimiento no valido o expirado")]
+    CodigoResetNoValido,
+
+    #[error("No tiene permiso para restablecer el password de este usuario")]
+    ResetNoPermitido,
+
+    #[error("Codigo de acceso no valido o expirado")]
+    CodigoAccesoNoValido,
+
+    #[error("El token solo permite acceder a la respuesta {0}")]
+    AccesoFueraDeRespuesta(String),
+
+    #[error("La respuesta {0} ya fue finalizada")]
+    RespuestaFinalizada(String),
 }
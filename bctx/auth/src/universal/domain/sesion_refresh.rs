@@ -0,0 +1,127 @@
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
+use uuid::Uuid;
+
+/// Tokens usados que se recuerdan para detectar reutilizaciones. Uno mas
+/// antiguo ya no se reconoce y solo es un token no valido.
+pub const USADOS_RECORDADOS: usize = 10;
+
+/// Refresh token de la sesion de un sujeto. Cada uso lo rota: el token
+/// presentado pasa a `usados` y se emite uno nuevo. El token lleva el id del
+/// sujeto como prefijo (`{sujeto_id}.{aleatorio}`) para ubicar su sesion.
+/// La sesion vence en `expira_en` (segundos unix) sin importar cuantas veces
+/// se rote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SesionRefresh {
+    pub sujeto_id: String,
+    pub rol: String,
+    pub activo: String,
+    pub usados: Vec<String>,
+    pub expira_en: i64,
+}
+
+impl SesionRefresh {
+    pub fn nueva(sujeto_id: String, rol: String, expiration: u64) -> Self {
+        Self {
+            activo: generar_token(&sujeto_id),
+            sujeto_id,
+            rol,
+            usados: Vec::new(),
+            expira_en: ahora_lima().timestamp() + expiration as i64,
+        }
+    }
+
+    /// Segundos que le quedan a la sesion, cero si ya vencio
+    pub fn segundos_restantes(&self) -> u64 {
+        (self.expira_en - ahora_lima().timestamp()).max(0) as u64
+    }
+
+    /// Sujeto al que pertenece un refresh token, si tiene el formato esperado
+    pub fn sujeto_de(token: &str) -> Option<&str> {
+        token
+            .rsplit_once('.')
+            .map(|(sujeto_id, _)| sujeto_id)
+            .filter(|sujeto_id| !sujeto_id.is_empty())
+    }
+
+    /// Cambia el token activo por uno nuevo. Presentar un token ya usado es
+    /// una reutilizacion; cualquier otro token no pertenece a esta sesion.
+    pub fn rotar(&mut self, token: &str) -> Result<(), LoginUniversalError> {
+        if self.segundos_restantes() == 0 {
+            return Err(LoginUniversalError::RefreshTokenNoValido);
+        }
+        if self.usados.iter().any(|usado| usado == token) {
+            return Err(LoginUniversalError::RefreshTokenReutilizado);
+        }
+        if self.activo != token {
+            return Err(LoginUniversalError::RefreshTokenNoValido);
+        }
+
+        let nuevo = generar_token(&self.sujeto_id);
+        self.usados.push(std::mem::replace(&mut self.activo, nuevo));
+        if self.usados.len() > USADOS_RECORDADOS {
+            self.usados.drain(..self.usados.len() - USADOS_RECORDADOS);
+        }
+        Ok(())
+    }
+}
+
+fn generar_token(sujeto_id: &str) -> String {
+    format!("{}.{}", sujeto_id, Uuid::new_v4().simple())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUJETO_ID: &str = "a1b2c3d4-e5f6-7890-abcd-ef1234567890";
+
+    #[test]
+    fn test_rotar_emite_un_token_nuevo_del_mismo_sujeto() {
+        let mut sesion = SesionRefresh::nueva(SUJETO_ID.to_string(), "admin".to_string(), 60);
+        let primero = sesion.activo.clone();
+        assert_eq!(SesionRefresh::sujeto_de(&primero), Some(SUJETO_ID));
+
+        sesion.rotar(&primero).unwrap();
+
+        assert_ne!(sesion.activo, primero);
+        assert_eq!(SesionRefresh::sujeto_de(&sesion.activo), Some(SUJETO_ID));
+        assert_eq!(sesion.usados, vec![primero]);
+    }
+
+    #[test]
+    fn test_rotar_detecta_reutilizacion() {
+        let mut sesion = SesionRefresh::nueva(SUJETO_ID.to_string(), "admin".to_string(), 60);
+        let primero = sesion.activo.clone();
+        sesion.rotar(&primero).unwrap();
+
+        assert!(matches!(
+            sesion.rotar(&primero),
+            Err(LoginUniversalError::RefreshTokenReutilizado)
+        ));
+        assert!(matches!(
+            sesion.rotar(&format!("{}.otro", SUJETO_ID)),
+            Err(LoginUniversalError::RefreshTokenNoValido)
+        ));
+        assert_eq!(SesionRefresh::sujeto_de("sin-formato"), None);
+    }
+
+    #[test]
+    fn test_rotar_no_extiende_la_sesion_ni_acumula_usados() {
+        let mut sesion = SesionRefresh::nueva(SUJETO_ID.to_string(), "admin".to_string(), 60);
+        let expira_en = sesion.expira_en;
+        for _ in 0..USADOS_RECORDADOS + 5 {
+            let token = sesion.activo.clone();
+            sesion.rotar(&token).unwrap();
+        }
+        assert_eq!(sesion.expira_en, expira_en);
+        assert_eq!(sesion.usados.len(), USADOS_RECORDADOS);
+
+        sesion.expira_en = ahora_lima().timestamp() - 1;
+        let token = sesion.activo.clone();
+        assert!(matches!(
+            sesion.rotar(&token),
+            Err(LoginUniversalError::RefreshTokenNoValido)
+        ));
+    }
+}
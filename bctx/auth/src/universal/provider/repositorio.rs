@@ -1,3 +1,5 @@
+use crate::universal::domain::bloqueo_login::ClaveIntento;
+use crate::universal::domain::sesion_refresh::SesionRefresh;
 use crate::universal::domain::usuario_login::UsuarioLogin;
 use async_trait::async_trait;
 use quizz_common::domain::entity::jwt::JwtObject;
@@ -5,14 +7,103 @@ use quizz_common::domain::entity::jwt::JwtObject;
 #[async_trait]
 pub trait RepositorioLoginUniversalLectura<Error>: Send + Sync {
     async fn buscar_por_documento(&self, documento: String) -> Result<UsuarioLogin, Error>;
+    async fn buscar_por_id(&self, sujeto_id: String) -> Result<UsuarioLogin, Error>;
+}
+
+#[async_trait]
+pub trait RepositorioLoginUniversalEscritura<Error>: Send + Sync {
+    /// Reemplaza el password cifrado del usuario en la coleccion de su rol
+    async fn actualizar_password(
+        &self,
+        usuario: &UsuarioLogin,
+        password_cifrado: String,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
 pub trait RepositorioLoginUniversalCacheEscritura<Error>: Send + Sync {
     async fn guardar_token(&self, jwt: JwtObject) -> Result<(), Error>;
+    /// Guarda un token limitado a una respuesta y lo asocia al sujeto, asi
+    /// borrar las sesiones del sujeto tambien lo revoca
+    async fn guardar_sesion_respuesta(
+        &self,
+        sujeto_id: String,
+        jwt: JwtObject,
+    ) -> Result<(), Error>;
+    async fn guardar_refresh(&self, sesion: SesionRefresh) -> Result<(), Error>;
+    /// Guarda la sesion rotada solo si su token activo sigue siendo
+    /// `anterior`, en una sola operacion. Devuelve `false` si otro pedido la
+    /// roto o la borro antes.
+    async fn rotar_refresh(&self, anterior: String, sesion: SesionRefresh) -> Result<bool, Error>;
+}
+
+/// Tokens de la sesion activa del sujeto, si existe
+#[async_trait]
+pub trait RepositorioLoginUniversalCacheLectura<Error>: Send + Sync {
+    async fn obtener_token(&self, sujeto_id: String) -> Result<Option<String>, Error>;
+    async fn obtener_refresh(&self, sujeto_id: String) -> Result<Option<SesionRefresh>, Error>;
 }
 
 #[async_trait]
 pub trait RepositorioLoginUniversalCacheBorrado<Error>: Send + Sync {
+    /// Borrar la sesion de un sujeto elimina su token, su refresh token y los
+    /// tokens limitados a sus respuestas
     async fn borrar_token(&self, sujeto_id: String) -> Result<(), Error>;
 }
+
+/// Contadores de intentos fallidos de login y bloqueos vigentes
+#[async_trait]
+pub trait RepositorioIntentosLogin<Error>: Send + Sync {
+    /// Segundos que le quedan al bloqueo de la clave, si esta bloqueada
+    async fn bloqueo_restante(&self, clave: &ClaveIntento) -> Result<Option<u64>, Error>;
+    /// Suma un fallo y devuelve el total acumulado en la ventana
+    async fn registrar_fallo(&self, clave: &ClaveIntento, ventana: u64) -> Result<u32, Error>;
+    async fn bloquear(&self, clave: &ClaveIntento, segundos: u64) -> Result<(), Error>;
+    /// Olvida los fallos y levanta el bloqueo de la clave
+    async fn reiniciar(&self, clave: &ClaveIntento) -> Result<(), Error>;
+}
+
+/// Codigos de restablecimiento de password pendientes, cifrados y por documento
+#[async_trait]
+pub trait RepositorioCodigoReset<Error>: Send + Sync {
+    async fn guardar_codigo(
+        &self,
+        documento: String,
+        codigo_cifrado: String,
+        expiration: u64,
+    ) -> Result<(), Error>;
+    async fn obtener_codigo(&self, documento: String) -> Result<Option<String>, Error>;
+    /// Borra el codigo solo si sigue siendo `codigo_cifrado`, en una sola
+    /// operacion. Devuelve `false` si otro pedido ya lo consumio o reemplazo
+    async fn consumir_codigo(
+        &self,
+        documento: String,
+        codigo_cifrado: String,
+    ) -> Result<bool, Error>;
+    async fn borrar_codigo(&self, documento: String) -> Result<(), Error>;
+}
+
+/// Codigos de acceso pendientes de un postulante, cada uno ligado a una respuesta
+#[async_trait]
+pub trait RepositorioCodigoAcceso<Error>: Send + Sync {
+    /// Una respuesta tiene a lo sumo un codigo: guardar otro invalida el anterior
+    async fn guardar_codigo(
+        &self,
+        postulante_id: String,
+        codigo: String,
+        respuesta_id: String,
+        expiration: u64,
+    ) -> Result<(), Error>;
+    /// Devuelve la respuesta del codigo y lo elimina en la misma operacion
+    async fn consumir_codigo(
+        &self,
+        postulante_id: String,
+        codigo: String,
+    ) -> Result<Option<String>, Error>;
+}
+
+#[async_trait]
+pub trait RepositorioRespuestaAcceso<Error>: Send + Sync {
+    /// Una respuesta que no existe se trata como finalizada
+    async fn respuesta_finalizada(&self, respuesta_id: String) -> Result<bool, Error>;
+}
@@ -1,2 +1,12 @@
+pub mod cambiar_password;
+pub mod desbloquear_login;
+pub mod emitir_codigo_acceso;
+pub mod emitir_codigo_reset;
 pub mod login;
+pub mod login_codigo_acceso;
 pub mod logout;
+pub mod refrescar_token;
+pub mod restablecer_password;
+pub mod revocar_sesiones;
+pub mod verificar_acceso_respuesta;
+pub mod verificar_sesion;
@@ -0,0 +1,198 @@
+use crate::universal::domain::credencial::validar_password_nuevo;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::{
+    RepositorioLoginUniversalCacheBorrado, RepositorioLoginUniversalEscritura,
+    RepositorioLoginUniversalLectura,
+};
+use async_trait::async_trait;
+use quizz_common::provider::seguridad::{SeguridadCifrar, SeguridadComparar};
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub sujeto_id: String,
+    pub password_actual: String,
+    pub password_nuevo: String,
+}
+
+/// Cambio de password del propio usuario. Exige el password actual y al
+/// terminar revoca sus sesiones, por lo que debe volver a iniciar sesion.
+pub struct CambiarPassword<RepoErr> {
+    crypto_comparar: Box<dyn SeguridadComparar<RepoErr>>,
+    crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+    lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+    escritura: Box<dyn RepositorioLoginUniversalEscritura<RepoErr>>,
+    repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+}
+
+impl<RepoErr> CambiarPassword<RepoErr> {
+    pub fn new(
+        crypto_comparar: Box<dyn SeguridadComparar<RepoErr>>,
+        crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+        lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+        escritura: Box<dyn RepositorioLoginUniversalEscritura<RepoErr>>,
+        repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+    ) -> CambiarPassword<RepoErr> {
+        Self {
+            crypto_comparar,
+            crypto_cifrar,
+            lectura,
+            escritura,
+            repositorio_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for CambiarPassword<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        validar_password_nuevo(&in_.password_nuevo)?;
+
+        let usuario = self.lectura.buscar_por_id(in_.sujeto_id).await?;
+        self.crypto_comparar
+            .comparar(in_.password_actual, usuario.password.clone())
+            .await?;
+
+        let password_cifrado = self.crypto_cifrar.cifrar(in_.password_nuevo).await?;
+        self.escritura
+            .actualizar_password(&usuario, password_cifrado)
+            .await?;
+
+        self.repositorio_cache.borrar_token(usuario.id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::domain::usuario_login::UsuarioLogin;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Estado {
+        password: String,
+        sesiones_borradas: Vec<String>,
+    }
+
+    #[derive(Clone)]
+    struct Mock {
+        estado: Arc<Mutex<Estado>>,
+    }
+
+    #[async_trait]
+    impl SeguridadComparar<LoginUniversalError> for Mock {
+        async fn comparar(
+            &self,
+            password: String,
+            hashed: String,
+        ) -> Result<(), LoginUniversalError> {
+            if format!("cifrado:{}", password) == hashed {
+                Ok(())
+            } else {
+                Err(LoginUniversalError::PasswordIncorrecto)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SeguridadCifrar<LoginUniversalError> for Mock {
+        async fn cifrar(&self, password: String) -> Result<String, LoginUniversalError> {
+            Ok(format!("cifrado:{}", password))
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalLectura<LoginUniversalError> for Mock {
+        async fn buscar_por_documento(
+            &self,
+            _documento: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Err(LoginUniversalError::UsuarioNoEncontrado)
+        }
+
+        async fn buscar_por_id(
+            &self,
+            sujeto_id: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Ok(UsuarioLogin {
+                id: sujeto_id,
+                password: self.estado.lock().unwrap().password.clone(),
+                rol: "postulante".to_string(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalEscritura<LoginUniversalError> for Mock {
+        async fn actualizar_password(
+            &self,
+            _usuario: &UsuarioLogin,
+            password_cifrado: String,
+        ) -> Result<(), LoginUniversalError> {
+            self.estado.lock().unwrap().password = password_cifrado;
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheBorrado<LoginUniversalError> for Mock {
+        async fn borrar_token(&self, sujeto_id: String) -> Result<(), LoginUniversalError> {
+            self.estado
+                .lock()
+                .unwrap()
+                .sesiones_borradas
+                .push(sujeto_id);
+            Ok(())
+        }
+    }
+
+    fn caso() -> (CambiarPassword<LoginUniversalError>, Mock) {
+        let mock = Mock {
+            estado: Arc::new(Mutex::new(Estado {
+                password: "cifrado:5678".to_string(),
+                ..Default::default()
+            })),
+        };
+        let use_case = CambiarPassword::new(
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+        );
+        (use_case, mock)
+    }
+
+    fn entrada(actual: &str) -> InputData {
+        InputData {
+            sujeto_id: "usr-123".to_string(),
+            password_actual: actual.to_string(),
+            password_nuevo: "clave-segura".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cambia_el_password_y_revoca_las_sesiones() {
+        let (use_case, mock) = caso();
+
+        use_case.ejecutar(entrada("5678")).await.unwrap();
+
+        let estado = mock.estado.lock().unwrap();
+        assert_eq!(estado.password, "cifrado:clave-segura");
+        assert_eq!(estado.sesiones_borradas, vec!["usr-123".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_exige_el_password_actual() {
+        let (use_case, mock) = caso();
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("0000")).await,
+            Err(LoginUniversalError::PasswordIncorrecto)
+        ));
+        assert_eq!(mock.estado.lock().unwrap().password, "cifrado:5678");
+    }
+}
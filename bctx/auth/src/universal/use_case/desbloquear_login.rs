@@ -0,0 +1,32 @@
+use crate::universal::domain::bloqueo_login::ClaveIntento;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::RepositorioIntentosLogin;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub clave: ClaveIntento,
+}
+
+/// Levanta el bloqueo de login de una cuenta o de una IP y olvida sus intentos
+/// fallidos
+pub struct DesbloquearLogin<RepoErr> {
+    intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+}
+
+impl<RepoErr> DesbloquearLogin<RepoErr> {
+    pub fn new(intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>) -> DesbloquearLogin<RepoErr> {
+        Self { intentos }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for DesbloquearLogin<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        self.intentos.reiniciar(&in_.clave).await?;
+        Ok(())
+    }
+}
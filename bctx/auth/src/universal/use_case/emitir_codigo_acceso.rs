@@ -0,0 +1,57 @@
+use crate::universal::domain::acceso_respuesta::generar_codigo_acceso;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::RepositorioCodigoAcceso;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub postulante_id: String,
+    pub respuesta_id: String,
+}
+
+pub struct OutputData {
+    pub codigo: String,
+    pub expiration: u64,
+}
+
+/// Emite el codigo de un solo uso con el que el postulante entra a una
+/// respuesta recien asignada, sin usar su password.
+pub struct EmitirCodigoAcceso<RepoErr> {
+    codigos: Box<dyn RepositorioCodigoAcceso<RepoErr>>,
+    expiration: u64,
+}
+
+impl<RepoErr> EmitirCodigoAcceso<RepoErr> {
+    pub fn new(
+        codigos: Box<dyn RepositorioCodigoAcceso<RepoErr>>,
+        expiration: u64,
+    ) -> EmitirCodigoAcceso<RepoErr> {
+        Self {
+            codigos,
+            expiration,
+        }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, OutputData, LoginUniversalError> for EmitirCodigoAcceso<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<OutputData, LoginUniversalError> {
+        let codigo = generar_codigo_acceso();
+        self.codigos
+            .guardar_codigo(
+                in_.postulante_id,
+                codigo.clone(),
+                in_.respuesta_id,
+                self.expiration,
+            )
+            .await?;
+
+        Ok(OutputData {
+            codigo,
+            expiration: self.expiration,
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use crate::autorizacion::domain::value_object::rol::Rol;
+use crate::universal::domain::credencial::generar_codigo_reset;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::{
+    RepositorioCodigoReset, RepositorioLoginUniversalLectura,
+};
+use async_trait::async_trait;
+use quizz_common::provider::seguridad::SeguridadCifrar;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub documento: String,
+    pub rol_solicitante: String,
+}
+
+pub struct OutputData {
+    pub codigo: String,
+    pub expiration: u64,
+}
+
+/// Emite un codigo de un solo uso para que un usuario restablezca su
+/// password. Un admin puede emitirlo para cualquier usuario; un psicologo
+/// solo para postulantes. Emitir otro reemplaza al anterior.
+pub struct EmitirCodigoReset<RepoErr> {
+    crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+    lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+    codigos: Box<dyn RepositorioCodigoReset<RepoErr>>,
+    expiration: u64,
+}
+
+impl<RepoErr> EmitirCodigoReset<RepoErr> {
+    pub fn new(
+        crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+        lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+        codigos: Box<dyn RepositorioCodigoReset<RepoErr>>,
+        expiration: u64,
+    ) -> EmitirCodigoReset<RepoErr> {
+        Self {
+            crypto_cifrar,
+            lectura,
+            codigos,
+            expiration,
+        }
+    }
+}
+
+fn puede_restablecer(solicitante: &str, usuario: &str) -> bool {
+    matches!(
+        (solicitante.parse::<Rol>(), usuario.parse::<Rol>()),
+        (Ok(Rol::Admin), Ok(_)) | (Ok(Rol::Psicologo), Ok(Rol::Postulante))
+    )
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, OutputData, LoginUniversalError> for EmitirCodigoReset<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<OutputData, LoginUniversalError> {
+        let usuario = self
+            .lectura
+            .buscar_por_documento(in_.documento.clone())
+            .await?;
+        if !puede_restablecer(&in_.rol_solicitante, &usuario.rol) {
+            return Err(LoginUniversalError::ResetNoPermitido);
+        }
+
+        let codigo = generar_codigo_reset();
+        let codigo_cifrado = self.crypto_cifrar.cifrar(codigo.clone()).await?;
+        self.codigos
+            .guardar_codigo(in_.documento, codigo_cifrado, self.expiration)
+            .await?;
+
+        Ok(OutputData {
+            codigo,
+            expiration: self.expiration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_psicologo_solo_restablece_postulantes() {
+        assert!(puede_restablecer("admin", "psicologo"));
+        assert!(puede_restablecer("admin", "admin"));
+        assert!(puede_restablecer("psicologo", "postulante"));
+        assert!(!puede_restablecer("psicologo", "admin"));
+        assert!(!puede_restablecer("psicologo", "psicologo"));
+        assert!(!puede_restablecer("postulante", "postulante"));
+    }
+}
@@ -1,6 +1,10 @@
+use crate::universal::domain::bloqueo_login::{ClaveIntento, PoliticaBloqueo};
 use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::domain::sesion_refresh::SesionRefresh;
+use crate::universal::domain::usuario_login::UsuarioLogin;
 use crate::universal::provider::repositorio::{
-    RepositorioLoginUniversalCacheEscritura, RepositorioLoginUniversalLectura,
+    RepositorioIntentosLogin, RepositorioLoginUniversalCacheEscritura,
+    RepositorioLoginUniversalLectura,
 };
 use async_trait::async_trait;
 use quizz_common::provider::jwt::JwtProviderGenerateConRol;
@@ -10,12 +14,15 @@ use quizz_common::use_case::CasoDeUso;
 pub struct InputData {
     pub documento: String,
     pub password: String,
+    pub ip: Option<String>,
 }
 
 pub struct OutputData {
     pub jwt_value: String,
     pub expiration: u64,
     pub rol: String,
+    pub refresh_token: String,
+    pub refresh_expiration: u64,
 }
 
 pub struct LoginUniversal<RepoErr> {
@@ -23,6 +30,9 @@ pub struct LoginUniversal<RepoErr> {
     repositorio: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
     repositorio_cache: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
     jwt: Box<dyn JwtProviderGenerateConRol<RepoErr>>,
+    refresh_expiration: u64,
+    intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+    politica: PoliticaBloqueo,
 }
 
 impl<RepoErr> LoginUniversal<RepoErr> {
@@ -31,41 +41,337 @@ impl<RepoErr> LoginUniversal<RepoErr> {
         repositorio: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
         repositorio_cache: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
         jwt: Box<dyn JwtProviderGenerateConRol<RepoErr>>,
+        refresh_expiration: u64,
+        intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+        politica: PoliticaBloqueo,
     ) -> LoginUniversal<RepoErr> {
         Self {
             crypto_comparar,
             repositorio,
             repositorio_cache,
             jwt,
+            refresh_expiration,
+            intentos,
+            politica,
         }
     }
 }
 
+impl<RepoErr> LoginUniversal<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn verificar_credenciales(
+        &self,
+        documento: String,
+        password: String,
+    ) -> Result<UsuarioLogin, LoginUniversalError> {
+        let usuario = self.repositorio.buscar_por_documento(documento).await?;
+        self.crypto_comparar
+            .comparar(password, usuario.password.clone())
+            .await?;
+        Ok(usuario)
+    }
+}
+
+/// Claves de throttling de un intento: el documento y, si se conoce, la ip
+pub(crate) fn claves_intento(documento: &str, ip: Option<String>) -> Vec<ClaveIntento> {
+    let mut claves = vec![ClaveIntento::Documento(documento.to_string())];
+    claves.extend(ip.map(ClaveIntento::Ip));
+    claves
+}
+
+/// Rechaza el intento con el bloqueo mas largo vigente entre las claves
+pub(crate) async fn verificar_bloqueo<RepoErr>(
+    intentos: &dyn RepositorioIntentosLogin<RepoErr>,
+    claves: &[ClaveIntento],
+) -> Result<(), LoginUniversalError>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    let mut bloqueo = None;
+    for clave in claves {
+        bloqueo = bloqueo.max(intentos.bloqueo_restante(clave).await?);
+    }
+    match bloqueo {
+        Some(segundos) => Err(LoginUniversalError::LoginBloqueado { segundos }),
+        None => Ok(()),
+    }
+}
+
+/// Cuenta el fallo en cada clave y bloquea las que llegan al limite
+pub(crate) async fn registrar_fallo<RepoErr>(
+    intentos: &dyn RepositorioIntentosLogin<RepoErr>,
+    politica: &PoliticaBloqueo,
+    claves: &[ClaveIntento],
+) -> Result<(), LoginUniversalError>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    for clave in claves {
+        let fallos = intentos
+            .registrar_fallo(clave, politica.ventana_segundos)
+            .await?;
+        if let Some(segundos) = politica.duracion_bloqueo(clave, fallos) {
+            intentos.bloquear(clave, segundos).await?;
+        }
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl<RepoErr> CasoDeUso<InputData, OutputData, LoginUniversalError> for LoginUniversal<RepoErr>
 where
     LoginUniversalError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<OutputData, LoginUniversalError> {
-        let usuario = self.repositorio.buscar_por_documento(in_.documento).await?;
+        let claves = claves_intento(&in_.documento, in_.ip);
+        verificar_bloqueo(self.intentos.as_ref(), &claves).await?;
 
-        self.crypto_comparar
-            .comparar(in_.password, usuario.password)
-            .await?;
+        let usuario = match self
+            .verificar_credenciales(in_.documento, in_.password)
+            .await
+        {
+            Ok(usuario) => usuario,
+            Err(
+                e @ (LoginUniversalError::UsuarioNoEncontrado
+                | LoginUniversalError::PasswordIncorrecto),
+            ) => {
+                registrar_fallo(self.intentos.as_ref(), &self.politica, &claves).await?;
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        // La ip no se reinicia: un login valido no debe cubrir intentos sobre otras cuentas
+        self.intentos.reiniciar(&claves[0]).await?;
 
         let jwt_object = self
             .jwt
-            .generar_con_rol(usuario.id, usuario.rol.clone())
+            .generar_con_rol(usuario.id.clone(), usuario.rol.clone())
             .await?;
 
         self.repositorio_cache
             .guardar_token(jwt_object.clone())
             .await?;
 
+        // Un login nuevo reemplaza la sesion anterior junto con su refresh token
+        let sesion = SesionRefresh::nueva(usuario.id, usuario.rol.clone(), self.refresh_expiration);
+        self.repositorio_cache
+            .guardar_refresh(sesion.clone())
+            .await?;
+
+        let refresh_expiration = sesion.segundos_restantes();
         Ok(OutputData {
             jwt_value: jwt_object.value,
             expiration: jwt_object.expiration,
             rol: usuario.rol,
+            refresh_token: sesion.activo,
+            refresh_expiration,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quizz_common::domain::entity::jwt::JwtObject;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    struct MockUsuarios;
+
+    #[async_trait]
+    impl RepositorioLoginUniversalLectura<LoginUniversalError> for MockUsuarios {
+        async fn buscar_por_documento(
+            &self,
+            _documento: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Ok(UsuarioLogin {
+                id: "usr-123".to_string(),
+                password: "secreto".to_string(),
+                rol: "psicologo".to_string(),
+            })
+        }
+
+        async fn buscar_por_id(
+            &self,
+            sujeto_id: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            self.buscar_por_documento(sujeto_id).await
+        }
+    }
+
+    struct MockCifrado;
+
+    #[async_trait]
+    impl SeguridadComparar<LoginUniversalError> for MockCifrado {
+        async fn comparar(
+            &self,
+            password: String,
+            hashed: String,
+        ) -> Result<(), LoginUniversalError> {
+            if password == hashed {
+                Ok(())
+            } else {
+                Err(LoginUniversalError::PasswordIncorrecto)
+            }
+        }
+    }
+
+    struct MockCache;
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for MockCache {
+        async fn guardar_token(&self, _jwt: JwtObject) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn guardar_sesion_respuesta(
+            &self,
+            _sujeto_id: String,
+            _jwt: JwtObject,
+        ) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn guardar_refresh(&self, _sesion: SesionRefresh) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn rotar_refresh(
+            &self,
+            _anterior: String,
+            _sesion: SesionRefresh,
+        ) -> Result<bool, LoginUniversalError> {
+            Ok(true)
+        }
+    }
+
+    struct MockJwt;
+
+    #[async_trait]
+    impl JwtProviderGenerateConRol<LoginUniversalError> for MockJwt {
+        async fn generar_con_rol(
+            &self,
+            sujeto_id: String,
+            rol: String,
+        ) -> Result<JwtObject, LoginUniversalError> {
+            Ok(JwtObject {
+                key: sujeto_id,
+                value: "jwt".to_string(),
+                expiration: 900,
+                rol: Some(rol),
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct Intentos {
+        fallos: HashMap<String, u32>,
+        bloqueos: HashMap<String, u64>,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockIntentos {
+        intentos: Arc<Mutex<Intentos>>,
+    }
+
+    #[async_trait]
+    impl RepositorioIntentosLogin<LoginUniversalError> for MockIntentos {
+        async fn bloqueo_restante(
+            &self,
+            clave: &ClaveIntento,
+        ) -> Result<Option<u64>, LoginUniversalError> {
+            Ok(self
+                .intentos
+                .lock()
+                .unwrap()
+                .bloqueos
+                .get(&clave.to_string())
+                .copied())
+        }
+
+        async fn registrar_fallo(
+            &self,
+            clave: &ClaveIntento,
+            _ventana: u64,
+        ) -> Result<u32, LoginUniversalError> {
+            let mut intentos = self.intentos.lock().unwrap();
+            let fallos = intentos.fallos.entry(clave.to_string()).or_default();
+            *fallos += 1;
+            Ok(*fallos)
+        }
+
+        async fn bloquear(
+            &self,
+            clave: &ClaveIntento,
+            segundos: u64,
+        ) -> Result<(), LoginUniversalError> {
+            self.intentos
+                .lock()
+                .unwrap()
+                .bloqueos
+                .insert(clave.to_string(), segundos);
+            Ok(())
+        }
+
+        async fn reiniciar(&self, clave: &ClaveIntento) -> Result<(), LoginUniversalError> {
+            let mut intentos = self.intentos.lock().unwrap();
+            intentos.fallos.remove(&clave.to_string());
+            intentos.bloqueos.remove(&clave.to_string());
+            Ok(())
+        }
+    }
+
+    fn caso(intentos: MockIntentos) -> LoginUniversal<LoginUniversalError> {
+        LoginUniversal::new(
+            Box::new(MockCifrado),
+            Box::new(MockUsuarios),
+            Box::new(MockCache),
+            Box::new(MockJwt),
+            3600,
+            Box::new(intentos),
+            PoliticaBloqueo {
+                max_intentos_documento: 2,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn entrada(password: &str) -> InputData {
+        InputData {
+            documento: "12345678".to_string(),
+            password: password.to_string(),
+            ip: Some("10.0.0.1".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bloquea_el_documento_tras_los_fallos_permitidos() {
+        let intentos = MockIntentos::default();
+        let use_case = caso(intentos.clone());
+
+        for _ in 0..2 {
+            assert!(matches!(
+                use_case.ejecutar(entrada("otro")).await,
+                Err(LoginUniversalError::PasswordIncorrecto)
+            ));
+        }
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("secreto")).await,
+            Err(LoginUniversalError::LoginBloqueado { segundos: 60 })
+        ));
+
+        intentos
+            .reiniciar(&ClaveIntento::Documento("12345678".to_string()))
+            .await
+            .unwrap();
+        assert!(use_case.ejecutar(entrada("secreto")).await.is_ok());
+        assert_eq!(
+            intentos.intentos.lock().unwrap().fallos.get("ip:10.0.0.1"),
+            Some(&2)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use crate::autorizacion::domain::value_object::rol::Rol;
+use crate::universal::domain::acceso_respuesta::normalizar_codigo_acceso;
+use crate::universal::domain::bloqueo_login::PoliticaBloqueo;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::domain::usuario_login::UsuarioLogin;
+use crate::universal::provider::repositorio::{
+    RepositorioCodigoAcceso, RepositorioIntentosLogin, RepositorioLoginUniversalCacheEscritura,
+    RepositorioLoginUniversalLectura,
+};
+use crate::universal::use_case::login::{claves_intento, registrar_fallo, verificar_bloqueo};
+use async_trait::async_trait;
+use quizz_common::provider::jwt::JwtProviderGenerateRespuesta;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub documento: String,
+    pub codigo: String,
+    pub ip: Option<String>,
+}
+
+pub struct OutputData {
+    pub jwt_value: String,
+    pub expiration: u64,
+    pub respuesta_id: String,
+}
+
+/// Login de un postulante con un codigo de acceso. El codigo se consume al
+/// usarlo y el token emitido solo sirve para la respuesta del codigo, sin
+/// refresh token. Los fallos cuentan para el mismo bloqueo que el login con
+/// password.
+pub struct LoginCodigoAcceso<RepoErr> {
+    repositorio: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+    codigos: Box<dyn RepositorioCodigoAcceso<RepoErr>>,
+    repositorio_cache: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
+    jwt: Box<dyn JwtProviderGenerateRespuesta<RepoErr>>,
+    intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+    politica: PoliticaBloqueo,
+}
+
+impl<RepoErr> LoginCodigoAcceso<RepoErr> {
+    pub fn new(
+        repositorio: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+        codigos: Box<dyn RepositorioCodigoAcceso<RepoErr>>,
+        repositorio_cache: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
+        jwt: Box<dyn JwtProviderGenerateRespuesta<RepoErr>>,
+        intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+        politica: PoliticaBloqueo,
+    ) -> LoginCodigoAcceso<RepoErr> {
+        Self {
+            repositorio,
+            codigos,
+            repositorio_cache,
+            jwt,
+            intentos,
+            politica,
+        }
+    }
+}
+
+impl<RepoErr> LoginCodigoAcceso<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn consumir_codigo(
+        &self,
+        documento: String,
+        codigo: String,
+    ) -> Result<(UsuarioLogin, String), LoginUniversalError> {
+        let usuario = match self
+            .repositorio
+            .buscar_por_documento(documento)
+            .await
+            .map_err(LoginUniversalError::from)
+        {
+            Ok(usuario) if matches!(usuario.rol.parse::<Rol>(), Ok(Rol::Postulante)) => usuario,
+            Ok(_) | Err(LoginUniversalError::UsuarioNoEncontrado) => {
+                return Err(LoginUniversalError::CodigoAccesoNoValido);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let respuesta_id = self
+            .codigos
+            .consumir_codigo(usuario.id.clone(), normalizar_codigo_acceso(&codigo))
+            .await?
+            .ok_or(LoginUniversalError::CodigoAccesoNoValido)?;
+        Ok((usuario, respuesta_id))
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, OutputData, LoginUniversalError> for LoginCodigoAcceso<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<OutputData, LoginUniversalError> {
+        let claves = claves_intento(&in_.documento, in_.ip);
+        verificar_bloqueo(self.intentos.as_ref(), &claves).await?;
+
+        let (usuario, respuesta_id) = match self.consumir_codigo(in_.documento, in_.codigo).await {
+            Ok(acceso) => acceso,
+            Err(e @ LoginUniversalError::CodigoAccesoNoValido) => {
+                registrar_fallo(self.intentos.as_ref(), &self.politica, &claves).await?;
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.intentos.reiniciar(&claves[0]).await?;
+
+        let jwt_object = self
+            .jwt
+            .generar_para_respuesta(usuario.id.clone(), respuesta_id.clone())
+            .await?;
+        self.repositorio_cache
+            .guardar_sesion_respuesta(usuario.id, jwt_object.clone())
+            .await?;
+
+        Ok(OutputData {
+            jwt_value: jwt_object.value,
+            expiration: jwt_object.expiration,
+            respuesta_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::domain::bloqueo_login::ClaveIntento;
+    use crate::universal::domain::sesion_refresh::SesionRefresh;
+    use quizz_common::domain::entity::jwt::JwtObject;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const POSTULANTE_ID: &str = "usr-123";
+    const RESPUESTA_ID: &str = "3f2b8c1a-9d4e-4a7b-b6c5-2e1f0a9d8c7b";
+
+    struct MockUsuarios {
+        rol: &'static str,
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalLectura<LoginUniversalError> for MockUsuarios {
+        async fn buscar_por_documento(
+            &self,
+            _documento: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Ok(UsuarioLogin {
+                id: POSTULANTE_ID.to_string(),
+                password: "secreto".to_string(),
+                rol: self.rol.to_string(),
+            })
+        }
+
+        async fn buscar_por_id(
+            &self,
+            sujeto_id: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            self.buscar_por_documento(sujeto_id).await
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockCodigos {
+        codigos: Arc<Mutex<HashMap<(String, String), String>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioCodigoAcceso<LoginUniversalError> for MockCodigos {
+        async fn guardar_codigo(
+            &self,
+            postulante_id: String,
+            codigo: String,
+            respuesta_id: String,
+            _expiration: u64,
+        ) -> Result<(), LoginUniversalError> {
+            self.codigos
+                .lock()
+                .unwrap()
+                .insert((postulante_id, codigo), respuesta_id);
+            Ok(())
+        }
+
+        async fn consumir_codigo(
+            &self,
+            postulante_id: String,
+            codigo: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            Ok(self
+                .codigos
+                .lock()
+                .unwrap()
+                .remove(&(postulante_id, codigo)))
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockCache {
+        tokens: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for MockCache {
+        async fn guardar_token(&self, _jwt: JwtObject) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn guardar_sesion_respuesta(
+            &self,
+            _sujeto_id: String,
+            jwt: JwtObject,
+        ) -> Result<(), LoginUniversalError> {
+            self.tokens.lock().unwrap().push(jwt.key);
+            Ok(())
+        }
+
+        async fn guardar_refresh(&self, _sesion: SesionRefresh) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn rotar_refresh(
+            &self,
+            _anterior: String,
+            _sesion: SesionRefresh,
+        ) -> Result<bool, LoginUniversalError> {
+            Ok(true)
+        }
+    }
+
+    struct MockJwt;
+
+    #[async_trait]
+    impl JwtProviderGenerateRespuesta<LoginUniversalError> for MockJwt {
+        async fn generar_para_respuesta(
+            &self,
+            _postulante_id: String,
+            respuesta_id: String,
+        ) -> Result<JwtObject, LoginUniversalError> {
+            Ok(JwtObject {
+                key: format!("respuesta:{}", respuesta_id),
+                value: "jwt".to_string(),
+                expiration: 900,
+                rol: Some("postulante".to_string()),
+            })
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockIntentos {
+        fallos: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl RepositorioIntentosLogin<LoginUniversalError> for MockIntentos {
+        async fn bloqueo_restante(
+            &self,
+            _clave: &ClaveIntento,
+        ) -> Result<Option<u64>, LoginUniversalError> {
+            Ok(None)
+        }
+
+        async fn registrar_fallo(
+            &self,
+            _clave: &ClaveIntento,
+            _ventana: u64,
+        ) -> Result<u32, LoginUniversalError> {
+            let mut fallos = self.fallos.lock().unwrap();
+            *fallos += 1;
+            Ok(*fallos)
+        }
+
+        async fn bloquear(
+            &self,
+            _clave: &ClaveIntento,
+            _segundos: u64,
+        ) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn reiniciar(&self, _clave: &ClaveIntento) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+    }
+
+    fn caso(
+        rol: &'static str,
+        codigos: MockCodigos,
+        cache: MockCache,
+        intentos: MockIntentos,
+    ) -> LoginCodigoAcceso<LoginUniversalError> {
+        LoginCodigoAcceso::new(
+            Box::new(MockUsuarios { rol }),
+            Box::new(codigos),
+            Box::new(cache),
+            Box::new(MockJwt),
+            Box::new(intentos),
+            PoliticaBloqueo::default(),
+        )
+    }
+
+    fn entrada(codigo: &str) -> InputData {
+        InputData {
+            documento: "12345678".to_string(),
+            codigo: codigo.to_string(),
+            ip: None,
+        }
+    }
+
+    async fn codigos_con(codigo: &str) -> MockCodigos {
+        let codigos = MockCodigos::default();
+        codigos
+            .guardar_codigo(
+                POSTULANTE_ID.to_string(),
+                codigo.to_string(),
+                RESPUESTA_ID.to_string(),
+                900,
+            )
+            .await
+            .unwrap();
+        codigos
+    }
+
+    #[tokio::test]
+    async fn test_el_codigo_entra_a_su_respuesta_una_sola_vez() {
+        let cache = MockCache::default();
+        let intentos = MockIntentos::default();
+        let use_case = caso(
+            "postulante",
+            codigos_con("K7M2P9QX").await,
+            cache.clone(),
+            intentos.clone(),
+        );
+
+        let salida = use_case.ejecutar(entrada(" k7m2p9qx")).await.unwrap();
+        assert_eq!(salida.respuesta_id, RESPUESTA_ID);
+        assert_eq!(
+            *cache.tokens.lock().unwrap(),
+            vec![format!("respuesta:{}", RESPUESTA_ID)]
+        );
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("K7M2P9QX")).await,
+            Err(LoginUniversalError::CodigoAccesoNoValido)
+        ));
+        assert_eq!(*intentos.fallos.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_solo_los_postulantes_entran_con_codigo() {
+        let use_case = caso(
+            "psicologo",
+            codigos_con("K7M2P9QX").await,
+            MockCache::default(),
+            MockIntentos::default(),
+        );
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("K7M2P9QX")).await,
+            Err(LoginUniversalError::CodigoAccesoNoValido)
+        ));
+    }
+}
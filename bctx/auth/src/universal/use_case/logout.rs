@@ -1,3 +1,4 @@
+use crate::universal::domain::acceso_respuesta::clave_sesion;
 use crate::universal::domain::error::login_universal::LoginUniversalError;
 use crate::universal::provider::repositorio::RepositorioLoginUniversalCacheBorrado;
 use async_trait::async_trait;
@@ -5,6 +6,7 @@ use quizz_common::use_case::CasoDeUso;
 
 pub struct InputData {
     pub sujeto_id: String,
+    pub respuesta_id: Option<String>,
 }
 
 pub struct Logout<RepoErr> {
@@ -25,7 +27,8 @@ where
     LoginUniversalError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
-        self.repositorio_cache.borrar_token(in_.sujeto_id).await?;
+        let clave = clave_sesion(&in_.sujeto_id, in_.respuesta_id.as_deref());
+        self.repositorio_cache.borrar_token(clave).await?;
         Ok(())
     }
 }
@@ -57,6 +60,7 @@ mod tests {
         let resultado = use_case
             .ejecutar(InputData {
                 sujeto_id: "usr-123".to_string(),
+                respuesta_id: None,
             })
             .await;
 
@@ -0,0 +1,298 @@
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::domain::sesion_refresh::SesionRefresh;
+use crate::universal::provider::repositorio::{
+    RepositorioLoginUniversalCacheBorrado, RepositorioLoginUniversalCacheEscritura,
+    RepositorioLoginUniversalCacheLectura,
+};
+use crate::universal::use_case::login::OutputData;
+use async_trait::async_trait;
+use quizz_common::provider::jwt::JwtProviderGenerateConRol;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub refresh_token: String,
+}
+
+/// Emite un token de acceso nuevo a cambio del refresh token y lo rota. Si se
+/// presenta un refresh token ya usado, se revocan todas las sesiones del
+/// sujeto: alguien mas pudo haberlo copiado. Dos pedidos simultaneos con el
+/// mismo token cuentan como reutilizacion: solo uno logra rotar la sesion.
+pub struct RefrescarToken<RepoErr> {
+    lectura: Box<dyn RepositorioLoginUniversalCacheLectura<RepoErr>>,
+    escritura: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
+    borrado: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+    jwt: Box<dyn JwtProviderGenerateConRol<RepoErr>>,
+}
+
+impl<RepoErr> RefrescarToken<RepoErr> {
+    pub fn new(
+        lectura: Box<dyn RepositorioLoginUniversalCacheLectura<RepoErr>>,
+        escritura: Box<dyn RepositorioLoginUniversalCacheEscritura<RepoErr>>,
+        borrado: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+        jwt: Box<dyn JwtProviderGenerateConRol<RepoErr>>,
+    ) -> RefrescarToken<RepoErr> {
+        Self {
+            lectura,
+            escritura,
+            borrado,
+            jwt,
+        }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, OutputData, LoginUniversalError> for RefrescarToken<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<OutputData, LoginUniversalError> {
+        let sujeto_id = SesionRefresh::sujeto_de(&in_.refresh_token)
+            .ok_or(LoginUniversalError::RefreshTokenNoValido)?
+            .to_string();
+
+        let mut sesion = self
+            .lectura
+            .obtener_refresh(sujeto_id.clone())
+            .await?
+            .ok_or(LoginUniversalError::RefreshTokenNoValido)?;
+
+        let rotada = match sesion.rotar(&in_.refresh_token) {
+            Ok(()) => {
+                self.escritura
+                    .rotar_refresh(in_.refresh_token, sesion.clone())
+                    .await?
+            }
+            Err(LoginUniversalError::RefreshTokenReutilizado) => false,
+            Err(e) => return Err(e),
+        };
+        if !rotada {
+            self.borrado.borrar_token(sujeto_id).await?;
+            return Err(LoginUniversalError::RefreshTokenReutilizado);
+        }
+
+        let jwt_object = self
+            .jwt
+            .generar_con_rol(sujeto_id, sesion.rol.clone())
+            .await?;
+        self.escritura.guardar_token(jwt_object.clone()).await?;
+
+        let refresh_expiration = sesion.segundos_restantes();
+        Ok(OutputData {
+            jwt_value: jwt_object.value,
+            expiration: jwt_object.expiration,
+            rol: sesion.rol,
+            refresh_token: sesion.activo,
+            refresh_expiration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quizz_common::domain::entity::jwt::JwtObject;
+    use std::sync::{Arc, Mutex};
+
+    const SUJETO_ID: &str = "a1b2c3d4-e5f6-7890-abcd-ef1234567890";
+
+    #[derive(Default)]
+    struct Cache {
+        token: Option<String>,
+        refresh: Option<SesionRefresh>,
+    }
+
+    #[derive(Clone)]
+    struct MockCache {
+        cache: Arc<Mutex<Cache>>,
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheLectura<LoginUniversalError> for MockCache {
+        async fn obtener_token(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            Ok(self.cache.lock().unwrap().token.clone())
+        }
+
+        async fn obtener_refresh(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<SesionRefresh>, LoginUniversalError> {
+            Ok(self.cache.lock().unwrap().refresh.clone())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for MockCache {
+        async fn guardar_token(&self, jwt: JwtObject) -> Result<(), LoginUniversalError> {
+            self.cache.lock().unwrap().token = Some(jwt.value);
+            Ok(())
+        }
+
+        async fn guardar_sesion_respuesta(
+            &self,
+            _sujeto_id: String,
+            _jwt: JwtObject,
+        ) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn guardar_refresh(&self, sesion: SesionRefresh) -> Result<(), LoginUniversalError> {
+            self.cache.lock().unwrap().refresh = Some(sesion);
+            Ok(())
+        }
+
+        async fn rotar_refresh(
+            &self,
+            anterior: String,
+            sesion: SesionRefresh,
+        ) -> Result<bool, LoginUniversalError> {
+            let mut cache = self.cache.lock().unwrap();
+            match &cache.refresh {
+                Some(actual) if actual.activo == anterior => {
+                    cache.refresh = Some(sesion);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheBorrado<LoginUniversalError> for MockCache {
+        async fn borrar_token(&self, _sujeto_id: String) -> Result<(), LoginUniversalError> {
+            let mut cache = self.cache.lock().unwrap();
+            cache.token = None;
+            cache.refresh = None;
+            Ok(())
+        }
+    }
+
+    struct MockJwt;
+
+    #[async_trait]
+    impl JwtProviderGenerateConRol<LoginUniversalError> for MockJwt {
+        async fn generar_con_rol(
+            &self,
+            sujeto_id: String,
+            rol: String,
+        ) -> Result<JwtObject, LoginUniversalError> {
+            Ok(JwtObject {
+                key: sujeto_id,
+                value: "jwt-nuevo".to_string(),
+                expiration: 900,
+                rol: Some(rol),
+            })
+        }
+    }
+
+    fn caso() -> (RefrescarToken<LoginUniversalError>, MockCache, String) {
+        let sesion = SesionRefresh::nueva(SUJETO_ID.to_string(), "psicologo".to_string(), 3600);
+        let token = sesion.activo.clone();
+        let cache = MockCache {
+            cache: Arc::new(Mutex::new(Cache {
+                token: Some("jwt-viejo".to_string()),
+                refresh: Some(sesion),
+            })),
+        };
+        let use_case = RefrescarToken::new(
+            Box::new(cache.clone()),
+            Box::new(cache.clone()),
+            Box::new(cache.clone()),
+            Box::new(MockJwt),
+        );
+        (use_case, cache, token)
+    }
+
+    #[tokio::test]
+    async fn test_refrescar_rota_el_refresh_token() {
+        let (use_case, cache, token) = caso();
+
+        let salida = use_case
+            .ejecutar(InputData {
+                refresh_token: token.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(salida.jwt_value, "jwt-nuevo");
+        assert_eq!(salida.rol, "psicologo");
+        assert_ne!(salida.refresh_token, token);
+        let cache = cache.cache.lock().unwrap();
+        assert_eq!(cache.token.as_deref(), Some("jwt-nuevo"));
+        assert_eq!(cache.refresh.as_ref().unwrap().activo, salida.refresh_token);
+    }
+
+    #[tokio::test]
+    async fn test_reutilizar_un_refresh_token_revoca_las_sesiones() {
+        let (use_case, cache, token) = caso();
+        use_case
+            .ejecutar(InputData {
+                refresh_token: token.clone(),
+            })
+            .await
+            .unwrap();
+
+        let resultado = use_case
+            .ejecutar(InputData {
+                refresh_token: token,
+            })
+            .await;
+
+        assert!(matches!(
+            resultado,
+            Err(LoginUniversalError::RefreshTokenReutilizado)
+        ));
+        let cache = cache.cache.lock().unwrap();
+        assert!(cache.token.is_none());
+        assert!(cache.refresh.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rotacion_concurrente_cuenta_como_reutilizacion() {
+        let (_, cache, token) = caso();
+        // Otro pedido con el mismo token roto la sesion despues de esta lectura
+        let leida = cache.cache.lock().unwrap().refresh.clone();
+        let mut rotada = leida.clone().unwrap();
+        rotada.rotar(&token).unwrap();
+        cache.cache.lock().unwrap().refresh = Some(rotada);
+
+        let concurrente = RefrescarToken::new(
+            Box::new(SesionFija(leida)),
+            Box::new(cache.clone()),
+            Box::new(cache.clone()),
+            Box::new(MockJwt),
+        );
+
+        assert!(matches!(
+            concurrente
+                .ejecutar(InputData {
+                    refresh_token: token,
+                })
+                .await,
+            Err(LoginUniversalError::RefreshTokenReutilizado)
+        ));
+        assert!(cache.cache.lock().unwrap().refresh.is_none());
+    }
+
+    /// Devuelve siempre la sesion leida antes de la rotacion concurrente
+    struct SesionFija(Option<SesionRefresh>);
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheLectura<LoginUniversalError> for SesionFija {
+        async fn obtener_token(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            Ok(None)
+        }
+
+        async fn obtener_refresh(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<SesionRefresh>, LoginUniversalError> {
+            Ok(self.0.clone())
+        }
+    }
+}
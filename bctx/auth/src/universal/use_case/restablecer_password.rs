@@ -0,0 +1,442 @@
+use crate::universal::domain::bloqueo_login::{ClaveIntento, PoliticaBloqueo};
+use crate::universal::domain::credencial::validar_password_nuevo;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::{
+    RepositorioCodigoReset, RepositorioIntentosLogin, RepositorioLoginUniversalCacheBorrado,
+    RepositorioLoginUniversalEscritura, RepositorioLoginUniversalLectura,
+};
+use crate::universal::use_case::login::{claves_intento, registrar_fallo, verificar_bloqueo};
+use async_trait::async_trait;
+use quizz_common::provider::seguridad::{SeguridadCifrar, SeguridadComparar};
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub documento: String,
+    pub codigo: String,
+    pub password_nuevo: String,
+    pub ip: Option<String>,
+}
+
+/// Fija un password nuevo con un codigo emitido por `EmitirCodigoReset`. El
+/// codigo se consume y se revocan las sesiones abiertas del usuario.
+///
+/// Los codigos errados cuentan para el mismo bloqueo que el login, y tras
+/// `max_intentos_documento` fallos el codigo pendiente se invalida.
+pub struct RestablecerPassword<RepoErr> {
+    crypto_comparar: Box<dyn SeguridadComparar<RepoErr>>,
+    crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+    lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+    escritura: Box<dyn RepositorioLoginUniversalEscritura<RepoErr>>,
+    codigos: Box<dyn RepositorioCodigoReset<RepoErr>>,
+    repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+    intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+    politica: PoliticaBloqueo,
+}
+
+impl<RepoErr> RestablecerPassword<RepoErr> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        crypto_comparar: Box<dyn SeguridadComparar<RepoErr>>,
+        crypto_cifrar: Box<dyn SeguridadCifrar<RepoErr>>,
+        lectura: Box<dyn RepositorioLoginUniversalLectura<RepoErr>>,
+        escritura: Box<dyn RepositorioLoginUniversalEscritura<RepoErr>>,
+        codigos: Box<dyn RepositorioCodigoReset<RepoErr>>,
+        repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+        intentos: Box<dyn RepositorioIntentosLogin<RepoErr>>,
+        politica: PoliticaBloqueo,
+    ) -> RestablecerPassword<RepoErr> {
+        Self {
+            crypto_comparar,
+            crypto_cifrar,
+            lectura,
+            escritura,
+            codigos,
+            repositorio_cache,
+            intentos,
+            politica,
+        }
+    }
+}
+
+impl<RepoErr> RestablecerPassword<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    /// Devuelve el codigo cifrado vigente si coincide con el enviado
+    async fn verificar_codigo(
+        &self,
+        documento: String,
+        codigo: String,
+    ) -> Result<String, LoginUniversalError> {
+        let codigo_cifrado = self
+            .codigos
+            .obtener_codigo(documento)
+            .await?
+            .ok_or(LoginUniversalError::CodigoResetNoValido)?;
+        self.crypto_comparar
+            .comparar(codigo, codigo_cifrado.clone())
+            .await
+            .map_err(|_| LoginUniversalError::CodigoResetNoValido)?;
+        Ok(codigo_cifrado)
+    }
+
+    /// Cuenta el fallo para el bloqueo y descarta el codigo al agotar sus intentos
+    async fn registrar_codigo_errado(
+        &self,
+        documento: &str,
+        claves: &[ClaveIntento],
+    ) -> Result<(), LoginUniversalError> {
+        registrar_fallo(self.intentos.as_ref(), &self.politica, claves).await?;
+
+        let clave_codigo = ClaveIntento::CodigoReset(documento.to_string());
+        let fallos = self
+            .intentos
+            .registrar_fallo(&clave_codigo, self.politica.ventana_segundos)
+            .await?;
+        if self
+            .politica
+            .duracion_bloqueo(&clave_codigo, fallos)
+            .is_some()
+        {
+            self.codigos.borrar_codigo(documento.to_string()).await?;
+            self.intentos.reiniciar(&clave_codigo).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for RestablecerPassword<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        validar_password_nuevo(&in_.password_nuevo)?;
+
+        let claves = claves_intento(&in_.documento, in_.ip);
+        verificar_bloqueo(self.intentos.as_ref(), &claves).await?;
+
+        let codigo_cifrado = match self
+            .verificar_codigo(in_.documento.clone(), in_.codigo)
+            .await
+        {
+            Ok(codigo_cifrado) => codigo_cifrado,
+            Err(e @ LoginUniversalError::CodigoResetNoValido) => {
+                self.registrar_codigo_errado(&in_.documento, &claves)
+                    .await?;
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Solo uno de varios pedidos concurrentes con el mismo codigo lo consume
+        if !self
+            .codigos
+            .consumir_codigo(in_.documento.clone(), codigo_cifrado)
+            .await?
+        {
+            return Err(LoginUniversalError::CodigoResetNoValido);
+        }
+
+        let usuario = self
+            .lectura
+            .buscar_por_documento(in_.documento.clone())
+            .await?;
+        let password_cifrado = self.crypto_cifrar.cifrar(in_.password_nuevo).await?;
+        self.escritura
+            .actualizar_password(&usuario, password_cifrado)
+            .await?;
+
+        self.intentos.reiniciar(&claves[0]).await?;
+        self.intentos
+            .reiniciar(&ClaveIntento::CodigoReset(in_.documento))
+            .await?;
+        self.repositorio_cache.borrar_token(usuario.id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::domain::usuario_login::UsuarioLogin;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Estado {
+        password: String,
+        codigo: Option<String>,
+        // Simula otro pedido que consume el codigo justo despues de leerlo
+        codigo_en_carrera: bool,
+        sesiones_borradas: Vec<String>,
+        fallos: HashMap<String, u32>,
+        bloqueos: HashMap<String, u64>,
+    }
+
+    #[derive(Clone)]
+    struct Mock {
+        estado: Arc<Mutex<Estado>>,
+    }
+
+    #[async_trait]
+    impl SeguridadComparar<LoginUniversalError> for Mock {
+        async fn comparar(
+            &self,
+            password: String,
+            hashed: String,
+        ) -> Result<(), LoginUniversalError> {
+            if format!("cifrado:{}", password) == hashed {
+                Ok(())
+            } else {
+                Err(LoginUniversalError::PasswordIncorrecto)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SeguridadCifrar<LoginUniversalError> for Mock {
+        async fn cifrar(&self, password: String) -> Result<String, LoginUniversalError> {
+            Ok(format!("cifrado:{}", password))
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalLectura<LoginUniversalError> for Mock {
+        async fn buscar_por_documento(
+            &self,
+            _documento: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Ok(UsuarioLogin {
+                id: "usr-123".to_string(),
+                password: self.estado.lock().unwrap().password.clone(),
+                rol: "postulante".to_string(),
+            })
+        }
+
+        async fn buscar_por_id(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<UsuarioLogin, LoginUniversalError> {
+            Err(LoginUniversalError::UsuarioNoEncontrado)
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalEscritura<LoginUniversalError> for Mock {
+        async fn actualizar_password(
+            &self,
+            _usuario: &UsuarioLogin,
+            password_cifrado: String,
+        ) -> Result<(), LoginUniversalError> {
+            self.estado.lock().unwrap().password = password_cifrado;
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioCodigoReset<LoginUniversalError> for Mock {
+        async fn guardar_codigo(
+            &self,
+            _documento: String,
+            codigo_cifrado: String,
+            _expiration: u64,
+        ) -> Result<(), LoginUniversalError> {
+            self.estado.lock().unwrap().codigo = Some(codigo_cifrado);
+            Ok(())
+        }
+
+        async fn obtener_codigo(
+            &self,
+            _documento: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            let mut estado = self.estado.lock().unwrap();
+            if estado.codigo_en_carrera {
+                return Ok(estado.codigo.take());
+            }
+            Ok(estado.codigo.clone())
+        }
+
+        async fn consumir_codigo(
+            &self,
+            _documento: String,
+            codigo_cifrado: String,
+        ) -> Result<bool, LoginUniversalError> {
+            let mut estado = self.estado.lock().unwrap();
+            if estado.codigo.as_ref() != Some(&codigo_cifrado) {
+                return Ok(false);
+            }
+            estado.codigo = None;
+            Ok(true)
+        }
+
+        async fn borrar_codigo(&self, _documento: String) -> Result<(), LoginUniversalError> {
+            self.estado.lock().unwrap().codigo = None;
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheBorrado<LoginUniversalError> for Mock {
+        async fn borrar_token(&self, sujeto_id: String) -> Result<(), LoginUniversalError> {
+            self.estado
+                .lock()
+                .unwrap()
+                .sesiones_borradas
+                .push(sujeto_id);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioIntentosLogin<LoginUniversalError> for Mock {
+        async fn bloqueo_restante(
+            &self,
+            clave: &ClaveIntento,
+        ) -> Result<Option<u64>, LoginUniversalError> {
+            Ok(self
+                .estado
+                .lock()
+                .unwrap()
+                .bloqueos
+                .get(&clave.to_string())
+                .copied())
+        }
+
+        async fn registrar_fallo(
+            &self,
+            clave: &ClaveIntento,
+            _ventana: u64,
+        ) -> Result<u32, LoginUniversalError> {
+            let mut estado = self.estado.lock().unwrap();
+            let fallos = estado.fallos.entry(clave.to_string()).or_default();
+            *fallos += 1;
+            Ok(*fallos)
+        }
+
+        async fn bloquear(
+            &self,
+            clave: &ClaveIntento,
+            segundos: u64,
+        ) -> Result<(), LoginUniversalError> {
+            self.estado
+                .lock()
+                .unwrap()
+                .bloqueos
+                .insert(clave.to_string(), segundos);
+            Ok(())
+        }
+
+        async fn reiniciar(&self, clave: &ClaveIntento) -> Result<(), LoginUniversalError> {
+            let mut estado = self.estado.lock().unwrap();
+            estado.fallos.remove(&clave.to_string());
+            estado.bloqueos.remove(&clave.to_string());
+            Ok(())
+        }
+    }
+
+    fn entrada(codigo: &str) -> InputData {
+        InputData {
+            documento: "12345678".to_string(),
+            codigo: codigo.to_string(),
+            password_nuevo: "clave-segura".to_string(),
+            ip: Some("10.0.0.1".to_string()),
+        }
+    }
+
+    fn caso_de_uso(
+        mock: &Mock,
+        politica: PoliticaBloqueo,
+    ) -> RestablecerPassword<LoginUniversalError> {
+        RestablecerPassword::new(
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            Box::new(mock.clone()),
+            politica,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_el_codigo_sirve_una_sola_vez() {
+        let mock = Mock {
+            estado: Arc::new(Mutex::new(Estado {
+                password: "cifrado:5678".to_string(),
+                codigo: Some("cifrado:A1B2C3D4E5".to_string()),
+                ..Default::default()
+            })),
+        };
+        let use_case = caso_de_uso(&mock, PoliticaBloqueo::default());
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("FFFFFFFFFF")).await,
+            Err(LoginUniversalError::CodigoResetNoValido)
+        ));
+        use_case.ejecutar(entrada("A1B2C3D4E5")).await.unwrap();
+        assert!(matches!(
+            use_case.ejecutar(entrada("A1B2C3D4E5")).await,
+            Err(LoginUniversalError::CodigoResetNoValido)
+        ));
+
+        let estado = mock.estado.lock().unwrap();
+        assert_eq!(estado.password, "cifrado:clave-segura");
+        assert_eq!(estado.sesiones_borradas, vec!["usr-123".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_el_codigo_se_invalida_tras_los_intentos_fallidos() {
+        let mock = Mock {
+            estado: Arc::new(Mutex::new(Estado {
+                password: "cifrado:5678".to_string(),
+                codigo: Some("cifrado:A1B2C3D4E5".to_string()),
+                ..Default::default()
+            })),
+        };
+        let politica = PoliticaBloqueo {
+            max_intentos_documento: 3,
+            ..PoliticaBloqueo::default()
+        };
+        let use_case = caso_de_uso(&mock, politica);
+
+        for _ in 0..3 {
+            assert!(matches!(
+                use_case.ejecutar(entrada("FFFFFFFFFF")).await,
+                Err(LoginUniversalError::CodigoResetNoValido)
+            ));
+        }
+        assert!(mock.estado.lock().unwrap().codigo.is_none());
+        assert!(matches!(
+            use_case.ejecutar(entrada("A1B2C3D4E5")).await,
+            Err(LoginUniversalError::LoginBloqueado { .. })
+        ));
+
+        let estado = mock.estado.lock().unwrap();
+        assert_eq!(estado.password, "cifrado:5678");
+        assert!(estado.sesiones_borradas.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_no_restablece_si_otro_pedido_consumio_el_codigo() {
+        let mock = Mock {
+            estado: Arc::new(Mutex::new(Estado {
+                password: "cifrado:5678".to_string(),
+                codigo: Some("cifrado:A1B2C3D4E5".to_string()),
+                codigo_en_carrera: true,
+                ..Default::default()
+            })),
+        };
+        let use_case = caso_de_uso(&mock, PoliticaBloqueo::default());
+
+        assert!(matches!(
+            use_case.ejecutar(entrada("A1B2C3D4E5")).await,
+            Err(LoginUniversalError::CodigoResetNoValido)
+        ));
+
+        let estado = mock.estado.lock().unwrap();
+        assert_eq!(estado.password, "cifrado:5678");
+        assert!(estado.sesiones_borradas.is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::RepositorioLoginUniversalCacheBorrado;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub sujeto_id: String,
+}
+
+/// Revoca todas las sesiones de un usuario, p. ej. tras un cambio de password
+/// o una desactivacion. A diferencia del logout, lo invoca un tercero sobre
+/// el sujeto indicado.
+pub struct RevocarSesiones<RepoErr> {
+    repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+}
+
+impl<RepoErr> RevocarSesiones<RepoErr> {
+    pub fn new(
+        repositorio_cache: Box<dyn RepositorioLoginUniversalCacheBorrado<RepoErr>>,
+    ) -> RevocarSesiones<RepoErr> {
+        Self { repositorio_cache }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for RevocarSesiones<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        self.repositorio_cache.borrar_token(in_.sujeto_id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::domain::acceso_respuesta::clave_sesion;
+    use crate::universal::domain::sesion_refresh::SesionRefresh;
+    use crate::universal::provider::repositorio::{
+        RepositorioLoginUniversalCacheEscritura, RepositorioLoginUniversalCacheLectura,
+    };
+    use crate::universal::use_case::verificar_sesion::{self, VerificarSesion};
+    use quizz_common::domain::entity::jwt::JwtObject;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const SUJETO_ID: &str = "usr-123";
+    const RESPUESTA_ID: &str = "3f2b8c1a-9d4e-4a7b-b6c5-2e1f0a9d8c7b";
+
+    #[derive(Default)]
+    struct Cache {
+        tokens: HashMap<String, String>,
+        sesiones_respuesta: HashMap<String, Vec<String>>,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockCache {
+        cache: Arc<Mutex<Cache>>,
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for MockCache {
+        async fn guardar_token(&self, jwt: JwtObject) -> Result<(), LoginUniversalError> {
+            self.cache.lock().unwrap().tokens.insert(jwt.key, jwt.value);
+            Ok(())
+        }
+
+        async fn guardar_sesion_respuesta(
+            &self,
+            sujeto_id: String,
+            jwt: JwtObject,
+        ) -> Result<(), LoginUniversalError> {
+            let mut cache = self.cache.lock().unwrap();
+            cache
+                .sesiones_respuesta
+                .entry(sujeto_id)
+                .or_default()
+                .push(jwt.key.clone());
+            cache.tokens.insert(jwt.key, jwt.value);
+            Ok(())
+        }
+
+        async fn guardar_refresh(&self, _sesion: SesionRefresh) -> Result<(), LoginUniversalError> {
+            Ok(())
+        }
+
+        async fn rotar_refresh(
+            &self,
+            _anterior: String,
+            _sesion: SesionRefresh,
+        ) -> Result<bool, LoginUniversalError> {
+            Ok(true)
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheLectura<LoginUniversalError> for MockCache {
+        async fn obtener_token(
+            &self,
+            clave: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            Ok(self.cache.lock().unwrap().tokens.get(&clave).cloned())
+        }
+
+        async fn obtener_refresh(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<SesionRefresh>, LoginUniversalError> {
+            Ok(None)
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheBorrado<LoginUniversalError> for MockCache {
+        async fn borrar_token(&self, sujeto_id: String) -> Result<(), LoginUniversalError> {
+            let mut cache = self.cache.lock().unwrap();
+            for clave in cache
+                .sesiones_respuesta
+                .remove(&sujeto_id)
+                .unwrap_or_default()
+            {
+                cache.tokens.remove(&clave);
+            }
+            cache.tokens.remove(&sujeto_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revocar_tambien_revoca_los_tokens_de_codigo_de_acceso() {
+        let cache = MockCache::default();
+        cache
+            .guardar_sesion_respuesta(
+                SUJETO_ID.to_string(),
+                JwtObject {
+                    key: clave_sesion(SUJETO_ID, Some(RESPUESTA_ID)),
+                    value: "jwt-respuesta".to_string(),
+                    expiration: 900,
+                    rol: Some("postulante".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        let verificar = VerificarSesion::new(Box::new(cache.clone()));
+        let entrada = || verificar_sesion::InputData {
+            sujeto_id: SUJETO_ID.to_string(),
+            token: "jwt-respuesta".to_string(),
+            respuesta_id: Some(RESPUESTA_ID.to_string()),
+        };
+        assert!(verificar.ejecutar(entrada()).await.is_ok());
+
+        RevocarSesiones::new(Box::new(cache.clone()))
+            .ejecutar(InputData {
+                sujeto_id: SUJETO_ID.to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            verificar.ejecutar(entrada()).await,
+            Err(LoginUniversalError::SesionRevocada)
+        ));
+    }
+}
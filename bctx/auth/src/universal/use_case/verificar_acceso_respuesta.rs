@@ -0,0 +1,90 @@
+use crate::universal::domain::acceso_respuesta::ruta_permitida;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::RepositorioRespuestaAcceso;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub respuesta_id: String,
+    pub ruta: String,
+}
+
+/// Limita un token emitido con codigo de acceso a su respuesta. El token deja
+/// de servir en cuanto la respuesta se finaliza.
+pub struct VerificarAccesoRespuesta<RepoErr> {
+    respuestas: Box<dyn RepositorioRespuestaAcceso<RepoErr>>,
+}
+
+impl<RepoErr> VerificarAccesoRespuesta<RepoErr> {
+    pub fn new(
+        respuestas: Box<dyn RepositorioRespuestaAcceso<RepoErr>>,
+    ) -> VerificarAccesoRespuesta<RepoErr> {
+        Self { respuestas }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for VerificarAccesoRespuesta<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        if !ruta_permitida(&in_.respuesta_id, &in_.ruta) {
+            return Err(LoginUniversalError::AccesoFueraDeRespuesta(
+                in_.respuesta_id,
+            ));
+        }
+        if self
+            .respuestas
+            .respuesta_finalizada(in_.respuesta_id.clone())
+            .await?
+        {
+            return Err(LoginUniversalError::RespuestaFinalizada(in_.respuesta_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPUESTA_ID: &str = "3f2b8c1a-9d4e-4a7b-b6c5-2e1f0a9d8c7b";
+
+    struct MockRespuestas {
+        finalizada: bool,
+    }
+
+    #[async_trait]
+    impl RepositorioRespuestaAcceso<LoginUniversalError> for MockRespuestas {
+        async fn respuesta_finalizada(
+            &self,
+            _respuesta_id: String,
+        ) -> Result<bool, LoginUniversalError> {
+            Ok(self.finalizada)
+        }
+    }
+
+    async fn verificar(finalizada: bool, ruta: &str) -> Result<(), LoginUniversalError> {
+        VerificarAccesoRespuesta::new(Box::new(MockRespuestas { finalizada }))
+            .ejecutar(InputData {
+                respuesta_id: RESPUESTA_ID.to_string(),
+                ruta: ruta.to_string(),
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_el_token_solo_sirve_para_su_respuesta_en_curso() {
+        let propia = format!("/respuestas/{}/empezar", RESPUESTA_ID);
+        assert!(verificar(false, &propia).await.is_ok());
+        assert!(matches!(
+            verificar(false, "/evaluaciones").await,
+            Err(LoginUniversalError::AccesoFueraDeRespuesta(_))
+        ));
+        assert!(matches!(
+            verificar(true, &propia).await,
+            Err(LoginUniversalError::RespuestaFinalizada(_))
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::universal::domain::acceso_respuesta::clave_sesion;
+use crate::universal::domain::error::login_universal::LoginUniversalError;
+use crate::universal::provider::repositorio::RepositorioLoginUniversalCacheLectura;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub sujeto_id: String,
+    pub token: String,
+    /// Respuesta a la que esta limitado el token, si fue emitido con codigo de acceso
+    pub respuesta_id: Option<String>,
+}
+
+/// Confirma que el token sigue siendo la sesion activa del sujeto. Un token
+/// sin sesion (logout, revocacion) o reemplazado por un login posterior esta
+/// revocado aunque su firma y expiracion sean validas.
+pub struct VerificarSesion<RepoErr> {
+    repositorio_cache: Box<dyn RepositorioLoginUniversalCacheLectura<RepoErr>>,
+}
+
+impl<RepoErr> VerificarSesion<RepoErr> {
+    pub fn new(
+        repositorio_cache: Box<dyn RepositorioLoginUniversalCacheLectura<RepoErr>>,
+    ) -> VerificarSesion<RepoErr> {
+        Self { repositorio_cache }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), LoginUniversalError> for VerificarSesion<RepoErr>
+where
+    LoginUniversalError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), LoginUniversalError> {
+        let clave = clave_sesion(&in_.sujeto_id, in_.respuesta_id.as_deref());
+        match self.repositorio_cache.obtener_token(clave).await? {
+            Some(token) if token == in_.token => Ok(()),
+            _ => Err(LoginUniversalError::SesionRevocada),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal::domain::sesion_refresh::SesionRefresh;
+
+    struct MockRepo {
+        token: Option<String>,
+    }
+
+    #[async_trait]
+    impl RepositorioLoginUniversalCacheLectura<LoginUniversalError> for MockRepo {
+        async fn obtener_token(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<String>, LoginUniversalError> {
+            Ok(self.token.clone())
+        }
+
+        async fn obtener_refresh(
+            &self,
+            _sujeto_id: String,
+        ) -> Result<Option<SesionRefresh>, LoginUniversalError> {
+            Ok(None)
+        }
+    }
+
+    async fn verificar(guardado: Option<&str>, token: &str) -> Result<(), LoginUniversalError> {
+        let use_case = VerificarSesion::new(Box::new(MockRepo {
+            token: guardado.map(str::to_string),
+        }));
+
+        use_case
+            .ejecutar(InputData {
+                sujeto_id: "usr-123".to_string(),
+                token: token.to_string(),
+                respuesta_id: None,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_sesion_activa() {
+        assert!(verificar(Some("jwt-1"), "jwt-1").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sesion_borrada_o_reemplazada_esta_revocada() {
+        assert!(matches!(
+            verificar(None, "jwt-1").await,
+            Err(LoginUniversalError::SesionRevocada)
+        ));
+        assert!(matches!(
+            verificar(Some("jwt-2"), "jwt-1").await,
+            Err(LoginUniversalError::SesionRevocada)
+        ));
+    }
+}
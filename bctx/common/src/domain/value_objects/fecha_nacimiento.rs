@@ -37,6 +37,11 @@ impl FechaNacimiento {
         Ok(fecha_nacimiento)
     }
 
+    /// Edad en años cumplidos a la fecha indicada.
+    pub fn edad_al(&self, fecha: NaiveDate) -> u32 {
+        fecha.years_since(self.value).unwrap_or(0)
+    }
+
     fn tiene_edad_minima(&self) -> bool {
         let hoy = chrono::Local::now().date_naive();
 
@@ -90,4 +95,12 @@ mod tests {
             FechaNacimientoError::EdadMinima
         ));
     }
+
+    #[test]
+    fn test_edad_al_cumpleanios() {
+        let fecha = FechaNacimiento::new("2000-02-15").unwrap();
+        let dia = |s: &str| NaiveDate::parse_from_str(s, DATE_FORMAT).unwrap();
+        assert_eq!(fecha.edad_al(dia("2025-02-14")), 24);
+        assert_eq!(fecha.edad_al(dia("2025-02-15")), 25);
+    }
 }
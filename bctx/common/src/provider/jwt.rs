@@ -20,3 +20,13 @@ pub trait JwtProviderVerify<Error>: Send + Sync {
 pub trait JwtProviderGenerateConRol<Error>: Send + Sync {
     async fn generar_con_rol(&self, sujeto_id: String, rol: String) -> Result<JwtObject, Error>;
 }
+
+/// Token limitado a una sola respuesta del postulante
+#[async_trait]
+pub trait JwtProviderGenerateRespuesta<Error>: Send + Sync {
+    async fn generar_para_respuesta(
+        &self,
+        postulante_id: String,
+        respuesta_id: String,
+    ) -> Result<JwtObject, Error>;
+}
@@ -0,0 +1,216 @@
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::baremo::domain::value_object::escala::Escala;
+use crate::baremo::domain::value_object::grupo_normativo::{GrupoNormativo, PerfilNormativo};
+use crate::baremo::domain::value_object::id::BaremoID;
+use crate::baremo::domain::value_object::puntuacion_normativa::PuntuacionNormativa;
+
+/// Fila de la tabla de conversion: puntaje bruto y su percentil.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilaBaremo {
+    pub puntaje: i64,
+    pub percentil: f64,
+}
+
+/// Tabla normativa para convertir el puntaje bruto de una escala en
+/// percentil, puntaje T y eneatipo.
+#[derive(Debug, Clone)]
+pub struct Baremo {
+    pub id: BaremoID,
+    pub nombre: String,
+    pub escala: Escala,
+    pub grupo: GrupoNormativo,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<FilaBaremo>,
+}
+
+impl Baremo {
+    pub fn new(
+        id: BaremoID,
+        nombre: String,
+        escala: Escala,
+        grupo: GrupoNormativo,
+        media: f64,
+        desviacion_estandar: f64,
+        mut tabla: Vec<FilaBaremo>,
+    ) -> Result<Self, BaremoError> {
+        if nombre.trim().is_empty() {
+            return Err(BaremoError::NombreNoValido);
+        }
+        if !desviacion_estandar.is_finite() || desviacion_estandar <= 0.0 {
+            return Err(BaremoError::DesviacionEstandarNoValida);
+        }
+        if tabla.is_empty() {
+            return Err(BaremoError::TablaVacia);
+        }
+        if let Some(fila) = tabla
+            .iter()
+            .find(|fila| !(0.0..=100.0).contains(&fila.percentil))
+        {
+            return Err(BaremoError::PercentilFueraDeRango(fila.percentil));
+        }
+
+        tabla.sort_by_key(|fila| fila.puntaje);
+        for par in tabla.windows(2) {
+            if par[0].puntaje == par[1].puntaje {
+                return Err(BaremoError::PuntajeRepetido(par[0].puntaje));
+            }
+            if par[0].percentil > par[1].percentil {
+                return Err(BaremoError::PercentilesNoOrdenados);
+            }
+        }
+
+        Ok(Self {
+            id,
+            nombre,
+            escala,
+            grupo,
+            media,
+            desviacion_estandar,
+            tabla,
+        })
+    }
+
+    /// El percentil sale de la fila con el mayor puntaje que no supera al
+    /// puntaje bruto; por debajo de la tabla se usa la primera fila.
+    pub fn convertir(&self, puntos: i64) -> PuntuacionNormativa {
+        let fila = self
+            .tabla
+            .iter()
+            .rev()
+            .find(|fila| fila.puntaje <= puntos)
+            .unwrap_or(&self.tabla[0]);
+
+        PuntuacionNormativa::new(
+            self.id.to_string(),
+            puntos,
+            fila.percentil,
+            self.media,
+            self.desviacion_estandar,
+        )
+    }
+}
+
+/// Elige, entre los baremos de la escala cuyo grupo incluye al perfil, el
+/// mas especifico.
+pub fn seleccionar_baremo<'a>(
+    baremos: &'a [Baremo],
+    escala: &Escala,
+    perfil: Option<&PerfilNormativo>,
+) -> Option<&'a Baremo> {
+    baremos
+        .iter()
+        .filter(|baremo| baremo.escala == *escala && baremo.grupo.incluye(perfil))
+        .max_by_key(|baremo| baremo.grupo.especificidad())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postulante::domain::value_object::genero::Genero;
+    use crate::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
+    use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+
+    const BAREMO_ID: &str = "0c9f5b7e-3d1a-4e82-9b46-7a2f1d8c5e30";
+    const BAREMO_MUJERES_ID: &str = "5d2e8a41-6b9c-4f07-a3d5-1e7c9b2f4a86";
+
+    fn tabla() -> Vec<FilaBaremo> {
+        vec![
+            FilaBaremo {
+                puntaje: 20,
+                percentil: 50.0,
+            },
+            FilaBaremo {
+                puntaje: 10,
+                percentil: 10.0,
+            },
+            FilaBaremo {
+                puntaje: 30,
+                percentil: 90.0,
+            },
+        ]
+    }
+
+    fn baremo(id: &str, grupo: GrupoNormativo) -> Baremo {
+        Baremo::new(
+            BaremoID::new(id).unwrap(),
+            "Neurotismo adultos".to_string(),
+            Escala::Rasgo(Etiqueta::Neurotismo),
+            grupo,
+            20.0,
+            5.0,
+            tabla(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_convertir_puntaje() {
+        let baremo = baremo(BAREMO_ID, GrupoNormativo::default());
+
+        let puntuacion = baremo.convertir(25);
+        assert_eq!(puntuacion.percentil, 50.0);
+        assert_eq!(puntuacion.puntaje_t, 60.0);
+        assert_eq!(puntuacion.eneatipo, 5);
+
+        assert_eq!(baremo.convertir(5).percentil, 10.0);
+        assert_eq!(baremo.convertir(40).percentil, 90.0);
+    }
+
+    #[test]
+    fn test_tabla_no_valida() {
+        let nuevo = |tabla: Vec<FilaBaremo>| {
+            Baremo::new(
+                BaremoID::new(BAREMO_ID).unwrap(),
+                "Neurotismo".to_string(),
+                Escala::Rasgo(Etiqueta::Neurotismo),
+                GrupoNormativo::default(),
+                20.0,
+                5.0,
+                tabla,
+            )
+        };
+
+        assert!(matches!(nuevo(vec![]), Err(BaremoError::TablaVacia)));
+
+        let mut repetida = tabla();
+        repetida[1].puntaje = 20;
+        assert!(matches!(
+            nuevo(repetida),
+            Err(BaremoError::PuntajeRepetido(20))
+        ));
+
+        let mut desordenada = tabla();
+        desordenada[1].percentil = 70.0;
+        assert!(matches!(
+            nuevo(desordenada),
+            Err(BaremoError::PercentilesNoOrdenados)
+        ));
+    }
+
+    #[test]
+    fn test_seleccionar_baremo_mas_especifico() {
+        let baremos = vec![
+            baremo(BAREMO_ID, GrupoNormativo::default()),
+            baremo(
+                BAREMO_MUJERES_ID,
+                GrupoNormativo::new(Some(Genero::Femenino), None, None, None).unwrap(),
+            ),
+        ];
+        let escala = Escala::Rasgo(Etiqueta::Neurotismo);
+        let perfil = PerfilNormativo {
+            genero: Genero::Femenino,
+            edad: 30,
+            grado_instruccion: GradoInstruccion::Secundaria,
+        };
+
+        let elegido = seleccionar_baremo(&baremos, &escala, Some(&perfil)).unwrap();
+        assert_eq!(elegido.id.to_string(), BAREMO_MUJERES_ID);
+
+        let elegido = seleccionar_baremo(&baremos, &escala, None).unwrap();
+        assert_eq!(elegido.id.to_string(), BAREMO_ID);
+
+        let otra = Escala::Rasgo(Etiqueta::Honestidad);
+        assert!(seleccionar_baremo(&baremos, &otra, Some(&perfil)).is_none());
+    }
+}
@@ -0,0 +1,44 @@
+use quizz_common::domain::value_objects::id::IdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BaremoError {
+    #[error("ID del baremo no valido")]
+    BaremoIdInvalido(#[from] IdError),
+
+    #[error("El nombre del baremo no es valido")]
+    NombreNoValido,
+
+    #[error("La escala del baremo no es valida: {0}")]
+    EscalaNoValida(String),
+
+    #[error("El grupo normativo no es valido: {0}")]
+    GrupoNormativoNoValido(String),
+
+    #[error("La desviacion estandar debe ser mayor que cero")]
+    DesviacionEstandarNoValida,
+
+    #[error("La tabla del baremo esta vacia")]
+    TablaVacia,
+
+    #[error("El percentil {0} esta fuera del rango 0 a 100")]
+    PercentilFueraDeRango(f64),
+
+    #[error("El puntaje {0} esta repetido en la tabla")]
+    PuntajeRepetido(i64),
+
+    #[error("Los percentiles deben crecer junto con el puntaje")]
+    PercentilesNoOrdenados,
+
+    #[error("Error al manipular la base de datos: {0:?}")]
+    BaremoRepositorioError(#[from] RepositorioError),
+}
+
+#[derive(Error, Debug)]
+pub enum RepositorioError {
+    #[error("Persistencia no finalizada")]
+    PersistenciaNoFinalizada,
+
+    #[error("Lectura no finalizada")]
+    LecturaNoFinalizada,
+}
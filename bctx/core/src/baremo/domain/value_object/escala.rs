@@ -0,0 +1,80 @@
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::examen::domain::value_object::id::ExamenID;
+use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+use std::fmt;
+use std::str::FromStr;
+
+const EXAMEN: &str = "examen";
+const RASGO: &str = "rasgo";
+
+/// Puntaje bruto al que se aplica un baremo: el total de un examen o la
+/// subescala de un rasgo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Escala {
+    Examen(String),
+    Rasgo(Etiqueta),
+}
+
+impl Escala {
+    pub fn new(tipo: &str, valor: &str) -> Result<Self, BaremoError> {
+        match tipo.to_lowercase().as_str() {
+            EXAMEN => {
+                let id = ExamenID::new(valor)
+                    .map_err(|_| BaremoError::EscalaNoValida(valor.to_string()))?;
+                Ok(Self::Examen(id.to_string()))
+            }
+            RASGO => match Etiqueta::from_str(valor) {
+                Ok(Etiqueta::No) | Err(_) => Err(BaremoError::EscalaNoValida(valor.to_string())),
+                Ok(etiqueta) => Ok(Self::Rasgo(etiqueta)),
+            },
+            _ => Err(BaremoError::EscalaNoValida(tipo.to_string())),
+        }
+    }
+
+    pub fn tipo(&self) -> &'static str {
+        match self {
+            Self::Examen(_) => EXAMEN,
+            Self::Rasgo(_) => RASGO,
+        }
+    }
+
+    pub fn valor(&self) -> String {
+        match self {
+            Self::Examen(id) => id.clone(),
+            Self::Rasgo(etiqueta) => etiqueta.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Escala {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.tipo(), self.valor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escala_examen() {
+        let escala = Escala::new("examen", "19573e4f-321d-41ad-a8a9-3807c6fd3d65").unwrap();
+        assert_eq!(
+            escala.to_string(),
+            "examen:19573e4f-321d-41ad-a8a9-3807c6fd3d65"
+        );
+    }
+
+    #[test]
+    fn test_escala_rasgo() {
+        let escala = Escala::new("rasgo", "neurotismo").unwrap();
+        assert_eq!(escala, Escala::Rasgo(Etiqueta::Neurotismo));
+    }
+
+    #[test]
+    fn test_escala_no_valida() {
+        assert!(Escala::new("rasgo", "no").is_err());
+        assert!(Escala::new("examen", "no-es-uuid").is_err());
+        assert!(Escala::new("otro", "neurotismo").is_err());
+    }
+}
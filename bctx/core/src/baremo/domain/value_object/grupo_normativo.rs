@@ -0,0 +1,106 @@
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::postulante::domain::value_object::genero::Genero;
+use crate::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
+
+/// Datos del postulante que deciden el grupo normativo con el que se compara.
+#[derive(Debug, Clone)]
+pub struct PerfilNormativo {
+    pub genero: Genero,
+    pub edad: u32,
+    pub grado_instruccion: GradoInstruccion,
+}
+
+/// Poblacion a la que aplica un baremo. Un campo vacio significa que el
+/// baremo no distingue por ese criterio.
+#[derive(Debug, Clone, Default)]
+pub struct GrupoNormativo {
+    pub genero: Option<Genero>,
+    pub edad_minima: Option<u32>,
+    pub edad_maxima: Option<u32>,
+    pub grado_instruccion: Option<GradoInstruccion>,
+}
+
+impl GrupoNormativo {
+    pub fn new(
+        genero: Option<Genero>,
+        edad_minima: Option<u32>,
+        edad_maxima: Option<u32>,
+        grado_instruccion: Option<GradoInstruccion>,
+    ) -> Result<Self, BaremoError> {
+        if let (Some(minima), Some(maxima)) = (edad_minima, edad_maxima)
+            && minima > maxima
+        {
+            return Err(BaremoError::GrupoNormativoNoValido(format!(
+                "la edad minima {minima} es mayor que la edad maxima {maxima}"
+            )));
+        }
+
+        Ok(Self {
+            genero,
+            edad_minima,
+            edad_maxima,
+            grado_instruccion,
+        })
+    }
+
+    /// Indica si el perfil pertenece al grupo. Sin perfil solo aplican los
+    /// grupos generales.
+    pub fn incluye(&self, perfil: Option<&PerfilNormativo>) -> bool {
+        let Some(perfil) = perfil else {
+            return self.especificidad() == 0;
+        };
+
+        self.genero.as_ref().is_none_or(|g| *g == perfil.genero)
+            && self.edad_minima.is_none_or(|minima| perfil.edad >= minima)
+            && self.edad_maxima.is_none_or(|maxima| perfil.edad <= maxima)
+            && self
+                .grado_instruccion
+                .as_ref()
+                .is_none_or(|g| *g == perfil.grado_instruccion)
+    }
+
+    /// Cantidad de criterios que definen el grupo; a mayor valor, mas
+    /// especifico es el baremo.
+    pub fn especificidad(&self) -> u8 {
+        u8::from(self.genero.is_some())
+            + u8::from(self.edad_minima.is_some() || self.edad_maxima.is_some())
+            + u8::from(self.grado_instruccion.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn perfil() -> PerfilNormativo {
+        PerfilNormativo {
+            genero: Genero::Femenino,
+            edad: 25,
+            grado_instruccion: GradoInstruccion::Superior,
+        }
+    }
+
+    #[test]
+    fn test_grupo_general_incluye_a_todos() {
+        let grupo = GrupoNormativo::default();
+        assert!(grupo.incluye(Some(&perfil())));
+        assert!(grupo.incluye(None));
+        assert_eq!(grupo.especificidad(), 0);
+    }
+
+    #[test]
+    fn test_grupo_por_genero_y_edad() {
+        let grupo = GrupoNormativo::new(Some(Genero::Femenino), Some(18), Some(30), None).unwrap();
+        assert!(grupo.incluye(Some(&perfil())));
+        assert!(!grupo.incluye(None));
+        assert_eq!(grupo.especificidad(), 2);
+
+        let grupo = GrupoNormativo::new(None, Some(31), None, None).unwrap();
+        assert!(!grupo.incluye(Some(&perfil())));
+    }
+
+    #[test]
+    fn test_rango_de_edad_invertido() {
+        assert!(GrupoNormativo::new(None, Some(40), Some(20), None).is_err());
+    }
+}
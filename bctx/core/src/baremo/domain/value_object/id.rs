@@ -0,0 +1,25 @@
+use quizz_common::domain::value_objects::id::{ID, IdError};
+use quizz_common::domain::value_objects::id_type::IdType;
+use std::fmt;
+
+/// Representa el ID unico del baremo
+#[derive(Debug, Clone)]
+pub struct BaremoID {
+    id: ID,
+}
+
+impl fmt::Display for BaremoID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id.value())
+    }
+}
+
+impl BaremoID {
+    pub fn new(id: &str) -> Result<Self, IdError> {
+        ID::new(id, IdType::Custom("Baremo".to_string())).map(|id| BaremoID { id })
+    }
+
+    pub fn value(&self) -> &ID {
+        &self.id
+    }
+}
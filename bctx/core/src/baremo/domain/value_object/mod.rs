@@ -0,0 +1,4 @@
+pub mod escala;
+pub mod grupo_normativo;
+pub mod id;
+pub mod puntuacion_normativa;
@@ -0,0 +1,60 @@
+/// Cortes de percentil que separan los nueve eneatipos (stanines).
+const CORTES_ENEATIPO: [f64; 8] = [4.0, 11.0, 23.0, 40.0, 60.0, 77.0, 89.0, 96.0];
+
+/// Puntaje bruto convertido con un baremo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuntuacionNormativa {
+    pub baremo_id: String,
+    pub percentil: f64,
+    pub puntaje_t: f64,
+    pub eneatipo: u8,
+}
+
+impl PuntuacionNormativa {
+    pub fn new(
+        baremo_id: String,
+        puntos: i64,
+        percentil: f64,
+        media: f64,
+        desviacion_estandar: f64,
+    ) -> Self {
+        let puntaje_t = 50.0 + 10.0 * (puntos as f64 - media) / desviacion_estandar;
+
+        Self {
+            baremo_id,
+            percentil,
+            puntaje_t: (puntaje_t * 10.0).round() / 10.0,
+            eneatipo: eneatipo(percentil),
+        }
+    }
+}
+
+fn eneatipo(percentil: f64) -> u8 {
+    1 + CORTES_ENEATIPO
+        .iter()
+        .filter(|corte| percentil > **corte)
+        .count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puntaje_t() {
+        let puntuacion = PuntuacionNormativa::new(String::new(), 65, 84.0, 50.0, 10.0);
+        assert_eq!(puntuacion.puntaje_t, 65.0);
+
+        let puntuacion = PuntuacionNormativa::new(String::new(), 42, 20.0, 50.0, 6.0);
+        assert_eq!(puntuacion.puntaje_t, 36.7);
+    }
+
+    #[test]
+    fn test_eneatipo() {
+        assert_eq!(eneatipo(0.0), 1);
+        assert_eq!(eneatipo(4.0), 1);
+        assert_eq!(eneatipo(50.0), 5);
+        assert_eq!(eneatipo(96.5), 9);
+        assert_eq!(eneatipo(100.0), 9);
+    }
+}
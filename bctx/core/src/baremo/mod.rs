@@ -0,0 +1,3 @@
+pub mod domain;
+pub mod provider;
+pub mod use_case;
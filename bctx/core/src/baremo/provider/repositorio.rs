@@ -0,0 +1,13 @@
+use crate::baremo::domain::entity::baremo::Baremo;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RepositorioBaremoEscritura<Error>: Send + Sync {
+    /// Registra el baremo o reemplaza el existente con el mismo ID.
+    async fn guardar_baremo(&self, baremo: Baremo) -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait RepositorioBaremoLectura<Error>: Send + Sync {
+    async fn obtener_baremos(&self) -> Result<Vec<Baremo>, Error>;
+}
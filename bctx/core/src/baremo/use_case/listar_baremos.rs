@@ -0,0 +1,78 @@
+use crate::baremo::domain::entity::baremo::Baremo;
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::baremo::provider::repositorio::RepositorioBaremoLectura;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {}
+
+pub struct OutputFila {
+    pub puntaje: i64,
+    pub percentil: f64,
+}
+
+pub struct OutputData {
+    pub id: String,
+    pub nombre: String,
+    pub escala_tipo: String,
+    pub escala_valor: String,
+    pub genero: Option<String>,
+    pub edad_minima: Option<u32>,
+    pub edad_maxima: Option<u32>,
+    pub grado_instruccion: Option<String>,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<OutputFila>,
+}
+
+impl From<Baremo> for OutputData {
+    fn from(b: Baremo) -> Self {
+        Self {
+            id: b.id.to_string(),
+            nombre: b.nombre,
+            escala_tipo: b.escala.tipo().to_string(),
+            escala_valor: b.escala.valor(),
+            genero: b.grupo.genero.map(|g| g.to_string()),
+            edad_minima: b.grupo.edad_minima,
+            edad_maxima: b.grupo.edad_maxima,
+            grado_instruccion: b.grupo.grado_instruccion.map(|g| g.to_string()),
+            media: b.media,
+            desviacion_estandar: b.desviacion_estandar,
+            tabla: b
+                .tabla
+                .into_iter()
+                .map(|fila| OutputFila {
+                    puntaje: fila.puntaje,
+                    percentil: fila.percentil,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct ListaOutput {
+    pub baremos: Vec<OutputData>,
+}
+
+pub struct ListarBaremos<RepoErr> {
+    repositorio: Box<dyn RepositorioBaremoLectura<RepoErr>>,
+}
+
+impl<RepoErr> ListarBaremos<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBaremoLectura<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, ListaOutput, BaremoError> for ListarBaremos<RepoErr>
+where
+    BaremoError: From<RepoErr>,
+{
+    async fn ejecutar(&self, _in: InputData) -> Result<ListaOutput, BaremoError> {
+        let baremos = self.repositorio.obtener_baremos().await?;
+        Ok(ListaOutput {
+            baremos: baremos.into_iter().map(OutputData::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod listar_baremos;
+pub mod registrar_baremo;
@@ -0,0 +1,80 @@
+use crate::baremo::domain::entity::baremo::{Baremo, FilaBaremo};
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::baremo::domain::value_object::escala::Escala;
+use crate::baremo::domain::value_object::grupo_normativo::GrupoNormativo;
+use crate::baremo::domain::value_object::id::BaremoID;
+use crate::baremo::provider::repositorio::RepositorioBaremoEscritura;
+use crate::postulante::domain::value_object::genero::Genero;
+use crate::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+use std::str::FromStr;
+
+pub struct InputFila {
+    pub puntaje: i64,
+    pub percentil: f64,
+}
+
+pub struct InputData {
+    pub id: String,
+    pub nombre: String,
+    pub escala_tipo: String,
+    pub escala_valor: String,
+    pub genero: Option<String>,
+    pub edad_minima: Option<u32>,
+    pub edad_maxima: Option<u32>,
+    pub grado_instruccion: Option<String>,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<InputFila>,
+}
+
+pub struct RegistrarBaremo<RepoErr> {
+    repositorio: Box<dyn RepositorioBaremoEscritura<RepoErr>>,
+}
+
+impl<RepoErr> RegistrarBaremo<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBaremoEscritura<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), BaremoError> for RegistrarBaremo<RepoErr>
+where
+    BaremoError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), BaremoError> {
+        let genero = in_
+            .genero
+            .as_deref()
+            .map(Genero::from_str)
+            .transpose()
+            .map_err(|e| BaremoError::GrupoNormativoNoValido(e.to_string()))?;
+        let grado_instruccion = in_
+            .grado_instruccion
+            .as_deref()
+            .map(GradoInstruccion::from_str)
+            .transpose()
+            .map_err(|e| BaremoError::GrupoNormativoNoValido(e.to_string()))?;
+
+        let baremo = Baremo::new(
+            BaremoID::new(&in_.id)?,
+            in_.nombre,
+            Escala::new(&in_.escala_tipo, &in_.escala_valor)?,
+            GrupoNormativo::new(genero, in_.edad_minima, in_.edad_maxima, grado_instruccion)?,
+            in_.media,
+            in_.desviacion_estandar,
+            in_.tabla
+                .into_iter()
+                .map(|fila| FilaBaremo {
+                    puntaje: fila.puntaje,
+                    percentil: fila.percentil,
+                })
+                .collect(),
+        )?;
+
+        self.repositorio.guardar_baremo(baremo).await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::domain::value_object::accion::AccionEvaluacion;
+use crate::evaluacion::domain::value_object::criterio_resultado::CriterioResultado;
 use crate::evaluacion::domain::value_object::evaluacion_estado::EvaluacionEstado;
 use crate::evaluacion::value_object::id::EvaluacionID;
 use crate::examen::domain::service::lista_examenes::ListaDeExamenes;
@@ -8,6 +10,8 @@ pub struct Evaluacion {
     pub id: EvaluacionID,
     pub nombre: String,
     pub descripcion: String,
+    pub duracion_minutos: Option<u32>,
+    pub criterio: CriterioResultado,
     pub esta_activo: EstadoGeneral,
     pub estado: EvaluacionEstado,
     pub examenes: ListaDeExamenes,
@@ -31,17 +35,152 @@ impl Evaluacion {
             id,
             nombre,
             descripcion,
+            duracion_minutos: None,
+            criterio: CriterioResultado::default(),
             esta_activo,
             estado,
             examenes: ListaDeExamenes::new(Vec::new()),
         })
     }
 
-    pub fn publicar(&mut self) {
-        self.estado = EvaluacionEstado::Publicado
+    /// Tiempo maximo en minutos para rendir toda la evaluacion. Si es `None`
+    /// el limite se obtiene de la suma de las duraciones de los examenes.
+    pub fn asignar_duracion(
+        &mut self,
+        duracion_minutos: Option<u32>,
+    ) -> Result<(), EvaluacionError> {
+        if duracion_minutos == Some(0) {
+            return Err(EvaluacionError::DuracionNoValida);
+        }
+
+        self.duracion_minutos = duracion_minutos;
+        Ok(())
+    }
+
+    pub fn asignar_criterio(&mut self, criterio: CriterioResultado) {
+        self.criterio = criterio;
+    }
+
+    pub fn publicar(&mut self) -> Result<(), EvaluacionError> {
+        match self.estado {
+            EvaluacionEstado::Borrador => {
+                self.estado = EvaluacionEstado::Publicado;
+                Ok(())
+            }
+            EvaluacionEstado::Publicado => Err(EvaluacionError::EvaluacionYaFuePublicada),
+            EvaluacionEstado::Inactivo => {
+                Err(self.transicion_no_valida(AccionEvaluacion::Publicar))
+            }
+        }
+    }
+
+    /// Vuelve a borrador una evaluacion publicada. Solo es posible mientras
+    /// no tenga asignaciones, porque estas guardan la copia publicada.
+    pub fn despublicar(&mut self, asignaciones: u64) -> Result<(), EvaluacionError> {
+        if self.estado != EvaluacionEstado::Publicado {
+            return Err(self.transicion_no_valida(AccionEvaluacion::Despublicar));
+        }
+
+        if asignaciones > 0 {
+            return Err(EvaluacionError::EvaluacionConAsignaciones(asignaciones));
+        }
+
+        self.estado = EvaluacionEstado::Borrador;
+        Ok(())
+    }
+
+    /// Retira una evaluacion publicada: no admite nuevas asignaciones, pero
+    /// las existentes se pueden seguir rindiendo.
+    pub fn archivar(&mut self) -> Result<(), EvaluacionError> {
+        if self.estado != EvaluacionEstado::Publicado {
+            return Err(self.transicion_no_valida(AccionEvaluacion::Archivar));
+        }
+
+        self.estado = EvaluacionEstado::Inactivo;
+        Ok(())
+    }
+
+    /// Copia una evaluacion publicada o archivada como un nuevo borrador con
+    /// los mismos examenes.
+    pub fn clonar(&self, id: String) -> Result<Evaluacion, EvaluacionError> {
+        if self.estado == EvaluacionEstado::Borrador {
+            return Err(self.transicion_no_valida(AccionEvaluacion::Clonar));
+        }
+
+        let mut clon = Evaluacion::new(id, self.nombre.clone(), self.descripcion.clone())?;
+        clon.asignar_duracion(self.duracion_minutos)?;
+        clon.asignar_criterio(self.criterio.clone());
+        clon.examenes = self.examenes.clone();
+        Ok(clon)
     }
 
     pub fn esta_publicada(&self) -> bool {
         self.estado == EvaluacionEstado::Publicado
     }
+
+    fn transicion_no_valida(&self, accion: AccionEvaluacion) -> EvaluacionError {
+        EvaluacionError::TransicionNoValida {
+            estado: self.estado.to_string(),
+            accion: accion.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVALUACION_ID: &str = "3c9d2e7a-5b4f-4a1e-8d6c-0f2b7a9e4c18";
+    const CLON_ID: &str = "8e1f4a2b-9c3d-4e5f-a6b7-c8d9e0f1a2b3";
+
+    fn evaluacion() -> Evaluacion {
+        Evaluacion::new(
+            EVALUACION_ID.to_string(),
+            "Ingreso".to_string(),
+            "Evaluacion de ingreso".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ciclo_de_vida() {
+        let mut evaluacion = evaluacion();
+        assert!(matches!(
+            evaluacion.archivar(),
+            Err(EvaluacionError::TransicionNoValida { .. })
+        ));
+
+        evaluacion.publicar().unwrap();
+        assert!(matches!(
+            evaluacion.publicar(),
+            Err(EvaluacionError::EvaluacionYaFuePublicada)
+        ));
+        assert!(matches!(
+            evaluacion.despublicar(2),
+            Err(EvaluacionError::EvaluacionConAsignaciones(2))
+        ));
+
+        evaluacion.archivar().unwrap();
+        assert_eq!(evaluacion.estado, EvaluacionEstado::Inactivo);
+        assert!(matches!(
+            evaluacion.publicar(),
+            Err(EvaluacionError::TransicionNoValida { .. })
+        ));
+        assert!(evaluacion.despublicar(0).is_err());
+    }
+
+    #[test]
+    fn test_clonar_crea_un_borrador() {
+        let mut evaluacion = evaluacion();
+        assert!(evaluacion.clonar(CLON_ID.to_string()).is_err());
+
+        evaluacion.asignar_duracion(Some(45)).unwrap();
+        evaluacion.publicar().unwrap();
+        let clon = evaluacion.clonar(CLON_ID.to_string()).unwrap();
+
+        assert_eq!(clon.id.to_string(), CLON_ID);
+        assert_eq!(clon.estado, EvaluacionEstado::Borrador);
+        assert_eq!(clon.nombre, evaluacion.nombre);
+        assert_eq!(clon.duracion_minutos, Some(45));
+    }
 }
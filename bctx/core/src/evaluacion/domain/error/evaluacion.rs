@@ -1,3 +1,4 @@
+use crate::evaluacion::domain::service::validacion::ProblemaPublicacion;
 use crate::evaluacion::domain::value_object::evaluacion_estado::EvaluacionEstadoError;
 use quizz_common::domain::value_objects::estado::EstadoGeneralError;
 use quizz_common::domain::value_objects::id::IdError;
@@ -17,6 +18,12 @@ pub enum EvaluacionError {
     #[error("La descripcion no es valida")]
     DescripcionNoValida,
 
+    #[error("La duracion de la evaluacion no es valida")]
+    DuracionNoValida,
+
+    #[error("El criterio de resultado no es valido: {0}")]
+    CriterioNoValido(String),
+
     #[error("Error al manipular la base de datos: {0:?}")]
     EvaluacionRepositorioError(#[from] RepositorioError),
 
@@ -25,6 +32,21 @@ pub enum EvaluacionError {
 
     #[error("La evaluacion ya fue publicada")]
     EvaluacionYaFuePublicada,
+
+    #[error("La evaluacion tiene {} problemas que impiden publicarla", .0.len())]
+    PublicacionNoValida(Vec<ProblemaPublicacion>),
+
+    #[error("No se puede {accion} una evaluacion en estado {estado}")]
+    TransicionNoValida { estado: String, accion: String },
+
+    #[error("La evaluacion tiene {0} asignaciones y no puede volver a borrador")]
+    EvaluacionConAsignaciones(u64),
+
+    #[error("La accion no es valida: {0}")]
+    AccionNoValida(String),
+
+    #[error("Ya existe una evaluacion con el id {0}")]
+    EvaluacionYaExiste(String),
 }
 
 #[derive(Error, Debug)]
@@ -1,5 +1,5 @@
 pub mod entity;
 pub mod error;
 mod event;
-mod service;
+pub mod service;
 pub mod value_object;
@@ -1 +1 @@
-
+pub mod validacion;
@@ -0,0 +1,300 @@
+use crate::evaluacion::domain::entity::evaluacion::Evaluacion;
+use crate::examen::domain::entity::examen::Examen;
+use crate::pregunta::domain::entity::strategy::strategy::strategy_selection;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use quizz_common::domain::value_objects::estado::EstadoGeneral;
+use std::fmt;
+
+/// Un problema que impide publicar una evaluacion
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProblemaPublicacion {
+    SinExamenes,
+    ExamenDuplicado {
+        examen_id: String,
+    },
+    ExamenInactivo {
+        examen_id: String,
+    },
+    ExamenSinPreguntas {
+        examen_id: String,
+    },
+    MuestreoInsuficiente {
+        examen_id: String,
+        etiqueta: String,
+        cantidad: u32,
+        disponibles: usize,
+    },
+    PreguntaSinPuntaje {
+        examen_id: String,
+        pregunta_id: String,
+    },
+    PreguntaNoValida {
+        examen_id: String,
+        pregunta_id: String,
+        motivo: String,
+    },
+    CriterioSinExamen {
+        examen_id: String,
+    },
+}
+
+impl fmt::Display for ProblemaPublicacion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SinExamenes => write!(f, "la evaluacion no tiene examenes"),
+            Self::ExamenDuplicado { examen_id } => {
+                write!(f, "el examen {examen_id} esta mas de una vez")
+            }
+            Self::ExamenInactivo { examen_id } => write!(f, "el examen {examen_id} esta inactivo"),
+            Self::ExamenSinPreguntas { examen_id } => {
+                write!(f, "el examen {examen_id} no tiene preguntas")
+            }
+            Self::MuestreoInsuficiente {
+                examen_id,
+                etiqueta,
+                cantidad,
+                disponibles,
+            } => write!(
+                f,
+                "el examen {examen_id} sortea {cantidad} preguntas de {etiqueta} pero solo tiene {disponibles}"
+            ),
+            Self::PreguntaSinPuntaje {
+                examen_id,
+                pregunta_id,
+            } => write!(
+                f,
+                "la pregunta {pregunta_id} del examen {examen_id} no tiene puntaje"
+            ),
+            Self::PreguntaNoValida {
+                examen_id,
+                pregunta_id,
+                motivo,
+            } => write!(
+                f,
+                "la pregunta {pregunta_id} del examen {examen_id} no es valida: {motivo}"
+            ),
+            Self::CriterioSinExamen { examen_id } => write!(
+                f,
+                "el criterio de resultado usa el examen {examen_id}, que no esta en la evaluacion"
+            ),
+        }
+    }
+}
+
+/// Revisa la evaluacion completa antes de publicarla y devuelve todos los
+/// problemas encontrados. Las preguntas se validan con la misma estrategia
+/// por tipo que se usa al crearlas.
+pub fn validar_publicacion(evaluacion: &Evaluacion) -> Vec<ProblemaPublicacion> {
+    let examenes = evaluacion.examenes.examenes();
+    let mut problemas = Vec::new();
+
+    if examenes.is_empty() {
+        problemas.push(ProblemaPublicacion::SinExamenes);
+    }
+
+    for (i, examen) in examenes.iter().enumerate() {
+        let examen_id = examen.id.to_string();
+        if examenes[..i]
+            .iter()
+            .any(|otro| otro.id.to_string() == examen_id)
+        {
+            problemas.push(ProblemaPublicacion::ExamenDuplicado { examen_id });
+            continue;
+        }
+        problemas.extend(validar_examen(examen));
+    }
+
+    for umbral in &evaluacion.criterio.examenes {
+        if !examenes
+            .iter()
+            .any(|examen| examen.id.to_string() == umbral.examen_id)
+        {
+            problemas.push(ProblemaPublicacion::CriterioSinExamen {
+                examen_id: umbral.examen_id.clone(),
+            });
+        }
+    }
+
+    problemas
+}
+
+fn validar_examen(examen: &Examen) -> Vec<ProblemaPublicacion> {
+    let examen_id = examen.id.to_string();
+    let preguntas = examen.preguntas.preguntas();
+    let mut problemas = Vec::new();
+
+    if matches!(examen.estado, EstadoGeneral::Inactivo) {
+        problemas.push(ProblemaPublicacion::ExamenInactivo {
+            examen_id: examen_id.clone(),
+        });
+    }
+
+    if preguntas.is_empty() {
+        problemas.push(ProblemaPublicacion::ExamenSinPreguntas {
+            examen_id: examen_id.clone(),
+        });
+    }
+
+    for regla in &examen.muestreo.reglas {
+        let disponibles = preguntas
+            .iter()
+            .filter(|pregunta| pregunta.etiqueta == regla.etiqueta)
+            .count();
+        if (regla.cantidad as usize) > disponibles {
+            problemas.push(ProblemaPublicacion::MuestreoInsuficiente {
+                examen_id: examen_id.clone(),
+                etiqueta: regla.etiqueta.to_string(),
+                cantidad: regla.cantidad,
+                disponibles,
+            });
+        }
+    }
+
+    for pregunta in preguntas {
+        let pregunta_id = pregunta.id.to_string();
+        match strategy_selection(&pregunta.tipo_de_pregunta)
+            .verify(&pregunta.alternativas, &pregunta.puntaje)
+        {
+            Ok(()) => {}
+            Err(PreguntaError::PuntajeNoExiste | PreguntaError::PuntajeVacio) => {
+                problemas.push(ProblemaPublicacion::PreguntaSinPuntaje {
+                    examen_id: examen_id.clone(),
+                    pregunta_id,
+                })
+            }
+            Err(e) => problemas.push(ProblemaPublicacion::PreguntaNoValida {
+                examen_id: examen_id.clone(),
+                pregunta_id,
+                motivo: e.to_string(),
+            }),
+        }
+    }
+
+    problemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::value_object::criterio_resultado::{
+        CriterioResultado, Umbral, UmbralExamen,
+    };
+    use crate::examen::domain::service::lista_examenes::ListaDeExamenes;
+    use crate::examen::domain::value_object::muestreo::{Muestreo, ReglaMuestreo};
+    use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+    use crate::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
+    use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+    use std::collections::HashMap;
+
+    const EVALUACION_ID: &str = "3c9d2e7a-5b4f-4a1e-8d6c-0f2b7a9e4c18";
+    const EXAMEN_1: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+    const EXAMEN_2: &str = "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d";
+    const EXAMEN_3: &str = "6d5c4b3a-2f1e-4d0c-9b8a-7f6e5d4c3b2a";
+
+    fn pregunta() -> PreguntaEntity {
+        PreguntaEntity::new(
+            "Cuanto es 2 + 2".to_string(),
+            "no".to_string(),
+            "alternativa_unica".to_string(),
+            None,
+            HashMap::from([
+                ("A".to_string(), "3".to_string()),
+                ("B".to_string(), "4".to_string()),
+            ]),
+            HashMap::from([("B".to_string(), 1)]),
+        )
+        .unwrap()
+    }
+
+    fn examen(id: &str, preguntas: Vec<PreguntaEntity>) -> Examen {
+        let mut examen = Examen::new(
+            id.to_string(),
+            "Aritmetica".to_string(),
+            "Sumas".to_string(),
+            "Responda".to_string(),
+        )
+        .unwrap();
+        examen.preguntas = ListaDePreguntas::new(preguntas);
+        examen
+    }
+
+    fn evaluacion(examenes: Vec<Examen>) -> Evaluacion {
+        let mut evaluacion = Evaluacion::new(
+            EVALUACION_ID.to_string(),
+            "Ingreso".to_string(),
+            "Evaluacion de ingreso".to_string(),
+        )
+        .unwrap();
+        evaluacion.examenes = ListaDeExamenes::new(examenes);
+        evaluacion
+    }
+
+    #[test]
+    fn test_evaluacion_valida() {
+        let evaluacion = evaluacion(vec![examen(EXAMEN_1, vec![pregunta()])]);
+        assert!(validar_publicacion(&evaluacion).is_empty());
+        assert_eq!(
+            validar_publicacion(&self::evaluacion(Vec::new())),
+            vec![ProblemaPublicacion::SinExamenes]
+        );
+    }
+
+    #[test]
+    fn test_reporta_todos_los_problemas() {
+        let mut sin_puntaje = pregunta();
+        sin_puntaje.puntaje.clear();
+        let sin_puntaje_id = sin_puntaje.id.to_string();
+
+        let mut inactivo = examen(EXAMEN_1, vec![pregunta(), sin_puntaje]);
+        inactivo.estado = EstadoGeneral::Inactivo;
+        inactivo.muestreo = Muestreo::new(vec![ReglaMuestreo {
+            etiqueta: Etiqueta::No,
+            cantidad: 3,
+        }])
+        .unwrap();
+
+        let mut evaluacion = evaluacion(vec![
+            inactivo,
+            examen(EXAMEN_2, Vec::new()),
+            examen(EXAMEN_2, Vec::new()),
+        ]);
+        evaluacion.asignar_criterio(
+            CriterioResultado::new(
+                None,
+                vec![UmbralExamen {
+                    examen_id: EXAMEN_3.to_string(),
+                    umbral: Umbral::new(10, None).unwrap(),
+                }],
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            validar_publicacion(&evaluacion),
+            vec![
+                ProblemaPublicacion::ExamenInactivo {
+                    examen_id: EXAMEN_1.to_string()
+                },
+                ProblemaPublicacion::MuestreoInsuficiente {
+                    examen_id: EXAMEN_1.to_string(),
+                    etiqueta: "no".to_string(),
+                    cantidad: 3,
+                    disponibles: 2,
+                },
+                ProblemaPublicacion::PreguntaSinPuntaje {
+                    examen_id: EXAMEN_1.to_string(),
+                    pregunta_id: sin_puntaje_id,
+                },
+                ProblemaPublicacion::ExamenSinPreguntas {
+                    examen_id: EXAMEN_2.to_string()
+                },
+                ProblemaPublicacion::ExamenDuplicado {
+                    examen_id: EXAMEN_2.to_string()
+                },
+                ProblemaPublicacion::CriterioSinExamen {
+                    examen_id: EXAMEN_3.to_string()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Transiciones del ciclo de vida de una evaluacion
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AccionEvaluacion {
+    #[default]
+    Publicar,
+    Despublicar,
+    Archivar,
+    Clonar,
+}
+
+impl fmt::Display for AccionEvaluacion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Publicar => write!(f, "publicar"),
+            Self::Despublicar => write!(f, "despublicar"),
+            Self::Archivar => write!(f, "archivar"),
+            Self::Clonar => write!(f, "clonar"),
+        }
+    }
+}
+
+impl FromStr for AccionEvaluacion {
+    type Err = EvaluacionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "publicar" => Ok(Self::Publicar),
+            "despublicar" => Ok(Self::Despublicar),
+            "archivar" => Ok(Self::Archivar),
+            "clonar" => Ok(Self::Clonar),
+            _ => Err(EvaluacionError::AccionNoValida(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::domain::value_object::resultado::Resultado;
+
+/// Puntajes de corte: desde `apto` el resultado es apto, desde `observado`
+/// queda observado y por debajo es no apto. Sin `observado` no hay zona
+/// intermedia.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Umbral {
+    pub apto: i64,
+    pub observado: Option<i64>,
+}
+
+impl Umbral {
+    pub fn new(apto: i64, observado: Option<i64>) -> Result<Self, EvaluacionError> {
+        if observado.is_some_and(|observado| observado > apto) {
+            return Err(EvaluacionError::CriterioNoValido(format!(
+                "el corte de observado no puede superar al de apto ({apto})"
+            )));
+        }
+
+        Ok(Self { apto, observado })
+    }
+
+    pub fn clasificar(&self, puntos: i64) -> Resultado {
+        if puntos >= self.apto {
+            Resultado::Apto
+        } else if self.observado.is_some_and(|observado| puntos >= observado) {
+            Resultado::Observado
+        } else {
+            Resultado::NoApto
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UmbralExamen {
+    pub examen_id: String,
+    pub umbral: Umbral,
+}
+
+/// Reglas de corte de una evaluacion sobre el puntaje total y sobre el
+/// puntaje de cada examen.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CriterioResultado {
+    pub total: Option<Umbral>,
+    pub examenes: Vec<UmbralExamen>,
+}
+
+impl CriterioResultado {
+    pub fn new(
+        total: Option<Umbral>,
+        examenes: Vec<UmbralExamen>,
+    ) -> Result<Self, EvaluacionError> {
+        for (i, examen) in examenes.iter().enumerate() {
+            if examenes[..i]
+                .iter()
+                .any(|otro| otro.examen_id == examen.examen_id)
+            {
+                return Err(EvaluacionError::CriterioNoValido(format!(
+                    "el examen {} tiene mas de una regla",
+                    examen.examen_id
+                )));
+            }
+        }
+
+        Ok(Self { total, examenes })
+    }
+
+    pub fn esta_vacio(&self) -> bool {
+        self.total.is_none() && self.examenes.is_empty()
+    }
+
+    /// Resultado sugerido: el peor de los dictamenes de cada regla. Las reglas
+    /// de examenes que no forman parte de la respuesta se ignoran. `None` si
+    /// la evaluacion no define reglas.
+    pub fn sugerir(&self, puntos_por_examen: &[(String, i64)]) -> Option<Resultado> {
+        let total: i64 = puntos_por_examen.iter().map(|(_, puntos)| puntos).sum();
+
+        let por_total = self.total.as_ref().map(|umbral| umbral.clasificar(total));
+        let por_examen = self.examenes.iter().filter_map(|regla| {
+            puntos_por_examen
+                .iter()
+                .find(|(examen_id, _)| *examen_id == regla.examen_id)
+                .map(|(_, puntos)| regla.umbral.clasificar(*puntos))
+        });
+
+        por_total.into_iter().chain(por_examen).min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMEN_A: &str = "19573e4f-321d-41ad-a8a9-3807c6fd3d65";
+    const EXAMEN_B: &str = "7e0b4c2d-9a15-4f63-b8d7-2c1e5a9f0b34";
+
+    fn puntos(a: i64, b: i64) -> Vec<(String, i64)> {
+        vec![(EXAMEN_A.to_string(), a), (EXAMEN_B.to_string(), b)]
+    }
+
+    #[test]
+    fn test_umbral_clasifica() {
+        let umbral = Umbral::new(30, Some(20)).unwrap();
+        assert_eq!(umbral.clasificar(30), Resultado::Apto);
+        assert_eq!(umbral.clasificar(25), Resultado::Observado);
+        assert_eq!(umbral.clasificar(19), Resultado::NoApto);
+
+        let sin_zona = Umbral::new(30, None).unwrap();
+        assert_eq!(sin_zona.clasificar(29), Resultado::NoApto);
+    }
+
+    #[test]
+    fn test_umbral_no_valido() {
+        assert!(Umbral::new(20, Some(30)).is_err());
+    }
+
+    #[test]
+    fn test_sugerir_toma_el_peor_dictamen() {
+        let criterio = CriterioResultado::new(
+            Some(Umbral::new(30, Some(20)).unwrap()),
+            vec![UmbralExamen {
+                examen_id: EXAMEN_B.to_string(),
+                umbral: Umbral::new(10, None).unwrap(),
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(criterio.sugerir(&puntos(25, 10)), Some(Resultado::Apto));
+        assert_eq!(
+            criterio.sugerir(&puntos(15, 10)),
+            Some(Resultado::Observado)
+        );
+        assert_eq!(criterio.sugerir(&puntos(30, 5)), Some(Resultado::NoApto));
+    }
+
+    #[test]
+    fn test_sin_reglas_no_sugiere() {
+        let criterio = CriterioResultado::default();
+        assert!(criterio.esta_vacio());
+        assert_eq!(criterio.sugerir(&puntos(25, 10)), None);
+    }
+
+    #[test]
+    fn test_regla_repetida_por_examen() {
+        let regla = UmbralExamen {
+            examen_id: EXAMEN_A.to_string(),
+            umbral: Umbral::new(10, None).unwrap(),
+        };
+        assert!(CriterioResultado::new(None, vec![regla.clone(), regla]).is_err());
+    }
+}
@@ -13,6 +13,7 @@ pub enum EvaluacionEstado {
     #[default]
     Borrador,
     Publicado,
+    Inactivo,
 }
 
 impl fmt::Display for EvaluacionEstado {
@@ -20,6 +21,7 @@ impl fmt::Display for EvaluacionEstado {
         match self {
             Self::Borrador => write!(f, "borrador"),
             Self::Publicado => write!(f, "publicado"),
+            Self::Inactivo => write!(f, "inactivo"),
         }
     }
 }
@@ -31,6 +33,7 @@ impl FromStr for EvaluacionEstado {
         match s.to_lowercase().as_str() {
             "borrador" => Ok(EvaluacionEstado::Borrador),
             "publicado" => Ok(EvaluacionEstado::Publicado),
+            "inactivo" => Ok(EvaluacionEstado::Inactivo),
             _ => Err(EvaluacionEstadoError::NoValido),
         }
     }
@@ -1 +1,4 @@
+pub mod accion;
+pub mod criterio_resultado;
 pub mod evaluacion_estado;
+pub mod resultado;
@@ -0,0 +1,41 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ResultadoError {
+    #[error("Resultado no valido, se espera apto, no_apto u observado")]
+    NoValido,
+}
+
+/// Dictamen de una evaluacion. El orden de las variantes va de la peor a la
+/// mejor, de modo que el dictamen combinado es el minimo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Resultado {
+    NoApto,
+    Observado,
+    Apto,
+}
+
+impl fmt::Display for Resultado {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoApto => write!(f, "no_apto"),
+            Self::Observado => write!(f, "observado"),
+            Self::Apto => write!(f, "apto"),
+        }
+    }
+}
+
+impl FromStr for Resultado {
+    type Err = ResultadoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "apto" => Ok(Resultado::Apto),
+            "no_apto" | "no apto" => Ok(Resultado::NoApto),
+            "observado" => Ok(Resultado::Observado),
+            _ => Err(ResultadoError::NoValido),
+        }
+    }
+}
@@ -27,6 +27,16 @@ pub trait RepositorioPublicarEvaluacion<Error>:
     async fn publicar_evaluacion(&self, evaluacion: Evaluacion) -> Result<(), Error>;
 }
 
+/// Cambios de estado posteriores a la publicacion
+#[async_trait]
+pub trait RepositorioCicloEvaluacion<Error>:
+    Send + Sync + RepositorioLeerEvaluacion<Error>
+{
+    async fn contar_asignaciones(&self, evaluacion_id: &EvaluacionID) -> Result<u64, Error>;
+
+    async fn cambiar_estado(&self, evaluacion: Evaluacion) -> Result<(), Error>;
+}
+
 #[async_trait]
 pub trait RepositorioEvaluacionListar<Error>: Send + Sync {
     async fn listar_evaluaciones(&self) -> Result<Vec<OutputData>, Error>;
@@ -0,0 +1,38 @@
+use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::provider::repositorio::RepositorioCicloEvaluacion;
+use crate::evaluacion::value_object::id::EvaluacionID;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub evaluacion_id: String,
+}
+
+pub struct ArchivarEvaluacion<RepoErr> {
+    repositorio: Box<dyn RepositorioCicloEvaluacion<RepoErr>>,
+}
+
+impl<RepoErr> ArchivarEvaluacion<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioCicloEvaluacion<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), EvaluacionError> for ArchivarEvaluacion<RepoErr>
+where
+    EvaluacionError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), EvaluacionError> {
+        let mut evaluacion = self
+            .repositorio
+            .obtener_evaluacion(EvaluacionID::new(in_.evaluacion_id.as_str())?)
+            .await?;
+
+        evaluacion.archivar()?;
+        self.repositorio.cambiar_estado(evaluacion).await?;
+
+        Ok(())
+    }
+}
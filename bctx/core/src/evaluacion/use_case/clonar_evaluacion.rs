@@ -0,0 +1,80 @@
+use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::domain::error::evaluacion::RepositorioError::EvaluacionNoExiste;
+use crate::evaluacion::provider::repositorio::{
+    RepositorioEvaluacionEscritura, RepositorioLeerEvaluacion,
+};
+use crate::evaluacion::value_object::examen_id::ExamenIDs;
+use crate::evaluacion::value_object::id::EvaluacionID;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub evaluacion_id: String,
+    pub nuevo_id: Option<String>,
+}
+
+/// Crea un borrador a partir de una evaluacion publicada o archivada. El
+/// borrador referencia los examenes vigentes, no la copia publicada. Sin
+/// `nuevo_id` se genera uno; el caso de uso devuelve el id del borrador.
+pub struct ClonarEvaluacion<RepoErr> {
+    lectura: Box<dyn RepositorioLeerEvaluacion<RepoErr>>,
+    escritura: Box<dyn RepositorioEvaluacionEscritura<RepoErr>>,
+}
+
+impl<RepoErr> ClonarEvaluacion<RepoErr> {
+    pub fn new(
+        lectura: Box<dyn RepositorioLeerEvaluacion<RepoErr>>,
+        escritura: Box<dyn RepositorioEvaluacionEscritura<RepoErr>>,
+    ) -> Self {
+        Self { lectura, escritura }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, String, EvaluacionError> for ClonarEvaluacion<RepoErr>
+where
+    EvaluacionError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<String, EvaluacionError> {
+        let original = self
+            .lectura
+            .obtener_evaluacion(EvaluacionID::new(in_.evaluacion_id.as_str())?)
+            .await?;
+
+        let nuevo_id = match in_.nuevo_id {
+            Some(id) => EvaluacionID::new(&id)?,
+            None => EvaluacionID::new_v4(),
+        }
+        .to_string();
+
+        match self
+            .lectura
+            .obtener_evaluacion(EvaluacionID::new(&nuevo_id)?)
+            .await
+            .map_err(EvaluacionError::from)
+        {
+            Ok(_) => return Err(EvaluacionError::EvaluacionYaExiste(nuevo_id)),
+            Err(EvaluacionError::EvaluacionRepositorioError(EvaluacionNoExiste)) => {}
+            Err(e) => return Err(e),
+        }
+
+        let clon = original.clonar(nuevo_id.clone())?;
+        let examen_ids = ExamenIDs::new(
+            clon.examenes
+                .examenes()
+                .iter()
+                .map(|examen| examen.id.to_string())
+                .collect(),
+        );
+
+        self.escritura.guardar_evaluacion(clon).await?;
+        if !examen_ids.examen_ids.is_empty() {
+            self.escritura
+                .agregar_examen(EvaluacionID::new(&nuevo_id)?, examen_ids)
+                .await?;
+        }
+
+        Ok(nuevo_id)
+    }
+}
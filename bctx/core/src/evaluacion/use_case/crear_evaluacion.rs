@@ -1,6 +1,10 @@
 use crate::evaluacion::domain::entity::evaluacion::Evaluacion;
 use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::domain::value_object::criterio_resultado::{
+    CriterioResultado, Umbral, UmbralExamen,
+};
 use crate::evaluacion::provider::repositorio::RepositorioEvaluacionEscritura;
+use crate::examen::domain::value_object::id::ExamenID;
 use async_trait::async_trait;
 use quizz_common::use_case::CasoDeUso;
 
@@ -9,6 +13,22 @@ pub struct InputData {
     pub id: String,
     pub titulo: String,
     pub descripcion: String,
+    pub duracion_minutos: Option<u32>,
+    pub criterio_total: Option<InputUmbral>,
+    pub criterio_examenes: Vec<InputUmbralExamen>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputUmbral {
+    pub apto: i64,
+    pub observado: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputUmbralExamen {
+    pub examen_id: String,
+    pub apto: i64,
+    pub observado: Option<i64>,
 }
 
 pub struct CrearEvaluacion<RepoErr> {
@@ -27,9 +47,26 @@ where
     EvaluacionError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<(), EvaluacionError> {
-        self.repositorio
-            .guardar_evaluacion(Evaluacion::new(in_.id, in_.titulo, in_.descripcion)?)
-            .await?;
+        let mut evaluacion = Evaluacion::new(in_.id, in_.titulo, in_.descripcion)?;
+        evaluacion.asignar_duracion(in_.duracion_minutos)?;
+
+        let total = in_
+            .criterio_total
+            .map(|umbral| Umbral::new(umbral.apto, umbral.observado))
+            .transpose()?;
+        let examenes = in_
+            .criterio_examenes
+            .into_iter()
+            .map(|regla| {
+                Ok(UmbralExamen {
+                    examen_id: ExamenID::new(&regla.examen_id)?.to_string(),
+                    umbral: Umbral::new(regla.apto, regla.observado)?,
+                })
+            })
+            .collect::<Result<Vec<UmbralExamen>, EvaluacionError>>()?;
+        evaluacion.asignar_criterio(CriterioResultado::new(total, examenes)?);
+
+        self.repositorio.guardar_evaluacion(evaluacion).await?;
         Ok(())
     }
 }
@@ -0,0 +1,125 @@
+use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::provider::repositorio::RepositorioCicloEvaluacion;
+use crate::evaluacion::value_object::id::EvaluacionID;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub evaluacion_id: String,
+}
+
+pub struct DespublicarEvaluacion<RepoErr> {
+    repositorio: Box<dyn RepositorioCicloEvaluacion<RepoErr>>,
+}
+
+impl<RepoErr> DespublicarEvaluacion<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioCicloEvaluacion<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), EvaluacionError> for DespublicarEvaluacion<RepoErr>
+where
+    EvaluacionError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), EvaluacionError> {
+        let evaluacion_id = EvaluacionID::new(in_.evaluacion_id.as_str())?;
+        let asignaciones = self.repositorio.contar_asignaciones(&evaluacion_id).await?;
+        let mut evaluacion = self.repositorio.obtener_evaluacion(evaluacion_id).await?;
+
+        evaluacion.despublicar(asignaciones)?;
+        self.repositorio.cambiar_estado(evaluacion).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::entity::evaluacion::Evaluacion;
+    use crate::evaluacion::domain::value_object::evaluacion_estado::EvaluacionEstado;
+    use crate::evaluacion::provider::repositorio::RepositorioLeerEvaluacion;
+    use std::sync::{Arc, Mutex};
+
+    const EVALUACION_ID: &str = "3c9d2e7a-5b4f-4a1e-8d6c-0f2b7a9e4c18";
+
+    struct MockRepositorio {
+        asignaciones: u64,
+        estado_guardado: Arc<Mutex<Option<EvaluacionEstado>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioLeerEvaluacion<EvaluacionError> for MockRepositorio {
+        async fn obtener_evaluacion(
+            &self,
+            evaluacion_id: EvaluacionID,
+        ) -> Result<Evaluacion, EvaluacionError> {
+            let mut evaluacion = Evaluacion::new(
+                evaluacion_id.to_string(),
+                "Ingreso".to_string(),
+                "Evaluacion de ingreso".to_string(),
+            )?;
+            evaluacion.publicar()?;
+            Ok(evaluacion)
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioCicloEvaluacion<EvaluacionError> for MockRepositorio {
+        async fn contar_asignaciones(
+            &self,
+            _evaluacion_id: &EvaluacionID,
+        ) -> Result<u64, EvaluacionError> {
+            Ok(self.asignaciones)
+        }
+
+        async fn cambiar_estado(&self, evaluacion: Evaluacion) -> Result<(), EvaluacionError> {
+            *self.estado_guardado.lock().unwrap() = Some(evaluacion.estado);
+            Ok(())
+        }
+    }
+
+    fn caso(
+        asignaciones: u64,
+    ) -> (
+        DespublicarEvaluacion<EvaluacionError>,
+        Arc<Mutex<Option<EvaluacionEstado>>>,
+    ) {
+        let estado_guardado = Arc::new(Mutex::new(None));
+        let caso = DespublicarEvaluacion::new(Box::new(MockRepositorio {
+            asignaciones,
+            estado_guardado: estado_guardado.clone(),
+        }));
+        (caso, estado_guardado)
+    }
+
+    fn input() -> InputData {
+        InputData {
+            evaluacion_id: EVALUACION_ID.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_despublica_sin_asignaciones() {
+        let (caso, estado_guardado) = caso(0);
+        caso.ejecutar(input()).await.unwrap();
+        assert_eq!(
+            *estado_guardado.lock().unwrap(),
+            Some(EvaluacionEstado::Borrador)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_despublica_con_asignaciones() {
+        let (caso, estado_guardado) = caso(3);
+        let resultado = caso.ejecutar(input()).await;
+        assert!(matches!(
+            resultado,
+            Err(EvaluacionError::EvaluacionConAsignaciones(3))
+        ));
+        assert!(estado_guardado.lock().unwrap().is_none());
+    }
+}
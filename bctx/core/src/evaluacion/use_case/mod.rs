@@ -1,4 +1,7 @@
 pub mod agregar_examen;
+pub mod archivar_evaluacion;
+pub mod clonar_evaluacion;
 pub mod crear_evaluacion;
+pub mod despublicar_evaluacion;
 pub mod listar_evaluaciones;
 pub mod publicar_evaluacion;
@@ -1,4 +1,5 @@
 use crate::evaluacion::domain::error::evaluacion::EvaluacionError;
+use crate::evaluacion::domain::service::validacion::validar_publicacion;
 use crate::evaluacion::provider::repositorio::RepositorioPublicarEvaluacion;
 use crate::evaluacion::value_object::id::EvaluacionID;
 use async_trait::async_trait;
@@ -25,13 +26,19 @@ where
     EvaluacionError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<(), EvaluacionError> {
-        let evaluacion = self
+        let mut evaluacion = self
             .repositorio
             .obtener_evaluacion(EvaluacionID::new(in_.evaluacion_id.as_str())?)
             .await?;
 
-        if evaluacion.esta_publicada() {
-            return Err(EvaluacionError::EvaluacionYaFuePublicada);
+        evaluacion.publicar()?;
+
+        let problemas = validar_publicacion(&evaluacion);
+        if !problemas.is_empty() {
+            return Err(EvaluacionError::PublicacionNoValida(problemas));
+        }
+        for examen in evaluacion.examenes.examenes_mut() {
+            examen.congelar_version();
         }
 
         self.repositorio.publicar_evaluacion(evaluacion).await?;
@@ -18,6 +18,12 @@ impl EvaluacionID {
         ID::new(id, IdType::Evaluacion).map(|id| EvaluacionID { id })
     }
 
+    pub fn new_v4() -> Self {
+        EvaluacionID {
+            id: ID::new_v4(IdType::Evaluacion),
+        }
+    }
+
     pub fn value(&self) -> &ID {
         &self.id
     }
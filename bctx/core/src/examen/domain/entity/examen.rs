@@ -1,5 +1,10 @@
 use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::domain::service::contenido::hash_contenido;
+use crate::examen::domain::value_object::aleatorizacion::Aleatorizacion;
 use crate::examen::domain::value_object::id::ExamenID;
+use crate::examen::domain::value_object::muestreo::Muestreo;
+use crate::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
+use crate::examen::domain::value_object::version::Version;
 use crate::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
 use quizz_common::domain::value_objects::estado::EstadoGeneral;
 
@@ -9,8 +14,13 @@ pub struct Examen {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    pub duracion_minutos: Option<u32>,
     pub estado: EstadoGeneral,
     pub preguntas: ListaDePreguntas,
+    pub politica_puntaje: PoliticaPuntaje,
+    pub aleatorizacion: Aleatorizacion,
+    pub muestreo: Muestreo,
+    pub version: Version,
 }
 
 impl Examen {
@@ -36,8 +46,192 @@ impl Examen {
             titulo,
             descripcion,
             instrucciones,
+            duracion_minutos: None,
             estado,
             preguntas: ListaDePreguntas::new(Vec::new()),
+            politica_puntaje: PoliticaPuntaje::default(),
+            aleatorizacion: Aleatorizacion::default(),
+            muestreo: Muestreo::default(),
+            version: Version::default(),
         })
     }
+
+    /// Tiempo maximo en minutos para rendir el examen. `None` indica que el
+    /// examen no tiene limite propio.
+    pub fn asignar_duracion(&mut self, duracion_minutos: Option<u32>) -> Result<(), ExamenError> {
+        if duracion_minutos == Some(0) {
+            return Err(ExamenError::DuracionInvalida);
+        }
+
+        self.duracion_minutos = duracion_minutos;
+        Ok(())
+    }
+
+    pub fn actualizar_textos(
+        &mut self,
+        titulo: Option<String>,
+        descripcion: Option<String>,
+        instrucciones: Option<String>,
+    ) -> Result<(), ExamenError> {
+        if let Some(titulo) = titulo {
+            if titulo.trim().is_empty() {
+                return Err(ExamenError::TituloInvalido);
+            }
+            self.titulo = titulo;
+        }
+
+        if let Some(descripcion) = descripcion {
+            if descripcion.trim().is_empty() {
+                return Err(ExamenError::DescripcionInvalida);
+            }
+            self.descripcion = descripcion;
+        }
+
+        if let Some(instrucciones) = instrucciones {
+            self.instrucciones = instrucciones;
+        }
+        Ok(())
+    }
+
+    /// Ordena las preguntas segun los ids indicados. Las que no figuran quedan
+    /// al final en su orden actual.
+    pub fn reordenar_preguntas(&mut self, orden: &[String]) -> Result<(), ExamenError> {
+        let preguntas = self.preguntas.preguntas();
+        for (i, id) in orden.iter().enumerate() {
+            if !preguntas
+                .iter()
+                .any(|pregunta| &pregunta.id.to_string() == id)
+            {
+                return Err(ExamenError::OrdenNoValido(format!(
+                    "la pregunta {id} no pertenece al examen"
+                )));
+            }
+            if orden[..i].contains(id) {
+                return Err(ExamenError::OrdenNoValido(format!(
+                    "la pregunta {id} esta repetida"
+                )));
+            }
+        }
+
+        let mut preguntas = preguntas.clone();
+        preguntas.sort_by_key(|pregunta| {
+            let id = pregunta.id.to_string();
+            orden
+                .iter()
+                .position(|otro| otro == &id)
+                .unwrap_or(usize::MAX)
+        });
+        self.preguntas = ListaDePreguntas::new(preguntas);
+        Ok(())
+    }
+
+    /// Baja logica; el examen y sus versiones se conservan.
+    pub fn desactivar(&mut self) {
+        self.estado = EstadoGeneral::Inactivo;
+    }
+
+    /// Aplica cambios al contenido. Si el contenido cambio y la version vigente
+    /// ya se publico, se abre un borrador nuevo.
+    pub fn editar_contenido<F>(&mut self, cambios: F) -> Result<(), ExamenError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), ExamenError>,
+    {
+        let antes = hash_contenido(self);
+        cambios(self)?;
+        if hash_contenido(self) != antes {
+            self.version.editar();
+        }
+        Ok(())
+    }
+
+    /// Congela la version vigente al publicar. Devuelve `true` si el contenido
+    /// produjo una version nueva.
+    pub fn congelar_version(&mut self) -> bool {
+        let hash = hash_contenido(self);
+        self.version.congelar(hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+    use crate::pregunta::domain::value_object::id::PreguntaID;
+    use std::collections::HashMap;
+
+    const EXAMEN_ID: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+    const PREGUNTA_1: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+    const PREGUNTA_2: &str = "0b1c2d3e-4f5a-4b6c-9d7e-8f9a0b1c2d3e";
+    const PREGUNTA_3: &str = "9e8d7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b";
+
+    fn examen() -> Examen {
+        let mut examen = Examen::new(
+            EXAMEN_ID.to_string(),
+            "Aritmetica".to_string(),
+            "Sumas".to_string(),
+            "Responda".to_string(),
+        )
+        .unwrap();
+        let preguntas = [PREGUNTA_1, PREGUNTA_2, PREGUNTA_3]
+            .iter()
+            .map(|id| {
+                let mut pregunta = PreguntaEntity::new(
+                    "Cuanto es 2 + 2".to_string(),
+                    "no".to_string(),
+                    "alternativa_unica".to_string(),
+                    None,
+                    HashMap::from([
+                        ("A".to_string(), "3".to_string()),
+                        ("B".to_string(), "4".to_string()),
+                    ]),
+                    HashMap::from([("B".to_string(), 1)]),
+                )
+                .unwrap();
+                pregunta.id = PreguntaID::new(id).unwrap();
+                pregunta
+            })
+            .collect();
+        examen.preguntas = ListaDePreguntas::new(preguntas);
+        examen
+    }
+
+    fn ids(examen: &Examen) -> Vec<String> {
+        examen
+            .preguntas
+            .preguntas()
+            .iter()
+            .map(|pregunta| pregunta.id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_reordenar_deja_al_final_las_preguntas_no_indicadas() {
+        let mut examen = examen();
+        examen
+            .reordenar_preguntas(&[PREGUNTA_3.to_string(), PREGUNTA_1.to_string()])
+            .unwrap();
+        assert_eq!(ids(&examen), vec![PREGUNTA_3, PREGUNTA_1, PREGUNTA_2]);
+
+        let resultado = examen.reordenar_preguntas(&[EXAMEN_ID.to_string()]);
+        assert!(matches!(resultado, Err(ExamenError::OrdenNoValido(_))));
+    }
+
+    #[test]
+    fn test_editar_contenido_publicado_abre_un_borrador() {
+        let mut examen = examen();
+        examen.congelar_version();
+
+        examen
+            .editar_contenido(|examen| examen.actualizar_textos(None, None, None))
+            .unwrap();
+        assert!(examen.version.esta_publicada());
+
+        examen
+            .editar_contenido(|examen| {
+                examen.actualizar_textos(Some("Aritmetica basica".to_string()), None, None)
+            })
+            .unwrap();
+        assert!(!examen.version.esta_publicada());
+        assert_eq!(examen.version.numero, 2);
+    }
 }
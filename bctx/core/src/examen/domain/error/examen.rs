@@ -48,6 +48,21 @@ pub enum ExamenError {
 
     #[error("Tipo de examen no valido")]
     TipoExamenNoValido,
+
+    #[error("Politica de puntaje no valida: {0}")]
+    PoliticaPuntajeNoValida(String),
+
+    #[error("Muestreo no valido: {0}")]
+    MuestreoNoValido(String),
+
+    #[error("Version no valida: {0}")]
+    VersionNoValida(String),
+
+    #[error("La version {0} del examen no existe")]
+    VersionNoExiste(u32),
+
+    #[error("Orden de preguntas no valido: {0}")]
+    OrdenNoValido(String),
 }
 
 #[derive(Error, Debug)]
@@ -0,0 +1,245 @@
+use crate::examen::domain::entity::examen::Examen;
+use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Un campo cuyo valor cambio entre dos versiones
+#[derive(Debug, Clone, PartialEq)]
+pub struct CambioCampo {
+    pub campo: String,
+    pub antes: String,
+    pub despues: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CambioPregunta {
+    pub id: String,
+    pub campos: Vec<CambioCampo>,
+}
+
+/// Diferencias entre dos versiones de un examen. Las preguntas se comparan
+/// por id; `orden_cambiado` indica que las preguntas comunes cambiaron de
+/// posicion.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiferenciaExamen {
+    pub campos: Vec<CambioCampo>,
+    pub preguntas_agregadas: Vec<String>,
+    pub preguntas_eliminadas: Vec<String>,
+    pub preguntas_modificadas: Vec<CambioPregunta>,
+    pub orden_cambiado: bool,
+}
+
+impl DiferenciaExamen {
+    pub fn sin_cambios(&self) -> bool {
+        self.campos.is_empty()
+            && self.preguntas_agregadas.is_empty()
+            && self.preguntas_eliminadas.is_empty()
+            && self.preguntas_modificadas.is_empty()
+            && !self.orden_cambiado
+    }
+}
+
+fn opcional<T: Display>(valor: &Option<T>) -> String {
+    valor.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+fn ordenado<T: Display>(mapa: &HashMap<String, T>) -> String {
+    let mut pares: Vec<String> = mapa.iter().map(|(k, v)| format!("{k}:{v}")).collect();
+    pares.sort();
+    pares.join(",")
+}
+
+/// Campos que forman el contenido de un examen. El estado no es contenido:
+/// activar o desactivar un examen no cambia su version.
+fn campos_examen(examen: &Examen) -> Vec<(&'static str, String)> {
+    vec![
+        ("titulo", examen.titulo.clone()),
+        ("descripcion", examen.descripcion.clone()),
+        ("instrucciones", examen.instrucciones.clone()),
+        ("duracion_minutos", opcional(&examen.duracion_minutos)),
+        ("politica_puntaje", format!("{:?}", examen.politica_puntaje)),
+        ("aleatorizacion", format!("{:?}", examen.aleatorizacion)),
+        ("muestreo", format!("{:?}", examen.muestreo.reglas)),
+    ]
+}
+
+fn campos_pregunta(pregunta: &PreguntaEntity) -> Vec<(&'static str, String)> {
+    vec![
+        ("contenido", pregunta.contenido.clone()),
+        ("imagen_ref", opcional(&pregunta.imagen_ref)),
+        ("etiqueta", pregunta.etiqueta.to_string()),
+        ("tipo_de_pregunta", pregunta.tipo_de_pregunta.to_string()),
+        ("alternativas", ordenado(&pregunta.alternativas)),
+        ("puntaje", ordenado(&pregunta.puntaje)),
+        ("inversa", pregunta.inversa.to_string()),
+        ("seleccion", format!("{:?}", pregunta.seleccion)),
+        ("ordenamiento", format!("{:?}", pregunta.ordenamiento)),
+        ("numerica", format!("{:?}", pregunta.numerica)),
+    ]
+}
+
+/// SHA-256 del contenido del examen, independiente del orden interno de los
+/// mapas de alternativas y puntajes.
+pub fn hash_contenido(examen: &Examen) -> String {
+    let mut hasher = Sha256::new();
+    for (campo, valor) in campos_examen(examen) {
+        hasher.update(format!("{campo}={valor:?}\n"));
+    }
+    for pregunta in examen.preguntas.preguntas() {
+        hasher.update(format!("pregunta={}\n", pregunta.id));
+        for (campo, valor) in campos_pregunta(pregunta) {
+            hasher.update(format!("{campo}={valor:?}\n"));
+        }
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn cambios(
+    antes: Vec<(&'static str, String)>,
+    despues: Vec<(&'static str, String)>,
+) -> Vec<CambioCampo> {
+    antes
+        .into_iter()
+        .zip(despues)
+        .filter(|((_, a), (_, d))| a != d)
+        .map(|((campo, antes), (_, despues))| CambioCampo {
+            campo: campo.to_string(),
+            antes,
+            despues,
+        })
+        .collect()
+}
+
+pub fn comparar(antes: &Examen, despues: &Examen) -> DiferenciaExamen {
+    let preguntas_antes = antes.preguntas.preguntas();
+    let preguntas_despues = despues.preguntas.preguntas();
+    let buscar = |preguntas: &[PreguntaEntity], id: &str| {
+        preguntas
+            .iter()
+            .find(|pregunta| pregunta.id.to_string() == id)
+            .cloned()
+    };
+
+    let mut diferencia = DiferenciaExamen {
+        campos: cambios(campos_examen(antes), campos_examen(despues)),
+        ..Default::default()
+    };
+
+    let mut comunes_antes = Vec::new();
+    for pregunta in preguntas_antes {
+        let id = pregunta.id.to_string();
+        match buscar(preguntas_despues, &id) {
+            Some(nueva) => {
+                let campos = cambios(campos_pregunta(pregunta), campos_pregunta(&nueva));
+                if !campos.is_empty() {
+                    diferencia.preguntas_modificadas.push(CambioPregunta {
+                        id: id.clone(),
+                        campos,
+                    });
+                }
+                comunes_antes.push(id);
+            }
+            None => diferencia.preguntas_eliminadas.push(id),
+        }
+    }
+
+    let mut comunes_despues = Vec::new();
+    for pregunta in preguntas_despues {
+        let id = pregunta.id.to_string();
+        if buscar(preguntas_antes, &id).is_some() {
+            comunes_despues.push(id);
+        } else {
+            diferencia.preguntas_agregadas.push(id);
+        }
+    }
+
+    diferencia.orden_cambiado = comunes_antes != comunes_despues;
+    diferencia
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
+    use crate::pregunta::domain::value_object::id::PreguntaID;
+
+    const EXAMEN_ID: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+    const PREGUNTA_1: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+    const PREGUNTA_2: &str = "0b1c2d3e-4f5a-4b6c-9d7e-8f9a0b1c2d3e";
+    const PREGUNTA_3: &str = "9e8d7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b";
+
+    fn pregunta(id: &str, contenido: &str) -> PreguntaEntity {
+        let mut pregunta = PreguntaEntity::new(
+            contenido.to_string(),
+            "no".to_string(),
+            "alternativa_unica".to_string(),
+            None,
+            HashMap::from([
+                ("A".to_string(), "3".to_string()),
+                ("B".to_string(), "4".to_string()),
+            ]),
+            HashMap::from([("B".to_string(), 1)]),
+        )
+        .unwrap();
+        pregunta.id = PreguntaID::new(id).unwrap();
+        pregunta
+    }
+
+    fn examen(preguntas: Vec<PreguntaEntity>) -> Examen {
+        let mut examen = Examen::new(
+            EXAMEN_ID.to_string(),
+            "Aritmetica".to_string(),
+            "Sumas".to_string(),
+            "Responda".to_string(),
+        )
+        .unwrap();
+        examen.preguntas = ListaDePreguntas::new(preguntas);
+        examen
+    }
+
+    #[test]
+    fn test_el_hash_no_depende_del_orden_de_los_mapas() {
+        let a = examen(vec![pregunta(PREGUNTA_1, "Cuanto es 2 + 2")]);
+        let mut b = a.clone();
+        let mut pregunta = b.preguntas.preguntas()[0].clone();
+        let mut alternativas: Vec<_> = pregunta.alternativas.into_iter().collect();
+        alternativas.reverse();
+        pregunta.alternativas = alternativas.into_iter().collect();
+        b.preguntas = ListaDePreguntas::new(vec![pregunta]);
+        assert_eq!(hash_contenido(&a), hash_contenido(&b));
+
+        b.duracion_minutos = Some(30);
+        assert_ne!(hash_contenido(&a), hash_contenido(&b));
+    }
+
+    #[test]
+    fn test_compara_preguntas_por_id() {
+        let antes = examen(vec![
+            pregunta(PREGUNTA_1, "Cuanto es 2 + 2"),
+            pregunta(PREGUNTA_2, "Cuanto es 3 + 3"),
+        ]);
+        let mut despues = examen(vec![
+            pregunta(PREGUNTA_1, "Cuanto es 2 + 3"),
+            pregunta(PREGUNTA_3, "Cuanto es 4 + 4"),
+        ]);
+        despues.titulo = "Aritmetica basica".to_string();
+
+        let diferencia = comparar(&antes, &despues);
+        assert_eq!(diferencia.campos.len(), 1);
+        assert_eq!(diferencia.campos[0].campo, "titulo");
+        assert_eq!(diferencia.preguntas_agregadas, vec![PREGUNTA_3.to_string()]);
+        assert_eq!(
+            diferencia.preguntas_eliminadas,
+            vec![PREGUNTA_2.to_string()]
+        );
+        assert_eq!(diferencia.preguntas_modificadas.len(), 1);
+        assert_eq!(diferencia.preguntas_modificadas[0].id, PREGUNTA_1);
+        assert_eq!(
+            diferencia.preguntas_modificadas[0].campos[0].campo,
+            "contenido"
+        );
+        assert!(!diferencia.orden_cambiado);
+        assert!(comparar(&antes, &antes).sin_cambios());
+    }
+}
@@ -11,4 +11,8 @@ impl ListaDeExamenes {
     pub fn examenes(&self) -> &Vec<Examen> {
         &self.0
     }
+
+    pub fn examenes_mut(&mut self) -> &mut Vec<Examen> {
+        &mut self.0
+    }
 }
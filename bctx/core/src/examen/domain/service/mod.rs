@@ -1 +1,2 @@
+pub mod contenido;
 pub mod lista_examenes;
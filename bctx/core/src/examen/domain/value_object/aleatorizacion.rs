@@ -0,0 +1,16 @@
+/// Indica si cada postulante ve las preguntas y/o las alternativas del examen
+/// en un orden propio. El orden se fija al asignar la evaluacion.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Aleatorizacion {
+    pub preguntas: bool,
+    pub alternativas: bool,
+}
+
+impl Aleatorizacion {
+    pub fn new(preguntas: bool, alternativas: bool) -> Self {
+        Self {
+            preguntas,
+            alternativas,
+        }
+    }
+}
@@ -1,2 +1,5 @@
+pub mod aleatorizacion;
 pub mod id;
+pub mod muestreo;
+pub mod politica_puntaje;
 pub mod version;
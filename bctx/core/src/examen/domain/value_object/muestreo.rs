@@ -0,0 +1,74 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+
+/// Cuantas preguntas de una etiqueta recibe cada postulante
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReglaMuestreo {
+    pub etiqueta: Etiqueta,
+    pub cantidad: u32,
+}
+
+/// Reglas para sortear un subconjunto de preguntas por postulante al asignar
+/// la evaluacion. Sin reglas se entregan todas las preguntas; las etiquetas
+/// sin regla tambien se entregan completas.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Muestreo {
+    pub reglas: Vec<ReglaMuestreo>,
+}
+
+impl Muestreo {
+    pub fn new(reglas: Vec<ReglaMuestreo>) -> Result<Self, ExamenError> {
+        for (i, regla) in reglas.iter().enumerate() {
+            if regla.cantidad == 0 {
+                return Err(ExamenError::MuestreoNoValido(format!(
+                    "la cantidad para {} debe ser mayor que cero",
+                    regla.etiqueta
+                )));
+            }
+            if reglas[..i]
+                .iter()
+                .any(|otra| otra.etiqueta == regla.etiqueta)
+            {
+                return Err(ExamenError::MuestreoNoValido(format!(
+                    "la etiqueta {} tiene mas de una regla",
+                    regla.etiqueta
+                )));
+            }
+        }
+
+        Ok(Self { reglas })
+    }
+
+    pub fn regla(&self, etiqueta: &Etiqueta) -> Option<&ReglaMuestreo> {
+        self.reglas.iter().find(|regla| &regla.etiqueta == etiqueta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rechaza_cantidad_cero() {
+        let resultado = Muestreo::new(vec![ReglaMuestreo {
+            etiqueta: Etiqueta::Honestidad,
+            cantidad: 0,
+        }]);
+        assert!(matches!(resultado, Err(ExamenError::MuestreoNoValido(_))));
+    }
+
+    #[test]
+    fn test_rechaza_etiqueta_repetida() {
+        let resultado = Muestreo::new(vec![
+            ReglaMuestreo {
+                etiqueta: Etiqueta::Honestidad,
+                cantidad: 10,
+            },
+            ReglaMuestreo {
+                etiqueta: Etiqueta::Honestidad,
+                cantidad: 5,
+            },
+        ]);
+        assert!(matches!(resultado, Err(ExamenError::MuestreoNoValido(_))));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use std::fmt;
+use std::str::FromStr;
+
+const CERO: &str = "cero";
+const PENALIZAR: &str = "penalizar";
+
+/// Que vale una pregunta de alternativa unica que se deja sin responder.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum EnBlanco {
+    #[default]
+    Cero,
+    Penalizar,
+}
+
+impl fmt::Display for EnBlanco {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnBlanco::Cero => write!(f, "{}", CERO),
+            EnBlanco::Penalizar => write!(f, "{}", PENALIZAR),
+        }
+    }
+}
+
+impl FromStr for EnBlanco {
+    type Err = ExamenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            CERO => Ok(EnBlanco::Cero),
+            PENALIZAR => Ok(EnBlanco::Penalizar),
+            _ => Err(ExamenError::PoliticaPuntajeNoValida(format!(
+                "en_blanco debe ser {CERO} o {PENALIZAR}"
+            ))),
+        }
+    }
+}
+
+/// Como se califican las preguntas de alternativa unica de un examen. La
+/// penalizacion se resta por cada respuesta que no otorga puntos; las
+/// alternativas con puntaje parcial se otorgan tal cual. Con `piso_cero` el
+/// total del examen no baja de cero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoliticaPuntaje {
+    pub penalizacion_incorrecta: u32,
+    pub en_blanco: EnBlanco,
+    pub piso_cero: bool,
+}
+
+impl Default for PoliticaPuntaje {
+    fn default() -> Self {
+        Self {
+            penalizacion_incorrecta: 0,
+            en_blanco: EnBlanco::Cero,
+            piso_cero: true,
+        }
+    }
+}
+
+impl PoliticaPuntaje {
+    pub fn new(penalizacion_incorrecta: u32, en_blanco: EnBlanco, piso_cero: bool) -> Self {
+        Self {
+            penalizacion_incorrecta,
+            en_blanco,
+            piso_cero,
+        }
+    }
+
+    /// Puntos de una pregunta segun lo obtenido; `None` si quedo en blanco.
+    pub fn calificar(&self, obtenido: Option<u32>) -> i64 {
+        let penalizacion = -i64::from(self.penalizacion_incorrecta);
+        match obtenido {
+            None if self.en_blanco == EnBlanco::Cero => 0,
+            None | Some(0) => penalizacion,
+            Some(puntos) => i64::from(puntos),
+        }
+    }
+
+    /// Puntos guardados de una pregunta al finalizar. Una pregunta de
+    /// alternativa unica sin puntos quedo en blanco; las demas valen cero.
+    pub fn puntos_pregunta(&self, tipo: &TipoPregunta, guardados: Option<i64>) -> i64 {
+        match (tipo, guardados) {
+            (_, Some(puntos)) => puntos,
+            (TipoPregunta::AlternativaUnica, None) => self.calificar(None),
+            (_, None) => 0,
+        }
+    }
+
+    pub fn totalizar(&self, puntos: impl IntoIterator<Item = i64>) -> i64 {
+        let total: i64 = puntos.into_iter().sum();
+        if self.piso_cero { total.max(0) } else { total }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calificar_penaliza_incorrecta() {
+        let politica = PoliticaPuntaje::new(1, EnBlanco::Cero, true);
+        assert_eq!(politica.calificar(Some(4)), 4);
+        assert_eq!(politica.calificar(Some(0)), -1);
+        assert_eq!(politica.calificar(None), 0);
+    }
+
+    #[test]
+    fn test_calificar_en_blanco_penalizado() {
+        let politica = PoliticaPuntaje::new(2, EnBlanco::Penalizar, true);
+        assert_eq!(politica.calificar(None), -2);
+    }
+
+    #[test]
+    fn test_totalizar_con_y_sin_piso() {
+        let con_piso = PoliticaPuntaje::new(1, EnBlanco::Cero, true);
+        let sin_piso = PoliticaPuntaje::new(1, EnBlanco::Cero, false);
+        assert_eq!(con_piso.totalizar([1, -1, -1]), 0);
+        assert_eq!(sin_piso.totalizar([1, -1, -1]), -1);
+    }
+
+    #[test]
+    fn test_pregunta_omitida_resta_al_total() {
+        let politica = PoliticaPuntaje::new(1, EnBlanco::Penalizar, false);
+        let puntos = [
+            politica.puntos_pregunta(&TipoPregunta::AlternativaUnica, Some(4)),
+            politica.puntos_pregunta(&TipoPregunta::AlternativaUnica, None),
+            politica.puntos_pregunta(&TipoPregunta::Likert, None),
+        ];
+        assert_eq!(politica.totalizar(puntos), 3);
+    }
+
+    #[test]
+    fn test_en_blanco_desde_texto() {
+        assert_eq!(
+            EnBlanco::from_str("Penalizar").unwrap(),
+            EnBlanco::Penalizar
+        );
+        assert!(EnBlanco::from_str("restar").is_err());
+    }
+}
@@ -1 +1,115 @@
-pub struct Version;
+use crate::examen::domain::error::examen::ExamenError;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum EstadoVersion {
+    #[default]
+    Borrador,
+    Publicada,
+}
+
+impl fmt::Display for EstadoVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Borrador => write!(f, "borrador"),
+            Self::Publicada => write!(f, "publicada"),
+        }
+    }
+}
+
+impl FromStr for EstadoVersion {
+    type Err = ExamenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "borrador" => Ok(Self::Borrador),
+            "publicada" => Ok(Self::Publicada),
+            _ => Err(ExamenError::VersionNoValida(s.to_string())),
+        }
+    }
+}
+
+/// Version del contenido de un examen. Publicar congela el numero junto con
+/// el hash del contenido; editar una version publicada abre un borrador con
+/// el numero siguiente.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Version {
+    pub numero: u32,
+    pub estado: EstadoVersion,
+    pub hash: Option<String>,
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self {
+            numero: 1,
+            estado: EstadoVersion::Borrador,
+            hash: None,
+        }
+    }
+}
+
+impl Version {
+    pub fn esta_publicada(&self) -> bool {
+        self.estado == EstadoVersion::Publicada
+    }
+
+    pub fn editar(&mut self) {
+        if self.esta_publicada() {
+            self.numero += 1;
+            self.estado = EstadoVersion::Borrador;
+            self.hash = None;
+        }
+    }
+
+    /// Congela la version con el hash de su contenido. Si ya estaba publicada
+    /// con otro hash (p. ej. cambio una pregunta del banco) se congela como
+    /// version nueva. Devuelve `true` si hay una version nueva que guardar.
+    pub fn congelar(&mut self, hash: String) -> bool {
+        match self.estado {
+            EstadoVersion::Borrador => {
+                self.estado = EstadoVersion::Publicada;
+                self.hash = Some(hash);
+                true
+            }
+            EstadoVersion::Publicada if self.hash.as_deref() == Some(hash.as_str()) => false,
+            EstadoVersion::Publicada => {
+                self.numero += 1;
+                self.hash = Some(hash);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editar_una_version_publicada_abre_un_borrador_nuevo() {
+        let mut version = Version::default();
+        assert!(version.congelar("abc".to_string()));
+
+        version.editar();
+        assert_eq!(version.numero, 2);
+        assert_eq!(version.estado, EstadoVersion::Borrador);
+        assert_eq!(version.hash, None);
+
+        version.editar();
+        assert_eq!(version.numero, 2);
+    }
+
+    #[test]
+    fn test_congelar_el_mismo_contenido_no_crea_version() {
+        let mut version = Version::default();
+        assert!(version.congelar("abc".to_string()));
+        assert!(!version.congelar("abc".to_string()));
+        assert_eq!(version.numero, 1);
+
+        assert!(version.congelar("def".to_string()));
+        assert_eq!(version.numero, 2);
+        assert_eq!(version.hash.as_deref(), Some("def"));
+    }
+}
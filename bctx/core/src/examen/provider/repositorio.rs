@@ -1,4 +1,5 @@
 use crate::examen::domain::entity::examen::Examen;
+use crate::examen::domain::value_object::version::Version;
 use crate::examen::use_case::listar_examenes::OutputData;
 use async_trait::async_trait;
 
@@ -12,7 +13,24 @@ pub trait RepositorioExamenLectura<Error>: Send + Sync {
     async fn obtener_examen(&self, id: &str) -> Result<Examen, Error>;
 }
 
+/// Guarda los datos del examen, su estado, el orden de sus preguntas y su
+/// version. Las preguntas se editan por separado.
+#[async_trait]
+pub trait RepositorioExamenActualizar<Error>:
+    Send + Sync + RepositorioExamenLectura<Error>
+{
+    async fn actualizar_examen(&self, examen: Examen) -> Result<(), Error>;
+}
+
 #[async_trait]
 pub trait RepositorioExamenListar<Error>: Send + Sync {
     async fn listar_examenes(&self) -> Result<Vec<OutputData>, Error>;
 }
+
+/// Versiones publicadas de un examen, con su contenido congelado
+#[async_trait]
+pub trait RepositorioVersionesExamen<Error>: Send + Sync {
+    async fn obtener_version(&self, examen_id: &str, numero: u32) -> Result<Option<Examen>, Error>;
+
+    async fn listar_versiones(&self, examen_id: &str) -> Result<Vec<Version>, Error>;
+}
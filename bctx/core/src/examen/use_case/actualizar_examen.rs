@@ -0,0 +1,143 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::provider::repositorio::RepositorioExamenActualizar;
+use crate::examen::use_case::crear_examen::{
+    InputAleatorizacion, InputPoliticaPuntaje, InputReglaMuestreo, construir_muestreo,
+};
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+/// Solo se modifican los campos presentes
+#[derive(Debug, Clone, Default)]
+pub struct InputData {
+    pub id: String,
+    pub titulo: Option<String>,
+    pub descripcion: Option<String>,
+    pub instrucciones: Option<String>,
+    pub duracion_minutos: Option<u32>,
+    pub politica_puntaje: Option<InputPoliticaPuntaje>,
+    pub aleatorizacion: Option<InputAleatorizacion>,
+    pub muestreo: Option<Vec<InputReglaMuestreo>>,
+}
+
+pub struct ActualizarExamen<RepoErr> {
+    repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>,
+}
+
+impl<RepoErr> ActualizarExamen<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), ExamenError> for ActualizarExamen<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), ExamenError> {
+        let mut examen = self.repositorio.obtener_examen(&in_.id).await?;
+
+        examen.editar_contenido(|examen| {
+            examen.actualizar_textos(in_.titulo, in_.descripcion, in_.instrucciones)?;
+            if in_.duracion_minutos.is_some() {
+                examen.asignar_duracion(in_.duracion_minutos)?;
+            }
+            if let Some(politica) = in_.politica_puntaje {
+                examen.politica_puntaje = politica.construir()?;
+            }
+            if let Some(aleatorizacion) = in_.aleatorizacion {
+                examen.aleatorizacion = aleatorizacion.into();
+            }
+            if let Some(muestreo) = in_.muestreo {
+                examen.muestreo = construir_muestreo(muestreo)?;
+            }
+            Ok(())
+        })?;
+
+        self.repositorio.actualizar_examen(examen).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examen::domain::entity::examen::Examen;
+    use crate::examen::provider::repositorio::RepositorioExamenLectura;
+    use std::sync::{Arc, Mutex};
+
+    const EXAMEN_ID: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+
+    struct MockRepo {
+        examen: Examen,
+        guardado: Arc<Mutex<Option<Examen>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioExamenLectura<ExamenError> for MockRepo {
+        async fn obtener_examen(&self, _id: &str) -> Result<Examen, ExamenError> {
+            Ok(self.examen.clone())
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioExamenActualizar<ExamenError> for MockRepo {
+        async fn actualizar_examen(&self, examen: Examen) -> Result<(), ExamenError> {
+            *self.guardado.lock().unwrap() = Some(examen);
+            Ok(())
+        }
+    }
+
+    fn caso() -> (ActualizarExamen<ExamenError>, Arc<Mutex<Option<Examen>>>) {
+        let mut examen = Examen::new(
+            EXAMEN_ID.to_string(),
+            "Aritmetica".to_string(),
+            "Sumas".to_string(),
+            "Responda".to_string(),
+        )
+        .unwrap();
+        examen.congelar_version();
+
+        let guardado = Arc::new(Mutex::new(None));
+        let repo = MockRepo {
+            examen,
+            guardado: guardado.clone(),
+        };
+        (ActualizarExamen::new(Box::new(repo)), guardado)
+    }
+
+    #[tokio::test]
+    async fn test_actualiza_solo_los_campos_enviados_y_abre_un_borrador() {
+        let (caso, guardado) = caso();
+        caso.ejecutar(InputData {
+            id: EXAMEN_ID.to_string(),
+            titulo: Some("Aritmetica basica".to_string()),
+            duracion_minutos: Some(20),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let examen = guardado.lock().unwrap().clone().unwrap();
+        assert_eq!(examen.titulo, "Aritmetica basica");
+        assert_eq!(examen.descripcion, "Sumas");
+        assert_eq!(examen.duracion_minutos, Some(20));
+        assert_eq!(examen.version.numero, 2);
+        assert!(!examen.version.esta_publicada());
+    }
+
+    #[tokio::test]
+    async fn test_rechaza_titulo_vacio() {
+        let (caso, guardado) = caso();
+        let resultado = caso
+            .ejecutar(InputData {
+                id: EXAMEN_ID.to_string(),
+                titulo: Some(" ".to_string()),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(resultado, Err(ExamenError::TituloInvalido)));
+        assert!(guardado.lock().unwrap().is_none());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::examen::domain::entity::examen::Examen;
+use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::domain::service::contenido::{DiferenciaExamen, comparar};
+use crate::examen::provider::repositorio::{RepositorioExamenLectura, RepositorioVersionesExamen};
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub examen_id: String,
+    pub desde: u32,
+    pub hasta: u32,
+}
+
+/// Compara dos versiones de un examen. Ademas de las versiones publicadas se
+/// puede pedir el borrador vigente por su numero.
+pub struct CompararVersiones<RepoErr> {
+    examenes: Box<dyn RepositorioExamenLectura<RepoErr>>,
+    versiones: Box<dyn RepositorioVersionesExamen<RepoErr>>,
+}
+
+impl<RepoErr> CompararVersiones<RepoErr> {
+    pub fn new(
+        examenes: Box<dyn RepositorioExamenLectura<RepoErr>>,
+        versiones: Box<dyn RepositorioVersionesExamen<RepoErr>>,
+    ) -> Self {
+        Self {
+            examenes,
+            versiones,
+        }
+    }
+}
+
+impl<RepoErr> CompararVersiones<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn version(&self, vigente: &Examen, numero: u32) -> Result<Examen, ExamenError> {
+        if vigente.version.numero == numero && !vigente.version.esta_publicada() {
+            return Ok(vigente.clone());
+        }
+
+        self.versiones
+            .obtener_version(&vigente.id.to_string(), numero)
+            .await?
+            .ok_or(ExamenError::VersionNoExiste(numero))
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, DiferenciaExamen, ExamenError> for CompararVersiones<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<DiferenciaExamen, ExamenError> {
+        let vigente = self.examenes.obtener_examen(&in_.examen_id).await?;
+        let desde = self.version(&vigente, in_.desde).await?;
+        let hasta = self.version(&vigente, in_.hasta).await?;
+
+        Ok(comparar(&desde, &hasta))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examen::domain::value_object::version::Version;
+
+    const EXAMEN_ID: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+
+    struct MockExamenes {
+        vigente: Examen,
+    }
+
+    #[async_trait]
+    impl RepositorioExamenLectura<ExamenError> for MockExamenes {
+        async fn obtener_examen(&self, _id: &str) -> Result<Examen, ExamenError> {
+            Ok(self.vigente.clone())
+        }
+    }
+
+    struct MockVersiones {
+        publicadas: Vec<Examen>,
+    }
+
+    #[async_trait]
+    impl RepositorioVersionesExamen<ExamenError> for MockVersiones {
+        async fn obtener_version(
+            &self,
+            _examen_id: &str,
+            numero: u32,
+        ) -> Result<Option<Examen>, ExamenError> {
+            Ok(self
+                .publicadas
+                .iter()
+                .find(|examen| examen.version.numero == numero)
+                .cloned())
+        }
+
+        async fn listar_versiones(&self, _examen_id: &str) -> Result<Vec<Version>, ExamenError> {
+            Ok(self
+                .publicadas
+                .iter()
+                .map(|examen| examen.version.clone())
+                .collect())
+        }
+    }
+
+    fn examenes() -> (Examen, Examen) {
+        let mut publicada = Examen::new(
+            EXAMEN_ID.to_string(),
+            "Aritmetica".to_string(),
+            "Sumas".to_string(),
+            "Responda".to_string(),
+        )
+        .unwrap();
+        publicada.congelar_version();
+
+        let mut borrador = publicada.clone();
+        borrador.version.editar();
+        borrador.instrucciones = "Responda sin calculadora".to_string();
+        (publicada, borrador)
+    }
+
+    fn caso(publicada: Examen, vigente: Examen) -> CompararVersiones<ExamenError> {
+        CompararVersiones::new(
+            Box::new(MockExamenes { vigente }),
+            Box::new(MockVersiones {
+                publicadas: vec![publicada],
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_compara_la_version_publicada_con_el_borrador() {
+        let (publicada, borrador) = examenes();
+        let diferencia = caso(publicada, borrador)
+            .ejecutar(InputData {
+                examen_id: EXAMEN_ID.to_string(),
+                desde: 1,
+                hasta: 2,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(diferencia.campos.len(), 1);
+        assert_eq!(diferencia.campos[0].campo, "instrucciones");
+        assert_eq!(diferencia.campos[0].despues, "Responda sin calculadora");
+    }
+
+    #[tokio::test]
+    async fn test_version_inexistente() {
+        let (publicada, borrador) = examenes();
+        let resultado = caso(publicada, borrador)
+            .ejecutar(InputData {
+                examen_id: EXAMEN_ID.to_string(),
+                desde: 1,
+                hasta: 3,
+            })
+            .await;
+
+        assert!(matches!(resultado, Err(ExamenError::VersionNoExiste(3))));
+    }
+}
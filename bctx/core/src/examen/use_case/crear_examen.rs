@@ -1,6 +1,10 @@
 use crate::examen::domain::entity::examen::Examen;
 use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::domain::value_object::aleatorizacion::Aleatorizacion;
+use crate::examen::domain::value_object::muestreo::{Muestreo, ReglaMuestreo};
+use crate::examen::domain::value_object::politica_puntaje::{EnBlanco, PoliticaPuntaje};
 use crate::examen::provider::repositorio::RepositorioExamenEscritura;
+use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
 use async_trait::async_trait;
 use quizz_common::use_case::CasoDeUso;
 
@@ -10,6 +14,64 @@ pub struct InputData {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    pub duracion_minutos: Option<u32>,
+    pub politica_puntaje: Option<InputPoliticaPuntaje>,
+    pub aleatorizacion: Option<InputAleatorizacion>,
+    pub muestreo: Vec<InputReglaMuestreo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputPoliticaPuntaje {
+    pub penalizacion_incorrecta: u32,
+    pub en_blanco: String,
+    pub piso_cero: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputAleatorizacion {
+    pub preguntas: bool,
+    pub alternativas: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputReglaMuestreo {
+    pub etiqueta: String,
+    pub cantidad: u32,
+}
+
+impl InputPoliticaPuntaje {
+    pub fn construir(self) -> Result<PoliticaPuntaje, ExamenError> {
+        Ok(PoliticaPuntaje::new(
+            self.penalizacion_incorrecta,
+            self.en_blanco.parse::<EnBlanco>()?,
+            self.piso_cero,
+        ))
+    }
+}
+
+impl From<InputAleatorizacion> for Aleatorizacion {
+    fn from(aleatorizacion: InputAleatorizacion) -> Self {
+        Aleatorizacion::new(aleatorizacion.preguntas, aleatorizacion.alternativas)
+    }
+}
+
+pub fn construir_muestreo(reglas: Vec<InputReglaMuestreo>) -> Result<Muestreo, ExamenError> {
+    Muestreo::new(
+        reglas
+            .into_iter()
+            .map(|regla| {
+                Ok(ReglaMuestreo {
+                    etiqueta: regla.etiqueta.parse::<Etiqueta>().map_err(|_| {
+                        ExamenError::MuestreoNoValido(format!(
+                            "etiqueta desconocida: {}",
+                            regla.etiqueta
+                        ))
+                    })?,
+                    cantidad: regla.cantidad,
+                })
+            })
+            .collect::<Result<Vec<ReglaMuestreo>, ExamenError>>()?,
+    )
 }
 
 pub struct CrearExamen<RepoErr> {
@@ -28,12 +90,20 @@ where
     ExamenError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<(), ExamenError> {
-        let examen = Examen::new(
+        let mut examen = Examen::new(
             in_.id.to_string(),
             in_.titulo,
             in_.descripcion,
             in_.instrucciones,
         )?;
+        examen.asignar_duracion(in_.duracion_minutos)?;
+        if let Some(politica) = in_.politica_puntaje {
+            examen.politica_puntaje = politica.construir()?;
+        }
+        if let Some(aleatorizacion) = in_.aleatorizacion {
+            examen.aleatorizacion = aleatorizacion.into();
+        }
+        examen.muestreo = construir_muestreo(in_.muestreo)?;
         self.repositorio.guardar_examen(examen).await?;
         Ok(())
     }
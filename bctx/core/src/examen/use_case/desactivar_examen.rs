@@ -0,0 +1,35 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::provider::repositorio::RepositorioExamenActualizar;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+}
+
+/// Baja logica: el examen se marca inactivo y las evaluaciones que ya lo
+/// publicaron conservan su copia.
+pub struct DesactivarExamen<RepoErr> {
+    repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>,
+}
+
+impl<RepoErr> DesactivarExamen<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), ExamenError> for DesactivarExamen<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), ExamenError> {
+        let mut examen = self.repositorio.obtener_examen(&in_.id).await?;
+        examen.desactivar();
+
+        self.repositorio.actualizar_examen(examen).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::domain::value_object::version::Version;
+use crate::examen::provider::repositorio::{RepositorioExamenLectura, RepositorioVersionesExamen};
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub examen_id: String,
+}
+
+/// Versiones publicadas de un examen seguidas del borrador vigente, si existe
+pub struct ListarVersiones<RepoErr> {
+    examenes: Box<dyn RepositorioExamenLectura<RepoErr>>,
+    versiones: Box<dyn RepositorioVersionesExamen<RepoErr>>,
+}
+
+impl<RepoErr> ListarVersiones<RepoErr> {
+    pub fn new(
+        examenes: Box<dyn RepositorioExamenLectura<RepoErr>>,
+        versiones: Box<dyn RepositorioVersionesExamen<RepoErr>>,
+    ) -> Self {
+        Self {
+            examenes,
+            versiones,
+        }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, Vec<Version>, ExamenError> for ListarVersiones<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<Vec<Version>, ExamenError> {
+        let vigente = self.examenes.obtener_examen(&in_.examen_id).await?;
+        let mut versiones = self.versiones.listar_versiones(&in_.examen_id).await?;
+        versiones.sort_by_key(|version| version.numero);
+
+        if !vigente.version.esta_publicada() {
+            versiones.push(vigente.version);
+        }
+
+        Ok(versiones)
+    }
+}
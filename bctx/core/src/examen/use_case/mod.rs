@@ -1,3 +1,8 @@
+pub mod actualizar_examen;
+pub mod comparar_versiones;
 pub mod crear_examen;
+pub mod desactivar_examen;
 mod leer_examen;
 pub mod listar_examenes;
+pub mod listar_versiones;
+pub mod reordenar_preguntas;
@@ -0,0 +1,35 @@
+use crate::examen::domain::error::examen::ExamenError;
+use crate::examen::provider::repositorio::RepositorioExamenActualizar;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+    pub pregunta_ids: Vec<String>,
+}
+
+/// Cambia el orden de las preguntas del examen, propias o del banco
+pub struct ReordenarPreguntas<RepoErr> {
+    repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>,
+}
+
+impl<RepoErr> ReordenarPreguntas<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioExamenActualizar<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), ExamenError> for ReordenarPreguntas<RepoErr>
+where
+    ExamenError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), ExamenError> {
+        let mut examen = self.repositorio.obtener_examen(&in_.id).await?;
+        examen.editar_contenido(|examen| examen.reordenar_preguntas(&in_.pregunta_ids))?;
+
+        self.repositorio.actualizar_examen(examen).await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 pub mod admin;
+pub mod baremo;
 pub mod evaluacion;
 pub mod examen;
 pub mod postulante;
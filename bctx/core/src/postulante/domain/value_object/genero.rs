@@ -2,7 +2,7 @@ use crate::postulante::domain::error::genero::GeneroError;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Genero {
     Masculino,
     Femenino,
@@ -19,6 +19,12 @@ impl PostulanteID {
         ID::new(id, IdType::Postulante).map(|id| PostulanteID { id })
     }
 
+    pub fn new_v4() -> Self {
+        PostulanteID {
+            id: ID::new_v4(IdType::Postulante),
+        }
+    }
+
     pub fn value(&self) -> &ID {
         &self.id
     }
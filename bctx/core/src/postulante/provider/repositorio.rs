@@ -3,6 +3,7 @@ use crate::postulante::domain::value_object::documento::Documento;
 use crate::postulante::domain::value_object::id::PostulanteID;
 
 use async_trait::async_trait;
+use std::collections::HashSet;
 
 #[async_trait]
 pub trait RepositorioPostulanteEscritura<Error>: Send + Sync {
@@ -25,3 +26,13 @@ pub trait RepositorioPostulanteLectura<Error>: Send + Sync {
 
     async fn obtener_lista_de_postulantes(&self) -> Result<Vec<Postulante>, Error>;
 }
+
+/// Registro masivo de postulantes. Cada metodo es una sola operacion contra
+/// el repositorio, sin importar cuantos postulantes se procesen.
+#[async_trait]
+pub trait RepositorioPostulanteImportar<Error>: Send + Sync {
+    async fn documentos_registrados(&self, documentos: &[String])
+    -> Result<HashSet<String>, Error>;
+
+    async fn registrar_postulantes(&self, postulantes: Vec<Postulante>) -> Result<(), Error>;
+}
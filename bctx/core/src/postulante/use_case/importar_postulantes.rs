@@ -0,0 +1,273 @@
+use crate::postulante::domain::entity::postulante::Postulante;
+use crate::postulante::domain::error::postulante::PostulanteError;
+use crate::postulante::domain::service::password::obtener_password_del_documento;
+use crate::postulante::domain::value_object::documento::Documento;
+use crate::postulante::domain::value_object::genero::Genero;
+use crate::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
+use crate::postulante::domain::value_object::id::PostulanteID;
+use crate::postulante::domain::value_object::nombre::Nombre;
+use crate::postulante::provider::password::SeguridadPassword;
+use crate::postulante::provider::repositorio::RepositorioPostulanteImportar;
+use async_trait::async_trait;
+use quizz_common::domain::value_objects::fecha_nacimiento::FechaNacimiento;
+use quizz_common::use_case::CasoDeUso;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Default)]
+pub struct InputFila {
+    pub documento: String,
+    pub nombre: String,
+    pub primer_apellido: String,
+    pub segundo_apellido: String,
+    pub fecha_nacimiento: String,
+    pub grado_instruccion: String,
+    pub genero: String,
+    pub password: Option<String>,
+}
+
+/// Filas del archivo en orden. `fila` es el numero de fila en el archivo,
+/// para que el reporte se pueda cruzar con el original.
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub filas: Vec<(usize, InputFila)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EstadoFila {
+    Creado { id: String },
+    Duplicado,
+    Invalido(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultadoFila {
+    pub fila: usize,
+    pub documento: String,
+    pub estado: EstadoFila,
+}
+
+/// Importa postulantes en bloque. Las filas validas se registran aunque otras
+/// fallen; un documento ya registrado o repetido en el mismo archivo se
+/// reporta como duplicado. Sin password se usa la temporal del documento,
+/// igual que en el registro individual.
+pub struct ImportarPostulantes<PassErr, RepoErr> {
+    password_crypto: Box<dyn SeguridadPassword<PassErr>>,
+    repositorio: Box<dyn RepositorioPostulanteImportar<RepoErr>>,
+}
+
+impl<PassErr, RepoErr> ImportarPostulantes<PassErr, RepoErr> {
+    pub fn new(
+        password_crypto: Box<dyn SeguridadPassword<PassErr>>,
+        repositorio: Box<dyn RepositorioPostulanteImportar<RepoErr>>,
+    ) -> Self {
+        Self {
+            password_crypto,
+            repositorio,
+        }
+    }
+}
+
+fn validar(fila: &InputFila) -> Result<(GradoInstruccion, Genero), PostulanteError> {
+    Documento::new(&fila.documento)?;
+    Nombre::new(
+        fila.nombre.clone(),
+        fila.primer_apellido.clone(),
+        fila.segundo_apellido.clone(),
+    )?;
+    FechaNacimiento::new(&fila.fecha_nacimiento)?;
+    let grado_instruccion = GradoInstruccion::from_str(&fila.grado_instruccion)?;
+    let genero = Genero::from_str(&fila.genero)?;
+    Ok((grado_instruccion, genero))
+}
+
+impl<PassErr, RepoErr> ImportarPostulantes<PassErr, RepoErr>
+where
+    PostulanteError: From<PassErr>,
+{
+    async fn crear(
+        &self,
+        fila: InputFila,
+        grado_instruccion: GradoInstruccion,
+        genero: Genero,
+    ) -> Result<Postulante, PostulanteError> {
+        let password = match fila.password.filter(|password| !password.trim().is_empty()) {
+            Some(password) => password,
+            None => obtener_password_del_documento(Documento::new(&fila.documento)?)?,
+        };
+        let password = self.password_crypto.cifrar(password).await?;
+
+        Postulante::new(
+            PostulanteID::new_v4().to_string(),
+            fila.documento,
+            fila.nombre,
+            fila.primer_apellido,
+            fila.segundo_apellido,
+            fila.fecha_nacimiento,
+            grado_instruccion,
+            genero,
+            password,
+        )
+    }
+}
+
+#[async_trait]
+impl<PassErr, RepoErr> CasoDeUso<InputData, Vec<ResultadoFila>, PostulanteError>
+    for ImportarPostulantes<PassErr, RepoErr>
+where
+    PostulanteError: From<PassErr>,
+    PostulanteError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<Vec<ResultadoFila>, PostulanteError> {
+        let mut filas = Vec::with_capacity(in_.filas.len());
+        for (numero, mut fila) in in_.filas {
+            fila.documento = fila.documento.trim().to_string();
+            let validacion = validar(&fila);
+            filas.push((numero, fila, validacion));
+        }
+
+        let documentos: Vec<String> = filas
+            .iter()
+            .filter(|(_, _, validacion)| validacion.is_ok())
+            .map(|(_, fila, _)| fila.documento.clone())
+            .collect();
+        let mut vistos = self.repositorio.documentos_registrados(&documentos).await?;
+
+        let mut resultados = Vec::with_capacity(filas.len());
+        let mut nuevos = Vec::new();
+        for (fila_numero, fila, validacion) in filas {
+            let documento = fila.documento.clone();
+            let estado = match validacion {
+                Err(e) => EstadoFila::Invalido(e.to_string()),
+                Ok(_) if !vistos.insert(documento.clone()) => EstadoFila::Duplicado,
+                Ok((grado_instruccion, genero)) => {
+                    match self.crear(fila, grado_instruccion, genero).await {
+                        Ok(postulante) => {
+                            let id = postulante.id.to_string();
+                            nuevos.push(postulante);
+                            EstadoFila::Creado { id }
+                        }
+                        Err(e) => EstadoFila::Invalido(e.to_string()),
+                    }
+                }
+            };
+            resultados.push(ResultadoFila {
+                fila: fila_numero,
+                documento,
+                estado,
+            });
+        }
+
+        if !nuevos.is_empty() {
+            self.repositorio.registrar_postulantes(nuevos).await?;
+        }
+
+        Ok(resultados)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    struct MockSeguridadPassword;
+
+    #[async_trait]
+    impl SeguridadPassword<PostulanteError> for MockSeguridadPassword {
+        async fn cifrar(&self, _password: String) -> Result<String, PostulanteError> {
+            Ok("$2a$12$/4Ikr2l8lEXk/1iHtiUN7.p/agp333D1PdZjhSzx22PaH0v6rZcZS".to_string())
+        }
+
+        async fn comparar(
+            &self,
+            _password: String,
+            _hashed: String,
+        ) -> Result<bool, PostulanteError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockRepositorio {
+        registrados: HashSet<String>,
+        guardados: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioPostulanteImportar<PostulanteError> for MockRepositorio {
+        async fn documentos_registrados(
+            &self,
+            documentos: &[String],
+        ) -> Result<HashSet<String>, PostulanteError> {
+            Ok(documentos
+                .iter()
+                .filter(|documento| self.registrados.contains(*documento))
+                .cloned()
+                .collect())
+        }
+
+        async fn registrar_postulantes(
+            &self,
+            postulantes: Vec<Postulante>,
+        ) -> Result<(), PostulanteError> {
+            self.guardados.lock().unwrap().extend(
+                postulantes
+                    .iter()
+                    .map(|postulante| postulante.documento.to_string()),
+            );
+            Ok(())
+        }
+    }
+
+    fn fila(documento: &str, genero: &str) -> InputFila {
+        InputFila {
+            documento: documento.to_string(),
+            nombre: "Juan".to_string(),
+            primer_apellido: "Perez".to_string(),
+            segundo_apellido: "Quispe".to_string(),
+            fecha_nacimiento: "1990-01-01".to_string(),
+            grado_instruccion: "SECUNDARIA".to_string(),
+            genero: genero.to_string(),
+            password: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reporta_cada_fila_e_inserta_las_validas() {
+        let guardados = Arc::new(Mutex::new(Vec::new()));
+        let caso = ImportarPostulantes::new(
+            Box::new(MockSeguridadPassword),
+            Box::new(MockRepositorio {
+                registrados: HashSet::from(["11111111".to_string()]),
+                guardados: guardados.clone(),
+            }),
+        );
+
+        let resultados = caso
+            .ejecutar(InputData {
+                filas: vec![
+                    (2, fila("12345678", "MASCULINO")),
+                    (3, fila("11111111", "MASCULINO")),
+                    (4, fila("87654321", "OTRO")),
+                    (5, fila(" 12345678 ", "FEMENINO")),
+                    (6, fila("22222222", "FEMENINO")),
+                ],
+            })
+            .await
+            .unwrap();
+
+        let estados: Vec<_> = resultados
+            .iter()
+            .map(|resultado| (resultado.fila, resultado.estado.clone()))
+            .collect();
+        assert!(matches!(estados[0], (2, EstadoFila::Creado { .. })));
+        assert_eq!(estados[1], (3, EstadoFila::Duplicado));
+        assert!(matches!(estados[2], (4, EstadoFila::Invalido(_))));
+        assert_eq!(estados[3], (5, EstadoFila::Duplicado));
+        assert!(matches!(estados[4], (6, EstadoFila::Creado { .. })));
+        assert_eq!(
+            *guardados.lock().unwrap(),
+            vec!["12345678".to_string(), "22222222".to_string()]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 pub mod actualizar_postulante_por_documento;
 pub mod buscar_postulante;
 pub mod buscar_postulante_por_documento;
+pub mod importar_postulantes;
 pub mod lista_postulantes;
 #[allow(dead_code)]
 mod login_postulante;
@@ -1,7 +1,11 @@
 use crate::pregunta::domain::entity::strategy::strategy::strategy_selection;
 use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::escala_likert::EscalaLikert;
 use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
 use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::domain::value_object::numerica::ReglaNumerica;
+use crate::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
+use crate::pregunta::domain::value_object::seleccion::{ModoCalificacion, ReglaSeleccion};
 use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -16,6 +20,16 @@ pub struct PreguntaEntity {
     pub tipo_de_pregunta: TipoPregunta,
     pub alternativas: HashMap<String, String>,
     pub puntaje: HashMap<String, u32>,
+    pub inversa: bool,
+
+    // seleccion solo aplica a preguntas de seleccion multiple
+    pub seleccion: Option<ReglaSeleccion>,
+
+    // ordenamiento solo aplica a preguntas de ordenamiento
+    pub ordenamiento: Option<ModoOrdenamiento>,
+
+    // numerica solo aplica a preguntas numericas
+    pub numerica: Option<ReglaNumerica>,
 }
 
 impl PreguntaEntity {
@@ -34,6 +48,30 @@ impl PreguntaEntity {
         let strategy = strategy_selection(&tipo_de_pregunta);
         strategy.verify(&alternativas, &puntaje)?;
 
+        let puntaje = match tipo_de_pregunta {
+            TipoPregunta::Likert => EscalaLikert::desde_anclas(&alternativas)?.puntaje(),
+            _ => puntaje,
+        };
+
+        let seleccion = match tipo_de_pregunta {
+            TipoPregunta::SeleccionMultiple => Some(ReglaSeleccion::new(
+                1,
+                alternativas.len() as u32,
+                ModoCalificacion::default(),
+            )?),
+            _ => None,
+        };
+
+        let ordenamiento = match tipo_de_pregunta {
+            TipoPregunta::Ordenamiento => Some(ModoOrdenamiento::default()),
+            _ => None,
+        };
+
+        let numerica = match tipo_de_pregunta {
+            TipoPregunta::Numerica => Some(ReglaNumerica::default()),
+            _ => None,
+        };
+
         Ok(Self {
             id,
             contenido,
@@ -42,11 +80,64 @@ impl PreguntaEntity {
             alternativas,
             puntaje,
             imagen_ref,
+            inversa: false,
+            seleccion,
+            ordenamiento,
+            numerica,
         })
     }
+
+    /// Reemplaza la regla por defecto de una pregunta de seleccion multiple,
+    /// que permite marcar de una a todas las alternativas.
+    pub fn asignar_seleccion(&mut self, seleccion: ReglaSeleccion) -> Result<(), PreguntaError> {
+        if !matches!(self.tipo_de_pregunta, TipoPregunta::SeleccionMultiple) {
+            return Err(PreguntaError::SeleccionNoValida(format!(
+                "una pregunta {} no admite seleccion",
+                self.tipo_de_pregunta
+            )));
+        }
+
+        if seleccion.maximo as usize > self.alternativas.len() {
+            return Err(PreguntaError::SeleccionNoValida(format!(
+                "el maximo ({}) supera las {} alternativas",
+                seleccion.maximo,
+                self.alternativas.len()
+            )));
+        }
+
+        self.seleccion = Some(seleccion);
+        Ok(())
+    }
+
+    pub fn asignar_ordenamiento(&mut self, modo: ModoOrdenamiento) -> Result<(), PreguntaError> {
+        if !matches!(self.tipo_de_pregunta, TipoPregunta::Ordenamiento) {
+            return Err(PreguntaError::OrdenNoValido(format!(
+                "una pregunta {} no admite modo de ordenamiento",
+                self.tipo_de_pregunta
+            )));
+        }
+
+        self.ordenamiento = Some(modo);
+        Ok(())
+    }
+
+    /// Reemplaza la regla por defecto de una pregunta numerica, que exige el
+    /// valor exacto y no tiene unidad.
+    pub fn asignar_numerica(&mut self, regla: ReglaNumerica) -> Result<(), PreguntaError> {
+        if !matches!(self.tipo_de_pregunta, TipoPregunta::Numerica) {
+            return Err(PreguntaError::NumericaNoValida(format!(
+                "una pregunta {} no admite tolerancia",
+                self.tipo_de_pregunta
+            )));
+        }
+
+        self.numerica = Some(regla);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
     use crate::pregunta::domain::value_object::alternativa::Alternativa;
@@ -197,4 +288,114 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_seleccion_multiple_regla() {
+        let alternativas = HashMap::from([
+            ("A".to_string(), "2".to_string()),
+            ("B".to_string(), "4".to_string()),
+            ("C".to_string(), "5".to_string()),
+        ]);
+        let puntaje = HashMap::from([("A".to_string(), 1), ("C".to_string(), 1)]);
+
+        let mut pregunta = PreguntaEntity::new(
+            "¿Cuales son primos?".to_string(),
+            "no".to_string(),
+            "seleccion_multiple".to_string(),
+            None,
+            alternativas,
+            puntaje,
+        )
+        .unwrap();
+        assert_eq!(pregunta.seleccion.as_ref().unwrap().maximo, 3);
+
+        let excede = ReglaSeleccion::new(1, 4, ModoCalificacion::Exacta).unwrap();
+        assert!(matches!(
+            pregunta.asignar_seleccion(excede),
+            Err(PreguntaError::SeleccionNoValida(_))
+        ));
+
+        let regla = ReglaSeleccion::new(2, 2, ModoCalificacion::Proporcional).unwrap();
+        assert!(pregunta.asignar_seleccion(regla).is_ok());
+    }
+
+    #[test]
+    fn test_likert_puntaje_desde_anclas() {
+        let anclas = HashMap::from([
+            ("1".to_string(), "Totalmente en desacuerdo".to_string()),
+            ("2".to_string(), "En desacuerdo".to_string()),
+            ("3".to_string(), "Neutral".to_string()),
+            ("4".to_string(), "De acuerdo".to_string()),
+            ("5".to_string(), "Totalmente de acuerdo".to_string()),
+        ]);
+
+        let pregunta = PreguntaEntity::new(
+            "Disfruto de las reuniones sociales".to_string(),
+            "extrovertido".to_string(),
+            "likert".to_string(),
+            None,
+            anclas,
+            HashMap::new(),
+        )
+        .unwrap();
+        assert_eq!(pregunta.puntaje.get("1"), Some(&1));
+        assert_eq!(pregunta.puntaje.get("5"), Some(&5));
+    }
+
+    #[test]
+    fn test_ordenamiento_posiciones() {
+        let alternativas = HashMap::from([
+            ("A".to_string(), "Encender el equipo".to_string()),
+            ("B".to_string(), "Iniciar sesion".to_string()),
+            ("C".to_string(), "Abrir el sistema".to_string()),
+        ]);
+        let crear = |puntaje: HashMap<String, u32>| {
+            PreguntaEntity::new(
+                "Ordene los pasos".to_string(),
+                "no".to_string(),
+                "ordenamiento".to_string(),
+                None,
+                alternativas.clone(),
+                puntaje,
+            )
+        };
+
+        let pregunta = crear(HashMap::from([
+            ("A".to_string(), 1),
+            ("B".to_string(), 2),
+            ("C".to_string(), 3),
+        ]))
+        .unwrap();
+        assert_eq!(pregunta.ordenamiento, Some(ModoOrdenamiento::Exacto));
+
+        let repetida = crear(HashMap::from([
+            ("A".to_string(), 1),
+            ("B".to_string(), 1),
+            ("C".to_string(), 3),
+        ]));
+        assert!(matches!(repetida, Err(PreguntaError::OrdenNoValido(_))));
+    }
+
+    #[test]
+    fn test_numerica_valor_esperado() {
+        let crear = |puntaje: HashMap<String, u32>| {
+            PreguntaEntity::new(
+                "Cuanto es 2,5 por 5".to_string(),
+                "no".to_string(),
+                "numerica".to_string(),
+                None,
+                HashMap::new(),
+                puntaje,
+            )
+        };
+
+        let pregunta = crear(HashMap::from([("12,5".to_string(), 2)])).unwrap();
+        assert_eq!(pregunta.numerica, Some(ReglaNumerica::default()));
+
+        let no_numerica = crear(HashMap::from([("doce".to_string(), 2)]));
+        assert!(matches!(
+            no_numerica,
+            Err(PreguntaError::NumericaNoValida(_))
+        ));
+    }
 }
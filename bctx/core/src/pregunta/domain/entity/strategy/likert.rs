@@ -0,0 +1,34 @@
+use crate::pregunta::domain::entity::strategy::strategy::TipoPreguntaStrategy;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::escala_likert::EscalaLikert;
+use std::collections::HashMap;
+
+pub struct PreguntaLikertStrategy;
+
+impl TipoPreguntaStrategy for PreguntaLikertStrategy {
+    fn ajustar_alternativas(
+        &self,
+        alternativas: &HashMap<String, String>,
+    ) -> Result<(), PreguntaError> {
+        EscalaLikert::desde_anclas(alternativas)?;
+        Ok(())
+    }
+
+    // El puntaje se deriva de las anclas, no se envia
+    fn ajustar_puntaje(&self, puntaje: &HashMap<String, u32>) -> Result<(), PreguntaError> {
+        if !puntaje.is_empty() {
+            return Err(PreguntaError::EscalaLikertNoValida(
+                "el puntaje se calcula a partir de las anclas".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn verificar_consistencia(
+        &self,
+        _alternativas: &HashMap<String, String>,
+        _puntaje: &HashMap<String, u32>,
+    ) -> Result<(), PreguntaError> {
+        Ok(())
+    }
+}
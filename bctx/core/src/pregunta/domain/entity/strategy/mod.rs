@@ -4,5 +4,9 @@ pub mod strategy;
 mod alternativa_peso;
 mod alternativa_unica;
 mod libre;
+mod likert;
+mod numerica;
+mod ordenamiento;
+mod seleccion_multiple;
 mod si_no;
 mod sola_respuesta;
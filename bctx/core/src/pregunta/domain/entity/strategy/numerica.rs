@@ -0,0 +1,41 @@
+use crate::pregunta::domain::entity::strategy::strategy::TipoPreguntaStrategy;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::numerica::parsear_decimal;
+use std::collections::HashMap;
+
+pub struct PreguntaNumericaStrategy;
+
+impl TipoPreguntaStrategy for PreguntaNumericaStrategy {
+    fn ajustar_alternativas(
+        &self,
+        _alternativas: &HashMap<String, String>,
+    ) -> Result<(), PreguntaError> {
+        Ok(())
+    }
+
+    fn ajustar_puntaje(&self, _puntaje: &HashMap<String, u32>) -> Result<(), PreguntaError> {
+        Ok(())
+    }
+
+    // La unica clave del puntaje es el valor esperado
+    fn verificar_consistencia(
+        &self,
+        _alternativas: &HashMap<String, String>,
+        puntaje: &HashMap<String, u32>,
+    ) -> Result<(), PreguntaError> {
+        if puntaje.is_empty() {
+            return Err(PreguntaError::PuntajeNoExiste);
+        }
+
+        if puntaje.len() > 1 {
+            return Err(PreguntaError::DebeTenerUnaSolaRespuesta);
+        }
+
+        match puntaje.keys().find(|k| parsear_decimal(k).is_none()) {
+            Some(valor) => Err(PreguntaError::NumericaNoValida(format!(
+                "el valor esperado '{valor}' no es un numero"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::pregunta::domain::entity::strategy::strategy::TipoPreguntaStrategy;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::HashMap;
+
+pub struct PreguntaOrdenamientoStrategy;
+
+impl TipoPreguntaStrategy for PreguntaOrdenamientoStrategy {
+    // El puntaje de cada alternativa es su posicion en el orden correcto
+    fn verificar_consistencia(
+        &self,
+        alternativas: &HashMap<String, String>,
+        puntaje: &HashMap<String, u32>,
+    ) -> Result<(), PreguntaError> {
+        if puntaje.len() != alternativas.len()
+            || puntaje.keys().any(|k| !alternativas.contains_key(k))
+        {
+            return Err(PreguntaError::OrdenNoValido(
+                "cada alternativa debe tener su posicion".to_string(),
+            ));
+        }
+
+        let mut posiciones: Vec<u32> = puntaje.values().copied().collect();
+        posiciones.sort_unstable();
+        if posiciones
+            .iter()
+            .enumerate()
+            .any(|(i, posicion)| *posicion != i as u32 + 1)
+        {
+            return Err(PreguntaError::OrdenNoValido(format!(
+                "las posiciones deben ir de 1 a {} sin repetirse",
+                alternativas.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::pregunta::domain::entity::strategy::strategy::TipoPreguntaStrategy;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::HashMap;
+
+pub struct PreguntaSeleccionMultipleStrategy;
+
+impl TipoPreguntaStrategy for PreguntaSeleccionMultipleStrategy {
+    fn verificar_consistencia(
+        &self,
+        alternativas: &HashMap<String, String>,
+        puntaje: &HashMap<String, u32>,
+    ) -> Result<(), PreguntaError> {
+        if puntaje.keys().any(|k| !alternativas.contains_key(k)) {
+            return Err(PreguntaError::PuntajeNoCoincideConAlternativa);
+        }
+
+        // Las alternativas correctas son las que tienen puntaje
+        if !puntaje.values().any(|puntos| *puntos > 0) {
+            return Err(PreguntaError::PuntajeNoAjustado);
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 use crate::pregunta::domain::entity::strategy::alternativa_peso::PreguntaAlternativasConPesoStrategy;
 use crate::pregunta::domain::entity::strategy::alternativa_unica::PreguntaAlternativaRespuestaUnicaStrategy;
 use crate::pregunta::domain::entity::strategy::libre::PreguntaLibreStrategy;
+use crate::pregunta::domain::entity::strategy::likert::PreguntaLikertStrategy;
+use crate::pregunta::domain::entity::strategy::numerica::PreguntaNumericaStrategy;
+use crate::pregunta::domain::entity::strategy::ordenamiento::PreguntaOrdenamientoStrategy;
+use crate::pregunta::domain::entity::strategy::seleccion_multiple::PreguntaSeleccionMultipleStrategy;
 use crate::pregunta::domain::entity::strategy::si_no::PreguntaSiNoStrategy;
 use crate::pregunta::domain::entity::strategy::sola_respuesta::PreguntaSolaRespuestaStrategy;
 use crate::pregunta::domain::error::alternativa::AlternativaError;
@@ -88,5 +92,9 @@ pub fn strategy_selection(tipo: &TipoPregunta) -> Box<dyn TipoPreguntaStrategy>
         TipoPregunta::Libre => Box::new(PreguntaLibreStrategy),
         TipoPregunta::SolaRespuesta => Box::new(PreguntaSolaRespuestaStrategy),
         TipoPregunta::SioNo => Box::new(PreguntaSiNoStrategy),
+        TipoPregunta::SeleccionMultiple => Box::new(PreguntaSeleccionMultipleStrategy),
+        TipoPregunta::Likert => Box::new(PreguntaLikertStrategy),
+        TipoPregunta::Ordenamiento => Box::new(PreguntaOrdenamientoStrategy),
+        TipoPregunta::Numerica => Box::new(PreguntaNumericaStrategy),
     }
 }
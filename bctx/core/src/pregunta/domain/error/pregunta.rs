@@ -36,6 +36,33 @@ pub enum PreguntaError {
     #[error("puntaje no coincide con alternativa")]
     PuntajeNoCoincideConAlternativa,
 
+    #[error("Seleccion no valida: {0}")]
+    SeleccionNoValida(String),
+
+    #[error("Escala likert no valida: {0}")]
+    EscalaLikertNoValida(String),
+
+    #[error("Orden no valido: {0}")]
+    OrdenNoValido(String),
+
+    #[error("Pregunta numerica no valida: {0}")]
+    NumericaNoValida(String),
+
+    #[error("Pregunta no existe en el banco: {0}")]
+    PreguntaNoExiste(String),
+
+    #[error("Pregunta ya existe en el banco: {0}")]
+    PreguntaYaExiste(String),
+
+    #[error("Pregunta en uso por al menos un examen: {0}")]
+    PreguntaEnUso(String),
+
+    #[error("La pregunta {0} no pertenece al examen")]
+    PreguntaNoEstaEnExamen(String),
+
+    #[error("La pregunta {0} es del banco y se edita desde el banco")]
+    PreguntaDelBanco(String),
+
     #[error("Validacion de examenID fallida")]
     PreguntaErrorExamenID(#[from] IdError),
 
@@ -62,4 +89,7 @@ pub enum RepositorioError {
 
     #[error("Persistencia no finalizada")]
     ActualizacionNoFinalizada,
+
+    #[error("Examen no existe")]
+    ExamenNoExiste,
 }
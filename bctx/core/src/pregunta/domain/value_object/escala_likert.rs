@@ -0,0 +1,72 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::HashMap;
+
+const MINIMO_PUNTOS: u32 = 3;
+const MAXIMO_PUNTOS: u32 = 11;
+
+/// Escala de acuerdo de una pregunta likert. Las anclas son las alternativas
+/// "1".."N" con su texto y cada punto vale su numero. Los items inversos se
+/// reflejan al puntuar su rasgo, no aqui.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscalaLikert {
+    pub puntos: u32,
+}
+
+impl EscalaLikert {
+    pub fn desde_anclas(anclas: &HashMap<String, String>) -> Result<Self, PreguntaError> {
+        let puntos = anclas.len() as u32;
+        if !(MINIMO_PUNTOS..=MAXIMO_PUNTOS).contains(&puntos) {
+            return Err(PreguntaError::EscalaLikertNoValida(format!(
+                "la escala debe tener entre {MINIMO_PUNTOS} y {MAXIMO_PUNTOS} puntos"
+            )));
+        }
+
+        for punto in 1..=puntos {
+            match anclas.get(&punto.to_string()) {
+                Some(texto) if !texto.trim().is_empty() => {}
+                _ => {
+                    return Err(PreguntaError::EscalaLikertNoValida(format!(
+                        "falta el texto del punto {punto}"
+                    )));
+                }
+            }
+        }
+
+        Ok(Self { puntos })
+    }
+
+    pub fn puntaje(&self) -> HashMap<String, u32> {
+        (1..=self.puntos)
+            .map(|punto| (punto.to_string(), punto))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anclas(puntos: u32) -> HashMap<String, String> {
+        (1..=puntos)
+            .map(|punto| (punto.to_string(), format!("Nivel {punto}")))
+            .collect()
+    }
+
+    #[test]
+    fn test_puntaje_por_punto() {
+        let escala = EscalaLikert::desde_anclas(&anclas(7)).unwrap();
+        assert_eq!(escala.puntaje().len(), 7);
+        assert_eq!(escala.puntaje().get("1"), Some(&1));
+        assert_eq!(escala.puntaje().get("7"), Some(&7));
+    }
+
+    #[test]
+    fn test_anclas_no_validas() {
+        assert!(EscalaLikert::desde_anclas(&anclas(2)).is_err());
+
+        let mut salteada = anclas(5);
+        salteada.remove("3");
+        salteada.insert("6".to_string(), "Nivel 6".to_string());
+        assert!(EscalaLikert::desde_anclas(&salteada).is_err());
+    }
+}
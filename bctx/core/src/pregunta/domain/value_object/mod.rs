@@ -1,4 +1,9 @@
 pub mod alternativa;
+pub mod escala_likert;
 pub mod etiqueta;
 pub mod id;
+pub mod numerica;
+pub mod ordenamiento;
+pub mod origen;
+pub mod seleccion;
 pub mod tipo_pregunta;
@@ -0,0 +1,180 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+const ABSOLUTA: &str = "absoluta";
+const RELATIVA: &str = "relativa";
+
+// margen para que errores de redondeo como 0.1 + 0.2 no dejen fuera una
+// respuesta que esta justo en el limite de la tolerancia
+const EPSILON: f64 = 1e-9;
+
+/// Interpreta un decimal escrito con `,` o `.` como separador. Si aparecen
+/// ambos, el ultimo es el separador decimal y el otro agrupa miles; si uno
+/// solo se repite, agrupa miles. Un separador unico siempre es decimal, por
+/// lo que "1,5" y "1.5" valen lo mismo.
+pub fn parsear_decimal(texto: &str) -> Option<f64> {
+    let texto: String = texto.chars().filter(|c| !c.is_whitespace()).collect();
+    let coma = texto.rfind(',');
+    let punto = texto.rfind('.');
+
+    let normalizado = match (coma, punto) {
+        (Some(coma), Some(punto)) if coma > punto => texto.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => texto.replace(',', ""),
+        (Some(_), None) if texto.matches(',').count() > 1 => texto.replace(',', ""),
+        (Some(_), None) => texto.replace(',', "."),
+        (None, Some(_)) if texto.matches('.').count() > 1 => texto.replace('.', ""),
+        _ => texto,
+    };
+
+    normalizado
+        .parse::<f64>()
+        .ok()
+        .filter(|valor| valor.is_finite())
+}
+
+/// Si la tolerancia es una distancia fija al valor esperado o una fraccion
+/// de el (0.05 es un 5%).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TipoTolerancia {
+    #[default]
+    Absoluta,
+    Relativa,
+}
+
+impl fmt::Display for TipoTolerancia {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TipoTolerancia::Absoluta => write!(f, "{}", ABSOLUTA),
+            TipoTolerancia::Relativa => write!(f, "{}", RELATIVA),
+        }
+    }
+}
+
+impl FromStr for TipoTolerancia {
+    type Err = PreguntaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            ABSOLUTA => Ok(TipoTolerancia::Absoluta),
+            RELATIVA => Ok(TipoTolerancia::Relativa),
+            _ => Err(PreguntaError::NumericaNoValida(format!(
+                "la tolerancia debe ser {ABSOLUTA} o {RELATIVA}"
+            ))),
+        }
+    }
+}
+
+/// Como se compara la respuesta de una pregunta numerica con el valor
+/// esperado, que es la unica clave de su puntaje. La unidad, si existe, puede
+/// acompanar a la respuesta ("12,5 cm").
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReglaNumerica {
+    pub tolerancia: f64,
+    pub tipo: TipoTolerancia,
+    pub unidad: Option<String>,
+}
+
+impl ReglaNumerica {
+    pub fn new(
+        tolerancia: f64,
+        tipo: TipoTolerancia,
+        unidad: Option<String>,
+    ) -> Result<Self, PreguntaError> {
+        if !tolerancia.is_finite() || tolerancia < 0.0 {
+            return Err(PreguntaError::NumericaNoValida(format!(
+                "la tolerancia ({tolerancia}) debe ser un numero no negativo"
+            )));
+        }
+
+        let unidad = unidad
+            .map(|unidad| unidad.trim().to_string())
+            .filter(|unidad| !unidad.is_empty());
+
+        Ok(Self {
+            tolerancia,
+            tipo,
+            unidad,
+        })
+    }
+
+    pub fn acepta(&self, esperado: f64, respuesta: &str) -> bool {
+        let respuesta = respuesta.trim();
+        let respuesta = match &self.unidad {
+            Some(unidad) => respuesta
+                .len()
+                .checked_sub(unidad.len())
+                .and_then(|corte| respuesta.get(..corte).zip(respuesta.get(corte..)))
+                .filter(|(_, sufijo)| sufijo.to_lowercase() == unidad.to_lowercase())
+                .map_or(respuesta, |(valor, _)| valor),
+            None => respuesta,
+        };
+
+        let Some(valor) = parsear_decimal(respuesta) else {
+            return false;
+        };
+
+        let margen = match self.tipo {
+            TipoTolerancia::Absoluta => self.tolerancia,
+            TipoTolerancia::Relativa => self.tolerancia * esperado.abs(),
+        };
+        (valor - esperado).abs() <= margen + EPSILON
+    }
+
+    /// Otorga el puntaje del valor esperado si la unica respuesta dada esta
+    /// dentro de la tolerancia.
+    pub fn calificar(&self, respuesta: &[String], puntaje: &HashMap<String, u32>) -> u32 {
+        let [respuesta] = respuesta else {
+            return 0;
+        };
+
+        puntaje
+            .iter()
+            .filter_map(|(esperado, puntos)| parsear_decimal(esperado).map(|e| (e, *puntos)))
+            .find(|(esperado, _)| self.acepta(*esperado, respuesta))
+            .map_or(0, |(_, puntos)| puntos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsear_decimal() {
+        assert_eq!(parsear_decimal("12,5"), Some(12.5));
+        assert_eq!(parsear_decimal("12.5"), Some(12.5));
+        assert_eq!(parsear_decimal("1.234,5"), Some(1234.5));
+        assert_eq!(parsear_decimal("1,234.5"), Some(1234.5));
+        assert_eq!(parsear_decimal("1.000.000"), Some(1_000_000.0));
+        assert_eq!(parsear_decimal(" -3 "), Some(-3.0));
+        assert_eq!(parsear_decimal("doce"), None);
+    }
+
+    #[test]
+    fn test_tolerancia_absoluta_con_unidad() {
+        let regla =
+            ReglaNumerica::new(0.1, TipoTolerancia::Absoluta, Some("cm".to_string())).unwrap();
+        assert!(regla.acepta(12.5, "12,6 cm"));
+        assert!(regla.acepta(12.5, "12.4"));
+        assert!(!regla.acepta(12.5, "12,7 cm"));
+        assert!(!regla.acepta(12.5, "12,5 kg"));
+    }
+
+    #[test]
+    fn test_tolerancia_relativa() {
+        let regla = ReglaNumerica::new(0.05, TipoTolerancia::Relativa, None).unwrap();
+        let puntaje = HashMap::from([("200".to_string(), 2)]);
+        assert_eq!(regla.calificar(&["209".to_string()], &puntaje), 2);
+        assert_eq!(regla.calificar(&["211".to_string()], &puntaje), 0);
+    }
+
+    #[test]
+    fn test_tolerancia_negativa() {
+        assert!(matches!(
+            ReglaNumerica::new(-1.0, TipoTolerancia::Absoluta, None),
+            Err(PreguntaError::NumericaNoValida(_))
+        ));
+    }
+}
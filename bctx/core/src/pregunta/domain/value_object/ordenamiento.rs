@@ -0,0 +1,169 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+const EXACTO: &str = "exacto";
+const POSICIONAL: &str = "posicional";
+const KENDALL: &str = "kendall";
+
+/// Como se califica una pregunta de ordenamiento. Cada alternativa bien
+/// ubicada vale un punto, de modo que el maximo es la cantidad de
+/// alternativas. `Exacto` otorga el maximo solo si todo el orden es correcto,
+/// `Posicional` cuenta las alternativas en su posicion y `Kendall` otorga la
+/// fraccion del maximo que corresponde a los pares en el orden correcto
+/// (redondeado hacia abajo).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ModoOrdenamiento {
+    #[default]
+    Exacto,
+    Posicional,
+    Kendall,
+}
+
+impl fmt::Display for ModoOrdenamiento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModoOrdenamiento::Exacto => write!(f, "{}", EXACTO),
+            ModoOrdenamiento::Posicional => write!(f, "{}", POSICIONAL),
+            ModoOrdenamiento::Kendall => write!(f, "{}", KENDALL),
+        }
+    }
+}
+
+impl FromStr for ModoOrdenamiento {
+    type Err = PreguntaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            EXACTO => Ok(ModoOrdenamiento::Exacto),
+            POSICIONAL => Ok(ModoOrdenamiento::Posicional),
+            KENDALL => Ok(ModoOrdenamiento::Kendall),
+            _ => Err(PreguntaError::OrdenNoValido(format!(
+                "el modo debe ser {EXACTO}, {POSICIONAL} o {KENDALL}"
+            ))),
+        }
+    }
+}
+
+impl ModoOrdenamiento {
+    /// En una pregunta de ordenamiento el puntaje guarda la posicion correcta
+    /// (1..N) de cada alternativa; el orden correcto son las claves ordenadas
+    /// por esa posicion.
+    pub fn orden_correcto(puntaje: &HashMap<String, u32>) -> Vec<&String> {
+        let mut orden: Vec<(&String, &u32)> = puntaje.iter().collect();
+        orden.sort_by_key(|(_, posicion)| **posicion);
+        orden.into_iter().map(|(clave, _)| clave).collect()
+    }
+
+    /// Verifica que la respuesta ordena todas las alternativas una sola vez.
+    pub fn es_permutacion(respuesta: &[String], puntaje: &HashMap<String, u32>) -> bool {
+        let mut marcadas: Vec<&String> = respuesta.iter().collect();
+        marcadas.sort();
+        marcadas.dedup();
+        marcadas.len() == respuesta.len()
+            && respuesta.len() == puntaje.len()
+            && respuesta.iter().all(|clave| puntaje.contains_key(clave))
+    }
+
+    pub fn calificar(&self, respuesta: &[String], puntaje: &HashMap<String, u32>) -> u32 {
+        if !Self::es_permutacion(respuesta, puntaje) {
+            return 0;
+        }
+
+        let total = puntaje.len() as u32;
+        let orden = Self::orden_correcto(puntaje);
+        let en_posicion = respuesta
+            .iter()
+            .zip(orden.iter())
+            .filter(|(dada, correcta)| dada == *correcta)
+            .count() as u32;
+
+        match self {
+            ModoOrdenamiento::Exacto if en_posicion == total => total,
+            ModoOrdenamiento::Exacto => 0,
+            ModoOrdenamiento::Posicional => en_posicion,
+            ModoOrdenamiento::Kendall => {
+                let posiciones: Vec<u32> = respuesta.iter().map(|clave| puntaje[clave]).collect();
+                let pares = posiciones.len() * posiciones.len().saturating_sub(1) / 2;
+                if pares == 0 {
+                    return total;
+                }
+
+                let concordantes = (0..posiciones.len())
+                    .flat_map(|i| (i + 1..posiciones.len()).map(move |j| (i, j)))
+                    .filter(|(i, j)| posiciones[*i] < posiciones[*j])
+                    .count();
+                total * concordantes as u32 / pares as u32
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puntaje() -> HashMap<String, u32> {
+        HashMap::from([
+            ("A".to_string(), 1),
+            ("B".to_string(), 2),
+            ("C".to_string(), 3),
+            ("D".to_string(), 4),
+        ])
+    }
+
+    fn ordenar(claves: &[&str]) -> Vec<String> {
+        claves.iter().map(|clave| clave.to_string()).collect()
+    }
+
+    #[test]
+    fn test_exacto() {
+        let modo = ModoOrdenamiento::Exacto;
+        assert_eq!(
+            modo.calificar(&ordenar(&["A", "B", "C", "D"]), &puntaje()),
+            4
+        );
+        assert_eq!(
+            modo.calificar(&ordenar(&["A", "B", "D", "C"]), &puntaje()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_posicional() {
+        let modo = ModoOrdenamiento::Posicional;
+        assert_eq!(
+            modo.calificar(&ordenar(&["A", "B", "D", "C"]), &puntaje()),
+            2
+        );
+        assert_eq!(
+            modo.calificar(&ordenar(&["D", "C", "B", "A"]), &puntaje()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_kendall() {
+        let modo = ModoOrdenamiento::Kendall;
+        // 5 de 6 pares en orden
+        assert_eq!(
+            modo.calificar(&ordenar(&["A", "B", "D", "C"]), &puntaje()),
+            3
+        );
+        assert_eq!(
+            modo.calificar(&ordenar(&["D", "C", "B", "A"]), &puntaje()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_respuesta_incompleta_no_puntua() {
+        let modo = ModoOrdenamiento::Posicional;
+        assert_eq!(modo.calificar(&ordenar(&["A", "B", "C"]), &puntaje()), 0);
+        assert_eq!(
+            modo.calificar(&ordenar(&["A", "A", "C", "D"]), &puntaje()),
+            0
+        );
+    }
+}
@@ -0,0 +1,7 @@
+/// Si una pregunta de un examen fue agregada al examen o vinculada desde el
+/// banco. Las del banco se editan en el banco.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrigenPregunta {
+    Propia,
+    Banco,
+}
@@ -0,0 +1,165 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+const EXACTA: &str = "exacta";
+const PROPORCIONAL: &str = "proporcional";
+
+/// Como se califica una pregunta de seleccion multiple. `Exacta` otorga el
+/// puntaje solo si se marcan exactamente las alternativas correctas.
+/// `Proporcional` otorga los puntos de cada alternativa correcta marcada y
+/// descuenta por cada incorrecta el puntaje promedio de las correctas, sin
+/// bajar de cero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ModoCalificacion {
+    #[default]
+    Exacta,
+    Proporcional,
+}
+
+impl fmt::Display for ModoCalificacion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModoCalificacion::Exacta => write!(f, "{}", EXACTA),
+            ModoCalificacion::Proporcional => write!(f, "{}", PROPORCIONAL),
+        }
+    }
+}
+
+impl FromStr for ModoCalificacion {
+    type Err = PreguntaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            EXACTA => Ok(ModoCalificacion::Exacta),
+            PROPORCIONAL => Ok(ModoCalificacion::Proporcional),
+            _ => Err(PreguntaError::SeleccionNoValida(format!(
+                "el modo debe ser {EXACTA} o {PROPORCIONAL}"
+            ))),
+        }
+    }
+}
+
+/// Cuantas alternativas puede marcar el postulante en una pregunta de
+/// seleccion multiple y como se califica lo marcado. Las alternativas
+/// correctas son las que tienen puntaje mayor que cero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReglaSeleccion {
+    pub minimo: u32,
+    pub maximo: u32,
+    pub modo: ModoCalificacion,
+}
+
+impl ReglaSeleccion {
+    pub fn new(minimo: u32, maximo: u32, modo: ModoCalificacion) -> Result<Self, PreguntaError> {
+        if minimo == 0 {
+            return Err(PreguntaError::SeleccionNoValida(
+                "se debe marcar al menos una alternativa".to_string(),
+            ));
+        }
+
+        if minimo > maximo {
+            return Err(PreguntaError::SeleccionNoValida(format!(
+                "el minimo ({minimo}) no puede superar al maximo ({maximo})"
+            )));
+        }
+
+        Ok(Self {
+            minimo,
+            maximo,
+            modo,
+        })
+    }
+
+    pub fn admite(&self, cantidad: usize) -> bool {
+        (self.minimo as usize..=self.maximo as usize).contains(&cantidad)
+    }
+
+    pub fn calificar(&self, respuesta: &[String], puntaje: &HashMap<String, u32>) -> u32 {
+        let marcadas: HashSet<&String> = respuesta.iter().collect();
+        let correctas: HashSet<&String> = puntaje
+            .iter()
+            .filter(|(_, puntos)| **puntos > 0)
+            .map(|(clave, _)| clave)
+            .collect();
+        let total: u32 = correctas
+            .iter()
+            .filter_map(|clave| puntaje.get(*clave))
+            .sum();
+
+        if correctas.is_empty() {
+            return 0;
+        }
+
+        match self.modo {
+            ModoCalificacion::Exacta if marcadas == correctas => total,
+            ModoCalificacion::Exacta => 0,
+            ModoCalificacion::Proporcional => {
+                let aciertos = marcadas.intersection(&correctas).count() as u32;
+                let errores = marcadas.len() as u32 - aciertos;
+                let obtenidos: u32 = marcadas
+                    .intersection(&correctas)
+                    .filter_map(|clave| puntaje.get(*clave))
+                    .sum();
+                let cantidad = correctas.len() as u32;
+                (obtenidos * cantidad).saturating_sub(errores * total) / cantidad
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puntaje() -> HashMap<String, u32> {
+        HashMap::from([
+            ("A".to_string(), 2),
+            ("B".to_string(), 0),
+            ("C".to_string(), 2),
+            ("D".to_string(), 0),
+        ])
+    }
+
+    fn marcar(claves: &[&str]) -> Vec<String> {
+        claves.iter().map(|clave| clave.to_string()).collect()
+    }
+
+    #[test]
+    fn test_regla_no_valida() {
+        assert!(ReglaSeleccion::new(0, 2, ModoCalificacion::Exacta).is_err());
+        assert!(ReglaSeleccion::new(3, 2, ModoCalificacion::Exacta).is_err());
+    }
+
+    #[test]
+    fn test_calificar_exacta() {
+        let regla = ReglaSeleccion::new(1, 4, ModoCalificacion::Exacta).unwrap();
+        assert_eq!(regla.calificar(&marcar(&["C", "A"]), &puntaje()), 4);
+        assert_eq!(regla.calificar(&marcar(&["A"]), &puntaje()), 0);
+        assert_eq!(regla.calificar(&marcar(&["A", "B", "C"]), &puntaje()), 0);
+    }
+
+    #[test]
+    fn test_calificar_proporcional() {
+        let regla = ReglaSeleccion::new(1, 4, ModoCalificacion::Proporcional).unwrap();
+        assert_eq!(regla.calificar(&marcar(&["A", "C"]), &puntaje()), 4);
+        assert_eq!(regla.calificar(&marcar(&["A"]), &puntaje()), 2);
+        assert_eq!(regla.calificar(&marcar(&["A", "B"]), &puntaje()), 0);
+        assert_eq!(regla.calificar(&marcar(&["A", "B", "C"]), &puntaje()), 2);
+    }
+
+    #[test]
+    fn test_calificar_proporcional_con_pesos() {
+        let regla = ReglaSeleccion::new(1, 4, ModoCalificacion::Proporcional).unwrap();
+        let puntaje = HashMap::from([
+            ("A".to_string(), 3),
+            ("B".to_string(), 0),
+            ("C".to_string(), 1),
+        ]);
+        assert_eq!(regla.calificar(&marcar(&["A"]), &puntaje), 3);
+        assert_eq!(regla.calificar(&marcar(&["C"]), &puntaje), 1);
+        assert_eq!(regla.calificar(&marcar(&["A", "B"]), &puntaje), 1);
+        assert_eq!(regla.calificar(&marcar(&["C", "B"]), &puntaje), 0);
+    }
+}
@@ -7,6 +7,10 @@ const ALTERNATIVA_PESO: &str = "alternativa_peso";
 const LIBRE: &str = "libre";
 const SOLA_RESPUESTA: &str = "sola_respuesta";
 const SI_O_NO: &str = "si_o_no";
+const SELECCION_MULTIPLE: &str = "seleccion_multiple";
+const LIKERT: &str = "likert";
+const ORDENAMIENTO: &str = "ordenamiento";
+const NUMERICA: &str = "numerica";
 
 #[derive(Debug, Clone)]
 pub enum TipoPregunta {
@@ -15,6 +19,10 @@ pub enum TipoPregunta {
     Libre,
     SolaRespuesta,
     SioNo,
+    SeleccionMultiple,
+    Likert,
+    Ordenamiento,
+    Numerica,
 }
 
 impl fmt::Display for TipoPregunta {
@@ -25,6 +33,10 @@ impl fmt::Display for TipoPregunta {
             TipoPregunta::Libre => write!(f, "{}", LIBRE),
             TipoPregunta::SolaRespuesta => write!(f, "{}", SOLA_RESPUESTA),
             TipoPregunta::SioNo => write!(f, "{}", SI_O_NO),
+            TipoPregunta::SeleccionMultiple => write!(f, "{}", SELECCION_MULTIPLE),
+            TipoPregunta::Likert => write!(f, "{}", LIKERT),
+            TipoPregunta::Ordenamiento => write!(f, "{}", ORDENAMIENTO),
+            TipoPregunta::Numerica => write!(f, "{}", NUMERICA),
         }
     }
 }
@@ -39,6 +51,10 @@ impl FromStr for TipoPregunta {
             LIBRE => Ok(TipoPregunta::Libre),
             SOLA_RESPUESTA => Ok(TipoPregunta::SolaRespuesta),
             SI_O_NO => Ok(TipoPregunta::SioNo),
+            SELECCION_MULTIPLE => Ok(TipoPregunta::SeleccionMultiple),
+            LIKERT => Ok(TipoPregunta::Likert),
+            ORDENAMIENTO => Ok(TipoPregunta::Ordenamiento),
+            NUMERICA => Ok(TipoPregunta::Numerica),
             _ => Err(TipoPreguntaError::NoValido),
         }
     }
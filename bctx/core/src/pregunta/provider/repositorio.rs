@@ -1,5 +1,8 @@
 use crate::examen::domain::value_object::id::ExamenID;
+use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
 use crate::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::domain::value_object::origen::OrigenPregunta;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -10,3 +13,45 @@ pub trait RepositorioAgregarPregunta<Error>: Send + Sync {
         lista_de_preguntas: ListaDePreguntas,
     ) -> Result<(), Error>;
 }
+
+/// Banco de preguntas reutilizables entre examenes
+#[async_trait]
+pub trait RepositorioBancoPreguntas<Error>: Send + Sync {
+    async fn guardar(&self, pregunta: PreguntaEntity) -> Result<(), Error>;
+
+    async fn listar(&self) -> Result<Vec<PreguntaEntity>, Error>;
+
+    async fn obtener(&self, id: &PreguntaID) -> Result<Option<PreguntaEntity>, Error>;
+
+    async fn actualizar(&self, pregunta: PreguntaEntity) -> Result<(), Error>;
+
+    async fn eliminar(&self, id: &PreguntaID) -> Result<(), Error>;
+
+    /// Indica si algun examen referencia la pregunta
+    async fn en_uso(&self, id: &PreguntaID) -> Result<bool, Error>;
+}
+
+#[async_trait]
+pub trait RepositorioVincularPreguntaBanco<Error>: Send + Sync {
+    async fn vincular(
+        &self,
+        examen_id: ExamenID,
+        pregunta_ids: Vec<PreguntaID>,
+    ) -> Result<(), Error>;
+}
+
+/// Preguntas que ya forman parte de un examen
+#[async_trait]
+pub trait RepositorioPreguntaExamen<Error>: Send + Sync {
+    /// `None` si el examen no contiene la pregunta
+    async fn origen(
+        &self,
+        examen_id: &ExamenID,
+        pregunta_id: &PreguntaID,
+    ) -> Result<Option<OrigenPregunta>, Error>;
+
+    async fn reemplazar(&self, examen_id: ExamenID, pregunta: PreguntaEntity) -> Result<(), Error>;
+
+    /// Quita una pregunta propia o desvincula una del banco
+    async fn quitar(&self, examen_id: ExamenID, pregunta_id: PreguntaID) -> Result<(), Error>;
+}
@@ -0,0 +1,43 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+use crate::pregunta::use_case::agregar_preguntas::PreguntaEntityInput;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+    pub pregunta: PreguntaEntityInput,
+}
+
+/// Reemplaza el contenido de una pregunta del banco. Los examenes que la
+/// referencian ven el cambio; las evaluaciones publicadas conservan su copia.
+pub struct ActualizarPreguntaBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> ActualizarPreguntaBanco<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for ActualizarPreguntaBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let id = PreguntaID::new(&in_.id)?;
+        if self.repositorio.obtener(&id).await?.is_none() {
+            return Err(PreguntaError::PreguntaNoExiste(id.to_string()));
+        }
+
+        let mut pregunta = in_.pregunta.construir()?;
+        pregunta.id = id;
+
+        self.repositorio.actualizar(pregunta).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::examen::domain::value_object::id::ExamenID;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::domain::value_object::origen::OrigenPregunta;
+use crate::pregunta::provider::repositorio::RepositorioPreguntaExamen;
+use crate::pregunta::use_case::agregar_preguntas::PreguntaEntityInput;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub examen_id: String,
+    pub pregunta_id: String,
+    pub pregunta: PreguntaEntityInput,
+}
+
+/// Reemplaza una pregunta propia del examen conservando su id y su posicion
+pub struct ActualizarPreguntaExamen<RepoErr> {
+    repositorio: Box<dyn RepositorioPreguntaExamen<RepoErr>>,
+}
+
+impl<RepoErr> ActualizarPreguntaExamen<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioPreguntaExamen<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for ActualizarPreguntaExamen<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let examen_id = ExamenID::new(&in_.examen_id)?;
+        let pregunta_id = PreguntaID::new(&in_.pregunta_id)?;
+
+        match self.repositorio.origen(&examen_id, &pregunta_id).await? {
+            None => {
+                return Err(PreguntaError::PreguntaNoEstaEnExamen(
+                    pregunta_id.to_string(),
+                ));
+            }
+            Some(OrigenPregunta::Banco) => {
+                return Err(PreguntaError::PreguntaDelBanco(pregunta_id.to_string()));
+            }
+            Some(OrigenPregunta::Propia) => {}
+        }
+
+        let mut pregunta = in_.pregunta.construir()?;
+        pregunta.id = pregunta_id;
+
+        self.repositorio.reemplazar(examen_id, pregunta).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const EXAMEN_ID: &str = "5f3a8c1e-7b2d-4e9f-a6c0-1d8e2b4f7a93";
+    const PREGUNTA_ID: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+
+    struct MockRepo {
+        origen: Option<OrigenPregunta>,
+        reemplazada: Arc<Mutex<Option<PreguntaEntity>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioPreguntaExamen<PreguntaError> for MockRepo {
+        async fn origen(
+            &self,
+            _examen_id: &ExamenID,
+            _pregunta_id: &PreguntaID,
+        ) -> Result<Option<OrigenPregunta>, PreguntaError> {
+            Ok(self.origen)
+        }
+
+        async fn reemplazar(
+            &self,
+            _examen_id: ExamenID,
+            pregunta: PreguntaEntity,
+        ) -> Result<(), PreguntaError> {
+            *self.reemplazada.lock().unwrap() = Some(pregunta);
+            Ok(())
+        }
+
+        async fn quitar(
+            &self,
+            _examen_id: ExamenID,
+            _pregunta_id: PreguntaID,
+        ) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+    }
+
+    fn caso(
+        origen: Option<OrigenPregunta>,
+    ) -> (
+        ActualizarPreguntaExamen<PreguntaError>,
+        Arc<Mutex<Option<PreguntaEntity>>>,
+    ) {
+        let reemplazada = Arc::new(Mutex::new(None));
+        let repo = MockRepo {
+            origen,
+            reemplazada: reemplazada.clone(),
+        };
+        (ActualizarPreguntaExamen::new(Box::new(repo)), reemplazada)
+    }
+
+    fn input() -> InputData {
+        InputData {
+            examen_id: EXAMEN_ID.to_string(),
+            pregunta_id: PREGUNTA_ID.to_string(),
+            pregunta: PreguntaEntityInput {
+                contenido: "Cuanto es 2 + 3".to_string(),
+                etiqueta: "no".to_string(),
+                tipo_de_pregunta: "alternativa_unica".to_string(),
+                imagen_ref: None,
+                alternativas: HashMap::from([
+                    ("A".to_string(), "5".to_string()),
+                    ("B".to_string(), "6".to_string()),
+                ]),
+                puntaje: HashMap::from([("A".to_string(), 1)]),
+                inversa: false,
+                seleccion: None,
+                ordenamiento: None,
+                numerica: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reemplaza_la_pregunta_conservando_su_id() {
+        let (caso, reemplazada) = caso(Some(OrigenPregunta::Propia));
+        caso.ejecutar(input()).await.unwrap();
+
+        let pregunta = reemplazada.lock().unwrap().clone().unwrap();
+        assert_eq!(pregunta.id.to_string(), PREGUNTA_ID);
+        assert_eq!(pregunta.contenido, "Cuanto es 2 + 3");
+    }
+
+    #[tokio::test]
+    async fn test_no_edita_preguntas_del_banco_desde_el_examen() {
+        let (caso, reemplazada) = caso(Some(OrigenPregunta::Banco));
+        let resultado = caso.ejecutar(input()).await;
+
+        assert!(matches!(resultado, Err(PreguntaError::PreguntaDelBanco(_))));
+        assert!(reemplazada.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pregunta_ajena_al_examen() {
+        let (caso, _) = caso(None);
+        let resultado = caso.ejecutar(input()).await;
+
+        assert!(matches!(
+            resultado,
+            Err(PreguntaError::PreguntaNoEstaEnExamen(_))
+        ));
+    }
+}
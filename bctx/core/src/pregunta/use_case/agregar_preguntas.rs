@@ -2,6 +2,9 @@ use crate::examen::domain::value_object::id::ExamenID;
 use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
 use crate::pregunta::domain::error::pregunta::PreguntaError;
 use crate::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
+use crate::pregunta::domain::value_object::numerica::ReglaNumerica;
+use crate::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
+use crate::pregunta::domain::value_object::seleccion::{ModoCalificacion, ReglaSeleccion};
 use crate::pregunta::provider::repositorio::RepositorioAgregarPregunta;
 use async_trait::async_trait;
 use quizz_common::use_case::CasoDeUso;
@@ -21,6 +24,57 @@ pub struct PreguntaEntityInput {
     pub imagen_ref: Option<String>,
     pub alternativas: HashMap<String, String>,
     pub puntaje: HashMap<String, u32>,
+    pub inversa: bool,
+    pub seleccion: Option<InputSeleccion>,
+    pub ordenamiento: Option<String>,
+    pub numerica: Option<InputNumerica>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputSeleccion {
+    pub minimo: u32,
+    pub maximo: u32,
+    pub modo: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputNumerica {
+    pub tolerancia: f64,
+    pub tipo_tolerancia: String,
+    pub unidad: Option<String>,
+}
+
+impl PreguntaEntityInput {
+    /// Valida la pregunta con la estrategia de su tipo y aplica las reglas opcionales
+    pub fn construir(self) -> Result<PreguntaEntity, PreguntaError> {
+        let mut pregunta = PreguntaEntity::new(
+            self.contenido,
+            self.etiqueta,
+            self.tipo_de_pregunta,
+            self.imagen_ref,
+            self.alternativas,
+            self.puntaje,
+        )?;
+        pregunta.inversa = self.inversa;
+        if let Some(modo) = self.ordenamiento {
+            pregunta.asignar_ordenamiento(modo.parse::<ModoOrdenamiento>()?)?;
+        }
+        if let Some(numerica) = self.numerica {
+            pregunta.asignar_numerica(ReglaNumerica::new(
+                numerica.tolerancia,
+                numerica.tipo_tolerancia.parse()?,
+                numerica.unidad,
+            )?)?;
+        }
+        if let Some(seleccion) = self.seleccion {
+            pregunta.asignar_seleccion(ReglaSeleccion::new(
+                seleccion.minimo,
+                seleccion.maximo,
+                seleccion.modo.parse::<ModoCalificacion>()?,
+            )?)?;
+        }
+        Ok(pregunta)
+    }
 }
 
 pub struct AgregarPreguntasParaExamen<RepoErr> {
@@ -43,16 +97,7 @@ where
         let preguntas = in_
             .preguntas
             .into_iter()
-            .map(|i| {
-                PreguntaEntity::new(
-                    i.contenido,
-                    i.etiqueta,
-                    i.tipo_de_pregunta,
-                    i.imagen_ref,
-                    i.alternativas,
-                    i.puntaje,
-                )
-            })
+            .map(PreguntaEntityInput::construir)
             .collect::<Result<Vec<PreguntaEntity>, PreguntaError>>()?;
         let preguntas = ListaDePreguntas::new(preguntas);
 
@@ -0,0 +1,41 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+use crate::pregunta::use_case::agregar_preguntas::PreguntaEntityInput;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+    pub pregunta: PreguntaEntityInput,
+}
+
+pub struct CrearPreguntaBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> CrearPreguntaBanco<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for CrearPreguntaBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let id = PreguntaID::new(&in_.id)?;
+        if self.repositorio.obtener(&id).await?.is_some() {
+            return Err(PreguntaError::PreguntaYaExiste(id.to_string()));
+        }
+
+        let mut pregunta = in_.pregunta.construir()?;
+        pregunta.id = id;
+
+        self.repositorio.guardar(pregunta).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+}
+
+/// Elimina una pregunta del banco solo si ningun examen la referencia.
+pub struct EliminarPreguntaBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> EliminarPreguntaBanco<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for EliminarPreguntaBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let id = PreguntaID::new(&in_.id)?;
+        if self.repositorio.obtener(&id).await?.is_none() {
+            return Err(PreguntaError::PreguntaNoExiste(id.to_string()));
+        }
+        if self.repositorio.en_uso(&id).await? {
+            return Err(PreguntaError::PreguntaEnUso(id.to_string()));
+        }
+
+        self.repositorio.eliminar(&id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const PREGUNTA_ID: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+
+    struct MockBanco {
+        pregunta: Option<PreguntaEntity>,
+        en_uso: bool,
+        eliminada: Arc<Mutex<bool>>,
+    }
+
+    #[async_trait]
+    impl RepositorioBancoPreguntas<PreguntaError> for MockBanco {
+        async fn guardar(&self, _pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+
+        async fn listar(&self) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+            Ok(self.pregunta.clone().into_iter().collect())
+        }
+
+        async fn obtener(&self, _id: &PreguntaID) -> Result<Option<PreguntaEntity>, PreguntaError> {
+            Ok(self.pregunta.clone())
+        }
+
+        async fn actualizar(&self, _pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+
+        async fn eliminar(&self, _id: &PreguntaID) -> Result<(), PreguntaError> {
+            *self.eliminada.lock().unwrap() = true;
+            Ok(())
+        }
+
+        async fn en_uso(&self, _id: &PreguntaID) -> Result<bool, PreguntaError> {
+            Ok(self.en_uso)
+        }
+    }
+
+    fn pregunta() -> PreguntaEntity {
+        let mut pregunta = PreguntaEntity::new(
+            "Cuanto es 2 + 2".to_string(),
+            "no".to_string(),
+            "alternativa_unica".to_string(),
+            None,
+            HashMap::from([
+                ("A".to_string(), "3".to_string()),
+                ("B".to_string(), "4".to_string()),
+            ]),
+            HashMap::from([("B".to_string(), 1)]),
+        )
+        .unwrap();
+        pregunta.id = PreguntaID::new(PREGUNTA_ID).unwrap();
+        pregunta
+    }
+
+    fn caso(
+        pregunta: Option<PreguntaEntity>,
+        en_uso: bool,
+    ) -> (EliminarPreguntaBanco<PreguntaError>, Arc<Mutex<bool>>) {
+        let eliminada = Arc::new(Mutex::new(false));
+        let repo = MockBanco {
+            pregunta,
+            en_uso,
+            eliminada: eliminada.clone(),
+        };
+        (EliminarPreguntaBanco::new(Box::new(repo)), eliminada)
+    }
+
+    fn input() -> InputData {
+        InputData {
+            id: PREGUNTA_ID.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_elimina_pregunta_sin_referencias() {
+        let (caso, eliminada) = caso(Some(pregunta()), false);
+        caso.ejecutar(input()).await.unwrap();
+        assert!(*eliminada.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_no_elimina_pregunta_referenciada_por_un_examen() {
+        let (caso, eliminada) = caso(Some(pregunta()), true);
+        let resultado = caso.ejecutar(input()).await;
+        assert!(matches!(resultado, Err(PreguntaError::PreguntaEnUso(_))));
+        assert!(!*eliminada.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_pregunta_inexistente() {
+        let (caso, _) = caso(None, false);
+        let resultado = caso.ejecutar(input()).await;
+        assert!(matches!(resultado, Err(PreguntaError::PreguntaNoExiste(_))));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::examen::domain::value_object::id::ExamenID;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::RepositorioPreguntaExamen;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub examen_id: String,
+    pub pregunta_id: String,
+}
+
+/// Quita una pregunta del examen. Si viene del banco solo se desvincula; la
+/// pregunta sigue en el banco.
+pub struct EliminarPreguntaExamen<RepoErr> {
+    repositorio: Box<dyn RepositorioPreguntaExamen<RepoErr>>,
+}
+
+impl<RepoErr> EliminarPreguntaExamen<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioPreguntaExamen<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for EliminarPreguntaExamen<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let examen_id = ExamenID::new(&in_.examen_id)?;
+        let pregunta_id = PreguntaID::new(&in_.pregunta_id)?;
+
+        if self
+            .repositorio
+            .origen(&examen_id, &pregunta_id)
+            .await?
+            .is_none()
+        {
+            return Err(PreguntaError::PreguntaNoEstaEnExamen(
+                pregunta_id.to_string(),
+            ));
+        }
+
+        self.repositorio.quitar(examen_id, pregunta_id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData;
+
+pub struct ListarPreguntasBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> ListarPreguntasBanco<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, Vec<PreguntaEntity>, PreguntaError>
+    for ListarPreguntasBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, _input: InputData) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+        Ok(self.repositorio.listar().await?)
+    }
+}
@@ -1 +1,9 @@
+pub mod actualizar_pregunta_banco;
+pub mod actualizar_pregunta_examen;
 pub mod agregar_preguntas;
+pub mod crear_pregunta_banco;
+pub mod eliminar_pregunta_banco;
+pub mod eliminar_pregunta_examen;
+pub mod listar_preguntas_banco;
+pub mod obtener_pregunta_banco;
+pub mod vincular_preguntas_banco;
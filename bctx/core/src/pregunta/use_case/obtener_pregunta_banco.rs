@@ -0,0 +1,35 @@
+use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub id: String,
+}
+
+pub struct ObtenerPreguntaBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> ObtenerPreguntaBanco<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioBancoPreguntas<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, PreguntaEntity, PreguntaError> for ObtenerPreguntaBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<PreguntaEntity, PreguntaError> {
+        let id = PreguntaID::new(&in_.id)?;
+        self.repositorio
+            .obtener(&id)
+            .await?
+            .ok_or(PreguntaError::PreguntaNoExiste(id.to_string()))
+    }
+}
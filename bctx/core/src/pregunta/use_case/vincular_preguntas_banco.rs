@@ -0,0 +1,183 @@
+use crate::examen::domain::value_object::id::ExamenID;
+use crate::pregunta::domain::error::pregunta::PreguntaError;
+use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::provider::repositorio::{
+    RepositorioBancoPreguntas, RepositorioVincularPreguntaBanco,
+};
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub examen_id: String,
+    pub pregunta_ids: Vec<String>,
+}
+
+/// Agrega al examen referencias a preguntas del banco. El examen guarda solo
+/// los IDs; el contenido se copia a la evaluacion cuando esta se publica.
+pub struct VincularPreguntasBanco<RepoErr> {
+    repositorio: Box<dyn RepositorioVincularPreguntaBanco<RepoErr>>,
+    repositorio_banco: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+}
+
+impl<RepoErr> VincularPreguntasBanco<RepoErr> {
+    pub fn new(
+        repositorio: Box<dyn RepositorioVincularPreguntaBanco<RepoErr>>,
+        repositorio_banco: Box<dyn RepositorioBancoPreguntas<RepoErr>>,
+    ) -> Self {
+        Self {
+            repositorio,
+            repositorio_banco,
+        }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), PreguntaError> for VincularPreguntasBanco<RepoErr>
+where
+    PreguntaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), PreguntaError> {
+        let examen_id = ExamenID::new(&in_.examen_id)?;
+
+        let mut pregunta_ids: Vec<PreguntaID> = Vec::new();
+        for id in &in_.pregunta_ids {
+            let id = PreguntaID::new(id)?;
+            if pregunta_ids
+                .iter()
+                .any(|otro| otro.to_string() == id.to_string())
+            {
+                continue;
+            }
+            if self.repositorio_banco.obtener(&id).await?.is_none() {
+                return Err(PreguntaError::PreguntaNoExiste(id.to_string()));
+            }
+            pregunta_ids.push(id);
+        }
+
+        self.repositorio.vincular(examen_id, pregunta_ids).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::entity::pregunta::PreguntaEntity;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const EXAMEN_ID: &str = "5f1c2d3e-4b5a-4c6d-8e7f-9a0b1c2d3e4f";
+    const PREGUNTA_ID: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+    const OTRA_PREGUNTA_ID: &str = "2c3d4e5f-6a7b-4c8d-9e0f-1a2b3c4d5e6f";
+
+    struct MockBanco {
+        existentes: Vec<String>,
+    }
+
+    #[async_trait]
+    impl RepositorioBancoPreguntas<PreguntaError> for MockBanco {
+        async fn guardar(&self, _pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+
+        async fn listar(&self) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+            Ok(Vec::new())
+        }
+
+        async fn obtener(&self, id: &PreguntaID) -> Result<Option<PreguntaEntity>, PreguntaError> {
+            if !self.existentes.contains(&id.to_string()) {
+                return Ok(None);
+            }
+            let mut pregunta = PreguntaEntity::new(
+                "Describa su ultimo proyecto".to_string(),
+                "no".to_string(),
+                "libre".to_string(),
+                None,
+                HashMap::new(),
+                HashMap::new(),
+            )?;
+            pregunta.id = id.clone();
+            Ok(Some(pregunta))
+        }
+
+        async fn actualizar(&self, _pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+
+        async fn eliminar(&self, _id: &PreguntaID) -> Result<(), PreguntaError> {
+            Ok(())
+        }
+
+        async fn en_uso(&self, _id: &PreguntaID) -> Result<bool, PreguntaError> {
+            Ok(false)
+        }
+    }
+
+    struct MockExamen {
+        vinculadas: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioVincularPreguntaBanco<PreguntaError> for MockExamen {
+        async fn vincular(
+            &self,
+            _examen_id: ExamenID,
+            pregunta_ids: Vec<PreguntaID>,
+        ) -> Result<(), PreguntaError> {
+            *self.vinculadas.lock().unwrap() =
+                pregunta_ids.iter().map(ToString::to_string).collect();
+            Ok(())
+        }
+    }
+
+    fn caso(
+        existentes: &[&str],
+    ) -> (
+        VincularPreguntasBanco<PreguntaError>,
+        Arc<Mutex<Vec<String>>>,
+    ) {
+        let vinculadas = Arc::new(Mutex::new(Vec::new()));
+        let caso = VincularPreguntasBanco::new(
+            Box::new(MockExamen {
+                vinculadas: vinculadas.clone(),
+            }),
+            Box::new(MockBanco {
+                existentes: existentes.iter().map(ToString::to_string).collect(),
+            }),
+        );
+        (caso, vinculadas)
+    }
+
+    #[tokio::test]
+    async fn test_vincula_preguntas_sin_repetir() {
+        let (caso, vinculadas) = caso(&[PREGUNTA_ID, OTRA_PREGUNTA_ID]);
+        caso.ejecutar(InputData {
+            examen_id: EXAMEN_ID.to_string(),
+            pregunta_ids: vec![
+                PREGUNTA_ID.to_string(),
+                OTRA_PREGUNTA_ID.to_string(),
+                PREGUNTA_ID.to_string(),
+            ],
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            *vinculadas.lock().unwrap(),
+            vec![PREGUNTA_ID.to_string(), OTRA_PREGUNTA_ID.to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_vincula_si_alguna_pregunta_no_existe() {
+        let (caso, vinculadas) = caso(&[PREGUNTA_ID]);
+        let resultado = caso
+            .ejecutar(InputData {
+                examen_id: EXAMEN_ID.to_string(),
+                pregunta_ids: vec![PREGUNTA_ID.to_string(), OTRA_PREGUNTA_ID.to_string()],
+            })
+            .await;
+        assert!(matches!(resultado, Err(PreguntaError::PreguntaNoExiste(_))));
+        assert!(vinculadas.lock().unwrap().is_empty());
+    }
+}
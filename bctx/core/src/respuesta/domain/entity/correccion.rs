@@ -1 +1,61 @@
-pub struct Correccion {}
+use crate::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
+use crate::pregunta::domain::value_object::numerica::ReglaNumerica;
+use crate::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
+use crate::pregunta::domain::value_object::seleccion::ReglaSeleccion;
+use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use crate::respuesta::domain::entity::pregunta::Puntaje;
+use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::domain::value_object::barajado::OrdenAlternativas;
+use std::collections::HashSet;
+
+/// Lo necesario para corregir una pregunta: su tipo, el puntaje de cada
+/// alternativa, la regla de seleccion, el modo de ordenamiento o la tolerancia
+/// numerica segun su tipo y la politica de puntaje del examen al que pertenece.
+/// Si el postulante vio las alternativas barajadas, tambien el orden en que
+/// las vio.
+#[derive(Debug, Clone)]
+pub struct Correccion {
+    pub tipo_de_pregunta: TipoPregunta,
+    pub puntaje: Puntaje,
+    pub politica: PoliticaPuntaje,
+    pub seleccion: Option<ReglaSeleccion>,
+    pub ordenamiento: Option<ModoOrdenamiento>,
+    pub numerica: Option<ReglaNumerica>,
+    pub orden_alternativas: Option<OrdenAlternativas>,
+}
+
+impl Correccion {
+    /// Traduce las etiquetas que vio el postulante a las claves originales
+    pub fn claves_originales(&self, respuesta: Vec<String>) -> Vec<String> {
+        match &self.orden_alternativas {
+            Some(orden) => respuesta
+                .iter()
+                .map(|mostrada| orden.a_original(mostrada))
+                .collect(),
+            None => respuesta,
+        }
+    }
+
+    /// Rechaza una respuesta que marca menos o mas alternativas de las que
+    /// permite la regla de seleccion, o un ordenamiento que no incluye cada
+    /// alternativa una sola vez. Dejar la pregunta en blanco es valido.
+    pub fn validar(&self, respuesta: &[String]) -> Result<(), RespuestaError> {
+        if self.ordenamiento.is_some()
+            && !respuesta.is_empty()
+            && !ModoOrdenamiento::es_permutacion(respuesta, &self.puntaje)
+        {
+            return Err(RespuestaError::OrdenIncompleto);
+        }
+
+        let marcadas = respuesta.iter().collect::<HashSet<_>>().len();
+        match &self.seleccion {
+            Some(regla) if marcadas > 0 && !regla.admite(marcadas) => {
+                Err(RespuestaError::SeleccionFueraDeRango {
+                    minimo: regla.minimo,
+                    maximo: regla.maximo,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::evaluacion::domain::value_object::criterio_resultado::CriterioResultado;
 use crate::evaluacion::value_object::id::EvaluacionID;
 use crate::respuesta::domain::entity::examen::Examen;
 
@@ -5,5 +6,18 @@ pub struct Evaluacion {
     pub id: EvaluacionID,
     pub nombre: String,
     pub descripcion: String,
+    pub duracion_minutos: Option<u32>,
+    pub criterio: CriterioResultado,
     pub examenes: Vec<Examen>,
 }
+
+impl Evaluacion {
+    /// Cantidad de preguntas libres que aun esperan la calificacion del psicologo
+    pub fn libres_sin_calificar(&self) -> usize {
+        self.examenes
+            .iter()
+            .flat_map(|examen| &examen.preguntas)
+            .filter(|pregunta| pregunta.pendiente_de_calificar())
+            .count()
+    }
+}
@@ -6,6 +6,7 @@ pub struct Examen {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    pub duracion_minutos: Option<u32>,
     pub observaciones: String,
     pub preguntas: Vec<Pregunta>,
     pub puntos_obtenidos: i64,
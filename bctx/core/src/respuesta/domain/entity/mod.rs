@@ -2,5 +2,6 @@ pub mod correccion;
 pub mod evaluacion;
 pub mod examen;
 pub mod pregunta;
+pub mod rasgo;
 pub mod respuesta;
 pub mod revision;
@@ -1,6 +1,10 @@
 use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
 use crate::pregunta::domain::value_object::id::PreguntaID;
+use crate::pregunta::domain::value_object::seleccion::ReglaSeleccion;
 use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use crate::respuesta::domain::entity::correccion::Correccion;
+use crate::respuesta::domain::entity::revision::CalificacionManual;
+use crate::respuesta::domain::value_object::barajado::OrdenAlternativas;
 use std::collections::HashMap;
 
 pub type Puntaje = HashMap<String, u32>;
@@ -18,15 +22,97 @@ pub struct Pregunta {
     pub puntaje: Puntaje,
     pub respuestas: Option<Vec<String>>,
     pub puntos: i64,
+
+    // inversa indica que el item puntua en sentido contrario a su rasgo
+    pub inversa: bool,
+
+    // seleccion indica cuantas alternativas se pueden marcar en seleccion multiple
+    pub seleccion: Option<ReglaSeleccion>,
+
+    // calificacion la asigna el psicologo a mano en las preguntas libres
+    pub calificacion: Option<CalificacionManual>,
+
+    // orden_alternativas es el orden barajado en que el postulante ve las alternativas
+    pub orden_alternativas: Option<OrdenAlternativas>,
+}
+
+impl Pregunta {
+    /// Una pregunta libre respondida espera la calificacion del psicologo; si
+    /// quedo en blanco vale cero y no hace falta calificarla.
+    pub fn pendiente_de_calificar(&self) -> bool {
+        matches!(self.tipo_de_pregunta, TipoPregunta::Libre)
+            && self.calificacion.is_none()
+            && self
+                .respuestas
+                .as_ref()
+                .is_some_and(|respuestas| respuestas.iter().any(|r| !r.trim().is_empty()))
+    }
+
+    /// Maximo que admite una calificacion manual, si la pregunta define puntaje
+    pub fn puntaje_maximo(&self) -> Option<u32> {
+        self.puntaje.values().max().copied()
+    }
 }
 
-pub fn corregir_respuesta(respuesta: &[String], puntaje: Puntaje) -> u32 {
-    respuesta.iter().filter_map(|key| puntaje.get(key)).sum()
+// En alternativa unica se aplica la politica de puntaje del examen: la
+// alternativa sin puntos se penaliza y una respuesta vacia cuenta como en blanco.
+// En seleccion multiple se califica segun la regla de seleccion y en
+// ordenamiento segun el modo, con la respuesta como el orden dado. En numerica
+// la respuesta se compara con el valor esperado segun la tolerancia
+pub fn corregir_respuesta(respuesta: &[String], correccion: &Correccion) -> i64 {
+    let obtenido: u32 = respuesta
+        .iter()
+        .filter_map(|key| correccion.puntaje.get(key))
+        .sum();
+
+    match correccion.tipo_de_pregunta {
+        TipoPregunta::AlternativaUnica => {
+            let en_blanco = respuesta.iter().all(|key| key.trim().is_empty());
+            correccion
+                .politica
+                .calificar((!en_blanco).then_some(obtenido))
+        }
+        TipoPregunta::SeleccionMultiple => match &correccion.seleccion {
+            Some(regla) => i64::from(regla.calificar(respuesta, &correccion.puntaje)),
+            None => i64::from(obtenido),
+        },
+        TipoPregunta::Ordenamiento => i64::from(
+            correccion
+                .ordenamiento
+                .unwrap_or_default()
+                .calificar(respuesta, &correccion.puntaje),
+        ),
+        TipoPregunta::Numerica => i64::from(
+            correccion
+                .numerica
+                .clone()
+                .unwrap_or_default()
+                .calificar(respuesta, &correccion.puntaje),
+        ),
+        _ => i64::from(obtenido),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examen::domain::value_object::politica_puntaje::{EnBlanco, PoliticaPuntaje};
+    use crate::pregunta::domain::value_object::numerica::{ReglaNumerica, TipoTolerancia};
+    use crate::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
+    use crate::pregunta::domain::value_object::seleccion::ModoCalificacion;
+    use crate::respuesta::domain::error::respuesta::RespuestaError;
+
+    fn correccion(tipo_de_pregunta: TipoPregunta, puntaje: Puntaje) -> Correccion {
+        Correccion {
+            tipo_de_pregunta,
+            puntaje,
+            politica: PoliticaPuntaje::default(),
+            seleccion: None,
+            ordenamiento: None,
+            numerica: None,
+            orden_alternativas: None,
+        }
+    }
 
     #[test]
     fn test_corregir_respuesta_suma_correcta() {
@@ -39,7 +125,10 @@ mod tests {
             ("E".to_string(), 3),
         ]);
 
-        let resultado = corregir_respuesta(&respuesta, puntaje);
+        let resultado = corregir_respuesta(
+            &respuesta,
+            &correccion(TipoPregunta::AlternativaConPeso, puntaje),
+        );
         assert_eq!(resultado, 5);
     }
 
@@ -48,7 +137,10 @@ mod tests {
         let respuesta: Vec<String> = vec!["B".to_string()];
         let puntaje = HashMap::from([("A".to_string(), 1)]);
 
-        let resultado = corregir_respuesta(&respuesta, puntaje);
+        let resultado = corregir_respuesta(
+            &respuesta,
+            &correccion(TipoPregunta::AlternativaUnica, puntaje),
+        );
         assert_eq!(resultado, 0);
     }
 
@@ -57,7 +149,99 @@ mod tests {
         let respuesta: Vec<String> = vec!["A".to_string()];
         let puntaje = HashMap::from([("A".to_string(), 1)]);
 
-        let resultado = corregir_respuesta(&respuesta, puntaje);
+        let resultado = corregir_respuesta(
+            &respuesta,
+            &correccion(TipoPregunta::AlternativaUnica, puntaje),
+        );
         assert_eq!(resultado, 1);
     }
+
+    #[test]
+    fn test_corregir_respuesta_unica_con_penalizacion() {
+        let mut correccion = correccion(
+            TipoPregunta::AlternativaUnica,
+            HashMap::from([("A".to_string(), 2), ("B".to_string(), 1)]),
+        );
+        correccion.politica = PoliticaPuntaje::new(1, EnBlanco::Penalizar, true);
+
+        assert_eq!(corregir_respuesta(&["B".to_string()], &correccion), 1);
+        assert_eq!(corregir_respuesta(&["C".to_string()], &correccion), -1);
+        assert_eq!(corregir_respuesta(&[], &correccion), -1);
+    }
+
+    #[test]
+    fn test_corregir_respuesta_seleccion_multiple() {
+        let mut correccion = correccion(
+            TipoPregunta::SeleccionMultiple,
+            HashMap::from([("A".to_string(), 1), ("C".to_string(), 1)]),
+        );
+        correccion.seleccion =
+            Some(ReglaSeleccion::new(1, 2, ModoCalificacion::Proporcional).unwrap());
+
+        let respuesta = vec!["A".to_string()];
+        assert_eq!(corregir_respuesta(&respuesta, &correccion), 1);
+
+        let excede = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+        assert!(matches!(
+            correccion.validar(&excede),
+            Err(RespuestaError::SeleccionFueraDeRango { .. })
+        ));
+        assert!(correccion.validar(&[]).is_ok());
+    }
+
+    #[test]
+    fn test_corregir_respuesta_ordenamiento() {
+        let mut correccion = correccion(
+            TipoPregunta::Ordenamiento,
+            HashMap::from([
+                ("A".to_string(), 1),
+                ("B".to_string(), 2),
+                ("C".to_string(), 3),
+            ]),
+        );
+        correccion.ordenamiento = Some(ModoOrdenamiento::Posicional);
+
+        let respuesta = vec!["A".to_string(), "C".to_string(), "B".to_string()];
+        assert_eq!(corregir_respuesta(&respuesta, &correccion), 1);
+
+        let incompleta = vec!["A".to_string(), "B".to_string()];
+        assert!(matches!(
+            correccion.validar(&incompleta),
+            Err(RespuestaError::OrdenIncompleto)
+        ));
+    }
+
+    #[test]
+    fn test_corregir_respuesta_numerica() {
+        let mut correccion = correccion(
+            TipoPregunta::Numerica,
+            HashMap::from([("12.5".to_string(), 2)]),
+        );
+        correccion.numerica = Some(
+            ReglaNumerica::new(0.1, TipoTolerancia::Absoluta, Some("cm".to_string())).unwrap(),
+        );
+
+        assert_eq!(
+            corregir_respuesta(&["12,45 cm".to_string()], &correccion),
+            2
+        );
+        assert_eq!(corregir_respuesta(&["12,7".to_string()], &correccion), 0);
+        assert_eq!(corregir_respuesta(&[], &correccion), 0);
+    }
+
+    #[test]
+    fn test_corregir_respuesta_barajada_con_claves_originales() {
+        let mut correccion = correccion(
+            TipoPregunta::AlternativaUnica,
+            HashMap::from([("A".to_string(), 0), ("B".to_string(), 1)]),
+        );
+        // el postulante ve el texto de B bajo la etiqueta A
+        correccion.orden_alternativas = Some(OrdenAlternativas {
+            claves: vec!["B".to_string(), "A".to_string()],
+        });
+
+        let respuesta = correccion.claves_originales(vec!["A".to_string()]);
+        assert_eq!(respuesta, vec!["B".to_string()]);
+        assert_eq!(corregir_respuesta(&respuesta, &correccion), 1);
+    }
 }
@@ -0,0 +1,156 @@
+use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+use crate::respuesta::domain::entity::pregunta::Pregunta;
+
+/// Puntuacion de una subescala (rasgo) obtenida de las preguntas que
+/// comparten la misma etiqueta.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuntuacionRasgo {
+    pub etiqueta: Etiqueta,
+    pub puntos_brutos: i64,
+    pub cantidad_items: u32,
+    pub promedio: f64,
+}
+
+/// Agrupa las preguntas respondidas por etiqueta y calcula la suma, la
+/// cantidad de items y el promedio de cada rasgo. Las preguntas sin etiqueta
+/// (`Etiqueta::No`) y las que no fueron respondidas no se consideran.
+pub fn puntuar_rasgos(preguntas: &[Pregunta]) -> Vec<PuntuacionRasgo> {
+    let mut rasgos: Vec<PuntuacionRasgo> = Vec::new();
+
+    let respondidas = preguntas.iter().filter(|pregunta| {
+        pregunta.etiqueta != Etiqueta::No
+            && pregunta
+                .respuestas
+                .as_ref()
+                .is_some_and(|respuestas| !respuestas.is_empty())
+    });
+
+    for pregunta in respondidas {
+        let puntos = puntos_item(pregunta);
+        match rasgos
+            .iter_mut()
+            .find(|rasgo| rasgo.etiqueta == pregunta.etiqueta)
+        {
+            Some(rasgo) => {
+                rasgo.puntos_brutos += puntos;
+                rasgo.cantidad_items += 1;
+            }
+            None => rasgos.push(PuntuacionRasgo {
+                etiqueta: pregunta.etiqueta.clone(),
+                puntos_brutos: puntos,
+                cantidad_items: 1,
+                promedio: 0.0,
+            }),
+        }
+    }
+
+    for rasgo in rasgos.iter_mut() {
+        rasgo.promedio = rasgo.puntos_brutos as f64 / f64::from(rasgo.cantidad_items);
+    }
+
+    rasgos
+}
+
+/// En los items inversos el puntaje se refleja dentro del rango de la
+/// pregunta: `minimo + maximo - puntos`.
+fn puntos_item(pregunta: &Pregunta) -> i64 {
+    if !pregunta.inversa {
+        return pregunta.puntos;
+    }
+
+    let valores: Vec<i64> = if pregunta.alternativas.is_empty() {
+        pregunta.puntaje.values().map(|v| i64::from(*v)).collect()
+    } else {
+        pregunta
+            .alternativas
+            .keys()
+            .map(|key| i64::from(pregunta.puntaje.get(key).copied().unwrap_or(0)))
+            .collect()
+    };
+
+    match (valores.iter().min(), valores.iter().max()) {
+        (Some(minimo), Some(maximo)) => minimo + maximo - pregunta.puntos,
+        _ => pregunta.puntos,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::domain::value_object::id::PreguntaID;
+    use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+    use std::collections::HashMap;
+
+    fn pregunta(etiqueta: Etiqueta, respuesta: &str, inversa: bool) -> Pregunta {
+        let puntaje = HashMap::from([
+            ("A".to_string(), 1),
+            ("B".to_string(), 2),
+            ("C".to_string(), 3),
+            ("D".to_string(), 4),
+            ("E".to_string(), 5),
+        ]);
+        let alternativas = puntaje
+            .keys()
+            .map(|key| (key.clone(), format!("Opcion {key}")))
+            .collect();
+        let puntos = i64::from(puntaje.get(respuesta).copied().unwrap_or(0));
+
+        Pregunta {
+            id: PreguntaID::new_v4(),
+            contenido: "Me gusta conocer gente nueva".to_string(),
+            observaciones: String::new(),
+            etiqueta,
+            tipo_de_pregunta: TipoPregunta::AlternativaConPeso,
+            imagen_ref: String::new(),
+            alternativas,
+            puntaje,
+            respuestas: Some(vec![respuesta.to_string()]),
+            puntos,
+            inversa,
+            seleccion: None,
+            calificacion: None,
+            orden_alternativas: None,
+        }
+    }
+
+    #[test]
+    fn test_agrupa_por_etiqueta() {
+        let preguntas = vec![
+            pregunta(Etiqueta::Extrovertido, "D", false),
+            pregunta(Etiqueta::Neurotismo, "B", false),
+            pregunta(Etiqueta::Extrovertido, "E", false),
+        ];
+
+        let rasgos = puntuar_rasgos(&preguntas);
+
+        assert_eq!(rasgos.len(), 2);
+        assert_eq!(rasgos[0].etiqueta, Etiqueta::Extrovertido);
+        assert_eq!(rasgos[0].puntos_brutos, 9);
+        assert_eq!(rasgos[0].cantidad_items, 2);
+        assert_eq!(rasgos[0].promedio, 4.5);
+        assert_eq!(rasgos[1].etiqueta, Etiqueta::Neurotismo);
+        assert_eq!(rasgos[1].puntos_brutos, 2);
+    }
+
+    #[test]
+    fn test_item_inverso_refleja_puntaje() {
+        let preguntas = vec![
+            pregunta(Etiqueta::Honestidad, "E", false),
+            pregunta(Etiqueta::Honestidad, "B", true),
+        ];
+
+        let rasgos = puntuar_rasgos(&preguntas);
+
+        assert_eq!(rasgos[0].puntos_brutos, 5 + 4);
+        assert_eq!(rasgos[0].promedio, 4.5);
+    }
+
+    #[test]
+    fn test_ignora_sin_etiqueta_y_sin_respuesta() {
+        let mut sin_respuesta = pregunta(Etiqueta::Neurotismo, "C", false);
+        sin_respuesta.respuestas = None;
+        let preguntas = vec![pregunta(Etiqueta::No, "C", false), sin_respuesta];
+
+        assert!(puntuar_rasgos(&preguntas).is_empty());
+    }
+}
@@ -1,5 +1,7 @@
+use crate::evaluacion::domain::value_object::resultado::Resultado;
 use crate::postulante::domain::value_object::id::PostulanteID;
 use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
 use crate::respuesta::domain::error::respuesta::{EstadoErr, RevisionErr};
 use crate::respuesta::domain::value_object::id::RespuestaID;
 use std::fmt;
@@ -12,7 +14,13 @@ pub struct Respuesta {
     pub evaluacion: Evaluacion,
     pub postulante: PostulanteID,
     pub revision: Revision,
-    pub resultado: String,
+    pub resultado: Option<Resultado>,
+    /// Dictamen en texto libre de revisiones anteriores a `Resultado`, que no
+    /// corresponde a ninguna variante; se conserva tal cual para no perderlo
+    pub resultado_anterior: Option<String>,
+    pub resultado_sugerido: Option<Resultado>,
+    pub justificacion: String,
+    pub rasgos: Vec<PuntuacionRasgo>,
 }
 
 pub struct RespuestaEvaluacion {
@@ -22,7 +30,7 @@ pub struct RespuestaEvaluacion {
     pub examen_id: String,
     pub pregunta_id: String,
     pub respuestas: Vec<String>,
-    pub puntos: u32,
+    pub puntos: i64,
 }
 
 #[derive(Clone, Debug)]
@@ -59,6 +67,10 @@ impl Estado {
     pub fn can_finalize(&self) -> bool {
         matches!(self, Self::EnProceso)
     }
+
+    pub fn can_answer(&self) -> bool {
+        matches!(self, Self::EnProceso)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -93,3 +105,24 @@ impl FromStr for Revision {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solo_en_proceso_puede_responder() {
+        assert!(!Estado::Creado.can_answer());
+        assert!(Estado::EnProceso.can_answer());
+        assert!(!Estado::Finalizado.can_answer());
+    }
+
+    #[test]
+    fn test_estado_desde_texto() {
+        assert!(matches!(
+            Estado::from_str("en_proceso"),
+            Ok(Estado::EnProceso)
+        ));
+        assert!(Estado::from_str("pausado").is_err());
+    }
+}
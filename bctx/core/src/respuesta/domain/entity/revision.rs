@@ -2,3 +2,10 @@ pub struct ExamenRevision {
     pub examen_id: String,
     pub observacion: String,
 }
+
+/// Puntos y comentario que el psicologo asigna a una respuesta libre
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalificacionManual {
+    pub puntos: u32,
+    pub comentario: String,
+}
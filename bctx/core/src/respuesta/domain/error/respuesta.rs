@@ -1,3 +1,5 @@
+use crate::baremo::domain::error::baremo::BaremoError;
+use crate::evaluacion::domain::value_object::resultado::ResultadoError;
 use quizz_common::domain::value_objects::id::IdError;
 use thiserror::Error;
 
@@ -18,6 +20,9 @@ pub enum RespuestaError {
     #[error("La evaluacion ya fue asignada")]
     EvaluacionAlreadyAssigned,
 
+    #[error("La evaluacion no admite asignaciones en estado {0}")]
+    EvaluacionNoDisponible(String),
+
     #[error("Error en el repositorio")]
     RepositorioError,
 
@@ -35,6 +40,48 @@ pub enum RespuestaError {
 
     #[error("La evaluacion ya fue iniciada")]
     EvaluacionYaIniciada,
+
+    #[error("El tiempo para rendir la evaluacion se agoto")]
+    TiempoAgotado,
+
+    #[error("El tiempo para rendir el examen se agoto")]
+    TiempoExamenAgotado,
+
+    #[error("La evaluacion aun no ha sido iniciada")]
+    EvaluacionNoIniciada,
+
+    #[error("La evaluacion ya fue finalizada")]
+    EvaluacionYaFinalizada,
+
+    #[error("La respuesta no pertenece al postulante")]
+    RespuestaDeOtroPostulante,
+
+    #[error("Error al aplicar el baremo: {0}")]
+    BaremoError(#[from] BaremoError),
+
+    #[error("{0}")]
+    ResultadoNoValido(#[from] ResultadoError),
+
+    #[error("Se requiere una justificacion cuando el resultado difiere del sugerido ({0})")]
+    JustificacionRequerida(String),
+
+    #[error("Se deben marcar entre {minimo} y {maximo} alternativas")]
+    SeleccionFueraDeRango { minimo: u32, maximo: u32 },
+
+    #[error("Se deben ordenar todas las alternativas, cada una una sola vez")]
+    OrdenIncompleto,
+
+    #[error("Solo las preguntas libres se califican manualmente")]
+    PreguntaNoEsLibre,
+
+    #[error("Los puntos no pueden superar el puntaje de la pregunta ({maximo})")]
+    PuntosFueraDeRango { maximo: u32 },
+
+    #[error("La revision ya fue finalizada")]
+    RevisionYaFinalizada,
+
+    #[error("Faltan calificar {0} preguntas libres")]
+    CalificacionPendiente(usize),
 }
 
 #[derive(Error, Debug)]
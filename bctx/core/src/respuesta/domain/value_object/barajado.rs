@@ -0,0 +1,284 @@
+use crate::examen::domain::value_object::muestreo::Muestreo;
+use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use std::collections::HashMap;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Generador pseudoaleatorio determinista (splitmix64). La semilla es un
+/// texto, asi la misma respuesta produce siempre el mismo orden.
+pub struct Barajador {
+    estado: u64,
+}
+
+impl Barajador {
+    pub fn new(semilla: &str) -> Self {
+        // FNV-1a: estable entre versiones, a diferencia de DefaultHasher
+        let estado = semilla.bytes().fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+        Self { estado }
+    }
+
+    fn siguiente(&mut self) -> u64 {
+        self.estado = self.estado.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.estado;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Fisher-Yates
+    pub fn barajar<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.siguiente() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Baraja las preguntas de un examen para una respuesta
+pub fn barajar_preguntas<T>(respuesta_id: &str, examen_id: &str, preguntas: &mut [T]) {
+    Barajador::new(&format!("{respuesta_id}:{examen_id}")).barajar(preguntas);
+}
+
+/// Lo que se sorteo con una regla de muestreo, para auditoria. `extraidas`
+/// son las posiciones de las preguntas sorteadas en el examen original.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SorteoRegla {
+    pub etiqueta: Etiqueta,
+    pub cantidad: u32,
+    pub disponibles: u32,
+    pub extraidas: Vec<usize>,
+}
+
+/// Preguntas que recibe una respuesta segun el muestreo del examen
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sorteo {
+    pub seleccionadas: Vec<usize>,
+    pub reglas: Vec<SorteoRegla>,
+}
+
+/// Sortea por cada regla `cantidad` preguntas de su etiqueta, o todas si hay
+/// menos. Las preguntas de etiquetas sin regla se conservan y el resultado
+/// respeta el orden original del examen.
+pub fn sortear_preguntas(
+    respuesta_id: &str,
+    examen_id: &str,
+    muestreo: &Muestreo,
+    etiquetas: &[Etiqueta],
+) -> Sorteo {
+    let mut seleccionadas: Vec<usize> = etiquetas
+        .iter()
+        .enumerate()
+        .filter(|(_, etiqueta)| muestreo.regla(etiqueta).is_none())
+        .map(|(i, _)| i)
+        .collect();
+
+    let reglas = muestreo
+        .reglas
+        .iter()
+        .map(|regla| {
+            let mut candidatas: Vec<usize> = etiquetas
+                .iter()
+                .enumerate()
+                .filter(|(_, etiqueta)| **etiqueta == regla.etiqueta)
+                .map(|(i, _)| i)
+                .collect();
+            let disponibles = candidatas.len() as u32;
+            Barajador::new(&format!("{respuesta_id}:{examen_id}:{}", regla.etiqueta))
+                .barajar(&mut candidatas);
+            candidatas.truncate(regla.cantidad as usize);
+            candidatas.sort_unstable();
+            seleccionadas.extend(&candidatas);
+
+            SorteoRegla {
+                etiqueta: regla.etiqueta.clone(),
+                cantidad: regla.cantidad,
+                disponibles,
+                extraidas: candidatas,
+            }
+        })
+        .collect();
+
+    seleccionadas.sort_unstable();
+    Sorteo {
+        seleccionadas,
+        reglas,
+    }
+}
+
+/// Orden en que un postulante ve las alternativas de una pregunta. Las
+/// etiquetas mostradas son las claves originales ordenadas; `claves[i]` es la
+/// clave original que aparece bajo la etiqueta `i`. Con este mapa la respuesta
+/// se traduce a las claves originales antes de corregirla.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrdenAlternativas {
+    pub claves: Vec<String>,
+}
+
+impl OrdenAlternativas {
+    /// Las escalas likert conservan el orden de sus anclas y las preguntas sin
+    /// alternativas no tienen nada que barajar
+    pub fn aplica(tipo_de_pregunta: &TipoPregunta) -> bool {
+        matches!(
+            tipo_de_pregunta,
+            TipoPregunta::AlternativaUnica
+                | TipoPregunta::AlternativaConPeso
+                | TipoPregunta::SeleccionMultiple
+                | TipoPregunta::Ordenamiento
+        )
+    }
+
+    pub fn generar(
+        respuesta_id: &str,
+        pregunta_id: &str,
+        alternativas: &HashMap<String, String>,
+    ) -> Self {
+        let mut claves: Vec<String> = alternativas.keys().cloned().collect();
+        claves.sort();
+        Barajador::new(&format!("{respuesta_id}:{pregunta_id}")).barajar(&mut claves);
+        Self { claves }
+    }
+
+    fn etiquetas(&self) -> Vec<&String> {
+        let mut etiquetas: Vec<&String> = self.claves.iter().collect();
+        etiquetas.sort();
+        etiquetas
+    }
+
+    /// Clave original de una etiqueta mostrada. Una etiqueta desconocida se
+    /// devuelve tal cual para que la validacion la rechace o puntue cero.
+    pub fn a_original(&self, mostrada: &str) -> String {
+        self.etiquetas()
+            .iter()
+            .position(|etiqueta| *etiqueta == mostrada)
+            .map(|i| self.claves[i].clone())
+            .unwrap_or_else(|| mostrada.to_string())
+    }
+
+    /// Etiqueta con la que se muestra una clave original
+    pub fn a_mostrada(&self, original: &str) -> String {
+        self.claves
+            .iter()
+            .position(|clave| clave == original)
+            .map(|i| self.etiquetas()[i].clone())
+            .unwrap_or_else(|| original.to_string())
+    }
+
+    /// Alternativas como las ve el postulante: cada etiqueta con el texto de
+    /// la clave original que le toco
+    pub fn mostrar(&self, alternativas: &HashMap<String, String>) -> HashMap<String, String> {
+        alternativas
+            .iter()
+            .map(|(clave, texto)| (self.a_mostrada(clave), texto.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPUESTA_ID: &str = "8f7c2a36-9d0e-4c5b-a1f3-2e6d4b8c0a17";
+    const OTRA_RESPUESTA_ID: &str = "c4e8a1f0-2b6d-4f39-8e57-0d3a9b1c6f28";
+    const PREGUNTA_ID: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+
+    fn alternativas() -> HashMap<String, String> {
+        ["A", "B", "C", "D", "E"]
+            .iter()
+            .map(|clave| (clave.to_string(), format!("texto {clave}")))
+            .collect()
+    }
+
+    #[test]
+    fn test_mismo_orden_para_la_misma_respuesta() {
+        let primero = OrdenAlternativas::generar(RESPUESTA_ID, PREGUNTA_ID, &alternativas());
+        let segundo = OrdenAlternativas::generar(RESPUESTA_ID, PREGUNTA_ID, &alternativas());
+        assert_eq!(primero, segundo);
+
+        let mut preguntas: Vec<u32> = (0..20).collect();
+        let mut otra_vez = preguntas.clone();
+        barajar_preguntas(RESPUESTA_ID, "examen", &mut preguntas);
+        barajar_preguntas(RESPUESTA_ID, "examen", &mut otra_vez);
+        assert_eq!(preguntas, otra_vez);
+    }
+
+    #[test]
+    fn test_respuestas_distintas_suelen_ver_ordenes_distintos() {
+        let mut preguntas: Vec<u32> = (0..20).collect();
+        let mut otras: Vec<u32> = (0..20).collect();
+        barajar_preguntas(RESPUESTA_ID, "examen", &mut preguntas);
+        barajar_preguntas(OTRA_RESPUESTA_ID, "examen", &mut otras);
+        assert_ne!(preguntas, otras);
+
+        let mut ordenadas = preguntas.clone();
+        ordenadas.sort();
+        assert_eq!(ordenadas, (0..20).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_traduce_etiqueta_mostrada_a_clave_original() {
+        let orden = OrdenAlternativas {
+            claves: vec!["C".into(), "A".into(), "B".into()],
+        };
+        // A muestra el texto de C, B el de A y C el de B
+        assert_eq!(orden.a_original("A"), "C");
+        assert_eq!(orden.a_original("B"), "A");
+        assert_eq!(orden.a_mostrada("C"), "A");
+        assert_eq!(orden.a_original("Z"), "Z");
+
+        let textos = HashMap::from([
+            ("A".to_string(), "uno".to_string()),
+            ("B".to_string(), "dos".to_string()),
+            ("C".to_string(), "tres".to_string()),
+        ]);
+        let mostradas = orden.mostrar(&textos);
+        assert_eq!(mostradas["A"], "tres");
+        assert_eq!(mostradas["B"], "uno");
+        assert_eq!(mostradas["C"], "dos");
+    }
+
+    #[test]
+    fn test_sorteo_por_etiqueta_conserva_las_preguntas_sin_regla() {
+        use crate::examen::domain::value_object::muestreo::ReglaMuestreo;
+
+        let etiquetas = [
+            vec![Etiqueta::Honestidad; 6],
+            vec![Etiqueta::Neurotismo; 4],
+            vec![Etiqueta::No; 2],
+        ]
+        .concat();
+        let muestreo = Muestreo::new(vec![
+            ReglaMuestreo {
+                etiqueta: Etiqueta::Honestidad,
+                cantidad: 3,
+            },
+            ReglaMuestreo {
+                etiqueta: Etiqueta::Neurotismo,
+                cantidad: 10,
+            },
+        ])
+        .unwrap();
+
+        let sorteo = sortear_preguntas(RESPUESTA_ID, "examen", &muestreo, &etiquetas);
+        assert_eq!(sorteo.seleccionadas.len(), 3 + 4 + 2);
+        assert!(sorteo.seleccionadas.windows(2).all(|par| par[0] < par[1]));
+        assert!(sorteo.seleccionadas.contains(&10) && sorteo.seleccionadas.contains(&11));
+
+        assert_eq!(sorteo.reglas[0].disponibles, 6);
+        assert_eq!(sorteo.reglas[0].extraidas.len(), 3);
+        assert!(sorteo.reglas[0].extraidas.iter().all(|i| *i < 6));
+        assert_eq!(sorteo.reglas[1].extraidas, vec![6, 7, 8, 9]);
+
+        let otra_vez = sortear_preguntas(RESPUESTA_ID, "examen", &muestreo, &etiquetas);
+        assert_eq!(sorteo, otra_vez);
+    }
+
+    #[test]
+    fn test_likert_no_se_baraja() {
+        assert!(!OrdenAlternativas::aplica(&TipoPregunta::Likert));
+        assert!(OrdenAlternativas::aplica(&TipoPregunta::AlternativaUnica));
+    }
+}
@@ -1 +1,3 @@
+pub mod barajado;
 pub mod id;
+pub mod plazo;
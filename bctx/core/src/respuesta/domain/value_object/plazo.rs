@@ -0,0 +1,100 @@
+use chrono::{DateTime, Duration, FixedOffset};
+
+/// Tiempo disponible para rendir una evaluacion asignada.
+///
+/// Si la evaluacion define su propia duracion se usa esa; en caso contrario
+/// el limite es la suma de las duraciones de sus examenes. Sin ninguna
+/// duracion configurada la evaluacion no tiene limite de tiempo. El plazo de
+/// un solo examen se arma con su propio inicio y su duracion.
+#[derive(Debug, Clone)]
+pub struct Plazo {
+    fecha_tiempo_inicio: String,
+    duracion_minutos: Option<u32>,
+}
+
+impl Plazo {
+    pub fn new(
+        fecha_tiempo_inicio: String,
+        duracion_evaluacion: Option<u32>,
+        duracion_examenes: &[Option<u32>],
+    ) -> Self {
+        let duracion_minutos = duracion_evaluacion.or_else(|| {
+            duracion_examenes
+                .iter()
+                .flatten()
+                .copied()
+                .reduce(|total, minutos| total.saturating_add(minutos))
+        });
+
+        Self {
+            fecha_tiempo_inicio,
+            duracion_minutos,
+        }
+    }
+
+    pub fn duracion_minutos(&self) -> Option<u32> {
+        self.duracion_minutos
+    }
+
+    /// Fecha y hora en la que vence la evaluacion. `None` si no fue iniciada
+    /// o si no tiene limite de tiempo.
+    pub fn fecha_limite(&self) -> Option<DateTime<FixedOffset>> {
+        let minutos = self.duracion_minutos?;
+        let inicio = DateTime::parse_from_rfc3339(&self.fecha_tiempo_inicio).ok()?;
+        Some(inicio + Duration::minutes(i64::from(minutos)))
+    }
+
+    pub fn esta_vencido(&self, ahora: &DateTime<FixedOffset>) -> bool {
+        self.fecha_limite()
+            .map(|limite| *ahora >= limite)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INICIO: &str = "2025-01-10T09:00:00.000000-05:00";
+
+    fn fecha(valor: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(valor).unwrap()
+    }
+
+    #[test]
+    fn test_duracion_evaluacion_tiene_prioridad() {
+        let plazo = Plazo::new(INICIO.to_string(), Some(30), &[Some(20), Some(20)]);
+        assert_eq!(plazo.duracion_minutos(), Some(30));
+    }
+
+    #[test]
+    fn test_suma_duracion_de_examenes() {
+        let plazo = Plazo::new(INICIO.to_string(), None, &[Some(20), None, Some(15)]);
+        assert_eq!(plazo.duracion_minutos(), Some(35));
+        assert_eq!(
+            plazo.fecha_limite(),
+            Some(fecha("2025-01-10T09:35:00.000000-05:00"))
+        );
+    }
+
+    #[test]
+    fn test_sin_duracion_no_vence() {
+        let plazo = Plazo::new(INICIO.to_string(), None, &[None, None]);
+        assert_eq!(plazo.fecha_limite(), None);
+        assert!(!plazo.esta_vencido(&fecha("2030-01-01T00:00:00-05:00")));
+    }
+
+    #[test]
+    fn test_sin_iniciar_no_vence() {
+        let plazo = Plazo::new(String::new(), Some(10), &[]);
+        assert!(!plazo.esta_vencido(&fecha("2030-01-01T00:00:00-05:00")));
+    }
+
+    #[test]
+    fn test_vence_al_llegar_al_limite() {
+        let plazo = Plazo::new(INICIO.to_string(), Some(10), &[]);
+        assert!(!plazo.esta_vencido(&fecha("2025-01-10T09:09:59-05:00")));
+        assert!(plazo.esta_vencido(&fecha("2025-01-10T09:10:00-05:00")));
+        assert!(plazo.esta_vencido(&fecha("2025-01-10T14:10:00Z")));
+    }
+}
@@ -1,30 +1,69 @@
+use crate::baremo::domain::value_object::grupo_normativo::PerfilNormativo;
+use crate::evaluacion::domain::value_object::resultado::Resultado;
 use crate::evaluacion::value_object::id::EvaluacionID;
 use crate::postulante::domain::value_object::id::PostulanteID;
-use crate::respuesta::domain::entity::pregunta::Puntaje;
+use crate::respuesta::domain::entity::correccion::Correccion;
+use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
 use crate::respuesta::domain::entity::respuesta::{
     Estado, Respuesta, RespuestaEvaluacion, Revision,
 };
-use crate::respuesta::domain::entity::revision::ExamenRevision;
+use crate::respuesta::domain::entity::revision::{CalificacionManual, ExamenRevision};
+use crate::respuesta::domain::value_object::id::RespuestaID;
+use crate::respuesta::domain::value_object::plazo::Plazo;
+use crate::respuesta::use_case::asignar_masivo::PostulanteConocido;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::collections::HashSet;
 
 #[async_trait]
 pub trait RepositorioRespuestaEscritura<Error>: Send + Sync {
+    /// Crea la respuesta del postulante y devuelve su id
     async fn asignar_evaluacion(
         &self,
         evaluacion_id: EvaluacionID,
         postulante_id: PostulanteID,
-    ) -> Result<(), Error>;
+    ) -> Result<RespuestaID, Error>;
 
     async fn responder_evaluacion(
         &self,
         respuesta_evaluacion: &RespuestaEvaluacion,
     ) -> Result<(), Error>;
 
-    // Se usa para obtener el puntaje correcto de una pregunta especifica para poder realizar la correccion
-    async fn obtener_puntaje(
+    // Se usa para verificar que el postulante que responde es el duenio de la respuesta
+    async fn obtener_postulante(&self, respuesta_id: &RespuestaID) -> Result<PostulanteID, Error>;
+
+    // Se usa para obtener el puntaje de una pregunta especifica y la politica de su examen para poder realizar la correccion
+    async fn obtener_correccion(
         &self,
         respuesta_evaluacion: &RespuestaEvaluacion,
-    ) -> Result<Puntaje, Error>;
+    ) -> Result<Correccion, Error>;
+}
+
+/// Asignacion de una evaluacion a muchos postulantes. Cada metodo es una sola
+/// consulta, sin importar cuantos postulantes se asignen.
+#[async_trait]
+pub trait RepositorioAsignacionMasiva<Error>: Send + Sync {
+    async fn resolver_postulantes(
+        &self,
+        postulante_ids: &[String],
+        documentos: &[String],
+    ) -> Result<Vec<PostulanteConocido>, Error>;
+
+    async fn postulantes_asignados(
+        &self,
+        evaluacion_id: &EvaluacionID,
+        postulante_ids: &[String],
+    ) -> Result<HashSet<String>, Error>;
+
+    /// Crea una respuesta por postulante y devuelve sus ids en el mismo
+    /// orden. Falla si la evaluacion no existe o no admite asignaciones,
+    /// aunque la lista este vacia.
+    async fn asignar_a_postulantes(
+        &self,
+        evaluacion_id: &EvaluacionID,
+        postulante_ids: &[String],
+    ) -> Result<Vec<String>, Error>;
 }
 
 #[async_trait]
@@ -41,6 +80,34 @@ pub trait RespositorioFinalizarEvaluacion<Error>: Send + Sync {
     async fn sumar_puntos(&self, evaluacion_id: String) -> Result<(), Error>;
     async fn obtener_estado(&self, evaluacion_id: String) -> Result<Estado, Error>;
     async fn alterar_estado(&self, evaluacion_id: String) -> Result<(), Error>;
+
+    // Se usa para saber hasta cuando el postulante puede seguir respondiendo
+    async fn obtener_plazo(&self, evaluacion_id: String) -> Result<Plazo, Error>;
+
+    // Marca el inicio del examen la primera vez que se responde una de sus
+    // preguntas y devuelve el plazo del examen segun su propia duracion
+    async fn iniciar_plazo_examen(
+        &self,
+        evaluacion_id: String,
+        examen_id: String,
+    ) -> Result<Plazo, Error>;
+
+    // Respuestas en proceso, de todos o de un postulante, para cerrar las
+    // que vencieron sin que nadie las tocara
+    async fn respuestas_en_proceso(
+        &self,
+        postulante_id: Option<String>,
+    ) -> Result<Vec<String>, Error>;
+
+    // Evaluacion con lo respondido y los puntos sumados, para puntuar los
+    // rasgos y sugerir el resultado
+    async fn obtener_evaluacion(&self, evaluacion_id: String) -> Result<Evaluacion, Error>;
+    async fn guardar_puntuacion(
+        &self,
+        evaluacion_id: String,
+        rasgos: Vec<PuntuacionRasgo>,
+        resultado_sugerido: Option<Resultado>,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -56,13 +123,44 @@ pub trait RespositorioRealizarRevision<Error>: Send + Sync {
         evaluacion_id: String,
         examenes: Vec<ExamenRevision>,
         estado: Revision,
-        resultado: String, // Deberia ser enum
+        resultado: Resultado,
+        justificacion: String,
+    ) -> Result<(), Error>;
+
+    async fn obtener_resultado_sugerido(
+        &self,
+        revision_id: String,
+    ) -> Result<Option<Resultado>, Error>;
+
+    // Se usa para verificar que todas las preguntas libres esten calificadas
+    async fn obtener_evaluacion(&self, revision_id: String) -> Result<Evaluacion, Error>;
+}
+
+#[async_trait]
+pub trait RepositorioCalificarRespuestaLibre<Error>: Send + Sync {
+    // Solo se obtienen respuestas finalizadas, las unicas que se revisan
+    async fn obtener_respuesta(&self, revision_id: String) -> Result<Respuesta, Error>;
+
+    async fn guardar_calificacion(
+        &self,
+        revision_id: String,
+        examen_id: String,
+        pregunta_id: String,
+        calificacion: &CalificacionManual,
     ) -> Result<(), Error>;
 }
 
 #[async_trait]
 pub trait RepositorioObtenerRevisionPorId<Error>: Send + Sync {
     async fn obtener_revision_por_id(&self, revision_id: String) -> Result<Respuesta, Error>;
+
+    /// Genero, edad cumplida a `fecha_referencia` y grado de instruccion del
+    /// postulante. `None` si el postulante ya no existe.
+    async fn obtener_perfil_normativo(
+        &self,
+        postulante_id: String,
+        fecha_referencia: NaiveDate,
+    ) -> Result<Option<PerfilNormativo>, Error>;
 }
 
 #[async_trait]
@@ -0,0 +1,257 @@
+use crate::evaluacion::value_object::id::EvaluacionID;
+use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::provider::repositorio::RepositorioAsignacionMasiva;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+/// Los postulantes se pueden indicar por id, por documento o ambos
+#[derive(Debug, Clone)]
+pub struct InputData {
+    pub evaluacion_id: String,
+    pub postulante_ids: Vec<String>,
+    pub documentos: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostulanteConocido {
+    pub id: String,
+    pub documento: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EstadoAsignacion {
+    Asignado { respuesta_id: String },
+    YaAsignado,
+    PostulanteDesconocido,
+}
+
+/// Resultado por cada postulante pedido. `postulante` es el id o documento
+/// tal como se envio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultadoAsignacion {
+    pub postulante: String,
+    pub postulante_id: Option<String>,
+    pub estado: EstadoAsignacion,
+}
+
+/// Asigna una evaluacion a varios postulantes con un numero fijo de
+/// consultas. Un postulante que aparece dos veces en el pedido se asigna una
+/// sola vez; la segunda aparicion se reporta como ya asignada.
+pub struct AsignarEvaluacionMasiva<RepoErr> {
+    repositorio: Box<dyn RepositorioAsignacionMasiva<RepoErr>>,
+}
+
+impl<RepoErr> AsignarEvaluacionMasiva<RepoErr> {
+    pub fn new(repositorio: Box<dyn RepositorioAsignacionMasiva<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, Vec<ResultadoAsignacion>, RespuestaError>
+    for AsignarEvaluacionMasiva<RepoErr>
+where
+    RespuestaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<Vec<ResultadoAsignacion>, RespuestaError> {
+        let evaluacion_id = EvaluacionID::new(in_.evaluacion_id.as_str())?;
+        let conocidos = self
+            .repositorio
+            .resolver_postulantes(&in_.postulante_ids, &in_.documentos)
+            .await?;
+
+        let por_id = in_.postulante_ids.into_iter().map(|id| {
+            let encontrado = conocidos.iter().find(|p| p.id == id).map(|p| p.id.clone());
+            (id, encontrado)
+        });
+        let por_documento = in_.documentos.into_iter().map(|documento| {
+            let encontrado = conocidos
+                .iter()
+                .find(|p| p.documento == documento.trim())
+                .map(|p| p.id.clone());
+            (documento, encontrado)
+        });
+        let pedidos: Vec<(String, Option<String>)> = por_id.chain(por_documento).collect();
+
+        let encontrados: Vec<String> = pedidos.iter().filter_map(|(_, id)| id.clone()).collect();
+        let mut vistos = self
+            .repositorio
+            .postulantes_asignados(&evaluacion_id, &encontrados)
+            .await?;
+
+        let mut resultados = Vec::with_capacity(pedidos.len());
+        let mut nuevos = Vec::new();
+        for (postulante, postulante_id) in pedidos {
+            let estado = match &postulante_id {
+                None => EstadoAsignacion::PostulanteDesconocido,
+                Some(id) if !vistos.insert(id.clone()) => EstadoAsignacion::YaAsignado,
+                Some(id) => {
+                    nuevos.push(id.clone());
+                    EstadoAsignacion::Asignado {
+                        respuesta_id: String::new(),
+                    }
+                }
+            };
+            resultados.push(ResultadoAsignacion {
+                postulante,
+                postulante_id,
+                estado,
+            });
+        }
+
+        let respuesta_ids = self
+            .repositorio
+            .asignar_a_postulantes(&evaluacion_id, &nuevos)
+            .await?;
+        // Sin un id por cada asignado no se puede saber que respuesta es de quien
+        if respuesta_ids.len() != nuevos.len() {
+            return Err(RespuestaError::RepositorioError);
+        }
+        let asignados = resultados
+            .iter_mut()
+            .filter_map(|resultado| match &mut resultado.estado {
+                EstadoAsignacion::Asignado { respuesta_id } => Some(respuesta_id),
+                _ => None,
+            });
+        for (respuesta_id, id) in asignados.zip(respuesta_ids) {
+            *respuesta_id = id;
+        }
+
+        Ok(resultados)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    const EVALUACION_ID: &str = "3c9d2e7a-5b4f-4a1e-8d6c-0f2b7a9e4c18";
+
+    struct MockRepositorio {
+        postulantes: Vec<PostulanteConocido>,
+        asignados: HashSet<String>,
+        consultas: Arc<Mutex<u32>>,
+        ids_perdidos: usize,
+    }
+
+    impl MockRepositorio {
+        fn contar(&self) {
+            *self.consultas.lock().unwrap() += 1;
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioAsignacionMasiva<RespuestaError> for MockRepositorio {
+        async fn resolver_postulantes(
+            &self,
+            postulante_ids: &[String],
+            documentos: &[String],
+        ) -> Result<Vec<PostulanteConocido>, RespuestaError> {
+            self.contar();
+            Ok(self
+                .postulantes
+                .iter()
+                .filter(|p| postulante_ids.contains(&p.id) || documentos.contains(&p.documento))
+                .cloned()
+                .collect())
+        }
+
+        async fn postulantes_asignados(
+            &self,
+            _evaluacion_id: &EvaluacionID,
+            postulante_ids: &[String],
+        ) -> Result<HashSet<String>, RespuestaError> {
+            self.contar();
+            Ok(postulante_ids
+                .iter()
+                .filter(|id| self.asignados.contains(*id))
+                .cloned()
+                .collect())
+        }
+
+        async fn asignar_a_postulantes(
+            &self,
+            _evaluacion_id: &EvaluacionID,
+            postulante_ids: &[String],
+        ) -> Result<Vec<String>, RespuestaError> {
+            self.contar();
+            Ok(postulante_ids
+                .iter()
+                .skip(self.ids_perdidos)
+                .map(|id| format!("respuesta-{id}"))
+                .collect())
+        }
+    }
+
+    fn postulante(id: &str, documento: &str) -> PostulanteConocido {
+        PostulanteConocido {
+            id: id.to_string(),
+            documento: documento.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reporta_cada_postulante_con_consultas_fijas() {
+        let consultas = Arc::new(Mutex::new(0));
+        let caso = AsignarEvaluacionMasiva::new(Box::new(MockRepositorio {
+            postulantes: vec![
+                postulante("p1", "11111111"),
+                postulante("p2", "22222222"),
+                postulante("p3", "33333333"),
+            ],
+            asignados: HashSet::from(["p2".to_string()]),
+            consultas: consultas.clone(),
+            ids_perdidos: 0,
+        }));
+
+        let resultados = caso
+            .ejecutar(InputData {
+                evaluacion_id: EVALUACION_ID.to_string(),
+                postulante_ids: vec!["p1".to_string(), "p2".to_string(), "p9".to_string()],
+                documentos: vec!["33333333".to_string(), "11111111".to_string()],
+            })
+            .await
+            .unwrap();
+
+        let estados: Vec<_> = resultados.iter().map(|r| r.estado.clone()).collect();
+        assert_eq!(
+            estados,
+            vec![
+                EstadoAsignacion::Asignado {
+                    respuesta_id: "respuesta-p1".to_string()
+                },
+                EstadoAsignacion::YaAsignado,
+                EstadoAsignacion::PostulanteDesconocido,
+                EstadoAsignacion::Asignado {
+                    respuesta_id: "respuesta-p3".to_string()
+                },
+                EstadoAsignacion::YaAsignado,
+            ]
+        );
+        assert_eq!(resultados[3].postulante, "33333333");
+        assert_eq!(resultados[3].postulante_id.as_deref(), Some("p3"));
+        assert_eq!(*consultas.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_falla_si_faltan_ids_de_respuesta() {
+        let caso = AsignarEvaluacionMasiva::new(Box::new(MockRepositorio {
+            postulantes: vec![postulante("p1", "11111111"), postulante("p2", "22222222")],
+            asignados: HashSet::new(),
+            consultas: Arc::new(Mutex::new(0)),
+            ids_perdidos: 1,
+        }));
+
+        let resultado = caso
+            .ejecutar(InputData {
+                evaluacion_id: EVALUACION_ID.to_string(),
+                postulante_ids: vec!["p1".to_string(), "p2".to_string()],
+                documentos: vec![],
+            })
+            .await;
+
+        assert!(matches!(resultado, Err(RespuestaError::RepositorioError)));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::evaluacion::value_object::id::EvaluacionID;
 use crate::postulante::domain::value_object::id::PostulanteID;
 use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::domain::value_object::id::RespuestaID;
 use crate::respuesta::provider::repositorio::RepositorioRespuestaEscritura;
 use async_trait::async_trait;
 use quizz_common::use_case::CasoDeUso;
@@ -22,17 +23,19 @@ impl<RepoErr> AsignarEvaluacionAPostulante<RepoErr> {
 }
 
 #[async_trait]
-impl<RepoErr> CasoDeUso<InputData, (), RespuestaError> for AsignarEvaluacionAPostulante<RepoErr>
+impl<RepoErr> CasoDeUso<InputData, RespuestaID, RespuestaError>
+    for AsignarEvaluacionAPostulante<RepoErr>
 where
     RespuestaError: From<RepoErr>,
 {
-    async fn ejecutar(&self, in_: InputData) -> Result<(), RespuestaError> {
-        self.repositorio
+    async fn ejecutar(&self, in_: InputData) -> Result<RespuestaID, RespuestaError> {
+        let respuesta_id = self
+            .repositorio
             .asignar_evaluacion(
                 EvaluacionID::new(in_.evaluacion_id.as_str())?,
                 PostulanteID::new(in_.postulante_id.as_str())?,
             )
             .await?;
-        Ok(())
+        Ok(respuesta_id)
     }
 }
@@ -0,0 +1,322 @@
+use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use crate::respuesta::domain::entity::respuesta::Revision;
+use crate::respuesta::domain::entity::revision::CalificacionManual;
+use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::provider::repositorio::{
+    RepositorioCalificarRespuestaLibre, RespositorioFinalizarEvaluacion,
+};
+use crate::respuesta::use_case::finalizar_evaluacion::recalcular;
+use async_trait::async_trait;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub revision_id: String,
+    pub examen_id: String,
+    pub pregunta_id: String,
+    pub puntos: u32,
+    pub comentario: String,
+}
+
+pub struct CalificarRespuestaLibre<RepoErr> {
+    repositorio: Box<dyn RepositorioCalificarRespuestaLibre<RepoErr>>,
+    repositorio_totales: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
+}
+
+impl<RepoErr> CalificarRespuestaLibre<RepoErr> {
+    pub fn new(
+        repositorio: Box<dyn RepositorioCalificarRespuestaLibre<RepoErr>>,
+        repositorio_totales: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
+    ) -> Self {
+        Self {
+            repositorio,
+            repositorio_totales,
+        }
+    }
+}
+
+/// El psicologo califica una respuesta libre de una evaluacion finalizada.
+/// Se puede volver a calificar hasta que la revision se finalice, y despues
+/// de cada calificacion se recalculan los totales, rasgos y resultado sugerido.
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, (), RespuestaError> for CalificarRespuestaLibre<RepoErr>
+where
+    RespuestaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<(), RespuestaError> {
+        let respuesta = self
+            .repositorio
+            .obtener_respuesta(in_.revision_id.clone())
+            .await?;
+        if matches!(respuesta.revision, Revision::Finalizada) {
+            return Err(RespuestaError::RevisionYaFinalizada);
+        }
+
+        let pregunta = respuesta
+            .evaluacion
+            .examenes
+            .iter()
+            .find(|examen| examen.id.to_string() == in_.examen_id)
+            .ok_or(RespuestaError::ExamenNotFound)?
+            .preguntas
+            .iter()
+            .find(|pregunta| pregunta.id.to_string() == in_.pregunta_id)
+            .ok_or(RespuestaError::PreguntaNotFound)?;
+
+        if !matches!(pregunta.tipo_de_pregunta, TipoPregunta::Libre) {
+            return Err(RespuestaError::PreguntaNoEsLibre);
+        }
+
+        if let Some(maximo) = pregunta.puntaje_maximo()
+            && in_.puntos > maximo
+        {
+            return Err(RespuestaError::PuntosFueraDeRango { maximo });
+        }
+
+        let calificacion = CalificacionManual {
+            puntos: in_.puntos,
+            comentario: in_.comentario.trim().to_string(),
+        };
+        self.repositorio
+            .guardar_calificacion(
+                in_.revision_id.clone(),
+                in_.examen_id,
+                in_.pregunta_id,
+                &calificacion,
+            )
+            .await?;
+
+        recalcular(self.repositorio_totales.as_ref(), in_.revision_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::value_object::criterio_resultado::CriterioResultado;
+    use crate::evaluacion::domain::value_object::resultado::Resultado;
+    use crate::evaluacion::value_object::id::EvaluacionID;
+    use crate::examen::domain::value_object::id::ExamenID;
+    use crate::postulante::domain::value_object::id::PostulanteID;
+    use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+    use crate::pregunta::domain::value_object::id::PreguntaID;
+    use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+    use crate::respuesta::domain::entity::examen::Examen;
+    use crate::respuesta::domain::entity::pregunta::Pregunta;
+    use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
+    use crate::respuesta::domain::entity::respuesta::{Estado, Respuesta};
+    use crate::respuesta::domain::value_object::id::RespuestaID;
+    use crate::respuesta::domain::value_object::plazo::Plazo;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const EXAMEN_ID: &str = "5f1c2d3e-4b5a-4c6d-8e7f-9a0b1c2d3e4f";
+    const PREGUNTA_ID: &str = "7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d";
+
+    fn evaluacion(tipo_de_pregunta: TipoPregunta) -> Evaluacion {
+        Evaluacion {
+            id: EvaluacionID::new("0b4a3f0e-1c1f-4b7a-9a53-3f2d0a6c8e11").unwrap(),
+            nombre: "Evaluacion".to_string(),
+            descripcion: String::new(),
+            duracion_minutos: None,
+            criterio: CriterioResultado::default(),
+            examenes: vec![Examen {
+                id: ExamenID::new(EXAMEN_ID).unwrap(),
+                titulo: "Examen".to_string(),
+                descripcion: String::new(),
+                instrucciones: String::new(),
+                duracion_minutos: None,
+                observaciones: String::new(),
+                preguntas: vec![Pregunta {
+                    id: PreguntaID::new(PREGUNTA_ID).unwrap(),
+                    contenido: "Describa una situacion de conflicto".to_string(),
+                    observaciones: String::new(),
+                    etiqueta: Etiqueta::No,
+                    tipo_de_pregunta,
+                    imagen_ref: String::new(),
+                    alternativas: HashMap::new(),
+                    puntaje: HashMap::from([("maximo".to_string(), 5)]),
+                    respuestas: Some(vec!["Hable con mi equipo".to_string()]),
+                    puntos: 0,
+                    inversa: false,
+                    seleccion: None,
+                    calificacion: None,
+                    orden_alternativas: None,
+                }],
+                puntos_obtenidos: 0,
+                observacion: String::new(),
+            }],
+        }
+    }
+
+    struct MockRepo {
+        tipo_de_pregunta: TipoPregunta,
+        revision: Revision,
+        guardada: Arc<Mutex<Option<CalificacionManual>>>,
+    }
+
+    #[async_trait]
+    impl RepositorioCalificarRespuestaLibre<RespuestaError> for MockRepo {
+        async fn obtener_respuesta(
+            &self,
+            _revision_id: String,
+        ) -> Result<Respuesta, RespuestaError> {
+            Ok(Respuesta {
+                id: RespuestaID::new_v4(),
+                fecha_tiempo_inicio: String::new(),
+                fecha_tiempo_fin: String::new(),
+                evaluacion: evaluacion(self.tipo_de_pregunta.clone()),
+                postulante: PostulanteID::new("9c8b7a6d-5e4f-4a3b-9c2d-1e0f9a8b7c6d").unwrap(),
+                revision: self.revision.clone(),
+                resultado: None,
+                resultado_anterior: None,
+                resultado_sugerido: None,
+                justificacion: String::new(),
+                rasgos: Vec::new(),
+            })
+        }
+
+        async fn guardar_calificacion(
+            &self,
+            _revision_id: String,
+            _examen_id: String,
+            _pregunta_id: String,
+            calificacion: &CalificacionManual,
+        ) -> Result<(), RespuestaError> {
+            *self.guardada.lock().unwrap() = Some(calificacion.clone());
+            Ok(())
+        }
+    }
+
+    struct MockTotales {
+        recalculos: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl RespositorioFinalizarEvaluacion<RespuestaError> for MockTotales {
+        async fn sumar_puntos(&self, _evaluacion_id: String) -> Result<(), RespuestaError> {
+            *self.recalculos.lock().unwrap() += 1;
+            Ok(())
+        }
+
+        async fn obtener_estado(&self, _evaluacion_id: String) -> Result<Estado, RespuestaError> {
+            Ok(Estado::Finalizado)
+        }
+
+        async fn alterar_estado(&self, _evaluacion_id: String) -> Result<(), RespuestaError> {
+            unreachable!("calificar no cambia el estado de la respuesta")
+        }
+
+        async fn obtener_plazo(&self, _evaluacion_id: String) -> Result<Plazo, RespuestaError> {
+            Ok(Plazo::new(String::new(), None, &[]))
+        }
+
+        async fn iniciar_plazo_examen(
+            &self,
+            _evaluacion_id: String,
+            _examen_id: String,
+        ) -> Result<Plazo, RespuestaError> {
+            unreachable!("calificar no responde preguntas")
+        }
+
+        async fn respuestas_en_proceso(
+            &self,
+            _postulante_id: Option<String>,
+        ) -> Result<Vec<String>, RespuestaError> {
+            Ok(vec![])
+        }
+
+        async fn obtener_evaluacion(
+            &self,
+            _evaluacion_id: String,
+        ) -> Result<Evaluacion, RespuestaError> {
+            Ok(evaluacion(TipoPregunta::Libre))
+        }
+
+        async fn guardar_puntuacion(
+            &self,
+            _evaluacion_id: String,
+            _rasgos: Vec<PuntuacionRasgo>,
+            _resultado_sugerido: Option<Resultado>,
+        ) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+    }
+
+    async fn calificar(
+        tipo_de_pregunta: TipoPregunta,
+        revision: Revision,
+        puntos: u32,
+    ) -> (Result<(), RespuestaError>, Option<CalificacionManual>, u32) {
+        let guardada = Arc::new(Mutex::new(None));
+        let recalculos = Arc::new(Mutex::new(0));
+        let use_case = CalificarRespuestaLibre::new(
+            Box::new(MockRepo {
+                tipo_de_pregunta,
+                revision,
+                guardada: guardada.clone(),
+            }),
+            Box::new(MockTotales {
+                recalculos: recalculos.clone(),
+            }),
+        );
+
+        let resultado = use_case
+            .ejecutar(InputData {
+                revision_id: "revision".to_string(),
+                examen_id: EXAMEN_ID.to_string(),
+                pregunta_id: PREGUNTA_ID.to_string(),
+                puntos,
+                comentario: " Identifica el conflicto ".to_string(),
+            })
+            .await;
+
+        let guardada = guardada.lock().unwrap().clone();
+        let recalculos = *recalculos.lock().unwrap();
+        (resultado, guardada, recalculos)
+    }
+
+    #[tokio::test]
+    async fn test_califica_y_recalcula_totales() {
+        let (resultado, guardada, recalculos) =
+            calificar(TipoPregunta::Libre, Revision::SinIniciar, 4).await;
+        assert!(resultado.is_ok());
+        assert_eq!(
+            guardada,
+            Some(CalificacionManual {
+                puntos: 4,
+                comentario: "Identifica el conflicto".to_string(),
+            })
+        );
+        assert_eq!(recalculos, 1);
+    }
+
+    #[tokio::test]
+    async fn test_solo_preguntas_libres() {
+        let (resultado, guardada, _) =
+            calificar(TipoPregunta::AlternativaUnica, Revision::SinIniciar, 1).await;
+        assert!(matches!(resultado, Err(RespuestaError::PreguntaNoEsLibre)));
+        assert_eq!(guardada, None);
+    }
+
+    #[tokio::test]
+    async fn test_puntos_no_superan_el_puntaje() {
+        let (resultado, _, recalculos) =
+            calificar(TipoPregunta::Libre, Revision::SinIniciar, 6).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::PuntosFueraDeRango { maximo: 5 })
+        ));
+        assert_eq!(recalculos, 0);
+    }
+
+    #[tokio::test]
+    async fn test_revision_finalizada_no_se_califica() {
+        let (resultado, guardada, _) =
+            calificar(TipoPregunta::Libre, Revision::Finalizada, 4).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::RevisionYaFinalizada)
+        ));
+        assert_eq!(guardada, None);
+    }
+}
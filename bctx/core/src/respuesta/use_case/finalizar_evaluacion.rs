@@ -1,3 +1,5 @@
+use crate::respuesta::domain::entity::pregunta::Pregunta;
+use crate::respuesta::domain::entity::rasgo::puntuar_rasgos;
 use crate::respuesta::domain::error::respuesta::RespuestaError;
 use crate::respuesta::provider::repositorio::RespositorioFinalizarEvaluacion;
 use async_trait::async_trait;
@@ -17,6 +19,51 @@ impl<RepoErr> FinalizarEvaluacion<RepoErr> {
     }
 }
 
+/// Cierra la respuesta despues de recalcular sus totales. Es el unico camino
+/// para finalizar una evaluacion, ya sea a pedido del postulante o por tiempo.
+pub async fn finalizar<RepoErr>(
+    repositorio: &dyn RespositorioFinalizarEvaluacion<RepoErr>,
+    id: String,
+) -> Result<(), RespuestaError>
+where
+    RespuestaError: From<RepoErr>,
+{
+    recalcular(repositorio, id.clone()).await?;
+    repositorio.alterar_estado(id).await?;
+    Ok(())
+}
+
+/// Suma los puntos obtenidos, puntua los rasgos y sugiere el resultado segun
+/// los criterios de la evaluacion. Se repite cada vez que cambian los puntos
+/// de una pregunta, como al calificar a mano una respuesta libre.
+pub async fn recalcular<RepoErr>(
+    repositorio: &dyn RespositorioFinalizarEvaluacion<RepoErr>,
+    id: String,
+) -> Result<(), RespuestaError>
+where
+    RespuestaError: From<RepoErr>,
+{
+    repositorio.sumar_puntos(id.clone()).await?;
+
+    let evaluacion = repositorio.obtener_evaluacion(id.clone()).await?;
+    let puntos_por_examen: Vec<(String, i64)> = evaluacion
+        .examenes
+        .iter()
+        .map(|examen| (examen.id.to_string(), examen.puntos_obtenidos))
+        .collect();
+    let resultado_sugerido = evaluacion.criterio.sugerir(&puntos_por_examen);
+
+    let preguntas: Vec<Pregunta> = evaluacion
+        .examenes
+        .into_iter()
+        .flat_map(|examen| examen.preguntas)
+        .collect();
+    repositorio
+        .guardar_puntuacion(id, puntuar_rasgos(&preguntas), resultado_sugerido)
+        .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl<RepoErr> CasoDeUso<InputData, (), RespuestaError> for FinalizarEvaluacion<RepoErr>
 where
@@ -28,8 +75,6 @@ where
             return Err(RespuestaError::EvaluacionNoEstaEnProceso);
         }
 
-        self.repositorio.sumar_puntos(in_.id.clone()).await?;
-        self.repositorio.alterar_estado(in_.id).await?;
-        Ok(())
+        finalizar(self.repositorio.as_ref(), in_.id).await
     }
 }
@@ -0,0 +1,216 @@
+use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::provider::repositorio::RespositorioFinalizarEvaluacion;
+use crate::respuesta::use_case::finalizar_evaluacion::finalizar;
+use async_trait::async_trait;
+use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
+use quizz_common::use_case::CasoDeUso;
+
+pub struct InputData {
+    pub id: String,
+}
+
+pub struct OutputData {
+    pub finalizada: bool,
+}
+
+/// Finaliza una respuesta en proceso cuyo plazo ya vencio.
+pub struct FinalizarPorTiempo<RepoErr> {
+    repositorio: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
+}
+
+impl<RepoErr> FinalizarPorTiempo<RepoErr> {
+    pub fn new(repositorio: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputData, OutputData, RespuestaError> for FinalizarPorTiempo<RepoErr>
+where
+    RespuestaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputData) -> Result<OutputData, RespuestaError> {
+        let finalizada = finalizar_si_vencida(self.repositorio.as_ref(), in_.id).await?;
+        Ok(OutputData { finalizada })
+    }
+}
+
+async fn finalizar_si_vencida<RepoErr>(
+    repositorio: &dyn RespositorioFinalizarEvaluacion<RepoErr>,
+    id: String,
+) -> Result<bool, RespuestaError>
+where
+    RespuestaError: From<RepoErr>,
+{
+    let estado = repositorio.obtener_estado(id.clone()).await?;
+    if !estado.can_finalize() {
+        return Ok(false);
+    }
+
+    let plazo = repositorio.obtener_plazo(id.clone()).await?;
+    if !plazo.esta_vencido(&ahora_lima()) {
+        return Ok(false);
+    }
+
+    finalizar(repositorio, id).await?;
+    Ok(true)
+}
+
+pub struct InputDataVencidas {
+    pub postulante_id: Option<String>,
+}
+
+/// Finaliza las respuestas en proceso cuyo plazo ya vencio, de todos los
+/// postulantes o de uno solo. El plazo se revisa de forma perezosa: se llama
+/// antes de listar respuestas o revisiones para que una respuesta abandonada
+/// no quede abierta y sin calificar. Devuelve cuantas se finalizaron.
+pub struct FinalizarVencidas<RepoErr> {
+    repositorio: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
+}
+
+impl<RepoErr> FinalizarVencidas<RepoErr> {
+    pub fn new(repositorio: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>) -> Self {
+        Self { repositorio }
+    }
+}
+
+#[async_trait]
+impl<RepoErr> CasoDeUso<InputDataVencidas, usize, RespuestaError> for FinalizarVencidas<RepoErr>
+where
+    RespuestaError: From<RepoErr>,
+{
+    async fn ejecutar(&self, in_: InputDataVencidas) -> Result<usize, RespuestaError> {
+        let en_proceso = self
+            .repositorio
+            .respuestas_en_proceso(in_.postulante_id)
+            .await?;
+
+        let mut finalizadas = 0;
+        for id in en_proceso {
+            match finalizar_si_vencida(self.repositorio.as_ref(), id).await {
+                Ok(true) => finalizadas += 1,
+                Ok(false) | Err(RespuestaError::RespuestaNoEncontrada) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(finalizadas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::value_object::criterio_resultado::CriterioResultado;
+    use crate::evaluacion::domain::value_object::resultado::Resultado;
+    use crate::evaluacion::value_object::id::EvaluacionID;
+    use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+    use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
+    use crate::respuesta::domain::entity::respuesta::Estado;
+    use crate::respuesta::domain::value_object::plazo::Plazo;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const INICIO: &str = "2025-01-10T09:00:00.000000-05:00";
+
+    /// Respuestas en proceso con su duracion en minutos; `None` no vence
+    #[derive(Clone)]
+    struct MockRepo {
+        duraciones: HashMap<String, Option<u32>>,
+        finalizadas: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl RespositorioFinalizarEvaluacion<RespuestaError> for MockRepo {
+        async fn sumar_puntos(&self, _evaluacion_id: String) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+
+        async fn obtener_estado(&self, evaluacion_id: String) -> Result<Estado, RespuestaError> {
+            if self.finalizadas.lock().unwrap().contains(&evaluacion_id) {
+                Ok(Estado::Finalizado)
+            } else {
+                Ok(Estado::EnProceso)
+            }
+        }
+
+        async fn alterar_estado(&self, evaluacion_id: String) -> Result<(), RespuestaError> {
+            self.finalizadas.lock().unwrap().push(evaluacion_id);
+            Ok(())
+        }
+
+        async fn obtener_plazo(&self, evaluacion_id: String) -> Result<Plazo, RespuestaError> {
+            let duracion = self
+                .duraciones
+                .get(&evaluacion_id)
+                .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+            Ok(Plazo::new(INICIO.to_string(), *duracion, &[]))
+        }
+
+        async fn iniciar_plazo_examen(
+            &self,
+            _evaluacion_id: String,
+            _examen_id: String,
+        ) -> Result<Plazo, RespuestaError> {
+            unreachable!("finalizar no responde preguntas")
+        }
+
+        async fn respuestas_en_proceso(
+            &self,
+            _postulante_id: Option<String>,
+        ) -> Result<Vec<String>, RespuestaError> {
+            let mut ids: Vec<String> = self.duraciones.keys().cloned().collect();
+            ids.push("borrada".to_string());
+            ids.sort();
+            Ok(ids)
+        }
+
+        async fn obtener_evaluacion(
+            &self,
+            _evaluacion_id: String,
+        ) -> Result<Evaluacion, RespuestaError> {
+            Ok(Evaluacion {
+                id: EvaluacionID::new("0b4a3f0e-1c1f-4b7a-9a53-3f2d0a6c8e11").unwrap(),
+                nombre: "Evaluacion".to_string(),
+                descripcion: String::new(),
+                duracion_minutos: None,
+                criterio: CriterioResultado::default(),
+                examenes: vec![],
+            })
+        }
+
+        async fn guardar_puntuacion(
+            &self,
+            _evaluacion_id: String,
+            _rasgos: Vec<PuntuacionRasgo>,
+            _resultado_sugerido: Option<Resultado>,
+        ) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_finaliza_solo_las_vencidas() {
+        let repo = MockRepo {
+            duraciones: HashMap::from([
+                ("vencida".to_string(), Some(10)),
+                ("sin_limite".to_string(), None),
+            ]),
+            finalizadas: Arc::new(Mutex::new(vec![])),
+        };
+        let use_case = FinalizarVencidas::new(Box::new(repo.clone()));
+
+        let finalizadas = use_case
+            .ejecutar(InputDataVencidas {
+                postulante_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(finalizadas, 1);
+        assert_eq!(
+            *repo.finalizadas.lock().unwrap(),
+            vec!["vencida".to_string()]
+        );
+    }
+}
@@ -1,6 +1,9 @@
+pub mod asignar_masivo;
 pub mod asignar_postulante;
+pub mod calificar_respuesta_libre;
 pub mod empezar_examen;
 pub mod finalizar_evaluacion;
+pub mod finalizar_por_tiempo;
 pub mod lista_respuesta_postulante;
 pub mod listar_asignaciones;
 pub mod obtener_revision;
@@ -1,9 +1,19 @@
+use crate::baremo::domain::entity::baremo::{Baremo, seleccionar_baremo};
+use crate::baremo::domain::value_object::escala::Escala;
+use crate::baremo::domain::value_object::grupo_normativo::PerfilNormativo;
+use crate::baremo::domain::value_object::puntuacion_normativa::PuntuacionNormativa;
+use crate::baremo::provider::repositorio::RepositorioBaremoLectura;
+use crate::pregunta::domain::value_object::seleccion::ReglaSeleccion;
 use crate::respuesta::domain::entity::evaluacion::Evaluacion;
 use crate::respuesta::domain::entity::examen::Examen;
 use crate::respuesta::domain::entity::pregunta::Pregunta;
+use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
+use crate::respuesta::domain::entity::revision::CalificacionManual;
 use crate::respuesta::domain::error::respuesta::RespuestaError;
 use crate::respuesta::provider::repositorio::RepositorioObtenerRevisionPorId;
 use async_trait::async_trait;
+use chrono::DateTime;
+use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
 use quizz_common::use_case::CasoDeUso;
 use std::collections::HashMap;
 
@@ -15,10 +25,52 @@ pub struct OutputData {
     pub id: String,
     pub postulante_id: String,
     pub resultado: String,
+    pub resultado_sugerido: Option<String>,
+    pub justificacion: String,
     pub revision: String,
     pub fecha_tiempo_inicio: String,
     pub fecha_tiempo_fin: String,
     pub evaluacion: OutputEvaluacion,
+    pub rasgos: Vec<OutputRasgo>,
+}
+
+pub struct OutputRasgo {
+    pub etiqueta: String,
+    pub puntos_brutos: i64,
+    pub cantidad_items: u32,
+    pub promedio: f64,
+    pub normativa: Option<OutputNormativa>,
+}
+
+/// Puntaje bruto convertido con el baremo que corresponde al postulante.
+pub struct OutputNormativa {
+    pub baremo_id: String,
+    pub percentil: f64,
+    pub puntaje_t: f64,
+    pub eneatipo: u8,
+}
+
+impl From<PuntuacionNormativa> for OutputNormativa {
+    fn from(puntuacion: PuntuacionNormativa) -> Self {
+        Self {
+            baremo_id: puntuacion.baremo_id,
+            percentil: puntuacion.percentil,
+            puntaje_t: puntuacion.puntaje_t,
+            eneatipo: puntuacion.eneatipo,
+        }
+    }
+}
+
+impl From<PuntuacionRasgo> for OutputRasgo {
+    fn from(rasgo: PuntuacionRasgo) -> Self {
+        Self {
+            etiqueta: rasgo.etiqueta.to_string(),
+            puntos_brutos: rasgo.puntos_brutos,
+            cantidad_items: rasgo.cantidad_items,
+            promedio: rasgo.promedio,
+            normativa: None,
+        }
+    }
 }
 
 pub struct OutputEvaluacion {
@@ -51,6 +103,7 @@ pub struct OutputExamen {
     pub preguntas: Vec<OutputPregunta>,
     pub puntos_obtenidos: i64,
     pub observacion: String,
+    pub normativa: Option<OutputNormativa>,
 }
 
 impl From<Examen> for OutputExamen {
@@ -67,6 +120,7 @@ impl From<Examen> for OutputExamen {
                 .collect(),
             puntos_obtenidos: examen.puntos_obtenidos,
             observacion: examen.observacion,
+            normativa: None,
         }
     }
 }
@@ -79,6 +133,8 @@ pub struct OutputPregunta {
     pub alternativas: HashMap<String, String>,
     pub respuestas: Option<Vec<String>>,
     pub puntos: i64,
+    pub seleccion: Option<ReglaSeleccion>,
+    pub calificacion: Option<CalificacionManual>,
 }
 
 impl From<Pregunta> for OutputPregunta {
@@ -91,36 +147,97 @@ impl From<Pregunta> for OutputPregunta {
             alternativas: pregunta.alternativas,
             respuestas: pregunta.respuestas,
             puntos: pregunta.puntos,
+            seleccion: pregunta.seleccion,
+            calificacion: pregunta.calificacion,
         }
     }
 }
 
-pub struct ObtenerRevisionPorId<RepoErr> {
+pub struct ObtenerRevisionPorId<RepoErr, BaremoErr> {
     repo: Box<dyn RepositorioObtenerRevisionPorId<RepoErr>>,
+    repo_baremo: Box<dyn RepositorioBaremoLectura<BaremoErr>>,
 }
 
-impl<RepoErr> ObtenerRevisionPorId<RepoErr> {
-    pub fn new(repo: Box<dyn RepositorioObtenerRevisionPorId<RepoErr>>) -> Self {
-        Self { repo }
+impl<RepoErr, BaremoErr> ObtenerRevisionPorId<RepoErr, BaremoErr> {
+    pub fn new(
+        repo: Box<dyn RepositorioObtenerRevisionPorId<RepoErr>>,
+        repo_baremo: Box<dyn RepositorioBaremoLectura<BaremoErr>>,
+    ) -> Self {
+        Self { repo, repo_baremo }
     }
 }
 
+fn convertir(
+    baremos: &[Baremo],
+    escala: Escala,
+    perfil: Option<&PerfilNormativo>,
+    puntos: i64,
+) -> Option<OutputNormativa> {
+    seleccionar_baremo(baremos, &escala, perfil).map(|baremo| baremo.convertir(puntos).into())
+}
+
 #[async_trait]
-impl<RepoErr> CasoDeUso<InputData, OutputData, RespuestaError> for ObtenerRevisionPorId<RepoErr>
+impl<RepoErr, BaremoErr> CasoDeUso<InputData, OutputData, RespuestaError>
+    for ObtenerRevisionPorId<RepoErr, BaremoErr>
 where
     RespuestaError: From<RepoErr>,
+    RespuestaError: From<BaremoErr>,
 {
     async fn ejecutar(&self, input: InputData) -> Result<OutputData, RespuestaError> {
         let respuesta = self.repo.obtener_revision_por_id(input.revision_id).await?;
 
+        // La edad del postulante se toma al dia en que termino la evaluacion.
+        let fecha_referencia = DateTime::parse_from_rfc3339(&respuesta.fecha_tiempo_fin)
+            .unwrap_or_else(|_| ahora_lima())
+            .date_naive();
+        let perfil = self
+            .repo
+            .obtener_perfil_normativo(respuesta.postulante.to_string(), fecha_referencia)
+            .await?;
+        let baremos = self.repo_baremo.obtener_baremos().await?;
+
+        let mut evaluacion: OutputEvaluacion = respuesta.evaluacion.into();
+        for examen in evaluacion.examenes.iter_mut() {
+            examen.normativa = convertir(
+                &baremos,
+                Escala::Examen(examen.id.clone()),
+                perfil.as_ref(),
+                examen.puntos_obtenidos,
+            );
+        }
+
+        let rasgos = respuesta
+            .rasgos
+            .into_iter()
+            .map(|rasgo| {
+                let normativa = convertir(
+                    &baremos,
+                    Escala::Rasgo(rasgo.etiqueta.clone()),
+                    perfil.as_ref(),
+                    rasgo.puntos_brutos,
+                );
+                OutputRasgo {
+                    normativa,
+                    ..rasgo.into()
+                }
+            })
+            .collect();
+
         Ok(OutputData {
             id: respuesta.id.to_string(),
             postulante_id: respuesta.postulante.to_string(),
-            resultado: respuesta.resultado,
+            resultado: respuesta
+                .resultado
+                .map(|resultado| resultado.to_string())
+                .or(respuesta.resultado_anterior)
+                .unwrap_or_default(),
+            resultado_sugerido: respuesta.resultado_sugerido.map(|r| r.to_string()),
+            justificacion: respuesta.justificacion,
             revision: respuesta.revision.to_string(),
             fecha_tiempo_inicio: respuesta.fecha_tiempo_inicio,
             fecha_tiempo_fin: respuesta.fecha_tiempo_fin,
-            evaluacion: respuesta.evaluacion.into(),
+            evaluacion,
+            rasgos,
         })
     }
 }
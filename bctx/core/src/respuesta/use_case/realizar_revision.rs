@@ -1,14 +1,17 @@
+use crate::evaluacion::domain::value_object::resultado::Resultado;
 use crate::respuesta::domain::entity::respuesta::Revision;
 use crate::respuesta::domain::entity::revision::ExamenRevision;
 use crate::respuesta::domain::error::respuesta::RespuestaError;
 use crate::respuesta::provider::repositorio::RespositorioRealizarRevision;
 use async_trait::async_trait;
 use quizz_common::use_case::CasoDeUso;
+use std::str::FromStr;
 
 pub struct InputData {
     pub respuesta_id: String,
     pub evaluacion_id: String,
     pub resultado: String,
+    pub justificacion: String,
     pub examenes: Vec<InputDataExamen>,
 }
 
@@ -33,6 +36,31 @@ where
     RespuestaError: From<RepoErr>,
 {
     async fn ejecutar(&self, in_: InputData) -> Result<(), RespuestaError> {
+        let resultado = Resultado::from_str(&in_.resultado)?;
+
+        // No se cierra la revision mientras haya respuestas libres sin calificar
+        let pendientes = self
+            .repo
+            .obtener_evaluacion(in_.respuesta_id.clone())
+            .await?
+            .libres_sin_calificar();
+        if pendientes > 0 {
+            return Err(RespuestaError::CalificacionPendiente(pendientes));
+        }
+
+        // El psicologo confirma o corrige el resultado sugerido; si lo
+        // corrige debe justificarlo.
+        let sugerido = self
+            .repo
+            .obtener_resultado_sugerido(in_.respuesta_id.clone())
+            .await?;
+        if let Some(sugerido) = sugerido
+            && sugerido != resultado
+            && in_.justificacion.trim().is_empty()
+        {
+            return Err(RespuestaError::JustificacionRequerida(sugerido.to_string()));
+        }
+
         Ok(self
             .repo
             .realizar_revision(
@@ -46,8 +74,191 @@ where
                     })
                     .collect::<Vec<ExamenRevision>>(),
                 Revision::Finalizada,
-                in_.resultado,
+                resultado,
+                in_.justificacion.trim().to_string(),
             )
             .await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::value_object::criterio_resultado::CriterioResultado;
+    use crate::evaluacion::value_object::id::EvaluacionID;
+    use crate::examen::domain::value_object::id::ExamenID;
+    use crate::pregunta::domain::value_object::etiqueta::Etiqueta;
+    use crate::pregunta::domain::value_object::id::PreguntaID;
+    use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+    use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+    use crate::respuesta::domain::entity::examen::Examen;
+    use crate::respuesta::domain::entity::pregunta::Pregunta;
+    use crate::respuesta::domain::entity::revision::CalificacionManual;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    struct MockRepo {
+        sugerido: Option<Resultado>,
+        calificada: bool,
+        guardado: Arc<Mutex<Option<(Resultado, String)>>>,
+    }
+
+    fn pregunta_libre(calificacion: Option<CalificacionManual>) -> Pregunta {
+        Pregunta {
+            id: PreguntaID::new_v4(),
+            contenido: "Describa una situacion de conflicto".to_string(),
+            observaciones: String::new(),
+            etiqueta: Etiqueta::No,
+            tipo_de_pregunta: TipoPregunta::Libre,
+            imagen_ref: String::new(),
+            alternativas: HashMap::new(),
+            puntaje: HashMap::new(),
+            respuestas: Some(vec!["Hable con mi equipo".to_string()]),
+            puntos: 0,
+            inversa: false,
+            seleccion: None,
+            calificacion,
+            orden_alternativas: None,
+        }
+    }
+
+    #[async_trait]
+    impl RespositorioRealizarRevision<RespuestaError> for MockRepo {
+        async fn realizar_revision(
+            &self,
+            _revision_id: String,
+            _evaluacion_id: String,
+            _examenes: Vec<ExamenRevision>,
+            _estado: Revision,
+            resultado: Resultado,
+            justificacion: String,
+        ) -> Result<(), RespuestaError> {
+            *self.guardado.lock().unwrap() = Some((resultado, justificacion));
+            Ok(())
+        }
+
+        async fn obtener_resultado_sugerido(
+            &self,
+            _revision_id: String,
+        ) -> Result<Option<Resultado>, RespuestaError> {
+            Ok(self.sugerido)
+        }
+
+        async fn obtener_evaluacion(
+            &self,
+            _revision_id: String,
+        ) -> Result<Evaluacion, RespuestaError> {
+            let calificacion = self.calificada.then(|| CalificacionManual {
+                puntos: 2,
+                comentario: "Respuesta adecuada".to_string(),
+            });
+
+            Ok(Evaluacion {
+                id: EvaluacionID::new("0b4a3f0e-1c1f-4b7a-9a53-3f2d0a6c8e11").unwrap(),
+                nombre: "Evaluacion".to_string(),
+                descripcion: String::new(),
+                duracion_minutos: None,
+                criterio: CriterioResultado::default(),
+                examenes: vec![Examen {
+                    id: ExamenID::new("5f1c2d3e-4b5a-4c6d-8e7f-9a0b1c2d3e4f").unwrap(),
+                    titulo: "Examen".to_string(),
+                    descripcion: String::new(),
+                    instrucciones: String::new(),
+                    duracion_minutos: None,
+                    observaciones: String::new(),
+                    preguntas: vec![pregunta_libre(calificacion)],
+                    puntos_obtenidos: 0,
+                    observacion: String::new(),
+                }],
+            })
+        }
+    }
+
+    async fn revisar(
+        sugerido: Option<Resultado>,
+        resultado: &str,
+        justificacion: &str,
+    ) -> (Result<(), RespuestaError>, Option<(Resultado, String)>) {
+        revisar_con_libres(sugerido, resultado, justificacion, true).await
+    }
+
+    async fn revisar_con_libres(
+        sugerido: Option<Resultado>,
+        resultado: &str,
+        justificacion: &str,
+        calificada: bool,
+    ) -> (Result<(), RespuestaError>, Option<(Resultado, String)>) {
+        let guardado = Arc::new(Mutex::new(None));
+        let use_case = RealizarRevision::new(Box::new(MockRepo {
+            sugerido,
+            calificada,
+            guardado: guardado.clone(),
+        }));
+
+        let resultado = use_case
+            .ejecutar(InputData {
+                respuesta_id: "respuesta".to_string(),
+                evaluacion_id: "evaluacion".to_string(),
+                resultado: resultado.to_string(),
+                justificacion: justificacion.to_string(),
+                examenes: Vec::new(),
+            })
+            .await;
+
+        let guardado = guardado.lock().unwrap().clone();
+        (resultado, guardado)
+    }
+
+    #[tokio::test]
+    async fn test_confirma_resultado_sugerido_sin_justificacion() {
+        let (resultado, guardado) = revisar(Some(Resultado::Apto), "apto", "").await;
+        assert!(resultado.is_ok());
+        assert_eq!(guardado, Some((Resultado::Apto, String::new())));
+    }
+
+    #[tokio::test]
+    async fn test_corregir_resultado_requiere_justificacion() {
+        let (resultado, guardado) = revisar(Some(Resultado::Apto), "observado", "  ").await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::JustificacionRequerida(_))
+        ));
+        assert_eq!(guardado, None);
+
+        let (resultado, guardado) = revisar(
+            Some(Resultado::Apto),
+            "observado",
+            "Inconsistencias en la entrevista",
+        )
+        .await;
+        assert!(resultado.is_ok());
+        assert_eq!(
+            guardado,
+            Some((
+                Resultado::Observado,
+                "Inconsistencias en la entrevista".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resultado_no_valido() {
+        let (resultado, guardado) = revisar(None, "aprobado con reservas", "").await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::ResultadoNoValido(_))
+        ));
+        assert_eq!(guardado, None);
+    }
+
+    #[tokio::test]
+    async fn test_libres_sin_calificar_bloquean_la_revision() {
+        let (resultado, guardado) =
+            revisar_con_libres(Some(Resultado::Apto), "apto", "", false).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::CalificacionPendiente(1))
+        ));
+        assert_eq!(guardado, None);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::respuesta::domain::entity::pregunta::corregir_respuesta;
-use crate::respuesta::domain::entity::respuesta::RespuestaEvaluacion;
+use crate::respuesta::domain::entity::respuesta::{Estado, RespuestaEvaluacion};
 use crate::respuesta::domain::error::respuesta::RespuestaError;
 use crate::respuesta::domain::value_object::id::RespuestaID;
-use crate::respuesta::provider::repositorio::RepositorioRespuestaEscritura;
+use crate::respuesta::provider::repositorio::{
+    RepositorioEmpezarExamen, RepositorioRespuestaEscritura, RespositorioFinalizarEvaluacion,
+};
+use crate::respuesta::use_case::finalizar_evaluacion::finalizar;
 use async_trait::async_trait;
+use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
 use quizz_common::use_case::CasoDeUso;
 
 #[derive(Debug, Clone)]
@@ -18,11 +22,21 @@ pub struct InputData {
 
 pub struct ResponderEvaluacion<RepoErr> {
     repositorio: Box<dyn RepositorioRespuestaEscritura<RepoErr>>,
+    repositorio_estado: Box<dyn RepositorioEmpezarExamen<RepoErr>>,
+    repositorio_finalizar: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
 }
 
 impl<RepoErr> ResponderEvaluacion<RepoErr> {
-    pub fn new(repositorio: Box<dyn RepositorioRespuestaEscritura<RepoErr>>) -> Self {
-        Self { repositorio }
+    pub fn new(
+        repositorio: Box<dyn RepositorioRespuestaEscritura<RepoErr>>,
+        repositorio_estado: Box<dyn RepositorioEmpezarExamen<RepoErr>>,
+        repositorio_finalizar: Box<dyn RespositorioFinalizarEvaluacion<RepoErr>>,
+    ) -> Self {
+        Self {
+            repositorio,
+            repositorio_estado,
+            repositorio_finalizar,
+        }
     }
 }
 
@@ -42,10 +56,260 @@ where
             respuestas: in_.respuestas,
         };
 
-        // TODO: No puede responder evaluaciones ya finalizadas
-        let puntaje = self.repositorio.obtener_puntaje(&resp).await?;
-        resp.puntos = corregir_respuesta(&resp.respuestas, puntaje);
+        let postulante = self.repositorio.obtener_postulante(&resp.id).await?;
+        if postulante.to_string() != resp.postulante_id {
+            return Err(RespuestaError::RespuestaDeOtroPostulante);
+        }
+
+        let estado = self
+            .repositorio_estado
+            .obtener_estado(in_.id.clone())
+            .await?;
+        if !estado.can_answer() {
+            return Err(match estado {
+                Estado::Creado => RespuestaError::EvaluacionNoIniciada,
+                _ => RespuestaError::EvaluacionYaFinalizada,
+            });
+        }
+
+        let plazo = self
+            .repositorio_finalizar
+            .obtener_plazo(in_.id.clone())
+            .await?;
+        if plazo.esta_vencido(&ahora_lima()) {
+            finalizar(self.repositorio_finalizar.as_ref(), in_.id).await?;
+            return Err(RespuestaError::TiempoAgotado);
+        }
+
+        let plazo_examen = self
+            .repositorio_finalizar
+            .iniciar_plazo_examen(in_.id.clone(), resp.examen_id.clone())
+            .await?;
+        if plazo_examen.esta_vencido(&ahora_lima()) {
+            return Err(RespuestaError::TiempoExamenAgotado);
+        }
+
+        let correccion = self.repositorio.obtener_correccion(&resp).await?;
+        resp.respuestas = correccion.claves_originales(resp.respuestas);
+        correccion.validar(&resp.respuestas)?;
+        resp.puntos = corregir_respuesta(&resp.respuestas, &correccion);
 
         Ok(self.repositorio.responder_evaluacion(&resp).await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluacion::domain::value_object::resultado::Resultado;
+    use crate::evaluacion::value_object::id::EvaluacionID;
+    use crate::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
+    use crate::postulante::domain::value_object::id::PostulanteID;
+    use crate::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+    use crate::respuesta::domain::entity::correccion::Correccion;
+    use crate::respuesta::domain::entity::evaluacion::Evaluacion;
+    use crate::respuesta::domain::entity::rasgo::PuntuacionRasgo;
+    use crate::respuesta::domain::value_object::plazo::Plazo;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const RESPUESTA_ID: &str = "8f7c2a36-9d0e-4c5b-a1f3-2e6d4b8c0a17";
+    const POSTULANTE_ID: &str = "3b1d9e52-7a4f-4c86-b0e2-5f9a1c7d3e64";
+    const OTRO_POSTULANTE_ID: &str = "c4e8a1f0-2b6d-4f39-8e57-0d3a9b1c6f28";
+    const INICIO_EXAMEN: &str = "2025-01-10T09:00:00.000000-05:00";
+
+    #[derive(Clone)]
+    struct MockRepo {
+        estado: Estado,
+        examen_vencido: bool,
+        guardadas: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl RepositorioRespuestaEscritura<RespuestaError> for MockRepo {
+        async fn asignar_evaluacion(
+            &self,
+            _evaluacion_id: EvaluacionID,
+            _postulante_id: PostulanteID,
+        ) -> Result<RespuestaID, RespuestaError> {
+            Ok(RespuestaID::new_v4())
+        }
+
+        async fn responder_evaluacion(
+            &self,
+            _respuesta_evaluacion: &RespuestaEvaluacion,
+        ) -> Result<(), RespuestaError> {
+            *self.guardadas.lock().unwrap() += 1;
+            Ok(())
+        }
+
+        async fn obtener_postulante(
+            &self,
+            _respuesta_id: &RespuestaID,
+        ) -> Result<PostulanteID, RespuestaError> {
+            Ok(PostulanteID::new(POSTULANTE_ID)?)
+        }
+
+        async fn obtener_correccion(
+            &self,
+            _respuesta_evaluacion: &RespuestaEvaluacion,
+        ) -> Result<Correccion, RespuestaError> {
+            Ok(Correccion {
+                tipo_de_pregunta: TipoPregunta::AlternativaUnica,
+                puntaje: HashMap::from([("A".to_string(), 1)]),
+                politica: PoliticaPuntaje::default(),
+                seleccion: None,
+                ordenamiento: None,
+                numerica: None,
+                orden_alternativas: None,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl RepositorioEmpezarExamen<RespuestaError> for MockRepo {
+        async fn obtener_estado(&self, _respuesta_id: String) -> Result<Estado, RespuestaError> {
+            Ok(self.estado.clone())
+        }
+
+        async fn empezar_examen(&self, _respuesta_id: String) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RespositorioFinalizarEvaluacion<RespuestaError> for MockRepo {
+        async fn sumar_puntos(&self, _evaluacion_id: String) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+
+        async fn obtener_estado(&self, _evaluacion_id: String) -> Result<Estado, RespuestaError> {
+            Ok(self.estado.clone())
+        }
+
+        async fn alterar_estado(&self, _evaluacion_id: String) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+
+        async fn obtener_plazo(&self, _evaluacion_id: String) -> Result<Plazo, RespuestaError> {
+            Ok(Plazo::new(String::new(), None, &[]))
+        }
+
+        async fn iniciar_plazo_examen(
+            &self,
+            _evaluacion_id: String,
+            _examen_id: String,
+        ) -> Result<Plazo, RespuestaError> {
+            if self.examen_vencido {
+                Ok(Plazo::new(INICIO_EXAMEN.to_string(), Some(10), &[]))
+            } else {
+                Ok(Plazo::new(String::new(), None, &[]))
+            }
+        }
+
+        async fn respuestas_en_proceso(
+            &self,
+            _postulante_id: Option<String>,
+        ) -> Result<Vec<String>, RespuestaError> {
+            Ok(vec![])
+        }
+
+        async fn obtener_evaluacion(
+            &self,
+            _evaluacion_id: String,
+        ) -> Result<Evaluacion, RespuestaError> {
+            Err(RespuestaError::RespuestaNoEncontrada)
+        }
+
+        async fn guardar_puntuacion(
+            &self,
+            _evaluacion_id: String,
+            _rasgos: Vec<PuntuacionRasgo>,
+            _resultado_sugerido: Option<Resultado>,
+        ) -> Result<(), RespuestaError> {
+            Ok(())
+        }
+    }
+
+    async fn responder(estado: Estado, postulante_id: &str) -> (Result<(), RespuestaError>, u32) {
+        responder_con(estado, false, postulante_id).await
+    }
+
+    async fn responder_con(
+        estado: Estado,
+        examen_vencido: bool,
+        postulante_id: &str,
+    ) -> (Result<(), RespuestaError>, u32) {
+        let repo = MockRepo {
+            estado,
+            examen_vencido,
+            guardadas: Arc::new(Mutex::new(0)),
+        };
+        let use_case = ResponderEvaluacion::new(
+            Box::new(repo.clone()),
+            Box::new(repo.clone()),
+            Box::new(repo.clone()),
+        );
+
+        let resultado = use_case
+            .ejecutar(InputData {
+                id: RESPUESTA_ID.to_string(),
+                postulante_id: postulante_id.to_string(),
+                evaluacion_id: String::new(),
+                examen_id: "examen".to_string(),
+                pregunta_id: "pregunta".to_string(),
+                respuestas: vec!["A".to_string()],
+            })
+            .await;
+
+        let guardadas = *repo.guardadas.lock().unwrap();
+        (resultado, guardadas)
+    }
+
+    #[tokio::test]
+    async fn test_responde_evaluacion_en_proceso() {
+        let (resultado, guardadas) = responder(Estado::EnProceso, POSTULANTE_ID).await;
+        assert!(resultado.is_ok());
+        assert_eq!(guardadas, 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_responde_evaluacion_sin_iniciar() {
+        let (resultado, guardadas) = responder(Estado::Creado, POSTULANTE_ID).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::EvaluacionNoIniciada)
+        ));
+        assert_eq!(guardadas, 0);
+    }
+
+    #[tokio::test]
+    async fn test_no_responde_evaluacion_finalizada() {
+        let (resultado, guardadas) = responder(Estado::Finalizado, POSTULANTE_ID).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::EvaluacionYaFinalizada)
+        ));
+        assert_eq!(guardadas, 0);
+    }
+
+    #[tokio::test]
+    async fn test_no_responde_respuesta_de_otro_postulante() {
+        let (resultado, guardadas) = responder(Estado::EnProceso, OTRO_POSTULANTE_ID).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::RespuestaDeOtroPostulante)
+        ));
+        assert_eq!(guardadas, 0);
+    }
+
+    #[tokio::test]
+    async fn test_no_responde_examen_con_tiempo_agotado() {
+        let (resultado, guardadas) = responder_con(Estado::EnProceso, true, POSTULANTE_ID).await;
+        assert!(matches!(
+            resultado,
+            Err(RespuestaError::TiempoExamenAgotado)
+        ));
+        assert_eq!(guardadas, 0);
+    }
+}
@@ -1,12 +1,14 @@
 use crate::postulante::domain::value_object::id::PostulanteID;
+use crate::pregunta::domain::value_object::seleccion::ReglaSeleccion;
 use crate::respuesta::domain::entity::evaluacion::Evaluacion;
 use crate::respuesta::domain::entity::examen::Examen;
 use crate::respuesta::domain::entity::pregunta::Pregunta;
 use crate::respuesta::domain::error::respuesta::RespuestaError;
+use crate::respuesta::domain::value_object::plazo::Plazo;
 use crate::respuesta::provider::repositorio::RepositorioRespuestaLectura;
 use async_trait::async_trait;
 use chrono::DateTime;
-use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
+use quizz_common::domain::value_objects::zona_horaria::{ahora_lima, formatear_rfc3339};
 use quizz_common::use_case::CasoDeUso;
 use std::collections::HashMap;
 
@@ -21,6 +23,7 @@ pub struct OutputData {
     pub fecha_tiempo_inicio: String,
     pub fecha_tiempo_transcurrido: i64,
     pub fecha_tiempo_fin: String,
+    pub fecha_tiempo_limite: Option<String>,
     pub estado: String,
     pub evaluacion: OutputEvaluacion,
     pub revision: String,
@@ -31,6 +34,7 @@ pub struct OutputEvaluacion {
     pub id: String,
     pub nombre: String,
     pub descripcion: String,
+    pub duracion_minutos: Option<u32>,
     pub examenes: Vec<OutputExamen>,
 }
 
@@ -40,6 +44,7 @@ impl From<Evaluacion> for OutputEvaluacion {
             id: evaluacion.id.to_string(),
             nombre: evaluacion.nombre,
             descripcion: evaluacion.descripcion,
+            duracion_minutos: evaluacion.duracion_minutos,
             examenes: evaluacion
                 .examenes
                 .into_iter()
@@ -54,6 +59,7 @@ pub struct OutputExamen {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    pub duracion_minutos: Option<u32>,
     pub preguntas: Vec<OutputPregunta>,
     pub puntos_obtenidos: i64,
     pub observacion: Option<String>,
@@ -66,6 +72,7 @@ impl From<Examen> for OutputExamen {
             titulo: examen.titulo,
             descripcion: examen.descripcion,
             instrucciones: examen.instrucciones,
+            duracion_minutos: examen.duracion_minutos,
             preguntas: examen
                 .preguntas
                 .into_iter()
@@ -85,18 +92,35 @@ pub struct OutputPregunta {
     pub alternativas: HashMap<String, String>,
     pub respuestas: Option<Vec<String>>,
     pub puntos: i64,
+    pub seleccion: Option<ReglaSeleccion>,
 }
 
+// El postulante ve las alternativas y sus propias respuestas con las
+// etiquetas barajadas, nunca con las claves originales
 impl From<Pregunta> for OutputPregunta {
     fn from(pregunta: Pregunta) -> Self {
+        let (alternativas, respuestas) = match &pregunta.orden_alternativas {
+            Some(orden) => (
+                orden.mostrar(&pregunta.alternativas),
+                pregunta.respuestas.map(|respuestas| {
+                    respuestas
+                        .iter()
+                        .map(|original| orden.a_mostrada(original))
+                        .collect()
+                }),
+            ),
+            None => (pregunta.alternativas, pregunta.respuestas),
+        };
+
         Self {
             id: pregunta.id.to_string(),
             contenido: pregunta.contenido,
             tipo_de_pregunta: pregunta.tipo_de_pregunta.to_string(),
             imagen_ref: pregunta.imagen_ref.to_string(),
-            alternativas: pregunta.alternativas,
-            respuestas: pregunta.respuestas,
+            alternativas,
+            respuestas,
             puntos: pregunta.puntos,
+            seleccion: pregunta.seleccion,
         }
     }
 }
@@ -136,6 +160,20 @@ where
                 0
             };
 
+        let duracion_examenes: Vec<Option<u32>> = respuestas
+            .evaluacion
+            .examenes
+            .iter()
+            .map(|examen| examen.duracion_minutos)
+            .collect();
+        let fecha_tiempo_limite = Plazo::new(
+            fecha_inicio_str.clone(),
+            respuestas.evaluacion.duracion_minutos,
+            &duracion_examenes,
+        )
+        .fecha_limite()
+        .map(|limite| formatear_rfc3339(&limite));
+
         let estado = if respuestas.fecha_tiempo_fin.is_empty()
             && respuestas.fecha_tiempo_inicio.is_empty()
         {
@@ -151,10 +189,15 @@ where
             fecha_tiempo_inicio: fecha_inicio_str,
             fecha_tiempo_transcurrido,
             fecha_tiempo_fin: respuestas.fecha_tiempo_fin.to_string(),
+            fecha_tiempo_limite,
             estado,
             evaluacion: respuestas.evaluacion.into(),
             revision: respuestas.revision.to_string(),
-            resultado: respuestas.resultado,
+            resultado: respuestas
+                .resultado
+                .map(|resultado| resultado.to_string())
+                .or(respuestas.resultado_anterior)
+                .unwrap_or_default(),
         })
     }
 }
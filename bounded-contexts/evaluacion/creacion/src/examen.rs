@@ -5,7 +5,7 @@ pub use error::ExamenError;
 
 use common::{Entity, Id, SimpleName, SimpleNameConfig};
 
-use crate::pregunta::Pregunta;
+use crate::pregunta::{PoliticaPuntaje, Pregunta};
 
 /// Configuración para el título del examen (3-150 caracteres).
 const TITULO_CONFIG: SimpleNameConfig = SimpleNameConfig::new(3, 150);
@@ -26,13 +26,15 @@ const INSTRUCCIONES_CONFIG: SimpleNameConfig = SimpleNameConfig::new(1, 500);
 /// - `titulo`: Obligatorio, 3-150 caracteres
 /// - `descripcion`: Opcional, hasta 250 caracteres
 /// - `instrucciones`: Opcional, hasta 500 caracteres
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// - `politica_puntaje`: Cómo se califican sus preguntas de alternativa única
+#[derive(Debug, Clone, PartialEq)]
 pub struct Examen {
     id: Id,
     titulo: SimpleName,
     descripcion: Option<SimpleName>,
     instrucciones: Option<SimpleName>,
     preguntas: Vec<Pregunta>,
+    politica_puntaje: PoliticaPuntaje,
 }
 
 impl Examen {
@@ -68,6 +70,7 @@ impl Examen {
             descripcion,
             instrucciones,
             preguntas: Vec::new(),
+            politica_puntaje: PoliticaPuntaje::default(),
         })
     }
 
@@ -105,6 +108,7 @@ impl Examen {
             descripcion,
             instrucciones,
             preguntas,
+            politica_puntaje: PoliticaPuntaje::default(),
         })
     }
 
@@ -137,6 +141,16 @@ impl Examen {
     pub fn esta_vacio(&self) -> bool {
         self.preguntas.is_empty()
     }
+
+    #[must_use]
+    pub fn politica_puntaje(&self) -> &PoliticaPuntaje {
+        &self.politica_puntaje
+    }
+
+    /// Reemplaza la política de puntuación del examen.
+    pub fn asignar_politica_puntaje(&mut self, politica_puntaje: PoliticaPuntaje) {
+        self.politica_puntaje = politica_puntaje;
+    }
 }
 
 impl Entity for Examen {
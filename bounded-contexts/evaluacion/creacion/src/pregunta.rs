@@ -24,6 +24,10 @@ pub enum Pregunta {
     Libre(PreguntaLibre),
     SolaRespuesta(PreguntaSolaRespuesta),
     SiNo(PreguntaSiNo),
+    SeleccionMultiple(PreguntaSeleccionMultiple),
+    Likert(PreguntaLikert),
+    Ordenamiento(PreguntaOrdenamiento),
+    Numerica(PreguntaNumerica),
 }
 
 impl Pregunta {
@@ -36,6 +40,10 @@ impl Pregunta {
             Self::Libre(p) => p.id(),
             Self::SolaRespuesta(p) => p.id(),
             Self::SiNo(p) => p.id(),
+            Self::SeleccionMultiple(p) => p.id(),
+            Self::Likert(p) => p.id(),
+            Self::Ordenamiento(p) => p.id(),
+            Self::Numerica(p) => p.id(),
         }
     }
 
@@ -48,6 +56,10 @@ impl Pregunta {
             Self::Libre(p) => p.contenido(),
             Self::SolaRespuesta(p) => p.contenido(),
             Self::SiNo(p) => p.contenido(),
+            Self::SeleccionMultiple(p) => p.contenido(),
+            Self::Likert(p) => p.contenido(),
+            Self::Ordenamiento(p) => p.contenido(),
+            Self::Numerica(p) => p.contenido(),
         }
     }
 
@@ -60,6 +72,10 @@ impl Pregunta {
             Self::Libre(p) => p.imagen(),
             Self::SolaRespuesta(p) => p.imagen(),
             Self::SiNo(p) => p.imagen(),
+            Self::SeleccionMultiple(p) => p.imagen(),
+            Self::Likert(p) => p.imagen(),
+            Self::Ordenamiento(p) => p.imagen(),
+            Self::Numerica(p) => p.imagen(),
         }
     }
 
@@ -72,6 +88,10 @@ impl Pregunta {
             Self::Libre(p) => p.etiqueta(),
             Self::SolaRespuesta(p) => p.etiqueta(),
             Self::SiNo(p) => p.etiqueta(),
+            Self::SeleccionMultiple(p) => p.etiqueta(),
+            Self::Likert(p) => p.etiqueta(),
+            Self::Ordenamiento(p) => p.etiqueta(),
+            Self::Numerica(p) => p.etiqueta(),
         }
     }
 
@@ -84,6 +104,10 @@ impl Pregunta {
             Self::Libre(_) => "libre",
             Self::SolaRespuesta(_) => "sola_respuesta",
             Self::SiNo(_) => "si_o_no",
+            Self::SeleccionMultiple(_) => "seleccion_multiple",
+            Self::Likert(_) => "likert",
+            Self::Ordenamiento(_) => "ordenamiento",
+            Self::Numerica(_) => "numerica",
         }
     }
 
@@ -117,6 +141,30 @@ impl Pregunta {
         matches!(self, Self::SiNo(_))
     }
 
+    /// Verifica si es una pregunta de selección múltiple.
+    #[must_use]
+    pub fn es_seleccion_multiple(&self) -> bool {
+        matches!(self, Self::SeleccionMultiple(_))
+    }
+
+    /// Verifica si es una pregunta de escala Likert.
+    #[must_use]
+    pub fn es_likert(&self) -> bool {
+        matches!(self, Self::Likert(_))
+    }
+
+    /// Verifica si es una pregunta de ordenamiento.
+    #[must_use]
+    pub fn es_ordenamiento(&self) -> bool {
+        matches!(self, Self::Ordenamiento(_))
+    }
+
+    /// Verifica si es una pregunta numérica.
+    #[must_use]
+    pub fn es_numerica(&self) -> bool {
+        matches!(self, Self::Numerica(_))
+    }
+
     /// Intenta obtener la pregunta como alternativa única.
     #[must_use]
     pub fn as_alternativa_unica(&self) -> Option<&PreguntaAlternativaUnica> {
@@ -161,6 +209,42 @@ impl Pregunta {
             _ => None,
         }
     }
+
+    /// Intenta obtener la pregunta como selección múltiple.
+    #[must_use]
+    pub fn as_seleccion_multiple(&self) -> Option<&PreguntaSeleccionMultiple> {
+        match self {
+            Self::SeleccionMultiple(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Intenta obtener la pregunta como escala Likert.
+    #[must_use]
+    pub fn as_likert(&self) -> Option<&PreguntaLikert> {
+        match self {
+            Self::Likert(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Intenta obtener la pregunta como ordenamiento.
+    #[must_use]
+    pub fn as_ordenamiento(&self) -> Option<&PreguntaOrdenamiento> {
+        match self {
+            Self::Ordenamiento(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Intenta obtener la pregunta como numérica.
+    #[must_use]
+    pub fn as_numerica(&self) -> Option<&PreguntaNumerica> {
+        match self {
+            Self::Numerica(p) => Some(p),
+            _ => None,
+        }
+    }
 }
 
 impl Entity for Pregunta {
@@ -200,6 +284,30 @@ impl From<PreguntaSiNo> for Pregunta {
     }
 }
 
+impl From<PreguntaSeleccionMultiple> for Pregunta {
+    fn from(p: PreguntaSeleccionMultiple) -> Self {
+        Self::SeleccionMultiple(p)
+    }
+}
+
+impl From<PreguntaLikert> for Pregunta {
+    fn from(p: PreguntaLikert) -> Self {
+        Self::Likert(p)
+    }
+}
+
+impl From<PreguntaOrdenamiento> for Pregunta {
+    fn from(p: PreguntaOrdenamiento) -> Self {
+        Self::Ordenamiento(p)
+    }
+}
+
+impl From<PreguntaNumerica> for Pregunta {
+    fn from(p: PreguntaNumerica) -> Self {
+        Self::Numerica(p)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
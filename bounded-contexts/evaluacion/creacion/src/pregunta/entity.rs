@@ -1,11 +1,19 @@
 mod alternativa_con_peso;
 mod alternativa_unica;
 mod libre;
+mod likert;
+mod numerica;
+mod ordenamiento;
+mod seleccion_multiple;
 mod si_no;
 mod sola_respuesta;
 
 pub use alternativa_con_peso::*;
 pub use alternativa_unica::*;
 pub use libre::*;
+pub use likert::*;
+pub use numerica::*;
+pub use ordenamiento::*;
+pub use seleccion_multiple::*;
 pub use si_no::*;
 pub use sola_respuesta::*;
@@ -0,0 +1,165 @@
+use common::{Entity, Id, SimpleName};
+
+use crate::pregunta::value_object::{EscalaLikert, Etiqueta, ImagenRef, Puntaje};
+
+/// Pregunta de escala Likert.
+///
+/// El postulante indica su grado de acuerdo eligiendo un punto de la
+/// escala (1..=N). Cada punto vale su número; si la pregunta está
+/// invertida (`invertida`), el punto `p` vale `N + 1 - p`.
+///
+/// # Ejemplo de uso
+///
+/// ```json
+/// {
+///   "contenido": "Disfruto de las reuniones sociales",
+///   "alternativas": {
+///     "1": "Totalmente en desacuerdo",
+///     "2": "En desacuerdo",
+///     "3": "Neutral",
+///     "4": "De acuerdo",
+///     "5": "Totalmente de acuerdo"
+///   },
+///   "invertida": false
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreguntaLikert {
+    id: Id,
+    contenido: SimpleName,
+    imagen: Option<ImagenRef>,
+    etiqueta: Etiqueta,
+    escala: EscalaLikert,
+    invertida: bool,
+}
+
+impl PreguntaLikert {
+    pub fn new(
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        escala: EscalaLikert,
+        invertida: bool,
+    ) -> Self {
+        Self {
+            id: Id::new(),
+            contenido,
+            imagen,
+            etiqueta,
+            escala,
+            invertida,
+        }
+    }
+
+    pub fn with_id(
+        id: Id,
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        escala: EscalaLikert,
+        invertida: bool,
+    ) -> Self {
+        Self {
+            id,
+            contenido,
+            imagen,
+            etiqueta,
+            escala,
+            invertida,
+        }
+    }
+
+    #[must_use]
+    pub fn contenido(&self) -> &SimpleName {
+        &self.contenido
+    }
+
+    #[must_use]
+    pub fn imagen(&self) -> Option<&ImagenRef> {
+        self.imagen.as_ref()
+    }
+
+    #[must_use]
+    pub fn etiqueta(&self) -> &Etiqueta {
+        &self.etiqueta
+    }
+
+    #[must_use]
+    pub fn escala(&self) -> &EscalaLikert {
+        &self.escala
+    }
+
+    #[must_use]
+    pub fn invertida(&self) -> bool {
+        self.invertida
+    }
+
+    /// Califica el punto elegido. Retorna `None` si el punto no pertenece
+    /// a la escala.
+    #[must_use]
+    pub fn calificar(&self, punto: usize) -> Option<Puntaje> {
+        let puntos = self.escala.puntos();
+        if !(1..=puntos).contains(&punto) {
+            return None;
+        }
+
+        let valor = if self.invertida {
+            puntos + 1 - punto
+        } else {
+            punto
+        };
+
+        Puntaje::new(valor as f32).ok()
+    }
+}
+
+impl Entity for PreguntaLikert {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for PreguntaLikert {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for PreguntaLikert {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crear_pregunta(invertida: bool) -> PreguntaLikert {
+        let escala = EscalaLikert::new(
+            (1..=5)
+                .map(|i| format!("Nivel {i}"))
+                .collect::<Vec<String>>(),
+        )
+        .unwrap();
+
+        PreguntaLikert::new(
+            SimpleName::new("Disfruto de las reuniones sociales".to_string()).unwrap(),
+            None,
+            Etiqueta::Extrovertido,
+            escala,
+            invertida,
+        )
+    }
+
+    #[test]
+    fn test_calificar_directa() {
+        let pregunta = crear_pregunta(false);
+        assert_eq!(pregunta.calificar(4).unwrap().valor(), 4.0);
+        assert!(pregunta.calificar(0).is_none());
+        assert!(pregunta.calificar(6).is_none());
+    }
+
+    #[test]
+    fn test_calificar_invertida() {
+        let pregunta = crear_pregunta(true);
+        assert_eq!(pregunta.calificar(1).unwrap().valor(), 5.0);
+        assert_eq!(pregunta.calificar(5).unwrap().valor(), 1.0);
+    }
+}
@@ -0,0 +1,127 @@
+use common::{Entity, Id, SimpleName};
+
+use crate::pregunta::value_object::{Etiqueta, ImagenRef, Puntaje, RespuestaNumerica};
+
+/// Pregunta con respuesta numérica.
+///
+/// El postulante escribe un número, con `,` o `.` como separador decimal y
+/// opcionalmente la unidad. Se otorga el puntaje si el valor está dentro de
+/// la tolerancia del valor esperado.
+///
+/// # Ejemplo de uso
+///
+/// ```json
+/// {
+///   "contenido": "¿Cuánto mide la diagonal de un cuadrado de 10 cm de lado?",
+///   "puntaje": { "14,14": 2 },
+///   "numerica": { "tolerancia": 0.01, "tipo_tolerancia": "absoluta", "unidad": "cm" }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreguntaNumerica {
+    id: Id,
+    contenido: SimpleName,
+    imagen: Option<ImagenRef>,
+    etiqueta: Etiqueta,
+    respuesta: RespuestaNumerica,
+    puntaje: Puntaje,
+}
+
+impl PreguntaNumerica {
+    pub fn new(
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        respuesta: RespuestaNumerica,
+        puntaje: Puntaje,
+    ) -> Self {
+        Self::with_id(Id::new(), contenido, imagen, etiqueta, respuesta, puntaje)
+    }
+
+    pub fn with_id(
+        id: Id,
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        respuesta: RespuestaNumerica,
+        puntaje: Puntaje,
+    ) -> Self {
+        Self {
+            id,
+            contenido,
+            imagen,
+            etiqueta,
+            respuesta,
+            puntaje,
+        }
+    }
+
+    #[must_use]
+    pub fn contenido(&self) -> &SimpleName {
+        &self.contenido
+    }
+
+    #[must_use]
+    pub fn imagen(&self) -> Option<&ImagenRef> {
+        self.imagen.as_ref()
+    }
+
+    #[must_use]
+    pub fn etiqueta(&self) -> &Etiqueta {
+        &self.etiqueta
+    }
+
+    #[must_use]
+    pub fn respuesta(&self) -> &RespuestaNumerica {
+        &self.respuesta
+    }
+
+    #[must_use]
+    pub fn puntaje(&self) -> Puntaje {
+        self.puntaje
+    }
+
+    /// Califica la respuesta escrita por el postulante.
+    #[must_use]
+    pub fn calificar(&self, respuesta: &str) -> Puntaje {
+        if self.respuesta.acepta(respuesta) {
+            self.puntaje
+        } else {
+            Puntaje::cero()
+        }
+    }
+}
+
+impl Entity for PreguntaNumerica {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for PreguntaNumerica {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for PreguntaNumerica {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::value_object::Tolerancia;
+
+    #[test]
+    fn test_calificar() {
+        let pregunta = PreguntaNumerica::new(
+            SimpleName::new("¿Cuánto es 2,5 por 5?".to_string()).unwrap(),
+            None,
+            Etiqueta::No,
+            RespuestaNumerica::new(12.5, Tolerancia::default(), None).unwrap(),
+            Puntaje::new(2.0).unwrap(),
+        );
+
+        assert_eq!(pregunta.calificar("12,5").valor(), 2.0);
+        assert_eq!(pregunta.calificar("12,6").valor(), 0.0);
+    }
+}
@@ -0,0 +1,192 @@
+use common::{Entity, Id, SimpleName};
+
+use crate::pregunta::value_object::{
+    AlternativaClave, AlternativasMultiples, Etiqueta, ImagenRef, Puntaje, PuntajeError,
+    PuntajeOrdenamiento,
+};
+
+/// Pregunta de ordenamiento ("ordene los pasos").
+///
+/// El postulante responde con todas las alternativas en el orden que
+/// considera correcto. El puntaje se otorga de forma exacta, por posición
+/// o por pares en el orden correcto (Kendall tau).
+///
+/// # Ejemplo de uso
+///
+/// ```json
+/// {
+///   "contenido": "Ordene de menor a mayor",
+///   "alternativas": { "A": "Decena", "B": "Unidad", "C": "Centena" },
+///   "puntaje": { "B": 1, "A": 2, "C": 3 },
+///   "ordenamiento": "kendall"
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreguntaOrdenamiento {
+    id: Id,
+    contenido: SimpleName,
+    imagen: Option<ImagenRef>,
+    etiqueta: Etiqueta,
+    alternativas: AlternativasMultiples,
+    puntaje: PuntajeOrdenamiento,
+}
+
+impl PreguntaOrdenamiento {
+    /// Crea una nueva pregunta de ordenamiento.
+    ///
+    /// # Errors
+    ///
+    /// Retorna `PuntajeError::ClaveNoExiste` si el orden correcto no incluye
+    /// exactamente las alternativas de la pregunta.
+    pub fn new(
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        alternativas: AlternativasMultiples,
+        puntaje: PuntajeOrdenamiento,
+    ) -> Result<Self, PuntajeError> {
+        Self::with_id(
+            Id::new(),
+            contenido,
+            imagen,
+            etiqueta,
+            alternativas,
+            puntaje,
+        )
+    }
+
+    /// Crea una pregunta con un ID específico (para reconstrucción desde persistencia).
+    ///
+    /// # Errors
+    ///
+    /// Retorna `PuntajeError::ClaveNoExiste` si el orden correcto no incluye
+    /// exactamente las alternativas de la pregunta.
+    pub fn with_id(
+        id: Id,
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        alternativas: AlternativasMultiples,
+        puntaje: PuntajeOrdenamiento,
+    ) -> Result<Self, PuntajeError> {
+        if let Some(clave) = puntaje
+            .orden_correcto()
+            .iter()
+            .find(|clave| !alternativas.contiene(**clave))
+        {
+            return Err(PuntajeError::ClaveNoExiste(clave.to_string()));
+        }
+
+        if let Some(alternativa) = alternativas
+            .items()
+            .iter()
+            .find(|a| !puntaje.orden_correcto().contains(&a.clave()))
+        {
+            return Err(PuntajeError::ClaveNoExiste(alternativa.clave().to_string()));
+        }
+
+        Ok(Self {
+            id,
+            contenido,
+            imagen,
+            etiqueta,
+            alternativas,
+            puntaje,
+        })
+    }
+
+    #[must_use]
+    pub fn contenido(&self) -> &SimpleName {
+        &self.contenido
+    }
+
+    #[must_use]
+    pub fn imagen(&self) -> Option<&ImagenRef> {
+        self.imagen.as_ref()
+    }
+
+    #[must_use]
+    pub fn etiqueta(&self) -> &Etiqueta {
+        &self.etiqueta
+    }
+
+    #[must_use]
+    pub fn alternativas(&self) -> &AlternativasMultiples {
+        &self.alternativas
+    }
+
+    #[must_use]
+    pub fn puntaje(&self) -> &PuntajeOrdenamiento {
+        &self.puntaje
+    }
+
+    /// Califica el orden dado por el postulante.
+    #[must_use]
+    pub fn calificar(&self, orden: &[AlternativaClave]) -> Puntaje {
+        self.puntaje.calificar(orden)
+    }
+}
+
+impl Entity for PreguntaOrdenamiento {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for PreguntaOrdenamiento {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for PreguntaOrdenamiento {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::value_object::{Alternativa, ModoOrdenamiento};
+
+    fn crear_alternativas() -> AlternativasMultiples {
+        let items = vec![
+            Alternativa::new(AlternativaClave::A, "Decena".to_string()),
+            Alternativa::new(AlternativaClave::B, "Unidad".to_string()),
+            Alternativa::new(AlternativaClave::C, "Centena".to_string()),
+        ];
+        AlternativasMultiples::new(items).unwrap()
+    }
+
+    fn crear_pregunta(orden: Vec<AlternativaClave>) -> Result<PreguntaOrdenamiento, PuntajeError> {
+        let puntaje =
+            PuntajeOrdenamiento::new(orden, Puntaje::uno(), ModoOrdenamiento::Exacto).unwrap();
+
+        PreguntaOrdenamiento::new(
+            SimpleName::new("Ordene de menor a mayor".to_string()).unwrap(),
+            None,
+            Etiqueta::No,
+            crear_alternativas(),
+            puntaje,
+        )
+    }
+
+    #[test]
+    fn test_calificar() {
+        use AlternativaClave::{A, B, C};
+        let pregunta = crear_pregunta(vec![B, A, C]).unwrap();
+
+        assert_eq!(pregunta.calificar(&[B, A, C]).valor(), 1.0);
+        assert_eq!(pregunta.calificar(&[A, B, C]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_orden_debe_cubrir_alternativas() {
+        use AlternativaClave::{A, B, D};
+        assert!(matches!(
+            crear_pregunta(vec![B, A]),
+            Err(PuntajeError::ClaveNoExiste(_))
+        ));
+        assert!(matches!(
+            crear_pregunta(vec![B, A, D]),
+            Err(PuntajeError::ClaveNoExiste(_))
+        ));
+    }
+}
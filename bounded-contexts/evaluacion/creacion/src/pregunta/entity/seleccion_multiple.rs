@@ -0,0 +1,199 @@
+use common::{Entity, Id, SimpleName};
+
+use crate::pregunta::value_object::{
+    AlternativaClave, AlternativasMultiples, Etiqueta, ImagenRef, Puntaje,
+    PuntajeSeleccionMultiple, RangoSeleccion, SeleccionError,
+};
+
+/// Pregunta de selección múltiple ("marque todas las que correspondan").
+///
+/// El postulante marca entre `minimo` y `maximo` alternativas. Varias
+/// alternativas pueden ser correctas y el puntaje se otorga de forma
+/// exacta (todo o nada) o proporcional.
+///
+/// # Ejemplo de uso
+///
+/// ```json
+/// {
+///   "contenido": "¿Cuáles son números primos?",
+///   "alternativas": { "A": "2", "B": "4", "C": "5", "D": "9" },
+///   "puntaje": { "A": 1, "C": 1 },
+///   "seleccion": { "minimo": 1, "maximo": 3, "modo": "proporcional" }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreguntaSeleccionMultiple {
+    id: Id,
+    contenido: SimpleName,
+    imagen: Option<ImagenRef>,
+    etiqueta: Etiqueta,
+    alternativas: AlternativasMultiples,
+    puntaje: PuntajeSeleccionMultiple,
+    rango: RangoSeleccion,
+}
+
+impl PreguntaSeleccionMultiple {
+    /// Crea una nueva pregunta de selección múltiple.
+    ///
+    /// # Errors
+    ///
+    /// Retorna `SeleccionError::MaximoMayorQueAlternativas` si el rango
+    /// permite marcar más alternativas de las que existen.
+    pub fn new(
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        alternativas: AlternativasMultiples,
+        puntaje: PuntajeSeleccionMultiple,
+        rango: RangoSeleccion,
+    ) -> Result<Self, SeleccionError> {
+        Self::with_id(
+            Id::new(),
+            contenido,
+            imagen,
+            etiqueta,
+            alternativas,
+            puntaje,
+            rango,
+        )
+    }
+
+    /// Crea una pregunta con un ID específico (para reconstrucción desde persistencia).
+    ///
+    /// # Errors
+    ///
+    /// Retorna `SeleccionError::MaximoMayorQueAlternativas` si el rango
+    /// permite marcar más alternativas de las que existen.
+    pub fn with_id(
+        id: Id,
+        contenido: SimpleName,
+        imagen: Option<ImagenRef>,
+        etiqueta: Etiqueta,
+        alternativas: AlternativasMultiples,
+        puntaje: PuntajeSeleccionMultiple,
+        rango: RangoSeleccion,
+    ) -> Result<Self, SeleccionError> {
+        if rango.maximo() > alternativas.len() {
+            return Err(SeleccionError::MaximoMayorQueAlternativas {
+                maximo: rango.maximo(),
+                alternativas: alternativas.len(),
+            });
+        }
+
+        Ok(Self {
+            id,
+            contenido,
+            imagen,
+            etiqueta,
+            alternativas,
+            puntaje,
+            rango,
+        })
+    }
+
+    #[must_use]
+    pub fn contenido(&self) -> &SimpleName {
+        &self.contenido
+    }
+
+    #[must_use]
+    pub fn imagen(&self) -> Option<&ImagenRef> {
+        self.imagen.as_ref()
+    }
+
+    #[must_use]
+    pub fn etiqueta(&self) -> &Etiqueta {
+        &self.etiqueta
+    }
+
+    #[must_use]
+    pub fn alternativas(&self) -> &AlternativasMultiples {
+        &self.alternativas
+    }
+
+    #[must_use]
+    pub fn puntaje(&self) -> &PuntajeSeleccionMultiple {
+        &self.puntaje
+    }
+
+    #[must_use]
+    pub fn rango(&self) -> RangoSeleccion {
+        self.rango
+    }
+
+    /// Califica las alternativas marcadas. Una selección fuera del rango
+    /// permitido no puntúa.
+    #[must_use]
+    pub fn calificar(&self, seleccion: &[AlternativaClave]) -> Puntaje {
+        if !self.rango.admite(seleccion.len()) {
+            return Puntaje::cero();
+        }
+
+        self.puntaje.calificar(seleccion)
+    }
+}
+
+impl Entity for PreguntaSeleccionMultiple {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for PreguntaSeleccionMultiple {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for PreguntaSeleccionMultiple {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregunta::value_object::{Alternativa, ModoCalificacion};
+
+    fn crear_alternativas() -> AlternativasMultiples {
+        let items = vec![
+            Alternativa::new(AlternativaClave::A, "2".to_string()),
+            Alternativa::new(AlternativaClave::B, "4".to_string()),
+            Alternativa::new(AlternativaClave::C, "5".to_string()),
+        ];
+        AlternativasMultiples::new(items).unwrap()
+    }
+
+    fn crear_pregunta(rango: RangoSeleccion) -> Result<PreguntaSeleccionMultiple, SeleccionError> {
+        let puntaje = PuntajeSeleccionMultiple::new(
+            [AlternativaClave::A, AlternativaClave::C],
+            Puntaje::uno(),
+            ModoCalificacion::Exacta,
+        )
+        .unwrap();
+
+        PreguntaSeleccionMultiple::new(
+            SimpleName::new("¿Cuáles son números primos?".to_string()).unwrap(),
+            None,
+            Etiqueta::No,
+            crear_alternativas(),
+            puntaje,
+            rango,
+        )
+    }
+
+    #[test]
+    fn test_calificar_respeta_rango() {
+        let pregunta = crear_pregunta(RangoSeleccion::new(2, 2).unwrap()).unwrap();
+
+        let correcta = [AlternativaClave::A, AlternativaClave::C];
+        assert_eq!(pregunta.calificar(&correcta).valor(), 1.0);
+        assert_eq!(pregunta.calificar(&[AlternativaClave::A]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_maximo_mayor_que_alternativas() {
+        let result = crear_pregunta(RangoSeleccion::new(1, 4).unwrap());
+        assert!(matches!(
+            result,
+            Err(SeleccionError::MaximoMayorQueAlternativas { .. })
+        ));
+    }
+}
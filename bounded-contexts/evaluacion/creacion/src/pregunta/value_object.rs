@@ -1,9 +1,15 @@
 mod alternativa;
+mod escala_likert;
 mod etiqueta;
 mod imagen_ref;
+mod numerica;
 mod puntaje;
+mod seleccion;
 
 pub use alternativa::*;
+pub use escala_likert::*;
 pub use etiqueta::*;
 pub use imagen_ref::*;
+pub use numerica::*;
 pub use puntaje::*;
+pub use seleccion::*;
@@ -0,0 +1,114 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EscalaLikertError {
+    #[error("La escala debe tener entre {min} y {max} puntos, se proporcionaron {actual}")]
+    CantidadDePuntos {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+
+    #[error("El ancla del punto {0} está vacía")]
+    AnclaVacia(usize),
+}
+
+/// Value Object que representa una escala de acuerdo tipo Likert.
+///
+/// Cada ancla es el texto de un punto de la escala, en orden: la primera
+/// corresponde al punto 1 y la última al punto N. La cantidad de puntos es
+/// la cantidad de anclas.
+///
+/// Garantiza que:
+/// - Hay entre 3 y 11 puntos
+/// - Ninguna ancla está vacía
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscalaLikert {
+    anclas: Vec<String>,
+}
+
+impl EscalaLikert {
+    const MIN_PUNTOS: usize = 3;
+    const MAX_PUNTOS: usize = 11;
+
+    /// Crea una nueva escala a partir de sus anclas.
+    ///
+    /// # Errors
+    ///
+    /// - `EscalaLikertError::CantidadDePuntos` si hay menos de 3 o más de 11 anclas
+    /// - `EscalaLikertError::AnclaVacia` si alguna ancla está vacía
+    pub fn new(anclas: Vec<String>) -> Result<Self, EscalaLikertError> {
+        if !(Self::MIN_PUNTOS..=Self::MAX_PUNTOS).contains(&anclas.len()) {
+            return Err(EscalaLikertError::CantidadDePuntos {
+                min: Self::MIN_PUNTOS,
+                max: Self::MAX_PUNTOS,
+                actual: anclas.len(),
+            });
+        }
+
+        if let Some(posicion) = anclas.iter().position(|a| a.trim().is_empty()) {
+            return Err(EscalaLikertError::AnclaVacia(posicion + 1));
+        }
+
+        Ok(Self { anclas })
+    }
+
+    #[must_use]
+    pub fn anclas(&self) -> &[String] {
+        &self.anclas
+    }
+
+    /// Cantidad de puntos de la escala.
+    #[must_use]
+    pub fn puntos(&self) -> usize {
+        self.anclas.len()
+    }
+
+    /// Obtiene el texto de un punto (1..=N).
+    #[must_use]
+    pub fn ancla(&self, punto: usize) -> Option<&str> {
+        punto
+            .checked_sub(1)
+            .and_then(|i| self.anclas.get(i))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anclas(cantidad: usize) -> Vec<String> {
+        (1..=cantidad).map(|i| format!("Nivel {i}")).collect()
+    }
+
+    #[test]
+    fn test_escala_valida() {
+        let escala = EscalaLikert::new(anclas(5)).unwrap();
+        assert_eq!(escala.puntos(), 5);
+        assert_eq!(escala.ancla(1), Some("Nivel 1"));
+        assert_eq!(escala.ancla(6), None);
+    }
+
+    #[test]
+    fn test_cantidad_de_puntos() {
+        assert!(matches!(
+            EscalaLikert::new(anclas(2)),
+            Err(EscalaLikertError::CantidadDePuntos { .. })
+        ));
+        assert!(matches!(
+            EscalaLikert::new(anclas(12)),
+            Err(EscalaLikertError::CantidadDePuntos { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ancla_vacia() {
+        let mut anclas = anclas(5);
+        anclas[2] = " ".to_string();
+        assert!(matches!(
+            EscalaLikert::new(anclas),
+            Err(EscalaLikertError::AnclaVacia(3))
+        ));
+    }
+}
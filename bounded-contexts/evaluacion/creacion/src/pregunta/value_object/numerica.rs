@@ -0,0 +1,179 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum NumericaError {
+    #[error("El valor esperado no es un número finito: {0}")]
+    ValorNoValido(f64),
+
+    #[error("La tolerancia no puede ser negativa: {0}")]
+    ToleranciaNegativa(f64),
+}
+
+/// Margen para que errores de redondeo (0.1 + 0.2) no dejen fuera una
+/// respuesta que está justo en el límite de la tolerancia.
+const EPSILON: f64 = 1e-9;
+
+/// Interpreta un decimal escrito con `,` o `.` como separador.
+///
+/// Si aparecen ambos, el último es el separador decimal y el otro agrupa
+/// miles; si uno solo se repite, agrupa miles. Un separador único siempre es
+/// decimal, por lo que `"1,5"` y `"1.5"` valen lo mismo.
+#[must_use]
+pub fn parsear_decimal(texto: &str) -> Option<f64> {
+    let texto: String = texto.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let normalizado = match (texto.rfind(','), texto.rfind('.')) {
+        (Some(coma), Some(punto)) if coma > punto => texto.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => texto.replace(',', ""),
+        (Some(_), None) if texto.matches(',').count() > 1 => texto.replace(',', ""),
+        (Some(_), None) => texto.replace(',', "."),
+        (None, Some(_)) if texto.matches('.').count() > 1 => texto.replace('.', ""),
+        _ => texto,
+    };
+
+    normalizado.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Cuánto puede alejarse la respuesta del valor esperado.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerancia {
+    /// Distancia fija al valor esperado.
+    Absoluta(f64),
+    /// Fracción del valor esperado (0.05 es un 5%).
+    Relativa(f64),
+}
+
+impl Tolerancia {
+    fn margen(&self, esperado: f64) -> f64 {
+        match self {
+            Self::Absoluta(margen) => *margen,
+            Self::Relativa(fraccion) => fraccion * esperado.abs(),
+        }
+    }
+
+    fn valor(&self) -> f64 {
+        match self {
+            Self::Absoluta(valor) | Self::Relativa(valor) => *valor,
+        }
+    }
+}
+
+impl Default for Tolerancia {
+    fn default() -> Self {
+        Self::Absoluta(0.0)
+    }
+}
+
+/// Value Object que representa la respuesta correcta de una pregunta
+/// numérica: el valor esperado, su tolerancia y una unidad opcional que el
+/// postulante puede escribir junto al número.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RespuestaNumerica {
+    esperado: f64,
+    tolerancia: Tolerancia,
+    unidad: Option<String>,
+}
+
+impl RespuestaNumerica {
+    /// Crea una nueva respuesta numérica.
+    ///
+    /// # Errors
+    ///
+    /// - `NumericaError::ValorNoValido` si el valor esperado no es finito
+    /// - `NumericaError::ToleranciaNegativa` si la tolerancia es negativa o no es finita
+    pub fn new(
+        esperado: f64,
+        tolerancia: Tolerancia,
+        unidad: Option<String>,
+    ) -> Result<Self, NumericaError> {
+        if !esperado.is_finite() {
+            return Err(NumericaError::ValorNoValido(esperado));
+        }
+
+        if !tolerancia.valor().is_finite() || tolerancia.valor() < 0.0 {
+            return Err(NumericaError::ToleranciaNegativa(tolerancia.valor()));
+        }
+
+        let unidad = unidad
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty());
+
+        Ok(Self {
+            esperado,
+            tolerancia,
+            unidad,
+        })
+    }
+
+    #[must_use]
+    pub fn esperado(&self) -> f64 {
+        self.esperado
+    }
+
+    #[must_use]
+    pub fn tolerancia(&self) -> Tolerancia {
+        self.tolerancia
+    }
+
+    #[must_use]
+    pub fn unidad(&self) -> Option<&str> {
+        self.unidad.as_deref()
+    }
+
+    /// Verifica si la respuesta escrita está dentro de la tolerancia.
+    #[must_use]
+    pub fn acepta(&self, respuesta: &str) -> bool {
+        let respuesta = respuesta.trim();
+        let respuesta = match &self.unidad {
+            Some(unidad) => respuesta
+                .len()
+                .checked_sub(unidad.len())
+                .and_then(|corte| respuesta.get(..corte).zip(respuesta.get(corte..)))
+                .filter(|(_, sufijo)| sufijo.to_lowercase() == unidad.to_lowercase())
+                .map_or(respuesta, |(valor, _)| valor),
+            None => respuesta,
+        };
+
+        parsear_decimal(respuesta).is_some_and(|valor| {
+            (valor - self.esperado).abs() <= self.tolerancia.margen(self.esperado) + EPSILON
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsear_decimal() {
+        assert_eq!(parsear_decimal("12,5"), Some(12.5));
+        assert_eq!(parsear_decimal("12.5"), Some(12.5));
+        assert_eq!(parsear_decimal("1.234,5"), Some(1234.5));
+        assert_eq!(parsear_decimal("1,234.5"), Some(1234.5));
+        assert_eq!(parsear_decimal("abc"), None);
+    }
+
+    #[test]
+    fn test_acepta_con_unidad() {
+        let respuesta =
+            RespuestaNumerica::new(12.5, Tolerancia::Absoluta(0.1), Some("cm".to_string()))
+                .unwrap();
+        assert!(respuesta.acepta("12,6 cm"));
+        assert!(respuesta.acepta("12.4"));
+        assert!(!respuesta.acepta("12,7 cm"));
+        assert!(!respuesta.acepta("12,5 kg"));
+    }
+
+    #[test]
+    fn test_tolerancia_relativa() {
+        let respuesta = RespuestaNumerica::new(200.0, Tolerancia::Relativa(0.05), None).unwrap();
+        assert!(respuesta.acepta("209"));
+        assert!(!respuesta.acepta("211"));
+    }
+
+    #[test]
+    fn test_tolerancia_negativa() {
+        let result = RespuestaNumerica::new(1.0, Tolerancia::Absoluta(-0.5), None);
+        assert!(matches!(result, Err(NumericaError::ToleranciaNegativa(_))));
+    }
+}
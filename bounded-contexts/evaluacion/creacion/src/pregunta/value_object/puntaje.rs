@@ -1,10 +1,16 @@
 mod alternativa_unica;
 mod con_peso;
+mod ordenamiento;
+mod politica;
+mod seleccion_multiple;
 mod si_no;
 mod sola_respuesta;
 
 pub use alternativa_unica::PuntajeAlternativaUnica;
 pub use con_peso::PuntajeConPeso;
+pub use ordenamiento::{ModoOrdenamiento, PuntajeOrdenamiento};
+pub use politica::{EnBlanco, PoliticaPuntaje};
+pub use seleccion_multiple::{ModoCalificacion, PuntajeSeleccionMultiple};
 pub use si_no::PuntajeSiNo;
 pub use sola_respuesta::PuntajeSolaRespuesta;
 
@@ -26,9 +32,15 @@ pub enum PuntajeError {
 
     #[error("Se requiere puntaje para SI y NO")]
     RequiereSiNo,
+
+    #[error("La clave {0} está repetida")]
+    ClaveDuplicada(String),
 }
 
 /// Value Object que representa un puntaje válido (no negativo).
+///
+/// Las penalizaciones no se modelan como puntajes negativos: ver
+/// [`PoliticaPuntaje`].
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Puntaje {
     valor: f32,
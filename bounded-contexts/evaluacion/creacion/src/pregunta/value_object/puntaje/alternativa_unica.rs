@@ -1,5 +1,5 @@
 use crate::pregunta::value_object::AlternativaClave;
-use crate::{Puntaje, PuntajeError};
+use crate::{PoliticaPuntaje, Puntaje, PuntajeError};
 
 /// Value Object que representa el puntaje de una pregunta de alternativa única.
 ///
@@ -34,6 +34,26 @@ impl PuntajeAlternativaUnica {
     pub fn puntaje(&self) -> Puntaje {
         self.puntaje
     }
+
+    /// Califica la alternativa elegida según la política del examen.
+    ///
+    /// `None` indica que la pregunta quedó en blanco.
+    #[must_use]
+    pub fn calificar(
+        &self,
+        respuesta: Option<AlternativaClave>,
+        politica: &PoliticaPuntaje,
+    ) -> f32 {
+        let obtenido = respuesta.map(|clave| {
+            if clave == self.respuesta_correcta {
+                self.puntaje
+            } else {
+                Puntaje::cero()
+            }
+        });
+
+        politica.calificar(obtenido)
+    }
 }
 
 #[cfg(test)]
@@ -51,4 +71,18 @@ mod tests {
         let result = PuntajeAlternativaUnica::new(AlternativaClave::Si, Puntaje::uno());
         assert!(matches!(result, Err(PuntajeError::ClaveNoExiste(_))));
     }
+
+    #[test]
+    fn test_calificar_con_penalizacion() {
+        let puntaje = PuntajeAlternativaUnica::new(AlternativaClave::C, Puntaje::uno()).unwrap();
+        let politica =
+            PoliticaPuntaje::new(Puntaje::new(0.5).unwrap(), crate::EnBlanco::Cero, true);
+
+        assert_eq!(puntaje.calificar(Some(AlternativaClave::C), &politica), 1.0);
+        assert_eq!(
+            puntaje.calificar(Some(AlternativaClave::A), &politica),
+            -0.5
+        );
+        assert_eq!(puntaje.calificar(None, &politica), 0.0);
+    }
 }
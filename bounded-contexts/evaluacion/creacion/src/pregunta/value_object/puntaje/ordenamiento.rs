@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use crate::pregunta::value_object::AlternativaClave;
+use crate::{Puntaje, PuntajeError};
+
+/// Forma de calificar una pregunta de ordenamiento.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModoOrdenamiento {
+    /// Todo o nada: solo puntúa si el orden completo es correcto.
+    #[default]
+    Exacto,
+    /// Puntúa la fracción de alternativas ubicadas en su posición.
+    Posicional,
+    /// Puntúa la fracción de pares de alternativas que quedaron en el orden
+    /// relativo correcto (crédito parcial al estilo Kendall tau).
+    Kendall,
+}
+
+/// Value Object que representa el puntaje de una pregunta de ordenamiento.
+///
+/// La respuesta correcta es una secuencia de claves; el puntaje se otorga
+/// según el [`ModoOrdenamiento`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuntajeOrdenamiento {
+    orden_correcto: Vec<AlternativaClave>,
+    puntaje: Puntaje,
+    modo: ModoOrdenamiento,
+}
+
+impl PuntajeOrdenamiento {
+    /// Crea un nuevo puntaje de ordenamiento.
+    ///
+    /// # Errors
+    ///
+    /// - `PuntajeError::Vacio` si el orden correcto está vacío
+    /// - `PuntajeError::ClaveNoExiste` si alguna clave no es de tipo múltiple
+    /// - `PuntajeError::ClaveDuplicada` si una clave aparece más de una vez
+    pub fn new(
+        orden_correcto: Vec<AlternativaClave>,
+        puntaje: Puntaje,
+        modo: ModoOrdenamiento,
+    ) -> Result<Self, PuntajeError> {
+        if orden_correcto.is_empty() {
+            return Err(PuntajeError::Vacio);
+        }
+
+        let mut vistas = HashSet::new();
+        for clave in &orden_correcto {
+            if !clave.es_multiple() {
+                return Err(PuntajeError::ClaveNoExiste(clave.to_string()));
+            }
+            if !vistas.insert(clave) {
+                return Err(PuntajeError::ClaveDuplicada(clave.to_string()));
+            }
+        }
+
+        Ok(Self {
+            orden_correcto,
+            puntaje,
+            modo,
+        })
+    }
+
+    #[must_use]
+    pub fn orden_correcto(&self) -> &[AlternativaClave] {
+        &self.orden_correcto
+    }
+
+    #[must_use]
+    pub fn puntaje(&self) -> Puntaje {
+        self.puntaje
+    }
+
+    #[must_use]
+    pub fn modo(&self) -> ModoOrdenamiento {
+        self.modo
+    }
+
+    /// Califica el orden dado por el postulante. Un orden que no incluye cada
+    /// alternativa exactamente una vez no puntúa.
+    #[must_use]
+    pub fn calificar(&self, orden: &[AlternativaClave]) -> Puntaje {
+        let Some(posiciones) = self.posiciones(orden) else {
+            return Puntaje::cero();
+        };
+
+        let total = posiciones.len() as f32;
+        let en_posicion = posiciones
+            .iter()
+            .enumerate()
+            .filter(|(i, posicion)| i == *posicion)
+            .count() as f32;
+
+        let fraccion = match self.modo {
+            ModoOrdenamiento::Exacto if en_posicion == total => 1.0,
+            ModoOrdenamiento::Exacto => 0.0,
+            ModoOrdenamiento::Posicional => en_posicion / total,
+            ModoOrdenamiento::Kendall => {
+                let pares = posiciones.len() * posiciones.len().saturating_sub(1) / 2;
+                if pares == 0 {
+                    1.0
+                } else {
+                    let concordantes = (0..posiciones.len())
+                        .flat_map(|i| (i + 1..posiciones.len()).map(move |j| (i, j)))
+                        .filter(|(i, j)| posiciones[*i] < posiciones[*j])
+                        .count();
+                    concordantes as f32 / pares as f32
+                }
+            }
+        };
+
+        Puntaje::new(self.puntaje.valor() * fraccion).unwrap_or_default()
+    }
+
+    /// Posición correcta de cada clave del orden dado, o `None` si el orden
+    /// no es una permutación del orden correcto.
+    fn posiciones(&self, orden: &[AlternativaClave]) -> Option<Vec<usize>> {
+        if orden.len() != self.orden_correcto.len() {
+            return None;
+        }
+
+        let posiciones: Vec<usize> = orden
+            .iter()
+            .map(|clave| self.orden_correcto.iter().position(|c| c == clave))
+            .collect::<Option<_>>()?;
+
+        let unicas: HashSet<_> = posiciones.iter().collect();
+        (unicas.len() == posiciones.len()).then_some(posiciones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use AlternativaClave::{A, B, C, D};
+
+    fn puntaje(modo: ModoOrdenamiento) -> PuntajeOrdenamiento {
+        PuntajeOrdenamiento::new(vec![A, B, C, D], Puntaje::new(6.0).unwrap(), modo).unwrap()
+    }
+
+    #[test]
+    fn test_exacto() {
+        let puntaje = puntaje(ModoOrdenamiento::Exacto);
+        assert_eq!(puntaje.calificar(&[A, B, C, D]).valor(), 6.0);
+        assert_eq!(puntaje.calificar(&[A, B, D, C]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_posicional() {
+        let puntaje = puntaje(ModoOrdenamiento::Posicional);
+        assert_eq!(puntaje.calificar(&[A, B, D, C]).valor(), 3.0);
+    }
+
+    #[test]
+    fn test_kendall() {
+        let puntaje = puntaje(ModoOrdenamiento::Kendall);
+        // 5 de 6 pares en el orden correcto
+        assert_eq!(puntaje.calificar(&[A, B, D, C]).valor(), 5.0);
+        assert_eq!(puntaje.calificar(&[D, C, B, A]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_orden_incompleto_no_puntua() {
+        let puntaje = puntaje(ModoOrdenamiento::Posicional);
+        assert_eq!(puntaje.calificar(&[A, B, C]).valor(), 0.0);
+        assert_eq!(puntaje.calificar(&[A, A, C, D]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_clave_duplicada() {
+        let result =
+            PuntajeOrdenamiento::new(vec![A, B, A], Puntaje::uno(), ModoOrdenamiento::Exacto);
+        assert!(matches!(result, Err(PuntajeError::ClaveDuplicada(_))));
+    }
+}
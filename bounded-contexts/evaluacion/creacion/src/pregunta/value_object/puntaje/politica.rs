@@ -0,0 +1,133 @@
+use crate::Puntaje;
+
+/// Tratamiento de una pregunta de alternativa única que quedó sin responder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnBlanco {
+    /// La pregunta en blanco vale cero.
+    #[default]
+    Cero,
+    /// La pregunta en blanco se penaliza igual que una respuesta incorrecta.
+    Penalizar,
+}
+
+/// Value Object con la política de puntuación de un examen para sus
+/// preguntas de alternativa única.
+///
+/// La penalización se guarda como un [`Puntaje`] (no negativo) y se resta al
+/// calificar, de modo que una pregunta puede restar puntos sin que exista un
+/// `Puntaje` negativo.
+///
+/// # Campos
+///
+/// - `penalizacion_incorrecta`: Puntos que se restan por cada respuesta incorrecta
+/// - `en_blanco`: Si una pregunta sin responder vale cero o se penaliza
+/// - `piso_cero`: Si el total del examen no puede bajar de cero
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoliticaPuntaje {
+    penalizacion_incorrecta: Puntaje,
+    en_blanco: EnBlanco,
+    piso_cero: bool,
+}
+
+impl PoliticaPuntaje {
+    /// Crea una nueva política de puntuación.
+    #[must_use]
+    pub const fn new(
+        penalizacion_incorrecta: Puntaje,
+        en_blanco: EnBlanco,
+        piso_cero: bool,
+    ) -> Self {
+        Self {
+            penalizacion_incorrecta,
+            en_blanco,
+            piso_cero,
+        }
+    }
+
+    #[must_use]
+    pub fn penalizacion_incorrecta(&self) -> Puntaje {
+        self.penalizacion_incorrecta
+    }
+
+    #[must_use]
+    pub fn en_blanco(&self) -> EnBlanco {
+        self.en_blanco
+    }
+
+    #[must_use]
+    pub fn piso_cero(&self) -> bool {
+        self.piso_cero
+    }
+
+    /// Califica una pregunta a partir del puntaje obtenido.
+    ///
+    /// `None` indica que la pregunta quedó en blanco. Un puntaje de cero
+    /// indica una respuesta incorrecta y se penaliza; cualquier otro puntaje
+    /// se otorga tal cual, lo que permite crédito parcial.
+    #[must_use]
+    pub fn calificar(&self, obtenido: Option<Puntaje>) -> f32 {
+        let penalizacion = -self.penalizacion_incorrecta.valor();
+
+        match obtenido {
+            None => match self.en_blanco {
+                EnBlanco::Cero => 0.0,
+                EnBlanco::Penalizar => penalizacion,
+            },
+            Some(puntaje) if puntaje.valor() > 0.0 => puntaje.valor(),
+            Some(_) => penalizacion,
+        }
+    }
+
+    /// Aplica el piso al total del examen.
+    #[must_use]
+    pub fn aplicar_piso(&self, total: f32) -> f32 {
+        if self.piso_cero {
+            total.max(0.0)
+        } else {
+            total
+        }
+    }
+}
+
+impl Default for PoliticaPuntaje {
+    /// Sin penalización, las preguntas en blanco valen cero y el total no
+    /// baja de cero.
+    fn default() -> Self {
+        Self::new(Puntaje::cero(), EnBlanco::Cero, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn politica(en_blanco: EnBlanco, piso_cero: bool) -> PoliticaPuntaje {
+        PoliticaPuntaje::new(Puntaje::new(0.25).unwrap(), en_blanco, piso_cero)
+    }
+
+    #[test]
+    fn test_incorrecta_resta_penalizacion() {
+        let politica = politica(EnBlanco::Cero, true);
+        assert_eq!(politica.calificar(Some(Puntaje::cero())), -0.25);
+        assert_eq!(politica.calificar(Some(Puntaje::uno())), 1.0);
+    }
+
+    #[test]
+    fn test_en_blanco() {
+        assert_eq!(politica(EnBlanco::Cero, true).calificar(None), 0.0);
+        assert_eq!(politica(EnBlanco::Penalizar, true).calificar(None), -0.25);
+    }
+
+    #[test]
+    fn test_piso_cero() {
+        assert_eq!(politica(EnBlanco::Cero, true).aplicar_piso(-1.5), 0.0);
+        assert_eq!(politica(EnBlanco::Cero, false).aplicar_piso(-1.5), -1.5);
+    }
+
+    #[test]
+    fn test_default_sin_penalizacion() {
+        let politica = PoliticaPuntaje::default();
+        assert_eq!(politica.calificar(Some(Puntaje::cero())), 0.0);
+        assert_eq!(politica.calificar(None), 0.0);
+    }
+}
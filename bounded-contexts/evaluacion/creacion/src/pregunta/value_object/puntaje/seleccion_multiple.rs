@@ -0,0 +1,127 @@
+use std::collections::BTreeSet;
+
+use crate::pregunta::value_object::AlternativaClave;
+use crate::{Puntaje, PuntajeError};
+
+/// Forma de calificar una pregunta de selección múltiple.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModoCalificacion {
+    /// Todo o nada: solo puntúa si se marcan exactamente las correctas.
+    #[default]
+    Exacta,
+    /// Cada correcta marcada suma y cada incorrecta marcada resta una parte
+    /// proporcional del puntaje, sin bajar de cero.
+    Proporcional,
+}
+
+/// Value Object que representa el puntaje de una pregunta de selección múltiple.
+///
+/// Varias alternativas pueden ser correctas; el puntaje se otorga según el
+/// [`ModoCalificacion`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuntajeSeleccionMultiple {
+    respuestas_correctas: BTreeSet<AlternativaClave>,
+    puntaje: Puntaje,
+    modo: ModoCalificacion,
+}
+
+impl PuntajeSeleccionMultiple {
+    /// Crea un nuevo puntaje de selección múltiple.
+    ///
+    /// # Errors
+    ///
+    /// - `PuntajeError::Vacio` si no hay respuestas correctas
+    /// - `PuntajeError::ClaveNoExiste` si alguna clave no es de tipo múltiple
+    pub fn new(
+        respuestas_correctas: impl IntoIterator<Item = AlternativaClave>,
+        puntaje: Puntaje,
+        modo: ModoCalificacion,
+    ) -> Result<Self, PuntajeError> {
+        let respuestas_correctas: BTreeSet<_> = respuestas_correctas.into_iter().collect();
+
+        if respuestas_correctas.is_empty() {
+            return Err(PuntajeError::Vacio);
+        }
+
+        if let Some(clave) = respuestas_correctas.iter().find(|c| !c.es_multiple()) {
+            return Err(PuntajeError::ClaveNoExiste(clave.to_string()));
+        }
+
+        Ok(Self {
+            respuestas_correctas,
+            puntaje,
+            modo,
+        })
+    }
+
+    #[must_use]
+    pub fn respuestas_correctas(&self) -> &BTreeSet<AlternativaClave> {
+        &self.respuestas_correctas
+    }
+
+    #[must_use]
+    pub fn puntaje(&self) -> Puntaje {
+        self.puntaje
+    }
+
+    #[must_use]
+    pub fn modo(&self) -> ModoCalificacion {
+        self.modo
+    }
+
+    /// Califica las alternativas marcadas por el postulante.
+    #[must_use]
+    pub fn calificar(&self, seleccion: &[AlternativaClave]) -> Puntaje {
+        let seleccion: BTreeSet<_> = seleccion.iter().copied().collect();
+
+        match self.modo {
+            ModoCalificacion::Exacta if seleccion == self.respuestas_correctas => self.puntaje,
+            ModoCalificacion::Exacta => Puntaje::cero(),
+            ModoCalificacion::Proporcional => {
+                let aciertos = seleccion.intersection(&self.respuestas_correctas).count();
+                let errores = seleccion.len() - aciertos;
+                let neto = aciertos.saturating_sub(errores) as f32;
+                let fraccion = neto / self.respuestas_correctas.len() as f32;
+
+                Puntaje::new(self.puntaje.valor() * fraccion).unwrap_or_default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puntaje(modo: ModoCalificacion) -> PuntajeSeleccionMultiple {
+        PuntajeSeleccionMultiple::new(
+            [AlternativaClave::A, AlternativaClave::C],
+            Puntaje::new(2.0).unwrap(),
+            modo,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_exacta() {
+        let puntaje = puntaje(ModoCalificacion::Exacta);
+        let correcta = [AlternativaClave::C, AlternativaClave::A];
+        assert_eq!(puntaje.calificar(&correcta).valor(), 2.0);
+        assert_eq!(puntaje.calificar(&[AlternativaClave::A]).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_proporcional() {
+        let puntaje = puntaje(ModoCalificacion::Proporcional);
+        assert_eq!(puntaje.calificar(&[AlternativaClave::A]).valor(), 1.0);
+
+        let con_error = [AlternativaClave::A, AlternativaClave::B];
+        assert_eq!(puntaje.calificar(&con_error).valor(), 0.0);
+    }
+
+    #[test]
+    fn test_vacio() {
+        let result = PuntajeSeleccionMultiple::new([], Puntaje::uno(), ModoCalificacion::Exacta);
+        assert!(matches!(result, Err(PuntajeError::Vacio)));
+    }
+}
@@ -0,0 +1,85 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SeleccionError {
+    #[error("Se debe seleccionar al menos una alternativa")]
+    MinimoCero,
+
+    #[error("El mínimo de selecciones ({minimo}) supera al máximo ({maximo})")]
+    MinimoMayorQueMaximo { minimo: usize, maximo: usize },
+
+    #[error("El máximo de selecciones ({maximo}) supera las {alternativas} alternativas")]
+    MaximoMayorQueAlternativas { maximo: usize, alternativas: usize },
+}
+
+/// Value Object que representa cuántas alternativas puede marcar el
+/// postulante en una pregunta de selección múltiple.
+///
+/// Garantiza que `1 <= minimo <= maximo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangoSeleccion {
+    minimo: usize,
+    maximo: usize,
+}
+
+impl RangoSeleccion {
+    /// Crea un nuevo rango de selección.
+    ///
+    /// # Errors
+    ///
+    /// - `SeleccionError::MinimoCero` si el mínimo es cero
+    /// - `SeleccionError::MinimoMayorQueMaximo` si el mínimo supera al máximo
+    pub fn new(minimo: usize, maximo: usize) -> Result<Self, SeleccionError> {
+        if minimo == 0 {
+            return Err(SeleccionError::MinimoCero);
+        }
+
+        if minimo > maximo {
+            return Err(SeleccionError::MinimoMayorQueMaximo { minimo, maximo });
+        }
+
+        Ok(Self { minimo, maximo })
+    }
+
+    #[must_use]
+    pub fn minimo(&self) -> usize {
+        self.minimo
+    }
+
+    #[must_use]
+    pub fn maximo(&self) -> usize {
+        self.maximo
+    }
+
+    /// Verifica si la cantidad de alternativas marcadas está dentro del rango.
+    #[must_use]
+    pub fn admite(&self, cantidad: usize) -> bool {
+        (self.minimo..=self.maximo).contains(&cantidad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rango_valido() {
+        let rango = RangoSeleccion::new(1, 3).unwrap();
+        assert!(rango.admite(1));
+        assert!(rango.admite(3));
+        assert!(!rango.admite(0));
+        assert!(!rango.admite(4));
+    }
+
+    #[test]
+    fn test_rango_invalido() {
+        assert!(matches!(
+            RangoSeleccion::new(0, 2),
+            Err(SeleccionError::MinimoCero)
+        ));
+        assert!(matches!(
+            RangoSeleccion::new(3, 2),
+            Err(SeleccionError::MinimoMayorQueMaximo { .. })
+        ));
+    }
+}
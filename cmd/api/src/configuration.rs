@@ -1,3 +1,5 @@
+use quizz_auth::universal::domain::bloqueo_login::PoliticaBloqueo;
+
 #[derive(serde::Deserialize)]
 pub struct Settings {
     pub database: DatabaseSettings,
@@ -5,12 +7,69 @@ pub struct Settings {
     pub application_port: u16,
     pub application_host: String,
     pub jwt: JwtSettings,
+    #[serde(default)]
+    pub login: LoginSettings,
 }
 
 #[derive(serde::Deserialize, Clone)]
 pub struct JwtSettings {
     pub secret: String,
     pub expiration_seconds: i64,
+    #[serde(default = "refresh_expiration_seconds_por_defecto")]
+    pub refresh_expiration_seconds: i64,
+}
+
+/// Siete dias
+fn refresh_expiration_seconds_por_defecto() -> i64 {
+    604800
+}
+
+/// Limites de intentos fallidos de login (ver `PoliticaBloqueo`), vigencia
+/// de los codigos de restablecimiento de password y de los codigos de acceso
+/// a una respuesta junto con el token que emiten
+#[derive(serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct LoginSettings {
+    pub max_intentos_documento: u32,
+    pub max_intentos_ip: u32,
+    pub bloqueo_base_segundos: u64,
+    pub bloqueo_max_segundos: u64,
+    pub ventana_segundos: u64,
+    pub codigo_reset_segundos: u64,
+    pub codigo_acceso_segundos: u64,
+    pub token_acceso_segundos: i64,
+    /// Header con la IP del cliente que escribe el proxy de confianza, como
+    /// `X-Forwarded-For`. Sin proxy se deja vacio y se usa la del socket.
+    pub ip_cliente_header: Option<String>,
+}
+
+impl Default for LoginSettings {
+    fn default() -> Self {
+        let politica = PoliticaBloqueo::default();
+        Self {
+            max_intentos_documento: politica.max_intentos_documento,
+            max_intentos_ip: politica.max_intentos_ip,
+            bloqueo_base_segundos: politica.bloqueo_base_segundos,
+            bloqueo_max_segundos: politica.bloqueo_max_segundos,
+            ventana_segundos: politica.ventana_segundos,
+            codigo_reset_segundos: 900,
+            codigo_acceso_segundos: 259200,
+            token_acceso_segundos: 14400,
+            ip_cliente_header: None,
+        }
+    }
+}
+
+impl LoginSettings {
+    pub fn politica(&self) -> PoliticaBloqueo {
+        PoliticaBloqueo {
+            max_intentos_documento: self.max_intentos_documento,
+            max_intentos_ip: self.max_intentos_ip,
+            bloqueo_base_segundos: self.bloqueo_base_segundos,
+            bloqueo_max_segundos: self.bloqueo_max_segundos,
+            ventana_segundos: self.ventana_segundos,
+        }
+    }
 }
 
 #[derive(serde::Deserialize, Clone)]
@@ -0,0 +1,97 @@
+use crate::configuration::{JwtSettings, LoginSettings};
+use crate::controller::auth::dto::{CodigoAccesoLoginRequestDTO, CodigoAccesoLoginResponseDTO};
+use crate::controller::auth::ip_cliente::ip_cliente;
+use crate::controller::auth::jwt::JWTProvider;
+use crate::controller::auth::mongo::universal_read::LoginUniversalMongo;
+use crate::controller::auth::redis::codigo_acceso::CodigoAccesoRedis;
+use crate::controller::auth::redis::intentos_login::IntentosLoginRedis;
+use crate::controller::auth::redis::universal_write::LoginUniversalRedis;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::use_case::login_codigo_acceso::{InputData, LoginCodigoAcceso};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::postulante::domain::value_object::documento::Documento;
+use serde_json::json;
+
+pub struct CodigoAccesoController;
+
+impl CodigoAccesoController {
+    pub async fn login(
+        req: HttpRequest,
+        body: web::Json<CodigoAccesoLoginRequestDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        jwt_settings: web::Data<JwtSettings>,
+        login_settings: web::Data<LoginSettings>,
+    ) -> HttpResponse {
+        let dto = body.into_inner();
+        info!("POST /login/codigo - documento={}", dto.documento);
+
+        let documento = match Documento::new(&dto.documento) {
+            Ok(d) => d,
+            Err(e) => {
+                warn!("POST /login/codigo - documento no válido: {:?}", e);
+                return HttpResponse::BadRequest().json("Documento no válido");
+            }
+        };
+
+        let redis_impl = match LoginUniversalRedis::new(redis_client.clone()) {
+            Ok(r) => r,
+            Err(e) => {
+                error!("POST /login/codigo - error al conectar con redis: {:?}", e);
+                return HttpResponse::InternalServerError().finish();
+            }
+        };
+
+        let use_case = LoginCodigoAcceso::new(
+            Box::new(LoginUniversalMongo::new(pool)),
+            Box::new(CodigoAccesoRedis::new(redis_client.clone())),
+            Box::new(redis_impl),
+            Box::new(JWTProvider::new(
+                jwt_settings.secret.clone(),
+                login_settings.token_acceso_segundos,
+            )),
+            Box::new(IntentosLoginRedis::new(redis_client)),
+            login_settings.politica(),
+        );
+
+        match use_case
+            .ejecutar(InputData {
+                documento: documento.value().clone(),
+                codigo: dto.codigo,
+                ip: ip_cliente(&req, &login_settings),
+            })
+            .await
+        {
+            Ok(acceso) => {
+                info!(
+                    "POST /login/codigo - acceso a la respuesta {}",
+                    acceso.respuesta_id
+                );
+                HttpResponse::Ok().json(CodigoAccesoLoginResponseDTO {
+                    token: acceso.jwt_value,
+                    expires_in: acceso.expiration,
+                    respuesta_id: acceso.respuesta_id,
+                })
+            }
+            Err(e @ LoginUniversalError::CodigoAccesoNoValido) => {
+                warn!("POST /login/codigo - {}", e);
+                HttpResponse::Unauthorized().json(json!({"error": e.to_string()}))
+            }
+            Err(e @ LoginUniversalError::LoginBloqueado { segundos }) => {
+                warn!(
+                    "POST /login/codigo - login bloqueado por {} segundos",
+                    segundos
+                );
+                HttpResponse::TooManyRequests()
+                    .insert_header(("Retry-After", segundos.to_string()))
+                    .json(json!({"error": e.to_string()}))
+            }
+            Err(e) => {
+                error!("POST /login/codigo - error en login: {:?}", e);
+                HttpResponse::InternalServerError().finish()
+            }
+        }
+    }
+}
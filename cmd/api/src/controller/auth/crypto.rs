@@ -1,7 +1,7 @@
 use async_trait::async_trait;
-use bcrypt::verify;
+use bcrypt::{DEFAULT_COST, hash, verify};
 use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
-use quizz_common::provider::seguridad::SeguridadComparar;
+use quizz_common::provider::seguridad::{SeguridadCifrar, SeguridadComparar};
 
 pub struct CifradoPorDefecto;
 
@@ -14,3 +14,10 @@ impl SeguridadComparar<LoginUniversalError> for CifradoPorDefecto {
         }
     }
 }
+
+#[async_trait]
+impl SeguridadCifrar<LoginUniversalError> for CifradoPorDefecto {
+    async fn cifrar(&self, password: String) -> Result<String, LoginUniversalError> {
+        hash(password, DEFAULT_COST).map_err(|_| LoginUniversalError::CifradoNoValido)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::controller::auth::redis::intentos_login::IntentosLoginRedis;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_auth::universal::domain::bloqueo_login::ClaveIntento;
+use quizz_auth::universal::use_case::desbloquear_login::{DesbloquearLogin, InputData};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::postulante::domain::value_object::documento::Documento;
+use serde_json::json;
+use std::net::IpAddr;
+
+pub struct DesbloquearLoginController;
+
+impl DesbloquearLoginController {
+    pub async fn unlock(req: HttpRequest, redis_client: web::Data<redis::Client>) -> HttpResponse {
+        let documento = match req.match_info().get("documento").map(Documento::new) {
+            Some(Ok(d)) => d.value().clone(),
+            _ => {
+                warn!("DELETE /bloqueos - documento no valido");
+                return HttpResponse::BadRequest().json("Documento no válido");
+            }
+        };
+
+        info!("DELETE /bloqueos/{}", documento);
+
+        let use_case = DesbloquearLogin::new(Box::new(IntentosLoginRedis::new(redis_client)));
+
+        match use_case
+            .ejecutar(InputData {
+                clave: ClaveIntento::Documento(documento.clone()),
+            })
+            .await
+        {
+            Ok(_) => {
+                info!("DELETE /bloqueos/{} - cuenta desbloqueada", documento);
+                HttpResponse::NoContent().finish()
+            }
+            Err(e) => {
+                error!(
+                    "DELETE /bloqueos/{} - error al desbloquear: {:?}",
+                    documento, e
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al desbloquear la cuenta"}))
+            }
+        }
+    }
+
+    pub async fn unlock_ip(
+        req: HttpRequest,
+        redis_client: web::Data<redis::Client>,
+    ) -> HttpResponse {
+        let ip = match req.match_info().get("ip").map(str::parse::<IpAddr>) {
+            Some(Ok(ip)) => ip.to_string(),
+            _ => {
+                warn!("DELETE /bloqueos/ip - ip no valida");
+                return HttpResponse::BadRequest().json(json!({"error": "IP no valida"}));
+            }
+        };
+
+        info!("DELETE /bloqueos/ip/{}", ip);
+
+        let use_case = DesbloquearLogin::new(Box::new(IntentosLoginRedis::new(redis_client)));
+
+        match use_case
+            .ejecutar(InputData {
+                clave: ClaveIntento::Ip(ip.clone()),
+            })
+            .await
+        {
+            Ok(_) => {
+                info!("DELETE /bloqueos/ip/{} - ip desbloqueada", ip);
+                HttpResponse::NoContent().finish()
+            }
+            Err(e) => {
+                error!("DELETE /bloqueos/ip/{} - error al desbloquear: {:?}", ip, e);
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al desbloquear la IP"}))
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use quizz_auth::universal::domain::sesion_refresh::SesionRefresh;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -12,4 +13,85 @@ pub struct LoginResponseDTO {
     pub token: String,
     pub expires_in: u64,
     pub rol: String,
+    pub refresh_token: String,
+    pub refresh_expires_in: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CodigoAccesoLoginRequestDTO {
+    pub documento: String,
+    pub codigo: String,
+}
+
+/// Token limitado a `respuesta_id`; no tiene refresh token
+#[derive(Serialize, Deserialize)]
+pub struct CodigoAccesoLoginResponseDTO {
+    pub token: String,
+    pub expires_in: u64,
+    pub respuesta_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RefreshTokenRequestDTO {
+    pub refresh_token: String,
+}
+
+/// Sesion de refresh tal como se guarda en redis
+#[derive(Serialize, Deserialize)]
+pub struct SesionRefreshRedisDTO {
+    pub sujeto_id: String,
+    pub rol: String,
+    pub activo: String,
+    pub usados: Vec<String>,
+    /// Segundos unix; las sesiones guardadas antes de este campo valen como vencidas
+    #[serde(default)]
+    pub expira_en: i64,
+}
+
+impl From<SesionRefresh> for SesionRefreshRedisDTO {
+    fn from(sesion: SesionRefresh) -> Self {
+        Self {
+            sujeto_id: sesion.sujeto_id,
+            rol: sesion.rol,
+            activo: sesion.activo,
+            usados: sesion.usados,
+            expira_en: sesion.expira_en,
+        }
+    }
+}
+
+impl From<SesionRefreshRedisDTO> for SesionRefresh {
+    fn from(dto: SesionRefreshRedisDTO) -> Self {
+        Self {
+            sujeto_id: dto.sujeto_id,
+            rol: dto.rol,
+            activo: dto.activo,
+            usados: dto.usados,
+            expira_en: dto.expira_en,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CambiarPasswordRequestDTO {
+    pub password_actual: String,
+    pub password_nuevo: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EmitirCodigoResetRequestDTO {
+    pub documento: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CodigoResetResponseDTO {
+    pub codigo: String,
+    pub expires_in: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RestablecerPasswordRequestDTO {
+    pub documento: String,
+    pub codigo: String,
+    pub password_nuevo: String,
 }
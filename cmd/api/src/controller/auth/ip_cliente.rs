@@ -0,0 +1,43 @@
+use crate::configuration::LoginSettings;
+use actix_web::HttpRequest;
+use std::net::IpAddr;
+
+/// IP del cliente con la que se cuentan los intentos de login. Detras de un
+/// proxy todas las conexiones llegan desde su direccion, asi que se toma del
+/// header que configura `login.ip_cliente_header`; sin el header se usa la
+/// direccion del socket.
+pub fn ip_cliente(req: &HttpRequest, login_settings: &LoginSettings) -> Option<String> {
+    let desde_header = login_settings
+        .ip_cliente_header
+        .as_deref()
+        .and_then(|header| req.headers().get(header))
+        .and_then(|valor| valor.to_str().ok())
+        .and_then(ip_de_header);
+
+    desde_header.or_else(|| req.peer_addr().map(|addr| addr.ip().to_string()))
+}
+
+/// El proxy de confianza agrega al final la direccion que vio, y lo anterior
+/// lo pudo escribir el cliente: solo se usa la ultima entrada.
+fn ip_de_header(valor: &str) -> Option<String> {
+    valor
+        .rsplit(',')
+        .next()
+        .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+        .map(|ip| ip.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usa_la_ultima_entrada_del_header() {
+        assert_eq!(
+            ip_de_header("1.2.3.4, 10.0.0.7"),
+            Some("10.0.0.7".to_string())
+        );
+        assert_eq!(ip_de_header("2001:db8::1"), Some("2001:db8::1".to_string()));
+        assert_eq!(ip_de_header("10.0.0.7, no-es-ip"), None);
+    }
+}
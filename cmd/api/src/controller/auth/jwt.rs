@@ -1,10 +1,12 @@
 use async_trait::async_trait;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use quizz_auth::autorizacion::domain::error::autorizacion::AutorizacionError;
+use quizz_auth::autorizacion::domain::value_object::rol::Rol;
+use quizz_auth::universal::domain::acceso_respuesta::clave_sesion;
 use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
 use quizz_common::domain::entity::jwt::JwtObject;
 use quizz_common::domain::value_objects::zona_horaria::ahora_lima;
-use quizz_common::provider::jwt::JwtProviderGenerateConRol;
+use quizz_common::provider::jwt::{JwtProviderGenerateConRol, JwtProviderGenerateRespuesta};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -13,6 +15,9 @@ pub struct Claims {
     pub exp: i64,
     pub iat: i64,
     pub rol: Option<String>,
+    /// Solo en tokens emitidos con codigo de acceso: la unica respuesta que alcanzan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub respuesta_id: Option<String>,
 }
 
 pub struct JWTProvider {
@@ -43,6 +48,15 @@ impl JWTProvider {
 
         Ok(token_data.claims)
     }
+
+    fn firmar(&self, claims: &Claims) -> Result<String, LoginUniversalError> {
+        encode(
+            &Header::new(Algorithm::HS256),
+            claims,
+            &EncodingKey::from_secret(self.secret.as_bytes()),
+        )
+        .map_err(|_| LoginUniversalError::JWTErrorAlGenerar)
+    }
 }
 
 #[async_trait]
@@ -60,18 +74,39 @@ impl JwtProviderGenerateConRol<LoginUniversalError> for JWTProvider {
             exp: expiration,
             iat: now,
             rol: Some(rol.clone()),
+            respuesta_id: None,
         };
 
-        let token = encode(
-            &Header::new(Algorithm::HS256),
-            &claims,
-            &EncodingKey::from_secret(self.secret.as_bytes()),
-        )
-        .map_err(|_| LoginUniversalError::JWTErrorAlGenerar)?;
-
         Ok(JwtObject {
             key: sujeto_id,
-            value: token,
+            value: self.firmar(&claims)?,
+            expiration: self.expiration_seconds as u64,
+            rol: Some(rol),
+        })
+    }
+}
+
+#[async_trait]
+impl JwtProviderGenerateRespuesta<LoginUniversalError> for JWTProvider {
+    async fn generar_para_respuesta(
+        &self,
+        postulante_id: String,
+        respuesta_id: String,
+    ) -> Result<JwtObject, LoginUniversalError> {
+        let now = ahora_lima().timestamp();
+        let rol = Rol::Postulante.to_string();
+
+        let claims = Claims {
+            sub: postulante_id.clone(),
+            exp: now + self.expiration_seconds,
+            iat: now,
+            rol: Some(rol.clone()),
+            respuesta_id: Some(respuesta_id.clone()),
+        };
+
+        Ok(JwtObject {
+            key: clave_sesion(&postulante_id, Some(&respuesta_id)),
+            value: self.firmar(&claims)?,
             expiration: self.expiration_seconds as u64,
             rol: Some(rol),
         })
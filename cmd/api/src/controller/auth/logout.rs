@@ -37,6 +37,7 @@ impl LogoutController {
         match use_case
             .ejecutar(InputData {
                 sujeto_id: claims.sub.clone(),
+                respuesta_id: claims.respuesta_id.clone(),
             })
             .await
         {
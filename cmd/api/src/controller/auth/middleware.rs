@@ -1,29 +1,44 @@
 use crate::controller::auth::casbin_enforcer::CasbinAutorizacion;
 use crate::controller::auth::jwt::JWTProvider;
+use crate::controller::auth::mongo::respuesta_acceso::RespuestaAccesoMongo;
+use crate::controller::auth::redis::universal_read::SesionUniversalRedis;
 use actix_web::body::EitherBody;
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
-use actix_web::{Error, HttpMessage, HttpResponse};
+use actix_web::{Error, HttpMessage, HttpResponse, web};
 use casbin::Enforcer;
 use futures::future::{LocalBoxFuture, Ready, ok};
-use log::{debug, info, warn};
+use log::{debug, error, info, warn};
 use quizz_auth::autorizacion::domain::entity::solicitud_acceso::SolicitudAcceso;
 use quizz_auth::autorizacion::domain::value_object::accion::Accion;
 use quizz_auth::autorizacion::domain::value_object::recurso::Recurso;
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
 use quizz_auth::autorizacion::provider::autorizacion::AutorizacionVerificar;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::use_case::verificar_acceso_respuesta::{self, VerificarAccesoRespuesta};
+use quizz_auth::universal::use_case::verificar_sesion::{self, VerificarSesion};
+use quizz_common::use_case::CasoDeUso;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 pub struct AuthMiddleware {
     jwt_secret: String,
     enforcer: Arc<RwLock<Enforcer>>,
+    redis_client: web::Data<redis::Client>,
+    mongo_client: web::Data<mongodb::Client>,
 }
 
 impl AuthMiddleware {
-    pub fn new(jwt_secret: String, enforcer: Arc<RwLock<Enforcer>>) -> Self {
+    pub fn new(
+        jwt_secret: String,
+        enforcer: Arc<RwLock<Enforcer>>,
+        redis_client: web::Data<redis::Client>,
+        mongo_client: web::Data<mongodb::Client>,
+    ) -> Self {
         Self {
             jwt_secret,
             enforcer,
+            redis_client,
+            mongo_client,
         }
     }
 }
@@ -44,6 +59,8 @@ where
             service: Arc::new(service),
             jwt_secret: self.jwt_secret.clone(),
             enforcer: self.enforcer.clone(),
+            redis_client: self.redis_client.clone(),
+            mongo_client: self.mongo_client.clone(),
         })
     }
 }
@@ -52,6 +69,8 @@ pub struct AuthMiddlewareService<S> {
     service: Arc<S>,
     jwt_secret: String,
     enforcer: Arc<RwLock<Enforcer>>,
+    redis_client: web::Data<redis::Client>,
+    mongo_client: web::Data<mongodb::Client>,
 }
 
 impl<S, B> Service<ServiceRequest> for AuthMiddlewareService<S>
@@ -74,6 +93,8 @@ where
         let service = self.service.clone();
         let jwt_secret = self.jwt_secret.clone();
         let enforcer = self.enforcer.clone();
+        let redis_client = self.redis_client.clone();
+        let mongo_client = self.mongo_client.clone();
 
         Box::pin(async move {
             let metodo = req.method().to_string();
@@ -103,6 +124,73 @@ where
                 }
             };
 
+            // Verificar que la sesion siga activa en redis
+            let verificar = VerificarSesion::new(Box::new(SesionUniversalRedis::new(redis_client)));
+            match verificar
+                .ejecutar(verificar_sesion::InputData {
+                    sujeto_id: claims.sub.clone(),
+                    token,
+                    respuesta_id: claims.respuesta_id.clone(),
+                })
+                .await
+            {
+                Ok(()) => {}
+                Err(LoginUniversalError::SesionRevocada) => {
+                    warn!("{} {} - token revocado, sub={}", metodo, ruta, claims.sub);
+                    let response = HttpResponse::Unauthorized()
+                        .json(serde_json::json!({"error": "Token revocado"}));
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+                Err(e) => {
+                    error!(
+                        "{} {} - error al verificar la sesion, sub={}: {:?}",
+                        metodo, ruta, claims.sub, e
+                    );
+                    let response = HttpResponse::InternalServerError()
+                        .json(serde_json::json!({"error": "Error al verificar la sesion"}));
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+            }
+
+            // Un token emitido con codigo de acceso solo alcanza su respuesta y
+            // deja de servir cuando la respuesta se finaliza
+            if let Some(respuesta_id) = claims.respuesta_id.clone() {
+                let verificar = VerificarAccesoRespuesta::new(Box::new(RespuestaAccesoMongo::new(
+                    mongo_client,
+                )));
+                match verificar
+                    .ejecutar(verificar_acceso_respuesta::InputData {
+                        respuesta_id,
+                        ruta: ruta.clone(),
+                    })
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(e @ LoginUniversalError::AccesoFueraDeRespuesta(_)) => {
+                        warn!("{} {} - {}, sub={}", metodo, ruta, e, claims.sub);
+                        let response = HttpResponse::Forbidden()
+                            .json(serde_json::json!({"error": e.to_string()}));
+                        return Ok(req.into_response(response).map_into_right_body());
+                    }
+                    Err(e @ LoginUniversalError::RespuestaFinalizada(_)) => {
+                        warn!("{} {} - {}, sub={}", metodo, ruta, e, claims.sub);
+                        let response = HttpResponse::Unauthorized()
+                            .json(serde_json::json!({"error": e.to_string()}));
+                        return Ok(req.into_response(response).map_into_right_body());
+                    }
+                    Err(e) => {
+                        error!(
+                            "{} {} - error al verificar el acceso a la respuesta, sub={}: {:?}",
+                            metodo, ruta, claims.sub, e
+                        );
+                        let response = HttpResponse::InternalServerError().json(
+                            serde_json::json!({"error": "Error al verificar el acceso a la respuesta"}),
+                        );
+                        return Ok(req.into_response(response).map_into_right_body());
+                    }
+                }
+            }
+
             // Extraer rol del token
             let rol_str = match &claims.rol {
                 Some(r) => r.clone(),
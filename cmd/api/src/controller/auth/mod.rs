@@ -1,10 +1,16 @@
 pub mod casbin_enforcer;
+mod codigo_acceso;
 mod crypto;
+mod desbloquear_login;
 mod dto;
+mod ip_cliente;
 pub mod jwt;
 mod logout;
 pub mod middleware;
 mod mongo;
-mod redis;
+mod password;
+pub mod redis;
+mod refrescar_token;
+mod revocar_sesiones;
 pub mod route;
 mod universal_login;
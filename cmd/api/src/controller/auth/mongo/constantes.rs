@@ -1,3 +1,4 @@
 pub const ADMIN_AUTH_COLLECTION_NAME: &str = "admin";
 pub const POSTULANTE_AUTH_COLLECTION_NAME: &str = "postulante";
 pub const PSICOLOGO_AUTH_COLLECTION_NAME: &str = "psicologo";
+pub const RESPUESTA_AUTH_COLLECTION_NAME: &str = "respuesta";
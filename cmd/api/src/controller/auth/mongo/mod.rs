@@ -1,2 +1,4 @@
 mod constantes;
+pub mod respuesta_acceso;
 pub mod universal_read;
+pub mod universal_write;
@@ -0,0 +1,49 @@
+use crate::controller::auth::mongo::constantes::RESPUESTA_AUTH_COLLECTION_NAME;
+use crate::controller::mongo_repository::MAIN_DATABASE_NAME;
+use actix_web::web;
+use async_trait::async_trait;
+use mongodb::bson::{Document, doc};
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::provider::repositorio::RepositorioRespuestaAcceso;
+use quizz_core::respuesta::domain::entity::respuesta::Estado;
+
+pub struct RespuestaAccesoMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl RespuestaAccesoMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl RepositorioRespuestaAcceso<LoginUniversalError> for RespuestaAccesoMongo {
+    async fn respuesta_finalizada(
+        &self,
+        respuesta_id: String,
+    ) -> Result<bool, LoginUniversalError> {
+        let respuesta = self
+            .client
+            .database(MAIN_DATABASE_NAME)
+            .collection::<Document>(RESPUESTA_AUTH_COLLECTION_NAME)
+            .find_one(doc! { "_id": &respuesta_id })
+            .projection(doc! { "estado": 1 })
+            .await
+            .map_err(|e| {
+                log::error!(
+                    "error al leer el estado de la respuesta {}: {:?}",
+                    respuesta_id,
+                    e
+                );
+                LoginUniversalError::RepositorioError
+            })?;
+
+        Ok(match respuesta {
+            Some(respuesta) => {
+                respuesta.get_str("estado").unwrap_or_default() == Estado::Finalizado.to_string()
+            }
+            None => true,
+        })
+    }
+}
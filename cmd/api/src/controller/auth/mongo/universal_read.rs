@@ -23,7 +23,7 @@ impl LoginUniversalMongo {
     async fn buscar_en_coleccion(
         &self,
         collection_name: &str,
-        documento: &str,
+        filter: Document,
         rol: Rol,
     ) -> Result<Option<UsuarioLogin>, LoginUniversalError> {
         let collection = self
@@ -31,8 +31,6 @@ impl LoginUniversalMongo {
             .database(DATABASE_NAME)
             .collection::<Document>(collection_name);
 
-        let filter = doc! { "documento": documento };
-
         match collection.find_one(filter).await {
             Ok(Some(doc)) => {
                 let id = doc
@@ -57,31 +55,29 @@ impl LoginUniversalMongo {
             Err(_) => Err(LoginUniversalError::RepositorioError),
         }
     }
-}
 
-#[async_trait]
-impl RepositorioLoginUniversalLectura<LoginUniversalError> for LoginUniversalMongo {
-    async fn buscar_por_documento(
-        &self,
-        documento: String,
-    ) -> Result<UsuarioLogin, LoginUniversalError> {
+    async fn buscar(&self, filter: Document) -> Result<UsuarioLogin, LoginUniversalError> {
         // Buscar en orden: admin -> psicologo -> postulante
         if let Some(usuario) = self
-            .buscar_en_coleccion(ADMIN_AUTH_COLLECTION_NAME, &documento, Rol::Admin)
+            .buscar_en_coleccion(ADMIN_AUTH_COLLECTION_NAME, filter.clone(), Rol::Admin)
             .await?
         {
             return Ok(usuario);
         }
 
         if let Some(usuario) = self
-            .buscar_en_coleccion(PSICOLOGO_AUTH_COLLECTION_NAME, &documento, Rol::Psicologo)
+            .buscar_en_coleccion(
+                PSICOLOGO_AUTH_COLLECTION_NAME,
+                filter.clone(),
+                Rol::Psicologo,
+            )
             .await?
         {
             return Ok(usuario);
         }
 
         if let Some(usuario) = self
-            .buscar_en_coleccion(POSTULANTE_AUTH_COLLECTION_NAME, &documento, Rol::Postulante)
+            .buscar_en_coleccion(POSTULANTE_AUTH_COLLECTION_NAME, filter, Rol::Postulante)
             .await?
         {
             return Ok(usuario);
@@ -90,3 +86,17 @@ impl RepositorioLoginUniversalLectura<LoginUniversalError> for LoginUniversalMon
         Err(LoginUniversalError::UsuarioNoEncontrado)
     }
 }
+
+#[async_trait]
+impl RepositorioLoginUniversalLectura<LoginUniversalError> for LoginUniversalMongo {
+    async fn buscar_por_documento(
+        &self,
+        documento: String,
+    ) -> Result<UsuarioLogin, LoginUniversalError> {
+        self.buscar(doc! { "documento": documento }).await
+    }
+
+    async fn buscar_por_id(&self, sujeto_id: String) -> Result<UsuarioLogin, LoginUniversalError> {
+        self.buscar(doc! { "_id": sujeto_id }).await
+    }
+}
@@ -0,0 +1,57 @@
+use crate::controller::auth::mongo::constantes::{
+    ADMIN_AUTH_COLLECTION_NAME, POSTULANTE_AUTH_COLLECTION_NAME, PSICOLOGO_AUTH_COLLECTION_NAME,
+};
+use crate::controller::mongo_repository::MAIN_DATABASE_NAME;
+use actix_web::web;
+use async_trait::async_trait;
+use mongodb::bson::{Document, doc};
+use quizz_auth::autorizacion::domain::value_object::rol::Rol;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::domain::usuario_login::UsuarioLogin;
+use quizz_auth::universal::provider::repositorio::RepositorioLoginUniversalEscritura;
+
+pub struct PasswordUniversalMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl PasswordUniversalMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl RepositorioLoginUniversalEscritura<LoginUniversalError> for PasswordUniversalMongo {
+    async fn actualizar_password(
+        &self,
+        usuario: &UsuarioLogin,
+        password_cifrado: String,
+    ) -> Result<(), LoginUniversalError> {
+        let collection_name = match usuario.rol.parse::<Rol>() {
+            Ok(Rol::Admin) => ADMIN_AUTH_COLLECTION_NAME,
+            Ok(Rol::Psicologo) => PSICOLOGO_AUTH_COLLECTION_NAME,
+            Ok(Rol::Postulante) => POSTULANTE_AUTH_COLLECTION_NAME,
+            Err(_) => return Err(LoginUniversalError::RepositorioError),
+        };
+
+        let resultado = self
+            .client
+            .database(MAIN_DATABASE_NAME)
+            .collection::<Document>(collection_name)
+            .update_one(
+                doc! { "_id": &usuario.id },
+                doc! { "$set": { "password": password_cifrado } },
+            )
+            .await
+            .map_err(|e| {
+                log::error!("error al actualizar el password de {}: {:?}", usuario.id, e);
+                LoginUniversalError::RepositorioError
+            })?;
+
+        if resultado.matched_count == 0 {
+            return Err(LoginUniversalError::UsuarioNoEncontrado);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use crate::configuration::LoginSettings;
+use crate::controller::auth::crypto::CifradoPorDefecto;
+use crate::controller::auth::dto::{
+    CambiarPasswordRequestDTO, CodigoResetResponseDTO, EmitirCodigoResetRequestDTO,
+    RestablecerPasswordRequestDTO,
+};
+use crate::controller::auth::ip_cliente::ip_cliente;
+use crate::controller::auth::jwt::Claims;
+use crate::controller::auth::mongo::universal_read::LoginUniversalMongo;
+use crate::controller::auth::mongo::universal_write::PasswordUniversalMongo;
+use crate::controller::auth::redis::codigo_reset::CodigoResetRedis;
+use crate::controller::auth::redis::intentos_login::IntentosLoginRedis;
+use crate::controller::auth::redis::universal_borrar::LogoutUniversalRedis;
+use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::use_case::{
+    cambiar_password, emitir_codigo_reset, restablecer_password,
+};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::postulante::domain::value_object::documento::Documento;
+use serde_json::json;
+
+pub struct PasswordController;
+
+impl PasswordController {
+    /// PUT /cuenta/password
+    pub async fn update(
+        req: HttpRequest,
+        body: web::Json<CambiarPasswordRequestDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+    ) -> HttpResponse {
+        let claims = match req.extensions().get::<Claims>().cloned() {
+            Some(c) => c,
+            None => {
+                warn!("PUT /cuenta/password - claims no encontrados");
+                return HttpResponse::Unauthorized().json(json!({"error": "Token no encontrado"}));
+            }
+        };
+
+        info!("PUT /cuenta/password - sub={}", claims.sub);
+
+        let dto = body.into_inner();
+        let use_case = cambiar_password::CambiarPassword::new(
+            Box::new(CifradoPorDefecto),
+            Box::new(CifradoPorDefecto),
+            Box::new(LoginUniversalMongo::new(pool.clone())),
+            Box::new(PasswordUniversalMongo::new(pool)),
+            Box::new(LogoutUniversalRedis::new(redis_client)),
+        );
+
+        match use_case
+            .ejecutar(cambiar_password::InputData {
+                sujeto_id: claims.sub.clone(),
+                password_actual: dto.password_actual,
+                password_nuevo: dto.password_nuevo,
+            })
+            .await
+        {
+            Ok(_) => {
+                info!(
+                    "PUT /cuenta/password - password cambiado, sub={}",
+                    claims.sub
+                );
+                HttpResponse::NoContent().finish()
+            }
+            Err(e @ LoginUniversalError::PasswordNoValido(_)) => {
+                warn!("PUT /cuenta/password - {}", e);
+                HttpResponse::BadRequest().json(json!({"error": e.to_string()}))
+            }
+            Err(LoginUniversalError::PasswordIncorrecto) => {
+                warn!(
+                    "PUT /cuenta/password - password actual incorrecto, sub={}",
+                    claims.sub
+                );
+                HttpResponse::Forbidden().json(json!({"error": "Password actual incorrecto"}))
+            }
+            Err(LoginUniversalError::UsuarioNoEncontrado) => {
+                warn!(
+                    "PUT /cuenta/password - usuario no encontrado, sub={}",
+                    claims.sub
+                );
+                HttpResponse::NotFound().json(json!({"error": "Usuario no encontrado"}))
+            }
+            Err(e) => {
+                error!(
+                    "PUT /cuenta/password - error al cambiar el password: {:?}",
+                    e
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al cambiar el password"}))
+            }
+        }
+    }
+
+    /// POST /cuenta/reset
+    pub async fn create_reset(
+        req: HttpRequest,
+        body: web::Json<EmitirCodigoResetRequestDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        login_settings: web::Data<LoginSettings>,
+    ) -> HttpResponse {
+        let claims = match req.extensions().get::<Claims>().cloned() {
+            Some(c) => c,
+            None => {
+                warn!("POST /cuenta/reset - claims no encontrados");
+                return HttpResponse::Unauthorized().json(json!({"error": "Token no encontrado"}));
+            }
+        };
+
+        let documento = match Documento::new(&body.documento) {
+            Ok(d) => d.value().clone(),
+            Err(e) => {
+                warn!("POST /cuenta/reset - documento no válido: {:?}", e);
+                return HttpResponse::BadRequest().json("Documento no válido");
+            }
+        };
+
+        info!(
+            "POST /cuenta/reset - documento={}, sub={}",
+            documento, claims.sub
+        );
+
+        let use_case = emitir_codigo_reset::EmitirCodigoReset::new(
+            Box::new(CifradoPorDefecto),
+            Box::new(LoginUniversalMongo::new(pool)),
+            Box::new(CodigoResetRedis::new(redis_client)),
+            login_settings.codigo_reset_segundos,
+        );
+
+        match use_case
+            .ejecutar(emitir_codigo_reset::InputData {
+                documento: documento.clone(),
+                rol_solicitante: claims.rol.unwrap_or_default(),
+            })
+            .await
+        {
+            Ok(codigo) => {
+                info!(
+                    "POST /cuenta/reset - codigo emitido, documento={}",
+                    documento
+                );
+                HttpResponse::Created().json(CodigoResetResponseDTO {
+                    codigo: codigo.codigo,
+                    expires_in: codigo.expiration,
+                })
+            }
+            Err(LoginUniversalError::UsuarioNoEncontrado) => {
+                warn!(
+                    "POST /cuenta/reset - usuario no encontrado, documento={}",
+                    documento
+                );
+                HttpResponse::NotFound().json(json!({"error": "Usuario no encontrado"}))
+            }
+            Err(e @ LoginUniversalError::ResetNoPermitido) => {
+                warn!("POST /cuenta/reset - {}, sub={}", e, claims.sub);
+                HttpResponse::Forbidden().json(json!({"error": e.to_string()}))
+            }
+            Err(e) => {
+                error!("POST /cuenta/reset - error al emitir el codigo: {:?}", e);
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al emitir el codigo de restablecimiento"}))
+            }
+        }
+    }
+
+    /// POST /password/reset (publica)
+    pub async fn reset(
+        req: HttpRequest,
+        body: web::Json<RestablecerPasswordRequestDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        login_settings: web::Data<LoginSettings>,
+    ) -> HttpResponse {
+        let dto = body.into_inner();
+        let documento = match Documento::new(&dto.documento) {
+            Ok(d) => d.value().clone(),
+            Err(e) => {
+                warn!("POST /password/reset - documento no válido: {:?}", e);
+                return HttpResponse::BadRequest().json("Documento no válido");
+            }
+        };
+
+        info!("POST /password/reset - documento={}", documento);
+
+        let use_case = restablecer_password::RestablecerPassword::new(
+            Box::new(CifradoPorDefecto),
+            Box::new(CifradoPorDefecto),
+            Box::new(LoginUniversalMongo::new(pool.clone())),
+            Box::new(PasswordUniversalMongo::new(pool)),
+            Box::new(CodigoResetRedis::new(redis_client.clone())),
+            Box::new(LogoutUniversalRedis::new(redis_client.clone())),
+            Box::new(IntentosLoginRedis::new(redis_client)),
+            login_settings.politica(),
+        );
+
+        match use_case
+            .ejecutar(restablecer_password::InputData {
+                documento: documento.clone(),
+                codigo: dto.codigo,
+                password_nuevo: dto.password_nuevo,
+                ip: ip_cliente(&req, &login_settings),
+            })
+            .await
+        {
+            Ok(_) => {
+                info!(
+                    "POST /password/reset - password restablecido, documento={}",
+                    documento
+                );
+                HttpResponse::NoContent().finish()
+            }
+            Err(
+                e @ (LoginUniversalError::PasswordNoValido(_)
+                | LoginUniversalError::CodigoResetNoValido),
+            ) => {
+                warn!("POST /password/reset - {}", e);
+                HttpResponse::BadRequest().json(json!({"error": e.to_string()}))
+            }
+            Err(e @ LoginUniversalError::LoginBloqueado { segundos }) => {
+                warn!(
+                    "POST /password/reset - bloqueado por {} segundos, documento={}",
+                    segundos, documento
+                );
+                HttpResponse::TooManyRequests()
+                    .insert_header(("Retry-After", segundos.to_string()))
+                    .json(json!({"error": e.to_string()}))
+            }
+            Err(e) => {
+                error!(
+                    "POST /password/reset - error al restablecer el password: {:?}",
+                    e
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al restablecer el password"}))
+            }
+        }
+    }
+}
@@ -1,2 +1,7 @@
+pub mod codigo_acceso;
+pub mod codigo_reset;
+pub mod constantes;
+pub mod intentos_login;
 pub mod universal_borrar;
+pub mod universal_read;
 pub mod universal_write;
@@ -0,0 +1,123 @@
+use crate::controller::auth::redis::constantes::{
+    CODIGO_ACCESO_KEY_PREFIX, CODIGO_ACCESO_RESPUESTA_KEY_PREFIX,
+};
+use actix_web::web;
+use async_trait::async_trait;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::provider::repositorio::RepositorioCodigoAcceso;
+use redis::aio::MultiplexedConnection;
+use sha2::{Digest, Sha256};
+
+/// Reemplaza el codigo vigente de la respuesta (KEYS[2]) por el nuevo (KEYS[1])
+const GUARDAR_CODIGO: &str = r"
+local anterior = redis.call('GET', KEYS[2])
+if anterior then
+    redis.call('DEL', anterior)
+end
+redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+redis.call('SET', KEYS[2], KEYS[1], 'EX', ARGV[2])
+";
+
+/// Consume el codigo y libera la clave de su respuesta si todavia apunta a el
+const CONSUMIR_CODIGO: &str = r"
+local respuesta_id = redis.call('GET', KEYS[1])
+if not respuesta_id then
+    return false
+end
+redis.call('DEL', KEYS[1])
+local clave_respuesta = ARGV[1] .. respuesta_id
+if redis.call('GET', clave_respuesta) == KEYS[1] then
+    redis.call('DEL', clave_respuesta)
+end
+return respuesta_id
+";
+
+pub struct CodigoAccesoRedis {
+    client: web::Data<redis::Client>,
+}
+
+impl CodigoAccesoRedis {
+    pub fn new(client: web::Data<redis::Client>) -> Self {
+        Self { client }
+    }
+
+    async fn conexion(&self) -> Result<MultiplexedConnection, LoginUniversalError> {
+        self.client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al conectar: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })
+    }
+}
+
+/// El codigo solo aparece cifrado en la clave: quien lea las claves de redis
+/// no puede usarlo para entrar
+fn clave(postulante_id: &str, codigo: &str) -> String {
+    let resumen = Sha256::digest(format!("{}:{}", postulante_id, codigo));
+    let resumen: String = resumen.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}:{}", CODIGO_ACCESO_KEY_PREFIX, postulante_id, resumen)
+}
+
+fn clave_respuesta(respuesta_id: &str) -> String {
+    format!("{}{}", CODIGO_ACCESO_RESPUESTA_KEY_PREFIX, respuesta_id)
+}
+
+fn error_cache(e: redis::RedisError) -> LoginUniversalError {
+    log::error!("error de redis en codigo de acceso: {:?}", e);
+    LoginUniversalError::ErrorGenericoCache
+}
+
+#[async_trait]
+impl RepositorioCodigoAcceso<LoginUniversalError> for CodigoAccesoRedis {
+    async fn guardar_codigo(
+        &self,
+        postulante_id: String,
+        codigo: String,
+        respuesta_id: String,
+        expiration: u64,
+    ) -> Result<(), LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let _: () = redis::Script::new(GUARDAR_CODIGO)
+            .key(clave(&postulante_id, &codigo))
+            .key(clave_respuesta(&respuesta_id))
+            .arg(respuesta_id)
+            .arg(expiration)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(error_cache)?;
+
+        Ok(())
+    }
+
+    async fn consumir_codigo(
+        &self,
+        postulante_id: String,
+        codigo: String,
+    ) -> Result<Option<String>, LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        // El script es atomico: dos logins simultaneos no pueden usar el mismo codigo
+        redis::Script::new(CONSUMIR_CODIGO)
+            .key(clave(&postulante_id, &codigo))
+            .arg(CODIGO_ACCESO_RESPUESTA_KEY_PREFIX)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(error_cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_la_clave_no_contiene_el_codigo() {
+        let clave_codigo = clave("usr-123", "K7M2P9QX");
+
+        assert!(clave_codigo.starts_with(&format!("{}usr-123:", CODIGO_ACCESO_KEY_PREFIX)));
+        assert!(!clave_codigo.contains("K7M2P9QX"));
+        assert_eq!(clave_codigo, clave("usr-123", "K7M2P9QX"));
+        assert_ne!(clave_codigo, clave("usr-456", "K7M2P9QX"));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::controller::auth::redis::constantes::RESET_PASSWORD_KEY_PREFIX;
+use actix_web::web;
+use async_trait::async_trait;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::provider::repositorio::RepositorioCodigoReset;
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+
+// Borra el codigo solo si no cambio desde que se verifico
+const CONSUMIR_CODIGO: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('DEL', KEYS[1])
+    return 1
+end
+return 0
+";
+
+pub struct CodigoResetRedis {
+    client: web::Data<redis::Client>,
+}
+
+impl CodigoResetRedis {
+    pub fn new(client: web::Data<redis::Client>) -> Self {
+        Self { client }
+    }
+
+    async fn conexion(&self) -> Result<MultiplexedConnection, LoginUniversalError> {
+        self.client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al conectar: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })
+    }
+}
+
+fn clave(documento: &str) -> String {
+    format!("{}{}", RESET_PASSWORD_KEY_PREFIX, documento)
+}
+
+fn error_cache(e: redis::RedisError) -> LoginUniversalError {
+    log::error!("error de redis en codigo de restablecimiento: {:?}", e);
+    LoginUniversalError::ErrorGenericoCache
+}
+
+#[async_trait]
+impl RepositorioCodigoReset<LoginUniversalError> for CodigoResetRedis {
+    async fn guardar_codigo(
+        &self,
+        documento: String,
+        codigo_cifrado: String,
+        expiration: u64,
+    ) -> Result<(), LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let _: () = conn
+            .set_ex(clave(&documento), codigo_cifrado, expiration)
+            .await
+            .map_err(error_cache)?;
+
+        Ok(())
+    }
+
+    async fn obtener_codigo(
+        &self,
+        documento: String,
+    ) -> Result<Option<String>, LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        conn.get(clave(&documento)).await.map_err(error_cache)
+    }
+
+    async fn consumir_codigo(
+        &self,
+        documento: String,
+        codigo_cifrado: String,
+    ) -> Result<bool, LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        redis::Script::new(CONSUMIR_CODIGO)
+            .key(clave(&documento))
+            .arg(codigo_cifrado)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(error_cache)
+    }
+
+    async fn borrar_codigo(&self, documento: String) -> Result<(), LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let _: () = conn.del(clave(&documento)).await.map_err(error_cache)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+/// El token de acceso se guarda bajo el id del sujeto; su refresh token bajo
+/// este prefijo seguido del mismo id.
+pub const REFRESH_KEY_PREFIX: &str = "refresh:";
+
+pub fn clave_refresh(sujeto_id: &str) -> String {
+    format!("{}{}", REFRESH_KEY_PREFIX, sujeto_id)
+}
+
+/// Conjunto con las claves de los tokens limitados a respuestas del sujeto
+/// (`respuesta:{respuesta_id}`), para poder revocarlos junto con su sesion.
+pub const SESIONES_RESPUESTA_KEY_PREFIX: &str = "sesiones_respuesta:";
+
+pub fn clave_sesiones_respuesta(sujeto_id: &str) -> String {
+    format!("{}{}", SESIONES_RESPUESTA_KEY_PREFIX, sujeto_id)
+}
+
+/// Contadores de intentos fallidos y bloqueos de login, seguidos de la clave
+/// (`documento:{documento}` o `ip:{ip}`).
+pub const LOGIN_FALLOS_KEY_PREFIX: &str = "login_fallos:";
+pub const LOGIN_BLOQUEO_KEY_PREFIX: &str = "login_bloqueo:";
+
+/// Codigo de restablecimiento de password cifrado, seguido del documento
+pub const RESET_PASSWORD_KEY_PREFIX: &str = "reset_password:";
+
+/// Respuesta a la que da acceso un codigo, seguido de `{postulante_id}:{codigo}`
+pub const CODIGO_ACCESO_KEY_PREFIX: &str = "codigo_acceso:";
+
+/// Clave del codigo vigente de una respuesta, seguido del id de la respuesta
+pub const CODIGO_ACCESO_RESPUESTA_KEY_PREFIX: &str = "codigo_acceso_respuesta:";
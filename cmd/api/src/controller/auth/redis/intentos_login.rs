@@ -0,0 +1,102 @@
+use crate::controller::auth::redis::constantes::{
+    LOGIN_BLOQUEO_KEY_PREFIX, LOGIN_FALLOS_KEY_PREFIX,
+};
+use actix_web::web;
+use async_trait::async_trait;
+use quizz_auth::universal::domain::bloqueo_login::ClaveIntento;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::provider::repositorio::RepositorioIntentosLogin;
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+
+pub struct IntentosLoginRedis {
+    client: web::Data<redis::Client>,
+}
+
+impl IntentosLoginRedis {
+    pub fn new(client: web::Data<redis::Client>) -> Self {
+        Self { client }
+    }
+
+    async fn conexion(&self) -> Result<MultiplexedConnection, LoginUniversalError> {
+        self.client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al conectar: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })
+    }
+}
+
+fn error_cache(e: redis::RedisError) -> LoginUniversalError {
+    log::error!("error de redis en intentos de login: {:?}", e);
+    LoginUniversalError::ErrorGenericoCache
+}
+
+#[async_trait]
+impl RepositorioIntentosLogin<LoginUniversalError> for IntentosLoginRedis {
+    async fn bloqueo_restante(
+        &self,
+        clave: &ClaveIntento,
+    ) -> Result<Option<u64>, LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        // TTL devuelve -2 si la clave no existe
+        let restante: i64 = conn
+            .ttl(format!("{}{}", LOGIN_BLOQUEO_KEY_PREFIX, clave))
+            .await
+            .map_err(error_cache)?;
+
+        Ok(u64::try_from(restante)
+            .ok()
+            .filter(|segundos| *segundos > 0))
+    }
+
+    async fn registrar_fallo(
+        &self,
+        clave: &ClaveIntento,
+        ventana: u64,
+    ) -> Result<u32, LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let key = format!("{}{}", LOGIN_FALLOS_KEY_PREFIX, clave);
+        let (fallos,): (u32,) = redis::pipe()
+            .atomic()
+            .incr(&key, 1)
+            .expire(&key, ventana as i64)
+            .ignore()
+            .query_async(&mut conn)
+            .await
+            .map_err(error_cache)?;
+
+        Ok(fallos)
+    }
+
+    async fn bloquear(
+        &self,
+        clave: &ClaveIntento,
+        segundos: u64,
+    ) -> Result<(), LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let _: () = conn
+            .set_ex(
+                format!("{}{}", LOGIN_BLOQUEO_KEY_PREFIX, clave),
+                1,
+                segundos,
+            )
+            .await
+            .map_err(error_cache)?;
+
+        Ok(())
+    }
+
+    async fn reiniciar(&self, clave: &ClaveIntento) -> Result<(), LoginUniversalError> {
+        let mut conn = self.conexion().await?;
+        let claves = [
+            format!("{}{}", LOGIN_FALLOS_KEY_PREFIX, clave),
+            format!("{}{}", LOGIN_BLOQUEO_KEY_PREFIX, clave),
+        ];
+        let _: () = conn.del(&claves).await.map_err(error_cache)?;
+
+        Ok(())
+    }
+}
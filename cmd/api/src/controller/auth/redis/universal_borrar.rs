@@ -1,3 +1,4 @@
+use crate::controller::auth::redis::constantes::{clave_refresh, clave_sesiones_respuesta};
 use actix_web::web;
 use async_trait::async_trait;
 use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
@@ -26,7 +27,13 @@ impl RepositorioLoginUniversalCacheBorrado<LoginUniversalError> for LogoutUniver
                 LoginUniversalError::ErrorGenericoCache
             })?;
 
-        let _: () = conn.del(&sujeto_id).await.map_err(|e| {
+        let sesiones = clave_sesiones_respuesta(&sujeto_id);
+        let mut claves: Vec<String> = conn.smembers(&sesiones).await.map_err(|e| {
+            log::error!("error de redis al leer sesiones de respuestas: {:?}", e);
+            LoginUniversalError::ErrorGenericoCache
+        })?;
+        claves.extend([sesiones, clave_refresh(&sujeto_id), sujeto_id]);
+        let _: () = conn.del(&claves).await.map_err(|e| {
             log::error!("error de redis al borrar token: {:?}", e);
             LoginUniversalError::ErrorGenericoCache
         })?;
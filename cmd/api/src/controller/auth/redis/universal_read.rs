@@ -0,0 +1,70 @@
+use crate::controller::auth::dto::SesionRefreshRedisDTO;
+use crate::controller::auth::redis::constantes::clave_refresh;
+use actix_web::web;
+use async_trait::async_trait;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::domain::sesion_refresh::SesionRefresh;
+use quizz_auth::universal::provider::repositorio::RepositorioLoginUniversalCacheLectura;
+use redis::AsyncCommands;
+
+pub struct SesionUniversalRedis {
+    client: web::Data<redis::Client>,
+}
+
+impl SesionUniversalRedis {
+    pub fn new(client: web::Data<redis::Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl RepositorioLoginUniversalCacheLectura<LoginUniversalError> for SesionUniversalRedis {
+    async fn obtener_token(
+        &self,
+        sujeto_id: String,
+    ) -> Result<Option<String>, LoginUniversalError> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al conectar: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        conn.get(&sujeto_id).await.map_err(|e| {
+            log::error!("error de redis al leer token: {:?}", e);
+            LoginUniversalError::ErrorGenericoCache
+        })
+    }
+
+    async fn obtener_refresh(
+        &self,
+        sujeto_id: String,
+    ) -> Result<Option<SesionRefresh>, LoginUniversalError> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al conectar: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        let valor: Option<String> = conn.get(clave_refresh(&sujeto_id)).await.map_err(|e| {
+            log::error!("error de redis al leer refresh token: {:?}", e);
+            LoginUniversalError::ErrorGenericoCache
+        })?;
+
+        valor
+            .map(|valor| {
+                serde_json::from_str::<SesionRefreshRedisDTO>(&valor)
+                    .map(SesionRefresh::from)
+                    .map_err(|e| {
+                        log::error!("refresh token de {} no legible: {:?}", sujeto_id, e);
+                        LoginUniversalError::ErrorGenericoCache
+                    })
+            })
+            .transpose()
+    }
+}
@@ -1,10 +1,23 @@
+use crate::controller::auth::dto::SesionRefreshRedisDTO;
+use crate::controller::auth::redis::constantes::{clave_refresh, clave_sesiones_respuesta};
 use actix_web::web;
 use async_trait::async_trait;
 use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::domain::sesion_refresh::SesionRefresh;
 use quizz_auth::universal::provider::repositorio::RepositorioLoginUniversalCacheEscritura;
 use quizz_common::domain::entity::jwt::JwtObject;
 use redis::AsyncCommands;
 
+/// Reemplaza la sesion de refresh solo si su token activo es el presentado
+const ROTAR_REFRESH: &str = r"
+local actual = redis.call('GET', KEYS[1])
+if not actual or cjson.decode(actual)['activo'] ~= ARGV[1] then
+    return 0
+end
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+return 1
+";
+
 pub struct LoginUniversalRedis {
     client: web::Data<redis::Client>,
 }
@@ -15,6 +28,18 @@ impl LoginUniversalRedis {
     }
 }
 
+/// Clave, valor y segundos restantes de la sesion de refresh. La sesion se
+/// guarda con el tiempo que le queda, asi rotarla no extiende su vigencia.
+fn valor_refresh(sesion: SesionRefresh) -> Result<(String, String, u64), LoginUniversalError> {
+    let clave = clave_refresh(&sesion.sujeto_id);
+    let restantes = sesion.segundos_restantes().max(1);
+    let valor = serde_json::to_string(&SesionRefreshRedisDTO::from(sesion)).map_err(|e| {
+        log::error!("error al serializar el refresh token: {:?}", e);
+        LoginUniversalError::ErrorGenericoCache
+    })?;
+    Ok((clave, valor, restantes))
+}
+
 #[async_trait]
 impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for LoginUniversalRedis {
     async fn guardar_token(&self, jwt: JwtObject) -> Result<(), LoginUniversalError> {
@@ -37,4 +62,86 @@ impl RepositorioLoginUniversalCacheEscritura<LoginUniversalError> for LoginUnive
 
         Ok(())
     }
+
+    async fn guardar_sesion_respuesta(
+        &self,
+        sujeto_id: String,
+        jwt: JwtObject,
+    ) -> Result<(), LoginUniversalError> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        let sesiones = clave_sesiones_respuesta(&sujeto_id);
+        let _: () = redis::pipe()
+            .atomic()
+            .set_ex(&jwt.key, &jwt.value, jwt.expiration)
+            .ignore()
+            .sadd(&sesiones, &jwt.key)
+            .ignore()
+            .expire(&sesiones, jwt.expiration as i64)
+            .ignore()
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| {
+                log::error!("error de redis: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        Ok(())
+    }
+
+    async fn guardar_refresh(&self, sesion: SesionRefresh) -> Result<(), LoginUniversalError> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        let (clave, valor, restantes) = valor_refresh(sesion)?;
+        let _: () = conn.set_ex(clave, valor, restantes).await.map_err(|e| {
+            log::error!("error de redis: {:?}", e);
+            LoginUniversalError::ErrorGenericoCache
+        })?;
+
+        Ok(())
+    }
+
+    async fn rotar_refresh(
+        &self,
+        anterior: String,
+        sesion: SesionRefresh,
+    ) -> Result<bool, LoginUniversalError> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                log::error!("error de redis: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        let (clave, valor, restantes) = valor_refresh(sesion)?;
+        let rotada: i64 = redis::Script::new(ROTAR_REFRESH)
+            .key(clave)
+            .arg(anterior)
+            .arg(valor)
+            .arg(restantes)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| {
+                log::error!("error de redis al rotar el refresh token: {:?}", e);
+                LoginUniversalError::ErrorGenericoCache
+            })?;
+
+        Ok(rotada == 1)
+    }
 }
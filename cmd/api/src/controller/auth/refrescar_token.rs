@@ -0,0 +1,71 @@
+use crate::configuration::JwtSettings;
+use crate::controller::auth::dto::{LoginResponseDTO, RefreshTokenRequestDTO};
+use crate::controller::auth::jwt::JWTProvider;
+use crate::controller::auth::redis::universal_borrar::LogoutUniversalRedis;
+use crate::controller::auth::redis::universal_read::SesionUniversalRedis;
+use crate::controller::auth::redis::universal_write::LoginUniversalRedis;
+use actix_web::{HttpResponse, web};
+use log::{error, info, warn};
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::use_case::refrescar_token::{InputData, RefrescarToken};
+use quizz_common::use_case::CasoDeUso;
+use serde_json::json;
+
+pub struct RefrescarTokenController;
+
+impl RefrescarTokenController {
+    pub async fn refresh(
+        body: web::Json<RefreshTokenRequestDTO>,
+        redis_client: web::Data<redis::Client>,
+        jwt_settings: web::Data<JwtSettings>,
+    ) -> HttpResponse {
+        info!("POST /token/refresh");
+
+        let redis_impl = match LoginUniversalRedis::new(redis_client.clone()) {
+            Ok(r) => r,
+            Err(e) => {
+                error!("POST /token/refresh - error al conectar con redis: {:?}", e);
+                return HttpResponse::InternalServerError().finish();
+            }
+        };
+
+        let use_case = RefrescarToken::new(
+            Box::new(SesionUniversalRedis::new(redis_client.clone())),
+            Box::new(redis_impl),
+            Box::new(LogoutUniversalRedis::new(redis_client)),
+            Box::new(JWTProvider::new(
+                jwt_settings.secret.clone(),
+                jwt_settings.expiration_seconds,
+            )),
+        );
+
+        match use_case
+            .ejecutar(InputData {
+                refresh_token: body.into_inner().refresh_token,
+            })
+            .await
+        {
+            Ok(jwt_data) => {
+                info!("POST /token/refresh - token renovado, rol={}", jwt_data.rol);
+                HttpResponse::Ok().json(LoginResponseDTO {
+                    token: jwt_data.jwt_value,
+                    expires_in: jwt_data.expiration,
+                    rol: jwt_data.rol,
+                    refresh_token: jwt_data.refresh_token,
+                    refresh_expires_in: jwt_data.refresh_expiration,
+                })
+            }
+            Err(
+                e @ (LoginUniversalError::RefreshTokenNoValido
+                | LoginUniversalError::RefreshTokenReutilizado),
+            ) => {
+                warn!("POST /token/refresh - {}", e);
+                HttpResponse::Unauthorized().json(json!({"error": e.to_string()}))
+            }
+            Err(e) => {
+                error!("POST /token/refresh - error al renovar el token: {:?}", e);
+                HttpResponse::InternalServerError().finish()
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::controller::auth::redis::universal_borrar::LogoutUniversalRedis;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_auth::universal::use_case::revocar_sesiones::{InputData, RevocarSesiones};
+use quizz_common::use_case::CasoDeUso;
+use serde_json::json;
+
+pub struct RevocarSesionesController;
+
+impl RevocarSesionesController {
+    pub async fn revoke(req: HttpRequest, redis_client: web::Data<redis::Client>) -> HttpResponse {
+        let sujeto_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("DELETE /sesiones - id no proporcionado");
+                return HttpResponse::BadRequest().json("no se esta enviando el id del usuario");
+            }
+        };
+
+        info!("DELETE /sesiones/{}", sujeto_id);
+
+        let use_case = RevocarSesiones::new(Box::new(LogoutUniversalRedis::new(redis_client)));
+
+        match use_case
+            .ejecutar(InputData {
+                sujeto_id: sujeto_id.clone(),
+            })
+            .await
+        {
+            Ok(_) => {
+                info!("DELETE /sesiones/{} - sesiones revocadas", sujeto_id);
+                HttpResponse::NoContent().finish()
+            }
+            Err(e) => {
+                error!("DELETE /sesiones/{} - error al revocar: {:?}", sujeto_id, e);
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al revocar las sesiones"}))
+            }
+        }
+    }
+}
@@ -1,8 +1,50 @@
+use crate::controller::auth::codigo_acceso::CodigoAccesoController;
+use crate::controller::auth::desbloquear_login::DesbloquearLoginController;
 use crate::controller::auth::logout::LogoutController;
+use crate::controller::auth::password::PasswordController;
+use crate::controller::auth::refrescar_token::RefrescarTokenController;
+use crate::controller::auth::revocar_sesiones::RevocarSesionesController;
 use crate::controller::auth::universal_login::UniversalLoginController;
 use actix_web::web;
 
 pub fn login_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(web::resource("/login").route(web::post().to(UniversalLoginController::login)));
+    cfg.service(
+        web::resource("/login/codigo").route(web::post().to(CodigoAccesoController::login)),
+    );
     cfg.service(web::resource("/logout").route(web::post().to(LogoutController::logout)));
+    cfg.service(
+        web::resource("/token/refresh").route(web::post().to(RefrescarTokenController::refresh)),
+    );
+    cfg.service(web::resource("/password/reset").route(web::post().to(PasswordController::reset)));
+}
+
+pub fn cuenta(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/cuenta")
+            .service(web::resource("/password").route(web::put().to(PasswordController::update)))
+            .service(
+                web::resource("/reset").route(web::post().to(PasswordController::create_reset)),
+            ),
+    );
+}
+
+pub fn sesion(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/sesiones").service(
+        web::resource("/{id}").route(web::delete().to(RevocarSesionesController::revoke)),
+    ));
+}
+
+pub fn bloqueo(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/bloqueos")
+            .service(
+                web::resource("/ip/{ip}")
+                    .route(web::delete().to(DesbloquearLoginController::unlock_ip)),
+            )
+            .service(
+                web::resource("/{documento}")
+                    .route(web::delete().to(DesbloquearLoginController::unlock)),
+            ),
+    );
 }
@@ -1,8 +1,10 @@
-use crate::configuration::JwtSettings;
+use crate::configuration::{JwtSettings, LoginSettings};
 use crate::controller::auth::crypto::CifradoPorDefecto;
 use crate::controller::auth::dto::{DocumentoLoginRequestDTO, LoginResponseDTO};
+use crate::controller::auth::ip_cliente::ip_cliente;
 use crate::controller::auth::jwt::JWTProvider;
 use crate::controller::auth::mongo::universal_read::LoginUniversalMongo;
+use crate::controller::auth::redis::intentos_login::IntentosLoginRedis;
 use crate::controller::auth::redis::universal_write::LoginUniversalRedis;
 use actix_web::{HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
@@ -10,16 +12,18 @@ use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
 use quizz_auth::universal::use_case::login::{InputData, LoginUniversal};
 use quizz_common::use_case::CasoDeUso;
 use quizz_core::postulante::domain::value_object::documento::Documento;
+use serde_json::json;
 
 pub struct UniversalLoginController;
 
 impl UniversalLoginController {
     pub async fn login(
-        _req: HttpRequest,
+        req: HttpRequest,
         body: web::Json<DocumentoLoginRequestDTO>,
         pool: web::Data<mongodb::Client>,
         redis_client: web::Data<redis::Client>,
         jwt_settings: web::Data<JwtSettings>,
+        login_settings: web::Data<LoginSettings>,
     ) -> HttpResponse {
         let dto = body.into_inner();
         info!("POST /login - documento={}", dto.documento);
@@ -32,7 +36,7 @@ impl UniversalLoginController {
             }
         };
 
-        let redis_impl = match LoginUniversalRedis::new(redis_client) {
+        let redis_impl = match LoginUniversalRedis::new(redis_client.clone()) {
             Ok(r) => r,
             Err(e) => {
                 error!("POST /login - error al conectar con redis: {:?}", e);
@@ -48,12 +52,17 @@ impl UniversalLoginController {
                 jwt_settings.secret.clone(),
                 jwt_settings.expiration_seconds,
             )),
+            jwt_settings.refresh_expiration_seconds as u64,
+            Box::new(IntentosLoginRedis::new(redis_client)),
+            login_settings.politica(),
         );
 
         match use_case
             .ejecutar(InputData {
                 documento: documento.value().clone(),
                 password: dto.password,
+                // Se usa la direccion del socket: las cabeceras de proxy las controla el cliente
+                ip: ip_cliente(&req, &login_settings),
             })
             .await
         {
@@ -63,6 +72,8 @@ impl UniversalLoginController {
                     token: jwt_data.jwt_value,
                     expires_in: jwt_data.expiration,
                     rol: jwt_data.rol,
+                    refresh_token: jwt_data.refresh_token,
+                    refresh_expires_in: jwt_data.refresh_expiration,
                 };
                 HttpResponse::Ok().json(response_dto)
             }
@@ -74,6 +85,12 @@ impl UniversalLoginController {
                 warn!("POST /login - password incorrecto");
                 HttpResponse::Unauthorized().json("Documento o password incorrectos")
             }
+            Err(e @ LoginUniversalError::LoginBloqueado { segundos }) => {
+                warn!("POST /login - login bloqueado por {} segundos", segundos);
+                HttpResponse::TooManyRequests()
+                    .insert_header(("Retry-After", segundos.to_string()))
+                    .json(json!({"error": e.to_string()}))
+            }
             Err(e) => {
                 error!("POST /login - error en login: {:?}", e);
                 HttpResponse::InternalServerError().finish()
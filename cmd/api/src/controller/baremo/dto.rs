@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct FilaBaremoDTO {
+    pub puntaje: i64,
+    pub percentil: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct GrupoNormativoDTO {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genero: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edad_minima: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edad_maxima: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grado_instruccion: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct EscalaDTO {
+    pub tipo: String,
+    pub valor: String,
+}
+
+#[derive(Deserialize)]
+pub struct RegistrarBaremoDTO {
+    pub nombre: String,
+    pub escala: EscalaDTO,
+    #[serde(default)]
+    pub grupo: Option<GrupoNormativoDTO>,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<FilaBaremoDTO>,
+}
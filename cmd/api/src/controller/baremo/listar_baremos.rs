@@ -0,0 +1,85 @@
+use crate::controller::baremo::dto::{EscalaDTO, FilaBaremoDTO, GrupoNormativoDTO};
+use crate::controller::baremo::mongo::read::BaremoReadMongo;
+use crate::controller::hateoas::{Link, Links, ListResponse};
+use actix_web::{HttpResponse, web};
+use log::{error, info};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::baremo::use_case::listar_baremos::{InputData, ListarBaremos};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize)]
+pub struct BaremoListItemDTO {
+    pub id: String,
+    pub nombre: String,
+    pub escala: EscalaDTO,
+    pub grupo: GrupoNormativoDTO,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<FilaBaremoDTO>,
+    #[serde(rename = "_links")]
+    pub links: Links,
+}
+
+pub struct ListarBaremosController;
+
+impl ListarBaremosController {
+    pub async fn list(pool: web::Data<mongodb::Client>) -> HttpResponse {
+        info!("GET /baremos");
+
+        let listar = ListarBaremos::new(Box::new(BaremoReadMongo::new(pool)));
+
+        match listar.ejecutar(InputData {}).await {
+            Ok(output) => {
+                info!("GET /baremos - {} resultados", output.baremos.len());
+
+                let items: Vec<BaremoListItemDTO> = output
+                    .baremos
+                    .into_iter()
+                    .map(|b| {
+                        let mut links = Links::new();
+                        links.insert("self".into(), Link::get(format!("/baremos/{}", b.id)));
+                        BaremoListItemDTO {
+                            id: b.id,
+                            nombre: b.nombre,
+                            escala: EscalaDTO {
+                                tipo: b.escala_tipo,
+                                valor: b.escala_valor,
+                            },
+                            grupo: GrupoNormativoDTO {
+                                genero: b.genero,
+                                edad_minima: b.edad_minima,
+                                edad_maxima: b.edad_maxima,
+                                grado_instruccion: b.grado_instruccion,
+                            },
+                            media: b.media,
+                            desviacion_estandar: b.desviacion_estandar,
+                            tabla: b
+                                .tabla
+                                .into_iter()
+                                .map(|fila| FilaBaremoDTO {
+                                    puntaje: fila.puntaje,
+                                    percentil: fila.percentil,
+                                })
+                                .collect(),
+                            links,
+                        }
+                    })
+                    .collect();
+
+                let mut collection_links = Links::new();
+                collection_links.insert("self".into(), Link::get("/baremos"));
+
+                HttpResponse::Ok().json(ListResponse {
+                    links: collection_links,
+                    items,
+                })
+            }
+            Err(e) => {
+                error!("GET /baremos - error: {}", e);
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al obtener baremos"}))
+            }
+        }
+    }
+}
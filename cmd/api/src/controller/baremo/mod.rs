@@ -0,0 +1,5 @@
+mod dto;
+pub mod listar_baremos;
+pub mod mongo;
+pub mod registrar_baremo;
+pub mod route;
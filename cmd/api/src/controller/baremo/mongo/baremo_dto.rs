@@ -0,0 +1,93 @@
+use crate::controller::baremo::dto::{EscalaDTO, FilaBaremoDTO, GrupoNormativoDTO};
+use quizz_core::baremo::domain::entity::baremo::{Baremo, FilaBaremo};
+use quizz_core::baremo::domain::error::baremo::BaremoError;
+use quizz_core::baremo::domain::value_object::escala::Escala;
+use quizz_core::baremo::domain::value_object::grupo_normativo::GrupoNormativo;
+use quizz_core::baremo::domain::value_object::id::BaremoID;
+use quizz_core::postulante::domain::value_object::genero::Genero;
+use quizz_core::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize)]
+pub struct BaremoMongoDTO {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub nombre: String,
+    pub escala: EscalaDTO,
+    pub grupo: GrupoNormativoDTO,
+    pub media: f64,
+    pub desviacion_estandar: f64,
+    pub tabla: Vec<FilaBaremoDTO>,
+}
+
+impl From<Baremo> for BaremoMongoDTO {
+    fn from(baremo: Baremo) -> Self {
+        Self {
+            id: baremo.id.to_string(),
+            nombre: baremo.nombre,
+            escala: EscalaDTO {
+                tipo: baremo.escala.tipo().to_string(),
+                valor: baremo.escala.valor(),
+            },
+            grupo: GrupoNormativoDTO {
+                genero: baremo.grupo.genero.map(|g| g.to_string()),
+                edad_minima: baremo.grupo.edad_minima,
+                edad_maxima: baremo.grupo.edad_maxima,
+                grado_instruccion: baremo.grupo.grado_instruccion.map(|g| g.to_string()),
+            },
+            media: baremo.media,
+            desviacion_estandar: baremo.desviacion_estandar,
+            tabla: baremo
+                .tabla
+                .into_iter()
+                .map(|fila| FilaBaremoDTO {
+                    puntaje: fila.puntaje,
+                    percentil: fila.percentil,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<BaremoMongoDTO> for Baremo {
+    type Error = BaremoError;
+
+    fn try_from(dto: BaremoMongoDTO) -> Result<Self, Self::Error> {
+        let genero = dto
+            .grupo
+            .genero
+            .as_deref()
+            .map(Genero::from_str)
+            .transpose()
+            .map_err(|e| BaremoError::GrupoNormativoNoValido(e.to_string()))?;
+        let grado_instruccion = dto
+            .grupo
+            .grado_instruccion
+            .as_deref()
+            .map(GradoInstruccion::from_str)
+            .transpose()
+            .map_err(|e| BaremoError::GrupoNormativoNoValido(e.to_string()))?;
+
+        Baremo::new(
+            BaremoID::new(&dto.id)?,
+            dto.nombre,
+            Escala::new(&dto.escala.tipo, &dto.escala.valor)?,
+            GrupoNormativo::new(
+                genero,
+                dto.grupo.edad_minima,
+                dto.grupo.edad_maxima,
+                grado_instruccion,
+            )?,
+            dto.media,
+            dto.desviacion_estandar,
+            dto.tabla
+                .into_iter()
+                .map(|fila| FilaBaremo {
+                    puntaje: fila.puntaje,
+                    percentil: fila.percentil,
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1 @@
+pub const BAREMO_COLLECTION_NAME: &str = "baremo";
@@ -0,0 +1,4 @@
+mod baremo_dto;
+mod constantes;
+pub mod read;
+pub mod write;
@@ -0,0 +1,62 @@
+use crate::controller::baremo::mongo::baremo_dto::BaremoMongoDTO;
+use crate::controller::baremo::mongo::constantes::BAREMO_COLLECTION_NAME;
+use crate::controller::mongo_repository::MongoRepository;
+use actix_web::web;
+use async_trait::async_trait;
+use log::error;
+use mongodb::bson;
+use mongodb::bson::doc;
+use quizz_core::baremo::domain::entity::baremo::Baremo;
+use quizz_core::baremo::domain::error::baremo::{BaremoError, RepositorioError};
+use quizz_core::baremo::provider::repositorio::RepositorioBaremoLectura;
+
+pub struct BaremoReadMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl BaremoReadMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+}
+
+impl MongoRepository for BaremoReadMongo {
+    fn get_collection_name(&self) -> &str {
+        BAREMO_COLLECTION_NAME
+    }
+
+    fn get_client(&self) -> &web::Data<mongodb::Client> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl RepositorioBaremoLectura<BaremoError> for BaremoReadMongo {
+    async fn obtener_baremos(&self) -> Result<Vec<Baremo>, BaremoError> {
+        let mut cursor = self.get_collection().find(doc! {}).await.map_err(|e| {
+            error!("Error finding baremos: {}", e);
+            BaremoError::BaremoRepositorioError(RepositorioError::LecturaNoFinalizada)
+        })?;
+
+        let mut baremos = Vec::new();
+
+        while cursor.advance().await.map_err(|e| {
+            error!("Error advancing cursor: {}", e);
+            BaremoError::BaremoRepositorioError(RepositorioError::LecturaNoFinalizada)
+        })? {
+            let doc = cursor.deserialize_current().map_err(|e| {
+                error!("Error deserializing cursor: {}", e);
+                BaremoError::BaremoRepositorioError(RepositorioError::LecturaNoFinalizada)
+            })?;
+
+            let dto: BaremoMongoDTO = bson::from_document(doc).map_err(|e| {
+                error!("Error deserializing baremo document: {}", e);
+                BaremoError::BaremoRepositorioError(RepositorioError::LecturaNoFinalizada)
+            })?;
+
+            baremos.push(dto.try_into()?);
+        }
+
+        Ok(baremos)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::controller::baremo::mongo::baremo_dto::BaremoMongoDTO;
+use crate::controller::baremo::mongo::constantes::BAREMO_COLLECTION_NAME;
+use crate::controller::mongo_repository::MongoRepository;
+use actix_web::web;
+use async_trait::async_trait;
+use log::error;
+use mongodb::bson;
+use mongodb::bson::doc;
+use quizz_core::baremo::domain::entity::baremo::Baremo;
+use quizz_core::baremo::domain::error::baremo::{BaremoError, RepositorioError};
+use quizz_core::baremo::provider::repositorio::RepositorioBaremoEscritura;
+
+pub struct BaremoMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl BaremoMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+}
+
+impl MongoRepository for BaremoMongo {
+    fn get_collection_name(&self) -> &str {
+        BAREMO_COLLECTION_NAME
+    }
+
+    fn get_client(&self) -> &web::Data<mongodb::Client> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl RepositorioBaremoEscritura<BaremoError> for BaremoMongo {
+    async fn guardar_baremo(&self, baremo: Baremo) -> Result<(), BaremoError> {
+        let id = baremo.id.to_string();
+        let documento = bson::to_document(&BaremoMongoDTO::from(baremo)).map_err(|e| {
+            error!("Error serializing baremo {}: {}", id, e);
+            BaremoError::BaremoRepositorioError(RepositorioError::PersistenciaNoFinalizada)
+        })?;
+
+        self.get_collection()
+            .replace_one(doc! { "_id": &id }, documento)
+            .upsert(true)
+            .await
+            .map_err(|e| {
+                error!("Database error while saving baremo: id={}, error={}", id, e);
+                BaremoError::BaremoRepositorioError(RepositorioError::PersistenciaNoFinalizada)
+            })?;
+
+        Ok(())
+    }
+}
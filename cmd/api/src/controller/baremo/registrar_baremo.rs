@@ -0,0 +1,73 @@
+use crate::controller::baremo::dto::RegistrarBaremoDTO;
+use crate::controller::baremo::mongo::write::BaremoMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::baremo::domain::error::baremo::BaremoError;
+use quizz_core::baremo::use_case::registrar_baremo::{InputData, InputFila, RegistrarBaremo};
+use serde_json::json;
+
+pub struct BaremoController;
+
+impl BaremoController {
+    pub async fn create(
+        req: HttpRequest,
+        body: web::Json<RegistrarBaremoDTO>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let baremo_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("POST /baremos - id no proporcionado");
+                return HttpResponse::BadRequest()
+                    .json(json!({"error": "Se debe enviar el ID del baremo"}));
+            }
+        };
+
+        info!("{} /baremos/{}", req.method(), baremo_id);
+
+        let registrar_baremo = RegistrarBaremo::new(Box::new(BaremoMongo::new(pool)));
+
+        let dto = body.into_inner();
+        let grupo = dto.grupo.unwrap_or_default();
+        let input = InputData {
+            id: baremo_id.clone(),
+            nombre: dto.nombre,
+            escala_tipo: dto.escala.tipo,
+            escala_valor: dto.escala.valor,
+            genero: grupo.genero,
+            edad_minima: grupo.edad_minima,
+            edad_maxima: grupo.edad_maxima,
+            grado_instruccion: grupo.grado_instruccion,
+            media: dto.media,
+            desviacion_estandar: dto.desviacion_estandar,
+            tabla: dto
+                .tabla
+                .into_iter()
+                .map(|fila| InputFila {
+                    puntaje: fila.puntaje,
+                    percentil: fila.percentil,
+                })
+                .collect(),
+        };
+
+        match registrar_baremo.ejecutar(input).await {
+            Ok(_) => {
+                info!("/baremos/{} - guardado exitosamente", baremo_id);
+                HttpResponse::Created().finish()
+            }
+            Err(BaremoError::BaremoRepositorioError(ref repo_err)) => {
+                error!(
+                    "/baremos/{} - error de repositorio: {:?}",
+                    baremo_id, repo_err
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al guardar el baremo"}))
+            }
+            Err(err) => {
+                warn!("/baremos/{} - baremo no valido: {}", baremo_id, err);
+                HttpResponse::BadRequest().json(json!({"error": err.to_string()}))
+            }
+        }
+    }
+}
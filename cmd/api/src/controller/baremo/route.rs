@@ -0,0 +1,15 @@
+use crate::controller::baremo::listar_baremos::ListarBaremosController;
+use crate::controller::baremo::registrar_baremo::BaremoController;
+use actix_web::web;
+
+pub fn baremo(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/baremos")
+            .service(web::resource("").route(web::get().to(ListarBaremosController::list)))
+            .service(
+                web::resource("/{id}")
+                    .route(web::post().to(BaremoController::create))
+                    .route(web::put().to(BaremoController::create)),
+            ),
+    );
+}
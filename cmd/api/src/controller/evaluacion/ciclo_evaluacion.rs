@@ -0,0 +1,148 @@
+use crate::controller::evaluacion::dto::{CambiarEstadoEvaluacionDTO, ProblemaPublicacionDTO};
+use crate::controller::evaluacion::mongo::write::EvaluacionMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::evaluacion::domain::error::evaluacion::EvaluacionError;
+use quizz_core::evaluacion::domain::error::evaluacion::RepositorioError::EvaluacionNoExiste;
+use quizz_core::evaluacion::domain::value_object::accion::AccionEvaluacion;
+use quizz_core::evaluacion::use_case::{
+    archivar_evaluacion, clonar_evaluacion, despublicar_evaluacion, publicar_evaluacion,
+};
+use serde_json::json;
+use std::str::FromStr;
+
+pub struct CicloEvaluacionController;
+
+impl CicloEvaluacionController {
+    /// `PATCH /evaluaciones/{id}`. Sin cuerpo publica la evaluacion; con
+    /// `{"accion": ...}` aplica la transicion indicada.
+    pub async fn cambiar_estado(
+        req: HttpRequest,
+        body: web::Bytes,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let evaluacion_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("PATCH /evaluaciones - id no proporcionado");
+                return HttpResponse::BadRequest()
+                    .json("no se esta enviando el id de la evaluacion");
+            }
+        };
+
+        let dto = if body.is_empty() {
+            CambiarEstadoEvaluacionDTO::default()
+        } else {
+            match serde_json::from_slice::<CambiarEstadoEvaluacionDTO>(&body) {
+                Ok(dto) => dto,
+                Err(e) => {
+                    warn!(
+                        "PATCH /evaluaciones/{} - cuerpo no valido: {}",
+                        evaluacion_id, e
+                    );
+                    return HttpResponse::BadRequest().json(e.to_string());
+                }
+            }
+        };
+
+        let accion = match dto.accion.as_deref().map(AccionEvaluacion::from_str) {
+            Some(Ok(accion)) => accion,
+            Some(Err(e)) => return respuesta_error(&evaluacion_id, e),
+            None => AccionEvaluacion::default(),
+        };
+
+        info!("PATCH /evaluaciones/{} - {}", evaluacion_id, accion);
+
+        let resultado = match accion {
+            AccionEvaluacion::Publicar => {
+                publicar_evaluacion::PublicarEvaluacion::new(Box::new(EvaluacionMongo::new(pool)))
+                    .ejecutar(publicar_evaluacion::InputData {
+                        evaluacion_id: evaluacion_id.clone(),
+                    })
+                    .await
+                    .map(|_| None)
+            }
+            AccionEvaluacion::Despublicar => despublicar_evaluacion::DespublicarEvaluacion::new(
+                Box::new(EvaluacionMongo::new(pool)),
+            )
+            .ejecutar(despublicar_evaluacion::InputData {
+                evaluacion_id: evaluacion_id.clone(),
+            })
+            .await
+            .map(|_| None),
+            AccionEvaluacion::Archivar => {
+                archivar_evaluacion::ArchivarEvaluacion::new(Box::new(EvaluacionMongo::new(pool)))
+                    .ejecutar(archivar_evaluacion::InputData {
+                        evaluacion_id: evaluacion_id.clone(),
+                    })
+                    .await
+                    .map(|_| None)
+            }
+            AccionEvaluacion::Clonar => clonar_evaluacion::ClonarEvaluacion::new(
+                Box::new(EvaluacionMongo::new(pool.clone())),
+                Box::new(EvaluacionMongo::new(pool)),
+            )
+            .ejecutar(clonar_evaluacion::InputData {
+                evaluacion_id: evaluacion_id.clone(),
+                nuevo_id: dto.nuevo_id,
+            })
+            .await
+            .map(Some),
+        };
+
+        match resultado {
+            Ok(Some(nuevo_id)) => {
+                info!(
+                    "PATCH /evaluaciones/{} - clonada como {}",
+                    evaluacion_id, nuevo_id
+                );
+                HttpResponse::Created()
+                    .insert_header(("Location", format!("/evaluaciones/{}", nuevo_id)))
+                    .json(json!({ "id": nuevo_id }))
+            }
+            Ok(None) => {
+                info!(
+                    "PATCH /evaluaciones/{} - {} exitosamente",
+                    evaluacion_id, accion
+                );
+                HttpResponse::Ok().finish()
+            }
+            Err(e) => respuesta_error(&evaluacion_id, e),
+        }
+    }
+}
+
+fn respuesta_error(evaluacion_id: &str, e: EvaluacionError) -> HttpResponse {
+    match e {
+        EvaluacionError::PublicacionNoValida(ref problemas) => {
+            warn!("PATCH /evaluaciones/{} - {}", evaluacion_id, e);
+            HttpResponse::UnprocessableEntity().json(json!({
+                "error": e.to_string(),
+                "problemas": problemas
+                    .iter()
+                    .map(ProblemaPublicacionDTO::from)
+                    .collect::<Vec<_>>(),
+            }))
+        }
+        e @ EvaluacionError::EvaluacionRepositorioError(EvaluacionNoExiste) => {
+            warn!("PATCH /evaluaciones/{} - {}", evaluacion_id, e);
+            HttpResponse::NotFound().json("la evaluacion no existe")
+        }
+        e @ (EvaluacionError::EvaluacionYaFuePublicada
+        | EvaluacionError::TransicionNoValida { .. }
+        | EvaluacionError::EvaluacionConAsignaciones(_)
+        | EvaluacionError::EvaluacionYaExiste(_)) => {
+            warn!("PATCH /evaluaciones/{} - {}", evaluacion_id, e);
+            HttpResponse::Conflict().json(e.to_string())
+        }
+        e @ (EvaluacionError::AccionNoValida(_) | EvaluacionError::EvaluacionIdInvalido(_)) => {
+            warn!("PATCH /evaluaciones/{} - {}", evaluacion_id, e);
+            HttpResponse::BadRequest().json(e.to_string())
+        }
+        e => {
+            error!("PATCH /evaluaciones/{} - error: {:?}", evaluacion_id, e);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
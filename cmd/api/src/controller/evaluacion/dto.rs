@@ -1,12 +1,154 @@
-use serde::Deserialize;
+use quizz_core::evaluacion::domain::service::validacion::ProblemaPublicacion;
+use quizz_core::evaluacion::domain::value_object::criterio_resultado::{
+    CriterioResultado, Umbral, UmbralExamen,
+};
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct RegistrarEvaluacionDTO {
     pub titulo: String,
     pub descripcion: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
+    #[serde(default)]
+    pub criterio_resultado: Option<CriterioResultadoDTO>,
 }
 
 #[derive(Deserialize)]
 pub struct AgregarExamenesDTO {
     pub examenes: Vec<String>,
 }
+
+/// Cuerpo opcional del `PATCH /evaluaciones/{id}`. Sin `accion` se publica.
+/// `nuevo_id` solo aplica al clonar.
+#[derive(Deserialize, Default)]
+pub struct CambiarEstadoEvaluacionDTO {
+    #[serde(default)]
+    pub accion: Option<String>,
+    #[serde(default)]
+    pub nuevo_id: Option<String>,
+}
+
+/// Un problema encontrado al validar la publicacion. `codigo` identifica el
+/// tipo de problema; `mensaje` es el texto para mostrar.
+#[derive(Serialize)]
+pub struct ProblemaPublicacionDTO {
+    pub codigo: &'static str,
+    pub mensaje: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examen_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pregunta_id: Option<String>,
+}
+
+impl From<&ProblemaPublicacion> for ProblemaPublicacionDTO {
+    fn from(problema: &ProblemaPublicacion) -> Self {
+        let (codigo, examen_id, pregunta_id) = match problema {
+            ProblemaPublicacion::SinExamenes => ("sin_examenes", None, None),
+            ProblemaPublicacion::ExamenDuplicado { examen_id } => {
+                ("examen_duplicado", Some(examen_id), None)
+            }
+            ProblemaPublicacion::ExamenInactivo { examen_id } => {
+                ("examen_inactivo", Some(examen_id), None)
+            }
+            ProblemaPublicacion::ExamenSinPreguntas { examen_id } => {
+                ("examen_sin_preguntas", Some(examen_id), None)
+            }
+            ProblemaPublicacion::MuestreoInsuficiente { examen_id, .. } => {
+                ("muestreo_insuficiente", Some(examen_id), None)
+            }
+            ProblemaPublicacion::PreguntaSinPuntaje {
+                examen_id,
+                pregunta_id,
+            } => ("pregunta_sin_puntaje", Some(examen_id), Some(pregunta_id)),
+            ProblemaPublicacion::PreguntaNoValida {
+                examen_id,
+                pregunta_id,
+                ..
+            } => ("pregunta_no_valida", Some(examen_id), Some(pregunta_id)),
+            ProblemaPublicacion::CriterioSinExamen { examen_id } => {
+                ("criterio_sin_examen", Some(examen_id), None)
+            }
+        };
+
+        Self {
+            codigo,
+            mensaje: problema.to_string(),
+            examen_id: examen_id.cloned(),
+            pregunta_id: pregunta_id.cloned(),
+        }
+    }
+}
+
+/// Reglas de corte de la evaluacion. Se guarda tal cual en la evaluacion y se
+/// copia a cada respuesta al asignarla.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct CriterioResultadoDTO {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<UmbralDTO>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examenes: Vec<UmbralExamenDTO>,
+}
+
+impl CriterioResultadoDTO {
+    pub fn esta_vacio(&self) -> bool {
+        self.total.is_none() && self.examenes.is_empty()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UmbralDTO {
+    pub apto: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observado: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UmbralExamenDTO {
+    pub examen_id: String,
+    pub apto: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observado: Option<i64>,
+}
+
+impl From<&CriterioResultado> for CriterioResultadoDTO {
+    fn from(criterio: &CriterioResultado) -> Self {
+        Self {
+            total: criterio.total.as_ref().map(|umbral| UmbralDTO {
+                apto: umbral.apto,
+                observado: umbral.observado,
+            }),
+            examenes: criterio
+                .examenes
+                .iter()
+                .map(|regla| UmbralExamenDTO {
+                    examen_id: regla.examen_id.clone(),
+                    apto: regla.umbral.apto,
+                    observado: regla.umbral.observado,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<CriterioResultadoDTO> for CriterioResultado {
+    fn from(criterio: CriterioResultadoDTO) -> Self {
+        Self {
+            total: criterio.total.map(|umbral| Umbral {
+                apto: umbral.apto,
+                observado: umbral.observado,
+            }),
+            examenes: criterio
+                .examenes
+                .into_iter()
+                .map(|regla| UmbralExamen {
+                    examen_id: regla.examen_id,
+                    umbral: Umbral {
+                        apto: regla.apto,
+                        observado: regla.observado,
+                    },
+                })
+                .collect(),
+        }
+    }
+}
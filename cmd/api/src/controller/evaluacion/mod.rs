@@ -1,7 +1,7 @@
 mod asociar_examen;
-mod dto;
+mod ciclo_evaluacion;
+pub mod dto;
 mod listar_evaluaciones;
 pub mod mongo;
-mod publicar_evaluacion;
 mod registrar_evaluacion;
 pub mod route;
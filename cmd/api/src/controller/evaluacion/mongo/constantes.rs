@@ -1 +1,2 @@
 pub const EVALUACION_COLLECTION_NAME: &str = "evaluacion";
+pub const RESPUESTA_COLLECTION_NAME: &str = "respuesta";
@@ -1,19 +1,29 @@
-use crate::controller::evaluacion::mongo::constantes::EVALUACION_COLLECTION_NAME;
+use crate::controller::evaluacion::dto::CriterioResultadoDTO;
+use crate::controller::evaluacion::mongo::constantes::{
+    EVALUACION_COLLECTION_NAME, RESPUESTA_COLLECTION_NAME,
+};
+use crate::controller::examen::dto::{
+    AleatorizacionDTO, PoliticaPuntajeDTO, ReglaMuestreoDTO, VersionDTO,
+};
+use crate::controller::examen::mongo::version::VersionesExamenMongo;
 use crate::controller::examen::mongo::write::ExamenMongo;
-use crate::controller::mongo_repository::MongoRepository;
+use crate::controller::mongo_repository::{MAIN_DATABASE_NAME, MongoRepository};
+use crate::controller::pregunta::dto::{NumericaDTO, SeleccionDTO};
 use actix_web::web;
 use async_trait::async_trait;
 use log::error;
-use mongodb::bson::doc;
+use mongodb::bson;
+use mongodb::bson::{Document, doc};
 use quizz_common::domain::value_objects::estado::EstadoGeneral;
 use quizz_core::evaluacion::domain::entity::evaluacion::Evaluacion;
 use quizz_core::evaluacion::domain::error::evaluacion::EvaluacionError;
 use quizz_core::evaluacion::domain::error::evaluacion::RepositorioError::{
-    EvaluacionNoExiste, PersistenciaNoFinalizada,
+    EvaluacionNoExiste, LecturaNoFinalizada, PersistenciaNoFinalizada,
 };
 use quizz_core::evaluacion::domain::value_object::evaluacion_estado::EvaluacionEstado;
 use quizz_core::evaluacion::provider::repositorio::{
-    RepositorioEvaluacionEscritura, RepositorioLeerEvaluacion, RepositorioPublicarEvaluacion,
+    RepositorioCicloEvaluacion, RepositorioEvaluacionEscritura, RepositorioLeerEvaluacion,
+    RepositorioPublicarEvaluacion,
 };
 use quizz_core::evaluacion::value_object::examen_id::ExamenIDs;
 use quizz_core::evaluacion::value_object::id::EvaluacionID;
@@ -24,14 +34,17 @@ use std::str::FromStr;
 pub struct EvaluacionMongo {
     client: web::Data<mongodb::Client>,
     repositorio_examen: ExamenMongo,
+    versiones_examen: VersionesExamenMongo,
 }
 
 impl EvaluacionMongo {
     pub fn new(client: web::Data<mongodb::Client>) -> Self {
         let repositorio_examen = ExamenMongo::new(client.clone());
+        let versiones_examen = VersionesExamenMongo::new(client.clone());
         EvaluacionMongo {
             client,
             repositorio_examen,
+            versiones_examen,
         }
     }
 }
@@ -49,10 +62,17 @@ impl MongoRepository for EvaluacionMongo {
 #[async_trait]
 impl RepositorioEvaluacionEscritura<EvaluacionError> for EvaluacionMongo {
     async fn guardar_evaluacion(&self, evaluacion: Evaluacion) -> Result<(), EvaluacionError> {
+        let criterio =
+            bson::to_bson(&CriterioResultadoDTO::from(&evaluacion.criterio)).map_err(|e| {
+                error!("Error al serializar el criterio de resultado: {e}");
+                EvaluacionError::EvaluacionRepositorioError(PersistenciaNoFinalizada)
+            })?;
         let documento = doc! {
             "_id": evaluacion.id.to_string(),
             "nombre": evaluacion.nombre,
             "descripcion": evaluacion.descripcion,
+            "duracion_minutos": evaluacion.duracion_minutos.map(i64::from),
+            "criterio_resultado": criterio,
             "esta_activo": evaluacion.esta_activo.to_string(),
             "estado": evaluacion.estado.to_string(),
         };
@@ -180,11 +200,17 @@ impl RepositorioLeerEvaluacion<EvaluacionError> for EvaluacionMongo {
                 let estado = EvaluacionEstado::from_str(estado_str)?;
                 let esta_activo = EstadoGeneral::from_str(esta_activo_str)?;
 
+                // En borrador `examenes` guarda ids; una vez publicada guarda la
+                // copia de cada examen.
                 let examenes_ids = doc
                     .get_array("examenes")
                     .map(|arr| {
                         arr.iter()
-                            .filter_map(|item| item.as_str())
+                            .filter_map(|item| match item {
+                                bson::Bson::String(id) => Some(id.as_str()),
+                                bson::Bson::Document(examen) => examen.get_str("_id").ok(),
+                                _ => None,
+                            })
                             .map(|s| s.to_string())
                             .collect::<Vec<String>>()
                     })
@@ -214,6 +240,18 @@ impl RepositorioLeerEvaluacion<EvaluacionError> for EvaluacionMongo {
                 let mut evaluacion =
                     Evaluacion::new(id.to_string(), nombre.to_string(), descripcion.to_string())?;
                 evaluacion.esta_activo = esta_activo;
+                evaluacion.duracion_minutos = doc
+                    .get_i64("duracion_minutos")
+                    .ok()
+                    .map(|minutos| minutos as u32);
+                if let Ok(criterio) = doc.get_document("criterio_resultado") {
+                    let criterio: CriterioResultadoDTO = bson::from_document(criterio.clone())
+                        .map_err(|e| {
+                            error!("Error al obtener criterio de resultado: {}", e);
+                            EvaluacionError::EvaluacionRepositorioError(PersistenciaNoFinalizada)
+                        })?;
+                    evaluacion.asignar_criterio(criterio.into());
+                }
                 evaluacion.estado = estado;
                 evaluacion.examenes = examenes;
 
@@ -228,7 +266,7 @@ impl RepositorioLeerEvaluacion<EvaluacionError> for EvaluacionMongo {
 
 #[async_trait]
 impl RepositorioPublicarEvaluacion<EvaluacionError> for EvaluacionMongo {
-    async fn publicar_evaluacion(&self, mut evaluacion: Evaluacion) -> Result<(), EvaluacionError> {
+    async fn publicar_evaluacion(&self, evaluacion: Evaluacion) -> Result<(), EvaluacionError> {
         let evaluation_exists = self
             .get_collection()
             .find_one(doc! {
@@ -261,6 +299,7 @@ impl RepositorioPublicarEvaluacion<EvaluacionError> for EvaluacionMongo {
                             "contenido": &pregunta.contenido,
                             "etiqueta": pregunta.etiqueta.to_string(),
                             "tipo_de_pregunta": pregunta.tipo_de_pregunta.to_string(),
+                            "inversa": pregunta.inversa,
                         };
 
                         if let Some(ref imagen) = pregunta.imagen_ref {
@@ -285,6 +324,20 @@ impl RepositorioPublicarEvaluacion<EvaluacionError> for EvaluacionMongo {
                             .collect();
                         pregunta_doc.insert("puntaje", puntaje_doc);
 
+                        if let Some(ref seleccion) = pregunta.seleccion {
+                            pregunta_doc
+                                .insert("seleccion", SeleccionDTO::from(seleccion).to_document());
+                        }
+
+                        if let Some(ordenamiento) = pregunta.ordenamiento {
+                            pregunta_doc.insert("ordenamiento", ordenamiento.to_string());
+                        }
+
+                        if let Some(ref numerica) = pregunta.numerica {
+                            pregunta_doc
+                                .insert("numerica", NumericaDTO::from(numerica).to_document());
+                        }
+
                         pregunta_doc
                     })
                     .collect();
@@ -294,13 +347,32 @@ impl RepositorioPublicarEvaluacion<EvaluacionError> for EvaluacionMongo {
                     "titulo": &examen.titulo,
                     "descripcion": &examen.descripcion,
                     "instrucciones": &examen.instrucciones,
+                    "duracion_minutos": examen.duracion_minutos.map(i64::from),
                     "estado": examen.estado.to_string(),
                     "preguntas": preguntas_docs,
+                    "politica_puntaje": PoliticaPuntajeDTO::from(&examen.politica_puntaje).to_document(),
+                    "aleatorizacion": AleatorizacionDTO::from(&examen.aleatorizacion).to_document(),
+                    "muestreo": examen
+                        .muestreo
+                        .reglas
+                        .iter()
+                        .map(|regla| ReglaMuestreoDTO::from(regla).to_document())
+                        .collect::<Vec<_>>(),
+                    "version": VersionDTO::from(&examen.version).to_document(),
                 }
             })
             .collect();
 
-        evaluacion.publicar();
+        for examen in evaluacion.examenes.examenes() {
+            self.versiones_examen.guardar(examen).await.map_err(|e| {
+                error!(
+                    "Error al congelar la version del examen {}: {}",
+                    examen.id, e
+                );
+                EvaluacionError::EvaluacionRepositorioError(PersistenciaNoFinalizada)
+            })?;
+        }
+
         let update_doc = doc! {
             "$set": {
                 "estado": evaluacion.estado.to_string(),
@@ -337,3 +409,62 @@ impl RepositorioPublicarEvaluacion<EvaluacionError> for EvaluacionMongo {
         }
     }
 }
+
+#[async_trait]
+impl RepositorioCicloEvaluacion<EvaluacionError> for EvaluacionMongo {
+    async fn contar_asignaciones(
+        &self,
+        evaluacion_id: &EvaluacionID,
+    ) -> Result<u64, EvaluacionError> {
+        self.client
+            .database(MAIN_DATABASE_NAME)
+            .collection::<Document>(RESPUESTA_COLLECTION_NAME)
+            .count_documents(doc! { "evaluacion._id": evaluacion_id.to_string() })
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error al contar asignaciones de la evaluacion {}: {}",
+                    evaluacion_id, e
+                );
+                EvaluacionError::EvaluacionRepositorioError(LecturaNoFinalizada)
+            })
+    }
+
+    async fn cambiar_estado(&self, evaluacion: Evaluacion) -> Result<(), EvaluacionError> {
+        let mut cambios = doc! { "estado": evaluacion.estado.to_string() };
+        // De vuelta a borrador la copia publicada deja de servir: se vuelve a
+        // referenciar los examenes vigentes.
+        if evaluacion.estado == EvaluacionEstado::Borrador {
+            let examen_ids: Vec<String> = evaluacion
+                .examenes
+                .examenes()
+                .iter()
+                .map(|examen| examen.id.to_string())
+                .collect();
+            cambios.insert("examenes", examen_ids);
+        }
+
+        let resultado = self
+            .get_collection()
+            .update_one(
+                doc! { "_id": evaluacion.id.to_string() },
+                doc! { "$set": cambios },
+            )
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error al cambiar el estado de la evaluacion {}: {}",
+                    evaluacion.id, e
+                );
+                EvaluacionError::EvaluacionRepositorioError(PersistenciaNoFinalizada)
+            })?;
+
+        if resultado.matched_count == 0 {
+            return Err(EvaluacionError::EvaluacionRepositorioError(
+                EvaluacionNoExiste,
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,10 @@ use crate::controller::evaluacion::mongo::write::EvaluacionMongo;
 use actix_web::{HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_common::use_case::CasoDeUso;
-use quizz_core::evaluacion::use_case::crear_evaluacion::{CrearEvaluacion, InputData};
+use quizz_core::evaluacion::domain::error::evaluacion::EvaluacionError;
+use quizz_core::evaluacion::use_case::crear_evaluacion::{
+    CrearEvaluacion, InputData, InputUmbral, InputUmbralExamen,
+};
 
 pub struct EvaluacionControlller;
 
@@ -25,10 +28,25 @@ impl EvaluacionControlller {
         info!("POST /evaluacion/{}", evaluacion_id);
 
         let dto = body.into_inner();
+        let criterio = dto.criterio_resultado.unwrap_or_default();
         let input = InputData {
             id: evaluacion_id.clone(),
             titulo: dto.titulo,
             descripcion: dto.descripcion,
+            duracion_minutos: dto.duracion_minutos,
+            criterio_total: criterio.total.map(|umbral| InputUmbral {
+                apto: umbral.apto,
+                observado: umbral.observado,
+            }),
+            criterio_examenes: criterio
+                .examenes
+                .into_iter()
+                .map(|regla| InputUmbralExamen {
+                    examen_id: regla.examen_id,
+                    apto: regla.apto,
+                    observado: regla.observado,
+                })
+                .collect(),
         };
 
         let registrar_evaluacion = CrearEvaluacion::new(Box::new(EvaluacionMongo::new(pool)));
@@ -37,6 +55,10 @@ impl EvaluacionControlller {
                 info!("POST /evaluacion/{} - creada exitosamente", evaluacion_id);
                 HttpResponse::Created().finish()
             }
+            Err(e @ EvaluacionError::CriterioNoValido(_)) => {
+                warn!("POST /evaluacion/{} - {}", evaluacion_id, e);
+                HttpResponse::BadRequest().json(e.to_string())
+            }
             Err(e) => {
                 error!(
                     "POST /evaluacion/{} - error al registrar: {}",
@@ -1,5 +1,5 @@
+use crate::controller::evaluacion::ciclo_evaluacion::CicloEvaluacionController;
 use crate::controller::evaluacion::listar_evaluaciones::ListarEvaluacionesController;
-use crate::controller::evaluacion::publicar_evaluacion::PublicarEvaluacionController;
 use crate::controller::evaluacion::registrar_evaluacion::EvaluacionControlller;
 use crate::controller::respuesta::asignar_evaluacion_postulante::AsignarEvaluacionPostulanteController;
 use actix_web::web;
@@ -12,11 +12,19 @@ pub fn evaluacion(cfg: &mut web::ServiceConfig) {
                 web::resource("/{id}")
                     .route(web::post().to(EvaluacionControlller::create))
                     .route(web::put().to(EvaluacionControlller::asociar_examen))
-                    .route(web::patch().to(PublicarEvaluacionController::publicar)),
+                    .route(web::patch().to(CicloEvaluacionController::cambiar_estado)),
             )
             .service(
                 web::resource("/{evaluacion_id}/respuestas")
                     .route(web::post().to(AsignarEvaluacionPostulanteController::create)),
+            )
+            .service(
+                web::resource("/{evaluacion_id}/respuestas/lote")
+                    .route(web::post().to(AsignarEvaluacionPostulanteController::create_batch)),
+            )
+            .service(
+                web::resource("/{evaluacion_id}/respuestas/codigo-acceso")
+                    .route(web::post().to(AsignarEvaluacionPostulanteController::reemitir_codigo)),
             ),
     );
 }
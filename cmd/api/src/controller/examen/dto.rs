@@ -1,6 +1,17 @@
+use crate::controller::pregunta::dto::{NumericaDTO, SeleccionDTO};
+use mongodb::bson::{Document, doc};
+use quizz_core::examen::domain::error::examen::ExamenError;
+use quizz_core::examen::domain::service::contenido::{
+    CambioCampo, CambioPregunta, DiferenciaExamen,
+};
+use quizz_core::examen::domain::value_object::aleatorizacion::Aleatorizacion;
+use quizz_core::examen::domain::value_object::muestreo::ReglaMuestreo;
+use quizz_core::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
+use quizz_core::examen::domain::value_object::version::Version;
 use quizz_core::pregunta::domain::entity::pregunta::PreguntaEntity;
 use quizz_core::pregunta::domain::value_object::etiqueta::Etiqueta;
 use quizz_core::pregunta::domain::value_object::id::PreguntaID;
+use quizz_core::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
 use quizz_core::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -11,6 +22,280 @@ pub struct RegistrarExamenDTO {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
+    #[serde(default)]
+    pub politica_puntaje: Option<PoliticaPuntajeDTO>,
+    #[serde(default)]
+    pub aleatorizacion: Option<AleatorizacionDTO>,
+    #[serde(default)]
+    pub muestreo: Vec<ReglaMuestreoDTO>,
+}
+
+/// Campos a modificar de un examen; los ausentes no cambian.
+#[derive(Deserialize)]
+pub struct ActualizarExamenDTO {
+    #[serde(default)]
+    pub titulo: Option<String>,
+    #[serde(default)]
+    pub descripcion: Option<String>,
+    #[serde(default)]
+    pub instrucciones: Option<String>,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
+    #[serde(default)]
+    pub politica_puntaje: Option<PoliticaPuntajeDTO>,
+    #[serde(default)]
+    pub aleatorizacion: Option<AleatorizacionDTO>,
+    #[serde(default)]
+    pub muestreo: Option<Vec<ReglaMuestreoDTO>>,
+}
+
+#[derive(Deserialize)]
+pub struct ReordenarPreguntasDTO {
+    pub pregunta_ids: Vec<String>,
+}
+
+/// Cuantas preguntas de una etiqueta se sortean para cada postulante.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReglaMuestreoDTO {
+    pub etiqueta: String,
+    pub cantidad: u32,
+}
+
+impl ReglaMuestreoDTO {
+    pub fn to_document(&self) -> Document {
+        doc! {
+            "etiqueta": &self.etiqueta,
+            "cantidad": i64::from(self.cantidad),
+        }
+    }
+}
+
+impl From<&ReglaMuestreo> for ReglaMuestreoDTO {
+    fn from(regla: &ReglaMuestreo) -> Self {
+        Self {
+            etiqueta: regla.etiqueta.to_string(),
+            cantidad: regla.cantidad,
+        }
+    }
+}
+
+impl TryFrom<ReglaMuestreoDTO> for ReglaMuestreo {
+    type Error = ExamenError;
+
+    fn try_from(regla: ReglaMuestreoDTO) -> Result<Self, Self::Error> {
+        Ok(ReglaMuestreo {
+            etiqueta: regla.etiqueta.parse().map_err(|_| {
+                ExamenError::MuestreoNoValido(format!("etiqueta desconocida: {}", regla.etiqueta))
+            })?,
+            cantidad: regla.cantidad,
+        })
+    }
+}
+
+/// Version del contenido del examen. Se copia a la evaluacion al publicarla y
+/// de ahi a cada respuesta asignada.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VersionDTO {
+    pub numero: u32,
+    pub estado: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+impl VersionDTO {
+    pub fn to_document(&self) -> Document {
+        let mut documento = doc! {
+            "numero": i64::from(self.numero),
+            "estado": &self.estado,
+        };
+        if let Some(ref hash) = self.hash {
+            documento.insert("hash", hash);
+        }
+        documento
+    }
+}
+
+impl From<&Version> for VersionDTO {
+    fn from(version: &Version) -> Self {
+        Self {
+            numero: version.numero,
+            estado: version.estado.to_string(),
+            hash: version.hash.clone(),
+        }
+    }
+}
+
+impl TryFrom<VersionDTO> for Version {
+    type Error = ExamenError;
+
+    fn try_from(version: VersionDTO) -> Result<Self, Self::Error> {
+        Ok(Version {
+            numero: version.numero,
+            estado: version.estado.parse()?,
+            hash: version.hash,
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct CambioCampoDTO {
+    pub campo: String,
+    pub antes: String,
+    pub despues: String,
+}
+
+impl From<CambioCampo> for CambioCampoDTO {
+    fn from(cambio: CambioCampo) -> Self {
+        Self {
+            campo: cambio.campo,
+            antes: cambio.antes,
+            despues: cambio.despues,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CambioPreguntaDTO {
+    pub id: String,
+    pub campos: Vec<CambioCampoDTO>,
+}
+
+impl From<CambioPregunta> for CambioPreguntaDTO {
+    fn from(cambio: CambioPregunta) -> Self {
+        Self {
+            id: cambio.id,
+            campos: cambio.campos.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DiferenciaPreguntasDTO {
+    pub agregadas: Vec<String>,
+    pub eliminadas: Vec<String>,
+    pub modificadas: Vec<CambioPreguntaDTO>,
+    pub orden_cambiado: bool,
+}
+
+#[derive(Serialize)]
+pub struct DiferenciaExamenDTO {
+    pub examen_id: String,
+    pub desde: u32,
+    pub hasta: u32,
+    pub sin_cambios: bool,
+    pub campos: Vec<CambioCampoDTO>,
+    pub preguntas: DiferenciaPreguntasDTO,
+}
+
+impl DiferenciaExamenDTO {
+    pub fn new(examen_id: String, desde: u32, hasta: u32, diferencia: DiferenciaExamen) -> Self {
+        Self {
+            examen_id,
+            desde,
+            hasta,
+            sin_cambios: diferencia.sin_cambios(),
+            campos: diferencia.campos.into_iter().map(Into::into).collect(),
+            preguntas: DiferenciaPreguntasDTO {
+                agregadas: diferencia.preguntas_agregadas,
+                eliminadas: diferencia.preguntas_eliminadas,
+                modificadas: diferencia
+                    .preguntas_modificadas
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                orden_cambiado: diferencia.orden_cambiado,
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CompararVersionesQuery {
+    pub desde: u32,
+    pub hasta: u32,
+}
+
+/// Si cada postulante ve las preguntas y/o las alternativas en un orden propio.
+/// Se copia a la evaluacion al publicarla y se aplica al asignarla.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(default)]
+pub struct AleatorizacionDTO {
+    pub preguntas: bool,
+    pub alternativas: bool,
+}
+
+impl AleatorizacionDTO {
+    pub fn to_document(self) -> Document {
+        doc! {
+            "preguntas": self.preguntas,
+            "alternativas": self.alternativas,
+        }
+    }
+}
+
+impl From<&Aleatorizacion> for AleatorizacionDTO {
+    fn from(aleatorizacion: &Aleatorizacion) -> Self {
+        Self {
+            preguntas: aleatorizacion.preguntas,
+            alternativas: aleatorizacion.alternativas,
+        }
+    }
+}
+
+impl From<AleatorizacionDTO> for Aleatorizacion {
+    fn from(aleatorizacion: AleatorizacionDTO) -> Self {
+        Aleatorizacion::new(aleatorizacion.preguntas, aleatorizacion.alternativas)
+    }
+}
+
+/// Politica de puntaje del examen para sus preguntas de alternativa unica.
+/// Se guarda en el examen y se copia a la evaluacion al publicarla.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct PoliticaPuntajeDTO {
+    pub penalizacion_incorrecta: u32,
+    pub en_blanco: String,
+    pub piso_cero: bool,
+}
+
+impl Default for PoliticaPuntajeDTO {
+    fn default() -> Self {
+        Self::from(&PoliticaPuntaje::default())
+    }
+}
+
+impl PoliticaPuntajeDTO {
+    pub fn to_document(&self) -> Document {
+        doc! {
+            "penalizacion_incorrecta": i64::from(self.penalizacion_incorrecta),
+            "en_blanco": &self.en_blanco,
+            "piso_cero": self.piso_cero,
+        }
+    }
+}
+
+impl From<&PoliticaPuntaje> for PoliticaPuntajeDTO {
+    fn from(politica: &PoliticaPuntaje) -> Self {
+        Self {
+            penalizacion_incorrecta: politica.penalizacion_incorrecta,
+            en_blanco: politica.en_blanco.to_string(),
+            piso_cero: politica.piso_cero,
+        }
+    }
+}
+
+impl TryFrom<PoliticaPuntajeDTO> for PoliticaPuntaje {
+    type Error = ExamenError;
+
+    fn try_from(politica: PoliticaPuntajeDTO) -> Result<Self, Self::Error> {
+        Ok(PoliticaPuntaje::new(
+            politica.penalizacion_incorrecta,
+            politica.en_blanco.parse()?,
+            politica.piso_cero,
+        ))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,9 +304,18 @@ pub struct PreguntaMongoDTO {
     contenido: String,
     etiqueta: String,
     tipo_de_pregunta: String,
+    #[serde(default)]
     imagen_ref: String,
     alternativas: HashMap<String, String>,
     puntaje: HashMap<String, i32>,
+    #[serde(default)]
+    inversa: bool,
+    #[serde(default)]
+    seleccion: Option<SeleccionDTO>,
+    #[serde(default)]
+    ordenamiento: Option<String>,
+    #[serde(default)]
+    numerica: Option<NumericaDTO>,
 }
 
 impl PreguntaMongoDTO {
@@ -50,6 +344,13 @@ impl PreguntaMongoDTO {
             tipo_de_pregunta,
             alternativas: self.alternativas,
             puntaje,
+            inversa: self.inversa,
+            seleccion: self.seleccion.map(TryInto::try_into).transpose()?,
+            ordenamiento: self
+                .ordenamiento
+                .map(|modo| modo.parse::<ModoOrdenamiento>())
+                .transpose()?,
+            numerica: self.numerica.map(TryInto::try_into).transpose()?,
         })
     }
 }
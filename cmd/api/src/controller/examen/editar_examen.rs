@@ -0,0 +1,142 @@
+use crate::controller::examen::dto::{ActualizarExamenDTO, ReordenarPreguntasDTO};
+use crate::controller::examen::mongo::write::ExamenMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::examen::domain::error::examen::ExamenError;
+use quizz_core::examen::use_case::actualizar_examen::{self, ActualizarExamen};
+use quizz_core::examen::use_case::crear_examen::{
+    InputAleatorizacion, InputPoliticaPuntaje, InputReglaMuestreo,
+};
+use quizz_core::examen::use_case::desactivar_examen::{self, DesactivarExamen};
+use quizz_core::examen::use_case::reordenar_preguntas::{self, ReordenarPreguntas};
+
+pub struct EditarExamenController;
+
+impl EditarExamenController {
+    pub async fn update(
+        req: HttpRequest,
+        body: web::Json<ActualizarExamenDTO>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let Some(examen_id) = examen_id(&req) else {
+            return HttpResponse::BadRequest().json("no se esta enviando el id del examen");
+        };
+
+        info!("PATCH /examenes/{}", examen_id);
+
+        let actualizar = ActualizarExamen::new(Box::new(ExamenMongo::new(pool)));
+        let dto = body.into_inner();
+        let input = actualizar_examen::InputData {
+            id: examen_id.clone(),
+            titulo: dto.titulo,
+            descripcion: dto.descripcion,
+            instrucciones: dto.instrucciones,
+            duracion_minutos: dto.duracion_minutos,
+            politica_puntaje: dto.politica_puntaje.map(|politica| InputPoliticaPuntaje {
+                penalizacion_incorrecta: politica.penalizacion_incorrecta,
+                en_blanco: politica.en_blanco,
+                piso_cero: politica.piso_cero,
+            }),
+            aleatorizacion: dto
+                .aleatorizacion
+                .map(|aleatorizacion| InputAleatorizacion {
+                    preguntas: aleatorizacion.preguntas,
+                    alternativas: aleatorizacion.alternativas,
+                }),
+            muestreo: dto.muestreo.map(|muestreo| {
+                muestreo
+                    .into_iter()
+                    .map(|regla| InputReglaMuestreo {
+                        etiqueta: regla.etiqueta,
+                        cantidad: regla.cantidad,
+                    })
+                    .collect()
+            }),
+        };
+
+        match actualizar.ejecutar(input).await {
+            Ok(_) => {
+                info!("PATCH /examenes/{} - actualizado exitosamente", examen_id);
+                HttpResponse::Ok().finish()
+            }
+            Err(e) => respuesta_error("PATCH", &examen_id, e),
+        }
+    }
+
+    pub async fn reorder(
+        req: HttpRequest,
+        body: web::Json<ReordenarPreguntasDTO>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let Some(examen_id) = examen_id(&req) else {
+            return HttpResponse::BadRequest().json("no se esta enviando el id del examen");
+        };
+
+        info!("PUT /examenes/{}/orden", examen_id);
+
+        let reordenar = ReordenarPreguntas::new(Box::new(ExamenMongo::new(pool)));
+        let input = reordenar_preguntas::InputData {
+            id: examen_id.clone(),
+            pregunta_ids: body.into_inner().pregunta_ids,
+        };
+
+        match reordenar.ejecutar(input).await {
+            Ok(_) => {
+                info!(
+                    "PUT /examenes/{}/orden - reordenado exitosamente",
+                    examen_id
+                );
+                HttpResponse::Ok().finish()
+            }
+            Err(e) => respuesta_error("PUT", &examen_id, e),
+        }
+    }
+
+    pub async fn deactivate(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        let Some(examen_id) = examen_id(&req) else {
+            return HttpResponse::BadRequest().json("no se esta enviando el id del examen");
+        };
+
+        info!("DELETE /examenes/{}", examen_id);
+
+        let desactivar = DesactivarExamen::new(Box::new(ExamenMongo::new(pool)));
+        let input = desactivar_examen::InputData {
+            id: examen_id.clone(),
+        };
+
+        match desactivar.ejecutar(input).await {
+            Ok(_) => {
+                info!("DELETE /examenes/{} - desactivado exitosamente", examen_id);
+                HttpResponse::NoContent().finish()
+            }
+            Err(e) => respuesta_error("DELETE", &examen_id, e),
+        }
+    }
+}
+
+fn examen_id(req: &HttpRequest) -> Option<String> {
+    req.match_info().get("id").map(ToString::to_string)
+}
+
+fn respuesta_error(metodo: &str, examen_id: &str, e: ExamenError) -> HttpResponse {
+    match e {
+        ExamenError::NoEncontrado => {
+            warn!("{} /examenes/{} - no encontrado", metodo, examen_id);
+            HttpResponse::NotFound().json("el examen no existe")
+        }
+        e @ (ExamenError::TituloInvalido
+        | ExamenError::DescripcionInvalida
+        | ExamenError::DuracionInvalida
+        | ExamenError::PoliticaPuntajeNoValida(_)
+        | ExamenError::MuestreoNoValido(_)
+        | ExamenError::OrdenNoValido(_)) => {
+            warn!("{} /examenes/{} - {}", metodo, examen_id, e);
+            HttpResponse::BadRequest().json(e.to_string())
+        }
+        e => {
+            error!("{} /examenes/{} - error: {}", metodo, examen_id, e);
+            HttpResponse::InternalServerError().json("error al actualizar el examen")
+        }
+    }
+}
@@ -1,5 +1,7 @@
-mod dto;
+pub mod dto;
+mod editar_examen;
 mod listar_examenes;
 pub mod mongo;
 mod registrar_examen;
 pub mod route;
+mod versiones_examen;
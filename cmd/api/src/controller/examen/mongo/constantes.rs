@@ -1 +1,2 @@
 pub const EXAMEN_COLLECTION_NAME: &str = "examen";
+pub const EXAMEN_VERSION_COLLECTION_NAME: &str = "examen_version";
@@ -1,3 +1,4 @@
 mod constantes;
 pub mod read;
+pub mod version;
 pub mod write;
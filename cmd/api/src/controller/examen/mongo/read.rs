@@ -1,10 +1,13 @@
-use crate::controller::examen::dto::PreguntaMongoDTO;
+use crate::controller::examen::dto::{
+    AleatorizacionDTO, PoliticaPuntajeDTO, PreguntaMongoDTO, ReglaMuestreoDTO, VersionDTO,
+};
 use crate::controller::examen::mongo::write::ExamenMongo;
 use crate::controller::mongo_repository::MongoRepository;
+use crate::controller::pregunta::mongo::banco::BancoPreguntasMongo;
 use async_trait::async_trait;
 use log::error;
 use mongodb::bson;
-use mongodb::bson::doc;
+use mongodb::bson::{Document, doc};
 use quizz_common::domain::value_objects::estado::EstadoGeneral;
 use quizz_core::examen::domain::entity::examen::Examen;
 use quizz_core::examen::domain::error::examen::ExamenError;
@@ -12,6 +15,9 @@ use quizz_core::examen::domain::error::examen::RepositorioError::{
     LecturaNoFinalizada, PersistenciaNoFinalizada,
 };
 use quizz_core::examen::domain::value_object::id::ExamenID;
+use quizz_core::examen::domain::value_object::muestreo::{Muestreo, ReglaMuestreo};
+use quizz_core::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
+use quizz_core::examen::domain::value_object::version::Version;
 use quizz_core::examen::provider::repositorio::{
     RepositorioExamenLectura, RepositorioExamenListar,
 };
@@ -27,67 +33,56 @@ impl RepositorioExamenLectura<ExamenError> for ExamenMongo {
 
         match self.get_collection().find_one(filter).await {
             Ok(Some(documento)) => {
-                let id_str = documento
-                    .get_str("_id")
-                    .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?;
-
-                let titulo = documento
-                    .get_str("titulo")
-                    .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
-                    .to_string();
-
-                let descripcion = documento
-                    .get_str("descripcion")
-                    .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
-                    .to_string();
-
-                let instrucciones = documento
-                    .get_str("instrucciones")
-                    .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
-                    .to_string();
-
-                let estado_str = documento
-                    .get_str("activo")
-                    .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?;
-
-                let estado = EstadoGeneral::from_str(estado_str)?;
-                let examen_id = ExamenID::new(id_str)?;
-
-                let preguntas = match documento.get("preguntas") {
-                    Some(bson::Bson::Array(arr)) => {
-                        let entities_result: Result<Vec<PreguntaEntity>, _> = arr
-                            .iter()
-                            .filter_map(|item| bson::from_bson(item.clone()).ok())
-                            .map(|dto: PreguntaMongoDTO| dto.into_entity())
-                            .collect();
-
-                        match entities_result {
-                            Ok(entities) => ListaDePreguntas::new(entities),
-                            Err(e) => {
-                                error!("Error converting preguntas to entities: {}", e);
-                                return Err(ExamenError::ExamenRepositorioError(
-                                    PersistenciaNoFinalizada,
-                                ));
-                            }
-                        }
-                    }
-                    _ => ListaDePreguntas::new(Vec::new()),
-                };
-
-                Ok(Examen {
-                    id: examen_id,
-                    titulo,
-                    descripcion,
-                    instrucciones,
-                    estado,
-                    preguntas,
-                })
+                let mut examen = documento_a_examen(&documento)?;
+
+                // Las preguntas del banco se resuelven al leer el examen; la
+                // evaluacion guarda su propia copia al publicarse.
+                let preguntas_banco = documento
+                    .get_array("preguntas_banco")
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|item| item.as_str())
+                            .map(ToString::to_string)
+                            .collect::<Vec<String>>()
+                    })
+                    .unwrap_or_default();
+                if !preguntas_banco.is_empty() {
+                    let mut todas = examen.preguntas.preguntas().clone();
+                    todas.extend(
+                        BancoPreguntasMongo::new(self.get_client().clone())
+                            .obtener_varias(&preguntas_banco)
+                            .await
+                            .map_err(|e| {
+                                error!("Error al obtener preguntas del banco: {}", e);
+                                ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+                            })?,
+                    );
+                    examen.preguntas = ListaDePreguntas::new(todas);
+                }
+
+                // orden guardado al reordenar; se ignoran las preguntas ya
+                // quitadas y las agregadas despues quedan al final
+                if let Ok(orden) = documento.get_array("orden") {
+                    let orden: Vec<String> = orden
+                        .iter()
+                        .filter_map(|item| item.as_str())
+                        .filter(|id| {
+                            examen
+                                .preguntas
+                                .preguntas()
+                                .iter()
+                                .any(|pregunta| pregunta.id.to_string() == *id)
+                        })
+                        .map(ToString::to_string)
+                        .collect();
+                    examen.reordenar_preguntas(&orden)?;
+                }
+
+                Ok(examen)
             }
             Ok(None) => {
                 error!("Examen not found with id: {}", id);
-                Err(ExamenError::ExamenRepositorioError(
-                    PersistenciaNoFinalizada,
-                ))
+                Err(ExamenError::NoEncontrado)
             }
             Err(e) => {
                 error!(
@@ -146,10 +141,13 @@ impl RepositorioExamenListar<ExamenError> for ExamenMongo {
                 .map_err(|_| ExamenError::ExamenRepositorioError(LecturaNoFinalizada))?
                 .to_string();
 
-            let cantidad_preguntas = match documento.get("preguntas") {
-                Some(bson::Bson::Array(arr)) => arr.len(),
-                _ => 0,
-            };
+            let cantidad_preguntas = ["preguntas", "preguntas_banco"]
+                .iter()
+                .map(|campo| match documento.get(campo) {
+                    Some(bson::Bson::Array(arr)) => arr.len(),
+                    _ => 0,
+                })
+                .sum();
 
             examenes.push(OutputData {
                 id,
@@ -165,4 +163,115 @@ impl RepositorioExamenListar<ExamenError> for ExamenMongo {
     }
 }
 
-impl ExamenMongo {}
+/// Convierte un documento de examen sin resolver las preguntas del banco. Las
+/// versiones congeladas ya guardan sus preguntas completas.
+pub(crate) fn documento_a_examen(documento: &Document) -> Result<Examen, ExamenError> {
+    let id_str = documento
+        .get_str("_id")
+        .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?;
+
+    let titulo = documento
+        .get_str("titulo")
+        .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
+        .to_string();
+
+    let descripcion = documento
+        .get_str("descripcion")
+        .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
+        .to_string();
+
+    let instrucciones = documento
+        .get_str("instrucciones")
+        .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?
+        .to_string();
+
+    let estado_str = documento
+        .get_str("activo")
+        .map_err(|_| ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada))?;
+
+    let duracion_minutos = documento
+        .get_i64("duracion_minutos")
+        .ok()
+        .map(|minutos| minutos as u32);
+
+    let politica_puntaje = match documento.get_document("politica_puntaje") {
+        Ok(politica) => bson::from_document::<PoliticaPuntajeDTO>(politica.clone())
+            .map_err(|e| {
+                error!("Error al obtener politica de puntaje: {}", e);
+                ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+            })?
+            .try_into()?,
+        Err(_) => PoliticaPuntaje::default(),
+    };
+
+    let aleatorizacion = documento
+        .get_document("aleatorizacion")
+        .ok()
+        .and_then(|aleatorizacion| {
+            bson::from_document::<AleatorizacionDTO>(aleatorizacion.clone()).ok()
+        })
+        .unwrap_or_default()
+        .into();
+
+    let muestreo = match documento.get("muestreo") {
+        Some(muestreo) => Muestreo::new(
+            bson::from_bson::<Vec<ReglaMuestreoDTO>>(muestreo.clone())
+                .map_err(|e| {
+                    error!("Error al obtener muestreo: {}", e);
+                    ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+                })?
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<ReglaMuestreo>, ExamenError>>()?,
+        )?,
+        None => Muestreo::default(),
+    };
+
+    let estado = EstadoGeneral::from_str(estado_str)?;
+    let examen_id = ExamenID::new(id_str)?;
+
+    let preguntas = match documento.get("preguntas") {
+        Some(bson::Bson::Array(arr)) => {
+            let entities_result: Result<Vec<PreguntaEntity>, _> = arr
+                .iter()
+                .filter_map(|item| bson::from_bson(item.clone()).ok())
+                .map(|dto: PreguntaMongoDTO| dto.into_entity())
+                .collect();
+
+            match entities_result {
+                Ok(entities) => ListaDePreguntas::new(entities),
+                Err(e) => {
+                    error!("Error converting preguntas to entities: {}", e);
+                    return Err(ExamenError::ExamenRepositorioError(
+                        PersistenciaNoFinalizada,
+                    ));
+                }
+            }
+        }
+        _ => ListaDePreguntas::new(Vec::new()),
+    };
+
+    let version = match documento.get_document("version") {
+        Ok(version) => bson::from_document::<VersionDTO>(version.clone())
+            .map_err(|e| {
+                error!("Error al obtener version: {}", e);
+                ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+            })?
+            .try_into()?,
+        Err(_) => Version::default(),
+    };
+
+    Ok(Examen {
+        id: examen_id,
+        titulo,
+        descripcion,
+        instrucciones,
+        duracion_minutos,
+        estado,
+        preguntas,
+        politica_puntaje,
+        aleatorizacion,
+        muestreo,
+        version,
+    })
+}
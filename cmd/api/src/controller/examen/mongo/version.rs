@@ -0,0 +1,151 @@
+use crate::controller::examen::dto::VersionDTO;
+use crate::controller::examen::mongo::constantes::{
+    EXAMEN_COLLECTION_NAME, EXAMEN_VERSION_COLLECTION_NAME,
+};
+use crate::controller::examen::mongo::read::documento_a_examen;
+use crate::controller::examen::mongo::write::examen_to_document;
+use crate::controller::mongo_repository::{MAIN_DATABASE_NAME, MongoRepository};
+use actix_web::web;
+use async_trait::async_trait;
+use log::error;
+use mongodb::bson;
+use mongodb::bson::{Document, doc};
+use quizz_common::domain::value_objects::zona_horaria::{ahora_lima, formatear_rfc3339};
+use quizz_core::examen::domain::entity::examen::Examen;
+use quizz_core::examen::domain::error::examen::ExamenError;
+use quizz_core::examen::domain::error::examen::RepositorioError::{
+    LecturaNoFinalizada, PersistenciaNoFinalizada,
+};
+use quizz_core::examen::domain::value_object::version::Version;
+use quizz_core::examen::provider::repositorio::RepositorioVersionesExamen;
+
+/// Versiones congeladas de los examenes. Cada documento guarda el examen
+/// completo tal como se publico, con `_id` `{examen_id}:{numero}`.
+pub struct VersionesExamenMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl VersionesExamenMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+
+    /// Guarda la version congelada y la marca como vigente en el examen.
+    /// Publicar otra evaluacion con la misma version no la duplica.
+    pub async fn guardar(&self, examen: &Examen) -> Result<(), mongodb::error::Error> {
+        let examen_id = examen.id.to_string();
+        let version = VersionDTO::from(&examen.version);
+
+        self.get_collection()
+            .update_one(
+                doc! { "_id": format!("{}:{}", examen_id, version.numero) },
+                doc! {
+                    "$setOnInsert": {
+                        "examen_id": &examen_id,
+                        "numero": i64::from(version.numero),
+                        "hash": version.hash.clone(),
+                        "publicada_en": formatear_rfc3339(&ahora_lima()),
+                        "examen": examen_to_document(examen),
+                    }
+                },
+            )
+            .upsert(true)
+            .await?;
+
+        self.client
+            .database(MAIN_DATABASE_NAME)
+            .collection::<Document>(EXAMEN_COLLECTION_NAME)
+            .update_one(
+                doc! { "_id": &examen_id },
+                doc! { "$set": { "version": version.to_document() } },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl MongoRepository for VersionesExamenMongo {
+    fn get_collection_name(&self) -> &str {
+        EXAMEN_VERSION_COLLECTION_NAME
+    }
+
+    fn get_client(&self) -> &web::Data<mongodb::Client> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl RepositorioVersionesExamen<ExamenError> for VersionesExamenMongo {
+    async fn obtener_version(
+        &self,
+        examen_id: &str,
+        numero: u32,
+    ) -> Result<Option<Examen>, ExamenError> {
+        let documento = self
+            .get_collection()
+            .find_one(doc! { "_id": format!("{}:{}", examen_id, numero) })
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error al buscar la version {} del examen {}: {}",
+                    numero, examen_id, e
+                );
+                ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+            })?;
+
+        match documento {
+            Some(documento) => {
+                let examen = documento.get_document("examen").map_err(|e| {
+                    error!(
+                        "Version {} del examen {} sin contenido: {}",
+                        numero, examen_id, e
+                    );
+                    ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+                })?;
+                Ok(Some(documento_a_examen(examen)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn listar_versiones(&self, examen_id: &str) -> Result<Vec<Version>, ExamenError> {
+        let mut cursor = self
+            .get_collection()
+            .find(doc! { "examen_id": examen_id })
+            .projection(doc! { "examen.version": 1 })
+            .await
+            .map_err(|e| {
+                error!("Error al listar versiones del examen {}: {}", examen_id, e);
+                ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+            })?;
+
+        let mut versiones = Vec::new();
+        while cursor.advance().await.map_err(|e| {
+            error!(
+                "Error al recorrer versiones del examen {}: {}",
+                examen_id, e
+            );
+            ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+        })? {
+            let documento = cursor.deserialize_current().map_err(|e| {
+                error!("Error al leer version del examen {}: {}", examen_id, e);
+                ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+            })?;
+            let version = documento
+                .get_document("examen")
+                .and_then(|examen| examen.get_document("version"))
+                .map_err(|e| {
+                    error!("Version del examen {} sin numero: {}", examen_id, e);
+                    ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+                })?;
+            let version: VersionDTO = bson::from_document(version.clone()).map_err(|e| {
+                error!("Error al leer version del examen {}: {}", examen_id, e);
+                ExamenError::ExamenRepositorioError(LecturaNoFinalizada)
+            })?;
+            versiones.push(version.try_into()?);
+        }
+
+        Ok(versiones)
+    }
+}
@@ -1,13 +1,20 @@
+use crate::controller::examen::dto::{
+    AleatorizacionDTO, PoliticaPuntajeDTO, ReglaMuestreoDTO, VersionDTO,
+};
 use crate::controller::examen::mongo::constantes::EXAMEN_COLLECTION_NAME;
 use crate::controller::mongo_repository::MongoRepository;
+use crate::controller::pregunta::mongo::write::pregunta_to_document;
 use actix_web::web;
 use async_trait::async_trait;
 use log::error;
-use mongodb::bson::doc;
+use mongodb::bson::{Document, doc};
 use quizz_core::examen::domain::entity::examen::Examen;
 use quizz_core::examen::domain::error::examen::ExamenError;
 use quizz_core::examen::domain::error::examen::RepositorioError::PersistenciaNoFinalizada;
-use quizz_core::examen::provider::repositorio::RepositorioExamenEscritura;
+use quizz_core::examen::domain::value_object::version::EstadoVersion;
+use quizz_core::examen::provider::repositorio::{
+    RepositorioExamenActualizar, RepositorioExamenEscritura,
+};
 
 pub struct ExamenMongo {
     client: web::Data<mongodb::Client>,
@@ -17,6 +24,53 @@ impl ExamenMongo {
     pub fn new(client: web::Data<mongodb::Client>) -> Self {
         ExamenMongo { client }
     }
+
+    /// Tras editar un examen cuya version ya se publico abre un borrador con
+    /// el numero siguiente, igual que `Version::editar`.
+    pub async fn abrir_borrador(&self, examen_id: &str) -> Result<(), mongodb::error::Error> {
+        self.get_collection()
+            .update_one(
+                doc! {
+                    "_id": examen_id,
+                    "version.estado": EstadoVersion::Publicada.to_string(),
+                },
+                doc! {
+                    "$inc": { "version.numero": 1 },
+                    "$set": { "version.estado": EstadoVersion::Borrador.to_string() },
+                    "$unset": { "version.hash": "" },
+                },
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Documento completo del examen, con las preguntas ya resueltas. Es el formato
+/// de la coleccion de examenes y de las versiones congeladas.
+pub(crate) fn examen_to_document(examen: &Examen) -> Document {
+    doc! {
+        "_id": examen.id.value().uuid().to_string(),
+        "titulo": examen.titulo.to_string(),
+        "descripcion": examen.descripcion.to_string(),
+        "instrucciones": examen.instrucciones.to_string(),
+        "duracion_minutos": examen.duracion_minutos.map(i64::from),
+        "activo": examen.estado.to_string(),
+        "politica_puntaje": PoliticaPuntajeDTO::from(&examen.politica_puntaje).to_document(),
+        "aleatorizacion": AleatorizacionDTO::from(&examen.aleatorizacion).to_document(),
+        "muestreo": examen
+            .muestreo
+            .reglas
+            .iter()
+            .map(|regla| ReglaMuestreoDTO::from(regla).to_document())
+            .collect::<Vec<_>>(),
+        "preguntas": examen
+            .preguntas
+            .preguntas()
+            .iter()
+            .map(pregunta_to_document)
+            .collect::<Vec<_>>(),
+        "version": VersionDTO::from(&examen.version).to_document(),
+    }
 }
 
 impl MongoRepository for ExamenMongo {
@@ -32,13 +86,7 @@ impl MongoRepository for ExamenMongo {
 #[async_trait]
 impl RepositorioExamenEscritura<ExamenError> for ExamenMongo {
     async fn guardar_examen(&self, examen: Examen) -> Result<(), ExamenError> {
-        let documento = doc! {
-            "_id": examen.id.value().uuid().to_string(),
-            "titulo": examen.titulo.to_string(),
-            "descripcion": examen.descripcion.to_string(),
-            "instrucciones": examen.instrucciones.to_string(),
-            "activo": examen.estado.to_string(),
-        };
+        let documento = examen_to_document(&examen);
 
         match self.get_collection().insert_one(documento).await {
             Ok(_) => Ok(()),
@@ -55,3 +103,41 @@ impl RepositorioExamenEscritura<ExamenError> for ExamenMongo {
         }
     }
 }
+
+#[async_trait]
+impl RepositorioExamenActualizar<ExamenError> for ExamenMongo {
+    async fn actualizar_examen(&self, examen: Examen) -> Result<(), ExamenError> {
+        let mut datos = examen_to_document(&examen);
+        datos.remove("_id");
+        datos.remove("preguntas");
+        datos.insert(
+            "orden",
+            examen
+                .preguntas
+                .preguntas()
+                .iter()
+                .map(|pregunta| pregunta.id.to_string())
+                .collect::<Vec<_>>(),
+        );
+
+        let resultado = self
+            .get_collection()
+            .update_one(
+                doc! { "_id": examen.id.to_string() },
+                doc! { "$set": datos },
+            )
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error while updating examen: id={}, error={}",
+                    examen.id, e
+                );
+                ExamenError::ExamenRepositorioError(PersistenciaNoFinalizada)
+            })?;
+
+        if resultado.matched_count == 0 {
+            return Err(ExamenError::NoEncontrado);
+        }
+        Ok(())
+    }
+}
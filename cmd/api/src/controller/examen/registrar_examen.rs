@@ -3,7 +3,10 @@ use crate::controller::examen::mongo::write::ExamenMongo;
 use actix_web::{HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_common::use_case::CasoDeUso;
-use quizz_core::examen::use_case::crear_examen::{CrearExamen, InputData};
+use quizz_core::examen::domain::error::examen::ExamenError;
+use quizz_core::examen::use_case::crear_examen::{
+    CrearExamen, InputAleatorizacion, InputData, InputPoliticaPuntaje, InputReglaMuestreo,
+};
 
 pub struct ExamenControlller;
 
@@ -30,6 +33,26 @@ impl ExamenControlller {
             titulo: dto.titulo,
             descripcion: dto.descripcion,
             instrucciones: dto.instrucciones,
+            duracion_minutos: dto.duracion_minutos,
+            politica_puntaje: dto.politica_puntaje.map(|politica| InputPoliticaPuntaje {
+                penalizacion_incorrecta: politica.penalizacion_incorrecta,
+                en_blanco: politica.en_blanco,
+                piso_cero: politica.piso_cero,
+            }),
+            aleatorizacion: dto
+                .aleatorizacion
+                .map(|aleatorizacion| InputAleatorizacion {
+                    preguntas: aleatorizacion.preguntas,
+                    alternativas: aleatorizacion.alternativas,
+                }),
+            muestreo: dto
+                .muestreo
+                .into_iter()
+                .map(|regla| InputReglaMuestreo {
+                    etiqueta: regla.etiqueta,
+                    cantidad: regla.cantidad,
+                })
+                .collect(),
         };
 
         match registrar_examen.ejecutar(input).await {
@@ -37,6 +60,12 @@ impl ExamenControlller {
                 info!("POST /examen/{} - creado exitosamente", examen_id);
                 HttpResponse::Created().finish()
             }
+            Err(
+                e @ (ExamenError::PoliticaPuntajeNoValida(_) | ExamenError::MuestreoNoValido(_)),
+            ) => {
+                warn!("POST /examen/{} - {}", examen_id, e);
+                HttpResponse::BadRequest().json(e.to_string())
+            }
             Err(e) => {
                 error!("POST /examen/{} - error al registrar: {}", examen_id, e);
                 HttpResponse::InternalServerError().json("error al registrar el examen")
@@ -1,6 +1,10 @@
+use crate::controller::examen::editar_examen::EditarExamenController;
 use crate::controller::examen::listar_examenes::ListarExamenesController;
 use crate::controller::examen::registrar_examen::ExamenControlller;
+use crate::controller::examen::versiones_examen::VersionesExamenController;
 use crate::controller::pregunta::agregar_pregunta::AgregarPreguntaController;
+use crate::controller::pregunta::pregunta_examen::PreguntaExamenController;
+use crate::controller::pregunta::vincular_preguntas_banco::VincularPreguntasBancoController;
 use actix_web::web;
 
 pub fn examen(cfg: &mut web::ServiceConfig) {
@@ -10,7 +14,29 @@ pub fn examen(cfg: &mut web::ServiceConfig) {
             .service(
                 web::resource("/{id}")
                     .route(web::post().to(ExamenControlller::create))
-                    .route(web::put().to(AgregarPreguntaController::create)),
+                    .route(web::put().to(AgregarPreguntaController::create))
+                    .route(web::patch().to(EditarExamenController::update))
+                    .route(web::delete().to(EditarExamenController::deactivate)),
+            )
+            .service(
+                web::resource("/{id}/orden").route(web::put().to(EditarExamenController::reorder)),
+            )
+            .service(
+                web::resource("/{id}/preguntas/{pregunta_id}")
+                    .route(web::put().to(PreguntaExamenController::update))
+                    .route(web::delete().to(PreguntaExamenController::delete)),
+            )
+            .service(
+                web::resource("/{id}/banco")
+                    .route(web::put().to(VincularPreguntasBancoController::update)),
+            )
+            .service(
+                web::resource("/{id}/versiones")
+                    .route(web::get().to(VersionesExamenController::list)),
+            )
+            .service(
+                web::resource("/{id}/versiones/comparar")
+                    .route(web::get().to(VersionesExamenController::compare)),
             ),
     );
 }
@@ -0,0 +1,115 @@
+use crate::controller::examen::dto::{CompararVersionesQuery, DiferenciaExamenDTO, VersionDTO};
+use crate::controller::examen::mongo::version::VersionesExamenMongo;
+use crate::controller::examen::mongo::write::ExamenMongo;
+use crate::controller::hateoas::{Link, Links, ListResponse};
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::examen::domain::error::examen::ExamenError;
+use quizz_core::examen::use_case::{comparar_versiones, listar_versiones};
+use serde_json::json;
+
+pub struct VersionesExamenController;
+
+impl VersionesExamenController {
+    pub async fn list(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        let examen_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("GET /examenes/versiones - id no proporcionado");
+                return HttpResponse::BadRequest().json("no se esta enviando el id del examen");
+            }
+        };
+
+        info!("GET /examenes/{}/versiones", examen_id);
+
+        let listar = listar_versiones::ListarVersiones::new(
+            Box::new(ExamenMongo::new(pool.clone())),
+            Box::new(VersionesExamenMongo::new(pool)),
+        );
+
+        match listar
+            .ejecutar(listar_versiones::InputData {
+                examen_id: examen_id.clone(),
+            })
+            .await
+        {
+            Ok(versiones) => {
+                let items: Vec<VersionDTO> = versiones.iter().map(VersionDTO::from).collect();
+
+                let mut links = Links::new();
+                links.insert(
+                    "self".into(),
+                    Link::get(format!("/examenes/{}/versiones", examen_id)),
+                );
+                links.insert(
+                    "compare".into(),
+                    Link::get(format!(
+                        "/examenes/{}/versiones/comparar?desde={{desde}}&hasta={{hasta}}",
+                        examen_id
+                    )),
+                );
+
+                HttpResponse::Ok().json(ListResponse { links, items })
+            }
+            Err(e) => {
+                error!("GET /examenes/{}/versiones - error: {}", examen_id, e);
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al obtener las versiones del examen"}))
+            }
+        }
+    }
+
+    pub async fn compare(
+        req: HttpRequest,
+        query: web::Query<CompararVersionesQuery>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let examen_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("GET /examenes/versiones/comparar - id no proporcionado");
+                return HttpResponse::BadRequest().json("no se esta enviando el id del examen");
+            }
+        };
+
+        let query = query.into_inner();
+        info!(
+            "GET /examenes/{}/versiones/comparar?desde={}&hasta={}",
+            examen_id, query.desde, query.hasta
+        );
+
+        let comparar = comparar_versiones::CompararVersiones::new(
+            Box::new(ExamenMongo::new(pool.clone())),
+            Box::new(VersionesExamenMongo::new(pool)),
+        );
+
+        match comparar
+            .ejecutar(comparar_versiones::InputData {
+                examen_id: examen_id.clone(),
+                desde: query.desde,
+                hasta: query.hasta,
+            })
+            .await
+        {
+            Ok(diferencia) => HttpResponse::Ok().json(DiferenciaExamenDTO::new(
+                examen_id,
+                query.desde,
+                query.hasta,
+                diferencia,
+            )),
+            Err(e @ ExamenError::VersionNoExiste(_)) => {
+                warn!("GET /examenes/{}/versiones/comparar - {}", examen_id, e);
+                HttpResponse::NotFound().json(json!({"error": e.to_string()}))
+            }
+            Err(e) => {
+                error!(
+                    "GET /examenes/{}/versiones/comparar - error: {}",
+                    examen_id, e
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al comparar las versiones del examen"}))
+            }
+        }
+    }
+}
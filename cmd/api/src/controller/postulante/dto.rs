@@ -1,4 +1,5 @@
 use crate::controller::hateoas::{Link, Links};
+use quizz_core::postulante::use_case::importar_postulantes::{EstadoFila, ResultadoFila};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Clone)]
@@ -12,6 +13,73 @@ pub struct RegistrarPostulanteDTO {
     pub genero: String,
 }
 
+/// Una fila del CSV de importacion. Las columnas faltantes quedan vacias y
+/// la fila se reporta como invalida.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct FilaImportacionDTO {
+    pub documento: String,
+    pub nombre: String,
+    pub primer_apellido: String,
+    pub segundo_apellido: String,
+    pub fecha_nacimiento: String,
+    pub grado_instruccion: String,
+    pub genero: String,
+    pub password: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ResultadoFilaDTO {
+    pub fila: usize,
+    pub documento: String,
+    pub estado: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ReporteImportacionDTO {
+    pub creados: usize,
+    pub duplicados: usize,
+    pub invalidos: usize,
+    pub filas: Vec<ResultadoFilaDTO>,
+}
+
+impl From<ResultadoFila> for ResultadoFilaDTO {
+    fn from(resultado: ResultadoFila) -> Self {
+        let (estado, id, error) = match resultado.estado {
+            EstadoFila::Creado { id } => ("creado", Some(id), None),
+            EstadoFila::Duplicado => ("duplicado", None, None),
+            EstadoFila::Invalido(error) => ("invalido", None, Some(error)),
+        };
+
+        Self {
+            fila: resultado.fila,
+            documento: resultado.documento,
+            estado,
+            id,
+            error,
+        }
+    }
+}
+
+impl From<Vec<ResultadoFila>> for ReporteImportacionDTO {
+    fn from(resultados: Vec<ResultadoFila>) -> Self {
+        let filas: Vec<ResultadoFilaDTO> =
+            resultados.into_iter().map(ResultadoFilaDTO::from).collect();
+        let contar = |estado: &str| filas.iter().filter(|fila| fila.estado == estado).count();
+
+        Self {
+            creados: contar("creado"),
+            duplicados: contar("duplicado"),
+            invalidos: contar("invalido"),
+            filas,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub struct PostulanteDocumentoQuery {
     pub id: Option<String>,
@@ -0,0 +1,116 @@
+use crate::controller::postulante::crypto::CifradoPorDefecto;
+use crate::controller::postulante::dto::{FilaImportacionDTO, ReporteImportacionDTO};
+use crate::controller::postulante::mongo::write::PostulanteMongo;
+use actix_web::{HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::postulante::use_case::importar_postulantes::{
+    EstadoFila, ImportarPostulantes, InputData, InputFila, ResultadoFila,
+};
+
+const COLUMNAS_REQUERIDAS: [&str; 7] = [
+    "documento",
+    "nombre",
+    "primer_apellido",
+    "segundo_apellido",
+    "fecha_nacimiento",
+    "grado_instruccion",
+    "genero",
+];
+
+pub struct ImportarPostulantesController;
+
+impl ImportarPostulantesController {
+    /// `POST /postulantes/importar` con un CSV cuya primera fila son las
+    /// columnas. `password` es opcional.
+    pub async fn import(body: web::Bytes, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        info!("POST /postulantes/importar - {} bytes", body.len());
+
+        let mut lector = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(body.as_ref());
+
+        let columnas = match lector.headers() {
+            Ok(columnas) => columnas.clone(),
+            Err(e) => {
+                warn!("POST /postulantes/importar - cabecera no valida: {}", e);
+                return HttpResponse::BadRequest().json(format!("CSV no valido: {}", e));
+            }
+        };
+        let faltantes: Vec<&str> = COLUMNAS_REQUERIDAS
+            .into_iter()
+            .filter(|columna| !columnas.iter().any(|c| c.eq_ignore_ascii_case(columna)))
+            .collect();
+        if !faltantes.is_empty() {
+            warn!(
+                "POST /postulantes/importar - columnas faltantes: {:?}",
+                faltantes
+            );
+            return HttpResponse::BadRequest()
+                .json(format!("Faltan las columnas: {}", faltantes.join(", ")));
+        }
+        let columnas = csv::StringRecord::from(
+            columnas
+                .iter()
+                .map(|columna| columna.to_lowercase())
+                .collect::<Vec<_>>(),
+        );
+
+        let mut filas = Vec::new();
+        let mut ilegibles = Vec::new();
+        for (i, registro) in lector.records().enumerate() {
+            let numero = i + 2;
+            let fila = registro
+                .and_then(|registro| registro.deserialize::<FilaImportacionDTO>(Some(&columnas)));
+            match fila {
+                Ok(fila) => filas.push((
+                    numero,
+                    InputFila {
+                        documento: fila.documento,
+                        nombre: fila.nombre,
+                        primer_apellido: fila.primer_apellido,
+                        segundo_apellido: fila.segundo_apellido,
+                        fecha_nacimiento: fila.fecha_nacimiento,
+                        grado_instruccion: fila.grado_instruccion,
+                        genero: fila.genero,
+                        password: fila.password,
+                    },
+                )),
+                Err(e) => ilegibles.push((numero, e.to_string())),
+            }
+        }
+
+        if filas.is_empty() && ilegibles.is_empty() {
+            warn!("POST /postulantes/importar - CSV sin filas");
+            return HttpResponse::BadRequest().json("El CSV no tiene filas");
+        }
+
+        let importar = ImportarPostulantes::new(
+            Box::new(CifradoPorDefecto),
+            Box::new(PostulanteMongo::new(pool)),
+        );
+
+        match importar.ejecutar(InputData { filas }).await {
+            Ok(mut resultados) => {
+                resultados.extend(ilegibles.into_iter().map(|(fila, error)| ResultadoFila {
+                    fila,
+                    documento: String::new(),
+                    estado: EstadoFila::Invalido(error),
+                }));
+                resultados.sort_by_key(|resultado| resultado.fila);
+
+                let reporte = ReporteImportacionDTO::from(resultados);
+                info!(
+                    "POST /postulantes/importar - creados: {}, duplicados: {}, invalidos: {}",
+                    reporte.creados, reporte.duplicados, reporte.invalidos
+                );
+                HttpResponse::Ok().json(reporte)
+            }
+            Err(e) => {
+                error!("POST /postulantes/importar - error: {:?}", e);
+                HttpResponse::InternalServerError().json("Error al importar los postulantes")
+            }
+        }
+    }
+}
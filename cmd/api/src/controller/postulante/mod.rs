@@ -1,6 +1,7 @@
 mod buscar_postulante;
 mod crypto;
 mod dto;
+mod importar_postulantes;
 pub mod mongo;
 pub mod registrar_postulante;
 pub mod route;
@@ -2,12 +2,16 @@ use crate::controller::mongo_repository::MongoRepository;
 use crate::controller::postulante::mongo::constantes::POSTULANTE_COLLECTION_NAME;
 use actix_web::web;
 use async_trait::async_trait;
+use futures::TryStreamExt;
 use log::error;
-use mongodb::bson::doc;
+use mongodb::bson::{Document, doc};
 use quizz_core::postulante::domain::entity::postulante::Postulante;
 use quizz_core::postulante::domain::error::postulante::{PostulanteError, RepositorioError};
 use quizz_core::postulante::domain::value_object::id::PostulanteID;
-use quizz_core::postulante::provider::repositorio::RepositorioPostulanteEscritura;
+use quizz_core::postulante::provider::repositorio::{
+    RepositorioPostulanteEscritura, RepositorioPostulanteImportar,
+};
+use std::collections::HashSet;
 
 pub struct PostulanteMongo {
     client: web::Data<mongodb::Client>,
@@ -29,28 +33,36 @@ impl MongoRepository for PostulanteMongo {
     }
 }
 
+fn postulante_a_documento(postulante: &mut Postulante) -> Result<Document, PostulanteError> {
+    let password = postulante
+        .password
+        .take()
+        .ok_or(PostulanteError::PostulanteRepositorioError(
+            RepositorioError::PasswordVacio,
+        ))?
+        .value();
+
+    Ok(doc! {
+        "_id": postulante.id.value().uuid().to_string(),
+        "documento": postulante.documento.to_string(),
+        "nombre": postulante.nombre_completo.nombre(),
+        "primer_apellido": postulante.nombre_completo.primer_apellido(),
+        "segundo_apellido": postulante.nombre_completo.segundo_apellido(),
+        "fecha_nacimiento": postulante.fecha_nacimiento.to_string(),
+        "grado_instruccion": postulante.grado_instruccion.to_string(),
+        "genero": postulante.genero.to_string(),
+        "password": password,
+        "fecha_registro": postulante.fecha_registro.to_string(),
+    })
+}
+
 #[async_trait]
 impl RepositorioPostulanteEscritura<PostulanteError> for PostulanteMongo {
-    async fn registrar_postulante(&self, postulante: Postulante) -> Result<(), PostulanteError> {
-        let password = postulante
-            .password
-            .ok_or(PostulanteError::PostulanteRepositorioError(
-                RepositorioError::PasswordVacio,
-            ))?
-            .value();
-
-        let documento = doc! {
-            "_id": postulante.id.value().uuid().to_string(),
-            "documento": postulante.documento.to_string(),
-            "nombre": postulante.nombre_completo.nombre(),
-            "primer_apellido": postulante.nombre_completo.primer_apellido(),
-            "segundo_apellido": postulante.nombre_completo.segundo_apellido(),
-            "fecha_nacimiento": postulante.fecha_nacimiento.to_string(),
-            "grado_instruccion": postulante.grado_instruccion.to_string(),
-            "genero": postulante.genero.to_string(),
-            "password": password,
-            "fecha_registro": postulante.fecha_registro.to_string(),
-        };
+    async fn registrar_postulante(
+        &self,
+        mut postulante: Postulante,
+    ) -> Result<(), PostulanteError> {
+        let documento = postulante_a_documento(&mut postulante)?;
 
         match self.get_collection().insert_one(documento).await {
             Ok(_) => Ok(()),
@@ -141,3 +153,58 @@ impl RepositorioPostulanteEscritura<PostulanteError> for PostulanteMongo {
         }
     }
 }
+
+#[async_trait]
+impl RepositorioPostulanteImportar<PostulanteError> for PostulanteMongo {
+    async fn documentos_registrados(
+        &self,
+        documentos: &[String],
+    ) -> Result<HashSet<String>, PostulanteError> {
+        let cursor = self
+            .get_collection()
+            .find(doc! { "documento": { "$in": documentos } })
+            .projection(doc! { "documento": 1 })
+            .await
+            .map_err(|e| {
+                error!("Database error while looking up documentos: {}", e);
+                PostulanteError::PostulanteRepositorioError(RepositorioError::LecturaNoFinalizada)
+            })?;
+
+        let registrados: Vec<Document> = cursor.try_collect().await.map_err(|e| {
+            error!("Database error while reading documentos: {}", e);
+            PostulanteError::PostulanteRepositorioError(RepositorioError::LecturaNoFinalizada)
+        })?;
+
+        Ok(registrados
+            .iter()
+            .filter_map(|documento| documento.get_str("documento").ok())
+            .map(ToString::to_string)
+            .collect())
+    }
+
+    async fn registrar_postulantes(
+        &self,
+        postulantes: Vec<Postulante>,
+    ) -> Result<(), PostulanteError> {
+        let documentos = postulantes
+            .into_iter()
+            .map(|mut postulante| postulante_a_documento(&mut postulante))
+            .collect::<Result<Vec<Document>, PostulanteError>>()?;
+        let cantidad = documentos.len();
+
+        self.get_collection()
+            .insert_many(documentos)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Database error while importing {} postulantes: {}",
+                    cantidad, e
+                );
+                PostulanteError::PostulanteRepositorioError(
+                    RepositorioError::PersistenciaNoFinalizada,
+                )
+            })?;
+
+        Ok(())
+    }
+}
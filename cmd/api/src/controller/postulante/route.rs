@@ -1,4 +1,5 @@
 use crate::controller::postulante::buscar_postulante::PostulanteObtenerPorDocumentoController;
+use crate::controller::postulante::importar_postulantes::ImportarPostulantesController;
 use crate::controller::postulante::registrar_postulante::PostulanteController;
 use actix_web::web;
 
@@ -10,6 +11,10 @@ pub fn postulante(cfg: &mut web::ServiceConfig) {
                     .route(web::get().to(PostulanteObtenerPorDocumentoController::get))
                     .route(web::put().to(PostulanteController::update_by_documento)),
             )
+            .service(
+                web::resource("/importar")
+                    .route(web::post().to(ImportarPostulantesController::import)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::post().to(PostulanteController::create))
@@ -3,7 +3,7 @@ use crate::controller::pregunta::mongo::write::PreguntaPorExamenMongo;
 use actix_web::{HttpRequest, HttpResponse, web};
 use log;
 use quizz_common::use_case::CasoDeUso;
-use quizz_core::pregunta::domain::error::pregunta::PreguntaError;
+use quizz_core::pregunta::domain::error::pregunta::{PreguntaError, RepositorioError};
 use quizz_core::pregunta::use_case::agregar_preguntas::{
     AgregarPreguntasParaExamen, InputData, PreguntaEntityInput,
 };
@@ -29,14 +29,7 @@ impl AgregarPreguntaController {
         let preguntas = dto
             .preguntas
             .into_iter()
-            .map(|dto| PreguntaEntityInput {
-                contenido: dto.contenido,
-                etiqueta: dto.etiqueta,
-                tipo_de_pregunta: dto.tipo_de_pregunta,
-                imagen_ref: dto.imagen_ref,
-                alternativas: dto.alternativas.unwrap_or_default(),
-                puntaje: dto.puntaje.unwrap_or_default(),
-            })
+            .map(PreguntaEntityInput::from)
             .collect();
 
         let input = InputData {
@@ -46,45 +39,85 @@ impl AgregarPreguntaController {
 
         match agregar_preguntas.ejecutar(input).await {
             Ok(_) => HttpResponse::Ok().finish(),
-            Err(err) => match err {
-                PreguntaError::RespuestaNoExiste => {
-                    HttpResponse::BadRequest().json("La pregunta debe tener una respuesta")
-                }
-                PreguntaError::RespuestaIncorrecta => {
-                    HttpResponse::BadRequest().json("La respuesta proporcionada es incorrecta")
-                }
-                PreguntaError::AlternativasNoExisten => {
-                    HttpResponse::BadRequest().json("La pregunta debe tener alternativas")
-                }
-                PreguntaError::AlternativasVacias => {
-                    HttpResponse::BadRequest().json("Las alternativas no pueden estar vacías")
-                }
-                PreguntaError::PuntajeNoExiste => {
-                    HttpResponse::BadRequest().json("La pregunta debe tener un puntaje definido")
-                }
-                PreguntaError::PuntajeVacio => {
-                    HttpResponse::BadRequest().json("El puntaje no puede estar vacío")
-                }
-                PreguntaError::PuntajeNoCoincideConAlternativa => HttpResponse::BadRequest()
-                    .json("El puntaje debe coincidir con las alternativas disponibles"),
-                PreguntaError::PreguntaErrorExamenID(id_error) => HttpResponse::BadRequest()
-                    .json(format!("Error en el ID del examen: {}", id_error)),
-                PreguntaError::PreguntaAlternativaError(alt_error) => HttpResponse::BadRequest()
-                    .json(format!("Error en las alternativas: {}", alt_error)),
-                PreguntaError::PreguntaEtiquetaError(etiqueta_error) => HttpResponse::BadRequest()
-                    .json(format!("Error en la etiqueta: {}", etiqueta_error)),
-                PreguntaError::PreguntaTipoPreguntaError(tipo_error) => HttpResponse::BadRequest()
-                    .json(format!("Error en el tipo de pregunta: {}", tipo_error)),
-                PreguntaError::PreguntaRepositorioError(repo_error) => {
-                    log::error!("Repository error: {}", repo_error);
-                    HttpResponse::InternalServerError().json("Error interno del servidor")
-                }
-                PreguntaError::DebeTenerUnaSolaRespuesta => {
-                    HttpResponse::BadRequest().json("La pregunta debe tener una sola respuesta")
-                }
-                PreguntaError::AlternativaNoAjustada => HttpResponse::BadRequest().json(""),
-                PreguntaError::PuntajeNoAjustado => HttpResponse::BadRequest().json(""),
-            },
+            Err(err) => respuesta_error(err),
+        }
+    }
+}
+
+/// Traduce los errores de pregunta compartidos por el examen y el banco.
+pub(crate) fn respuesta_error(err: PreguntaError) -> HttpResponse {
+    match err {
+        PreguntaError::RespuestaNoExiste => {
+            HttpResponse::BadRequest().json("La pregunta debe tener una respuesta")
+        }
+        PreguntaError::RespuestaIncorrecta => {
+            HttpResponse::BadRequest().json("La respuesta proporcionada es incorrecta")
+        }
+        PreguntaError::AlternativasNoExisten => {
+            HttpResponse::BadRequest().json("La pregunta debe tener alternativas")
+        }
+        PreguntaError::AlternativasVacias => {
+            HttpResponse::BadRequest().json("Las alternativas no pueden estar vacías")
+        }
+        PreguntaError::PuntajeNoExiste => {
+            HttpResponse::BadRequest().json("La pregunta debe tener un puntaje definido")
+        }
+        PreguntaError::PuntajeVacio => {
+            HttpResponse::BadRequest().json("El puntaje no puede estar vacío")
+        }
+        PreguntaError::PuntajeNoCoincideConAlternativa => HttpResponse::BadRequest()
+            .json("El puntaje debe coincidir con las alternativas disponibles"),
+        PreguntaError::PreguntaErrorExamenID(id_error) => {
+            HttpResponse::BadRequest().json(format!("Error en el ID: {}", id_error))
+        }
+        PreguntaError::PreguntaAlternativaError(alt_error) => {
+            HttpResponse::BadRequest().json(format!("Error en las alternativas: {}", alt_error))
+        }
+        PreguntaError::PreguntaEtiquetaError(etiqueta_error) => {
+            HttpResponse::BadRequest().json(format!("Error en la etiqueta: {}", etiqueta_error))
+        }
+        PreguntaError::PreguntaTipoPreguntaError(tipo_error) => {
+            HttpResponse::BadRequest().json(format!("Error en el tipo de pregunta: {}", tipo_error))
+        }
+        PreguntaError::PreguntaNoExiste(id) => {
+            HttpResponse::NotFound().json(format!("La pregunta {} no existe en el banco", id))
+        }
+        PreguntaError::PreguntaYaExiste(id) => {
+            HttpResponse::Conflict().json(format!("La pregunta {} ya existe en el banco", id))
+        }
+        PreguntaError::PreguntaEnUso(id) => {
+            HttpResponse::Conflict().json(format!("La pregunta {} esta vinculada a un examen", id))
+        }
+        PreguntaError::PreguntaNoEstaEnExamen(id) => {
+            HttpResponse::NotFound().json(format!("La pregunta {} no pertenece al examen", id))
+        }
+        PreguntaError::PreguntaDelBanco(id) => HttpResponse::Conflict().json(format!(
+            "La pregunta {} es del banco; se edita en /preguntas/{}",
+            id, id
+        )),
+        PreguntaError::PreguntaRepositorioError(RepositorioError::ExamenNoExiste) => {
+            HttpResponse::NotFound().json("El examen no existe")
+        }
+        PreguntaError::PreguntaRepositorioError(repo_error) => {
+            log::error!("Repository error: {}", repo_error);
+            HttpResponse::InternalServerError().json("Error interno del servidor")
+        }
+        PreguntaError::DebeTenerUnaSolaRespuesta => {
+            HttpResponse::BadRequest().json("La pregunta debe tener una sola respuesta")
+        }
+        PreguntaError::AlternativaNoAjustada => HttpResponse::BadRequest().json(""),
+        PreguntaError::PuntajeNoAjustado => HttpResponse::BadRequest().json(""),
+        PreguntaError::SeleccionNoValida(detalle) => {
+            HttpResponse::BadRequest().json(format!("Seleccion no valida: {}", detalle))
+        }
+        PreguntaError::EscalaLikertNoValida(detalle) => {
+            HttpResponse::BadRequest().json(format!("Escala likert no valida: {}", detalle))
+        }
+        PreguntaError::OrdenNoValido(detalle) => {
+            HttpResponse::BadRequest().json(format!("Orden no valido: {}", detalle))
+        }
+        PreguntaError::NumericaNoValida(detalle) => {
+            HttpResponse::BadRequest().json(format!("Pregunta numerica no valida: {}", detalle))
         }
     }
 }
@@ -0,0 +1,133 @@
+use crate::controller::hateoas::{Link, Links, ListResponse};
+use crate::controller::pregunta::agregar_pregunta::respuesta_error;
+use crate::controller::pregunta::dto::{PreguntaBancoDTO, PreguntaDetailDto};
+use crate::controller::pregunta::mongo::banco::BancoPreguntasMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::pregunta::use_case::actualizar_pregunta_banco::{self, ActualizarPreguntaBanco};
+use quizz_core::pregunta::use_case::crear_pregunta_banco::{self, CrearPreguntaBanco};
+use quizz_core::pregunta::use_case::eliminar_pregunta_banco::{self, EliminarPreguntaBanco};
+use quizz_core::pregunta::use_case::listar_preguntas_banco::{self, ListarPreguntasBanco};
+use quizz_core::pregunta::use_case::obtener_pregunta_banco::{self, ObtenerPreguntaBanco};
+
+pub struct BancoPreguntasController;
+
+impl BancoPreguntasController {
+    pub async fn list(pool: web::Data<mongodb::Client>) -> HttpResponse {
+        info!("GET /preguntas");
+
+        let listar = ListarPreguntasBanco::new(Box::new(BancoPreguntasMongo::new(pool)));
+
+        match listar.ejecutar(listar_preguntas_banco::InputData).await {
+            Ok(preguntas) => {
+                info!("GET /preguntas - {} resultados", preguntas.len());
+
+                let mut links = Links::new();
+                links.insert("self".into(), Link::get("/preguntas"));
+
+                HttpResponse::Ok().json(ListResponse {
+                    links,
+                    items: preguntas.into_iter().map(PreguntaBancoDTO::from).collect(),
+                })
+            }
+            Err(err) => respuesta_error(err),
+        }
+    }
+
+    pub async fn get(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        let Some(id) = pregunta_id(&req) else {
+            return HttpResponse::BadRequest().json("se debe enviar el ID de la pregunta");
+        };
+
+        info!("GET /preguntas/{}", id);
+
+        let obtener = ObtenerPreguntaBanco::new(Box::new(BancoPreguntasMongo::new(pool)));
+
+        match obtener
+            .ejecutar(obtener_pregunta_banco::InputData { id })
+            .await
+        {
+            Ok(pregunta) => HttpResponse::Ok().json(PreguntaBancoDTO::from(pregunta)),
+            Err(err) => respuesta_error(err),
+        }
+    }
+
+    pub async fn create(
+        req: HttpRequest,
+        body: web::Json<PreguntaDetailDto>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let Some(id) = pregunta_id(&req) else {
+            warn!("POST /preguntas - id no proporcionado");
+            return HttpResponse::BadRequest().json("se debe enviar el ID de la pregunta");
+        };
+
+        info!("POST /preguntas/{}", id);
+
+        let crear = CrearPreguntaBanco::new(Box::new(BancoPreguntasMongo::new(pool)));
+        let input = crear_pregunta_banco::InputData {
+            id: id.clone(),
+            pregunta: body.into_inner().into(),
+        };
+
+        match crear.ejecutar(input).await {
+            Ok(_) => {
+                info!("POST /preguntas/{} - creada exitosamente", id);
+                HttpResponse::Created().finish()
+            }
+            Err(err) => respuesta_error(err),
+        }
+    }
+
+    pub async fn update(
+        req: HttpRequest,
+        body: web::Json<PreguntaDetailDto>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let Some(id) = pregunta_id(&req) else {
+            return HttpResponse::BadRequest().json("se debe enviar el ID de la pregunta");
+        };
+
+        info!("PUT /preguntas/{}", id);
+
+        let actualizar = ActualizarPreguntaBanco::new(Box::new(BancoPreguntasMongo::new(pool)));
+        let input = actualizar_pregunta_banco::InputData {
+            id: id.clone(),
+            pregunta: body.into_inner().into(),
+        };
+
+        match actualizar.ejecutar(input).await {
+            Ok(_) => {
+                info!("PUT /preguntas/{} - actualizada exitosamente", id);
+                HttpResponse::Ok().finish()
+            }
+            Err(err) => respuesta_error(err),
+        }
+    }
+
+    pub async fn delete(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        let Some(id) = pregunta_id(&req) else {
+            return HttpResponse::BadRequest().json("se debe enviar el ID de la pregunta");
+        };
+
+        info!("DELETE /preguntas/{}", id);
+
+        let eliminar = EliminarPreguntaBanco::new(Box::new(BancoPreguntasMongo::new(pool)));
+
+        match eliminar
+            .ejecutar(eliminar_pregunta_banco::InputData { id: id.clone() })
+            .await
+        {
+            Ok(_) => {
+                info!("DELETE /preguntas/{} - eliminada exitosamente", id);
+                HttpResponse::NoContent().finish()
+            }
+            Err(err) => respuesta_error(err),
+        }
+    }
+}
+
+fn pregunta_id(req: &HttpRequest) -> Option<String> {
+    req.match_info().get("id").map(ToString::to_string)
+}
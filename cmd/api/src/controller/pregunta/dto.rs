@@ -1,3 +1,12 @@
+use crate::controller::hateoas::{Link, Links};
+use mongodb::bson::{Document, doc};
+use quizz_core::pregunta::domain::entity::pregunta::PreguntaEntity;
+use quizz_core::pregunta::domain::error::pregunta::PreguntaError;
+use quizz_core::pregunta::domain::value_object::numerica::{ReglaNumerica, TipoTolerancia};
+use quizz_core::pregunta::domain::value_object::seleccion::{ModoCalificacion, ReglaSeleccion};
+use quizz_core::pregunta::use_case::agregar_preguntas::{
+    InputNumerica, InputSeleccion, PreguntaEntityInput,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -14,4 +23,186 @@ pub struct PreguntaDetailDto {
     pub imagen_ref: Option<String>,
     pub alternativas: Option<HashMap<String, String>>,
     pub puntaje: Option<HashMap<String, u32>>,
+    #[serde(default)]
+    pub inversa: bool,
+    #[serde(default)]
+    pub seleccion: Option<SeleccionDTO>,
+    // ordenamiento: exacto, posicional o kendall
+    #[serde(default)]
+    pub ordenamiento: Option<String>,
+    #[serde(default)]
+    pub numerica: Option<NumericaDTO>,
+}
+
+impl From<PreguntaDetailDto> for PreguntaEntityInput {
+    fn from(dto: PreguntaDetailDto) -> Self {
+        PreguntaEntityInput {
+            contenido: dto.contenido,
+            etiqueta: dto.etiqueta,
+            tipo_de_pregunta: dto.tipo_de_pregunta,
+            imagen_ref: dto.imagen_ref,
+            alternativas: dto.alternativas.unwrap_or_default(),
+            puntaje: dto.puntaje.unwrap_or_default(),
+            inversa: dto.inversa,
+            seleccion: dto.seleccion.map(|seleccion| InputSeleccion {
+                minimo: seleccion.minimo,
+                maximo: seleccion.maximo,
+                modo: seleccion.modo,
+            }),
+            ordenamiento: dto.ordenamiento,
+            numerica: dto.numerica.map(|numerica| InputNumerica {
+                tolerancia: numerica.tolerancia,
+                tipo_tolerancia: numerica.tipo_tolerancia,
+                unidad: numerica.unidad,
+            }),
+        }
+    }
+}
+
+/// Pregunta del banco tal como se devuelve en `/preguntas`.
+#[derive(Debug, Serialize)]
+pub struct PreguntaBancoDTO {
+    pub id: String,
+    pub contenido: String,
+    pub etiqueta: String,
+    pub tipo_de_pregunta: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imagen_ref: Option<String>,
+    pub alternativas: HashMap<String, String>,
+    pub puntaje: HashMap<String, u32>,
+    pub inversa: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seleccion: Option<SeleccionDTO>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordenamiento: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numerica: Option<NumericaDTO>,
+    #[serde(rename = "_links")]
+    pub links: Links,
+}
+
+impl From<PreguntaEntity> for PreguntaBancoDTO {
+    fn from(pregunta: PreguntaEntity) -> Self {
+        let id = pregunta.id.to_string();
+        let mut links = Links::new();
+        links.insert("self".into(), Link::get(format!("/preguntas/{}", id)));
+        links.insert("update".into(), Link::put(format!("/preguntas/{}", id)));
+        links.insert("delete".into(), Link::delete(format!("/preguntas/{}", id)));
+
+        Self {
+            id,
+            contenido: pregunta.contenido,
+            etiqueta: pregunta.etiqueta.to_string(),
+            tipo_de_pregunta: pregunta.tipo_de_pregunta.to_string(),
+            imagen_ref: pregunta.imagen_ref,
+            alternativas: pregunta.alternativas,
+            puntaje: pregunta.puntaje,
+            inversa: pregunta.inversa,
+            seleccion: pregunta.seleccion.as_ref().map(SeleccionDTO::from),
+            ordenamiento: pregunta.ordenamiento.map(|modo| modo.to_string()),
+            numerica: pregunta.numerica.as_ref().map(NumericaDTO::from),
+            links,
+        }
+    }
+}
+
+/// IDs de preguntas del banco que se vinculan a un examen.
+#[derive(Debug, Deserialize)]
+pub struct VincularPreguntasBancoDTO {
+    pub pregunta_ids: Vec<String>,
+}
+
+/// Cuantas alternativas se pueden marcar en una pregunta de seleccion
+/// multiple y como se califica (`exacta` o `proporcional`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SeleccionDTO {
+    pub minimo: u32,
+    pub maximo: u32,
+    #[serde(default = "modo_por_defecto")]
+    pub modo: String,
+}
+
+fn modo_por_defecto() -> String {
+    ModoCalificacion::default().to_string()
+}
+
+impl SeleccionDTO {
+    pub fn to_document(&self) -> Document {
+        doc! {
+            "minimo": i64::from(self.minimo),
+            "maximo": i64::from(self.maximo),
+            "modo": &self.modo,
+        }
+    }
+}
+
+impl From<&ReglaSeleccion> for SeleccionDTO {
+    fn from(regla: &ReglaSeleccion) -> Self {
+        Self {
+            minimo: regla.minimo,
+            maximo: regla.maximo,
+            modo: regla.modo.to_string(),
+        }
+    }
+}
+
+impl TryFrom<SeleccionDTO> for ReglaSeleccion {
+    type Error = PreguntaError;
+
+    fn try_from(seleccion: SeleccionDTO) -> Result<Self, Self::Error> {
+        ReglaSeleccion::new(seleccion.minimo, seleccion.maximo, seleccion.modo.parse()?)
+    }
+}
+
+/// Tolerancia y unidad de una pregunta numerica. La tolerancia `relativa` es
+/// una fraccion del valor esperado (0.05 es un 5%).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NumericaDTO {
+    #[serde(default)]
+    pub tolerancia: f64,
+    #[serde(default = "tipo_tolerancia_por_defecto")]
+    pub tipo_tolerancia: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unidad: Option<String>,
+}
+
+fn tipo_tolerancia_por_defecto() -> String {
+    TipoTolerancia::default().to_string()
+}
+
+impl NumericaDTO {
+    pub fn to_document(&self) -> Document {
+        let mut document = doc! {
+            "tolerancia": self.tolerancia,
+            "tipo_tolerancia": &self.tipo_tolerancia,
+        };
+
+        if let Some(ref unidad) = self.unidad {
+            document.insert("unidad", unidad);
+        }
+
+        document
+    }
+}
+
+impl From<&ReglaNumerica> for NumericaDTO {
+    fn from(regla: &ReglaNumerica) -> Self {
+        Self {
+            tolerancia: regla.tolerancia,
+            tipo_tolerancia: regla.tipo.to_string(),
+            unidad: regla.unidad.clone(),
+        }
+    }
+}
+
+impl TryFrom<NumericaDTO> for ReglaNumerica {
+    type Error = PreguntaError;
+
+    fn try_from(numerica: NumericaDTO) -> Result<Self, Self::Error> {
+        ReglaNumerica::new(
+            numerica.tolerancia,
+            numerica.tipo_tolerancia.parse()?,
+            numerica.unidad,
+        )
+    }
 }
@@ -1,3 +1,7 @@
 pub mod agregar_pregunta;
-mod dto;
-mod mongo;
+pub mod banco_preguntas;
+pub mod dto;
+pub mod mongo;
+pub mod pregunta_examen;
+pub mod route;
+pub mod vincular_preguntas_banco;
@@ -0,0 +1,161 @@
+use crate::controller::examen::dto::PreguntaMongoDTO;
+use crate::controller::mongo_repository::{MAIN_DATABASE_NAME, MongoRepository};
+use crate::controller::pregunta::mongo::constantes::{
+    BANCO_PREGUNTA_COLLECTION_NAME, EXAMEN_COLLECTION_NAME,
+};
+use crate::controller::pregunta::mongo::write::pregunta_to_document;
+use actix_web::web;
+use async_trait::async_trait;
+use log::{error, info};
+use mongodb::bson::{self, Document, doc};
+use quizz_core::pregunta::domain::entity::pregunta::PreguntaEntity;
+use quizz_core::pregunta::domain::error::pregunta::PreguntaError;
+use quizz_core::pregunta::domain::error::pregunta::RepositorioError::{
+    ActualizacionNoFinalizada, LecturaNoFinalizada, PersistenciaNoFinalizada,
+};
+use quizz_core::pregunta::domain::value_object::id::PreguntaID;
+use quizz_core::pregunta::provider::repositorio::RepositorioBancoPreguntas;
+
+pub struct BancoPreguntasMongo {
+    client: web::Data<mongodb::Client>,
+}
+
+impl BancoPreguntasMongo {
+    pub fn new(client: web::Data<mongodb::Client>) -> Self {
+        Self { client }
+    }
+
+    /// Devuelve las preguntas del banco en el orden de `ids`. Los IDs que ya
+    /// no existen en el banco se omiten.
+    pub async fn obtener_varias(
+        &self,
+        ids: &[String],
+    ) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let preguntas = self.buscar(doc! { "_id": { "$in": ids } }).await?;
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| {
+                let pregunta = preguntas.iter().find(|p| &p.id.to_string() == id);
+                if pregunta.is_none() {
+                    error!("Bank question {} referenced but not found", id);
+                }
+                pregunta.cloned()
+            })
+            .collect())
+    }
+
+    async fn buscar(&self, filtro: Document) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+        let mut cursor = self.get_collection().find(filtro).await.map_err(|e| {
+            error!("Error reading bank questions: {}", e);
+            PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+        })?;
+
+        let mut preguntas = Vec::new();
+        while cursor.advance().await.map_err(|e| {
+            error!("Error advancing cursor over bank questions: {}", e);
+            PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+        })? {
+            let documento = cursor.deserialize_current().map_err(|e| {
+                error!("Error deserializing bank question: {}", e);
+                PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+            })?;
+            let pregunta = bson::from_document::<PreguntaMongoDTO>(documento)
+                .map_err(|e| e.into())
+                .and_then(PreguntaMongoDTO::into_entity)
+                .map_err(|e| {
+                    error!("Error converting bank question to entity: {}", e);
+                    PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+                })?;
+            preguntas.push(pregunta);
+        }
+
+        Ok(preguntas)
+    }
+}
+
+impl MongoRepository for BancoPreguntasMongo {
+    fn get_collection_name(&self) -> &str {
+        BANCO_PREGUNTA_COLLECTION_NAME
+    }
+
+    fn get_client(&self) -> &web::Data<mongodb::Client> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl RepositorioBancoPreguntas<PreguntaError> for BancoPreguntasMongo {
+    async fn guardar(&self, pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+        self.get_collection()
+            .insert_one(pregunta_to_document(&pregunta))
+            .await
+            .map_err(|e| {
+                error!("Error saving bank question {}: {}", pregunta.id, e);
+                PreguntaError::PreguntaRepositorioError(PersistenciaNoFinalizada)
+            })?;
+
+        info!("Bank question {} saved", pregunta.id);
+        Ok(())
+    }
+
+    async fn listar(&self) -> Result<Vec<PreguntaEntity>, PreguntaError> {
+        self.buscar(doc! {}).await
+    }
+
+    async fn obtener(&self, id: &PreguntaID) -> Result<Option<PreguntaEntity>, PreguntaError> {
+        Ok(self
+            .buscar(doc! { "_id": id.to_string() })
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn actualizar(&self, pregunta: PreguntaEntity) -> Result<(), PreguntaError> {
+        self.get_collection()
+            .replace_one(
+                doc! { "_id": pregunta.id.to_string() },
+                pregunta_to_document(&pregunta),
+            )
+            .await
+            .map_err(|e| {
+                error!("Error updating bank question {}: {}", pregunta.id, e);
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })?;
+
+        info!("Bank question {} updated", pregunta.id);
+        Ok(())
+    }
+
+    async fn eliminar(&self, id: &PreguntaID) -> Result<(), PreguntaError> {
+        self.get_collection()
+            .delete_one(doc! { "_id": id.to_string() })
+            .await
+            .map_err(|e| {
+                error!("Error deleting bank question {}: {}", id, e);
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })?;
+
+        info!("Bank question {} deleted", id);
+        Ok(())
+    }
+
+    async fn en_uso(&self, id: &PreguntaID) -> Result<bool, PreguntaError> {
+        let examenes = self
+            .client
+            .database(MAIN_DATABASE_NAME)
+            .collection::<Document>(EXAMEN_COLLECTION_NAME)
+            .count_documents(doc! { "preguntas_banco": id.to_string() })
+            .await
+            .map_err(|e| {
+                error!("Error checking exams referencing question {}: {}", id, e);
+                PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+            })?;
+
+        Ok(examenes > 0)
+    }
+}
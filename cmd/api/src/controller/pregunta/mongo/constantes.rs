@@ -1 +1,2 @@
 pub const EXAMEN_COLLECTION_NAME: &str = "examen";
+pub const BANCO_PREGUNTA_COLLECTION_NAME: &str = "pregunta";
@@ -1,2 +1,3 @@
+pub mod banco;
 mod constantes;
 pub mod write;
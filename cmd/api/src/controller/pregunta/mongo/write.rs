@@ -1,4 +1,6 @@
+use crate::controller::examen::mongo::write::ExamenMongo;
 use crate::controller::mongo_repository::MongoRepository;
+use crate::controller::pregunta::dto::{NumericaDTO, SeleccionDTO};
 use crate::controller::pregunta::mongo::constantes::EXAMEN_COLLECTION_NAME;
 use actix_web::web;
 use async_trait::async_trait;
@@ -8,10 +10,14 @@ use quizz_core::examen::domain::value_object::id::ExamenID;
 use quizz_core::pregunta::domain::entity::pregunta::PreguntaEntity;
 use quizz_core::pregunta::domain::error::pregunta::PreguntaError;
 use quizz_core::pregunta::domain::error::pregunta::RepositorioError::{
-    ActualizacionNoFinalizada, PersistenciaNoFinalizada,
+    ActualizacionNoFinalizada, ExamenNoExiste, LecturaNoFinalizada, PersistenciaNoFinalizada,
 };
 use quizz_core::pregunta::domain::service::lista_preguntas::ListaDePreguntas;
-use quizz_core::pregunta::provider::repositorio::RepositorioAgregarPregunta;
+use quizz_core::pregunta::domain::value_object::id::PreguntaID;
+use quizz_core::pregunta::domain::value_object::origen::OrigenPregunta;
+use quizz_core::pregunta::provider::repositorio::{
+    RepositorioAgregarPregunta, RepositorioPreguntaExamen, RepositorioVincularPreguntaBanco,
+};
 
 pub struct PreguntaPorExamenMongo {
     client: web::Data<mongodb::Client>,
@@ -21,6 +27,20 @@ impl PreguntaPorExamenMongo {
     pub fn new(client: web::Data<mongodb::Client>) -> Self {
         Self { client }
     }
+
+    /// Editar un examen publicado abre una version nueva en borrador
+    async fn abrir_borrador(&self, examen_id: &ExamenID) -> Result<(), PreguntaError> {
+        ExamenMongo::new(self.client.clone())
+            .abrir_borrador(&examen_id.to_string())
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error opening a draft version for exam {}: {}",
+                    examen_id, e
+                );
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })
+    }
 }
 
 impl MongoRepository for PreguntaPorExamenMongo {
@@ -82,43 +102,201 @@ impl RepositorioAgregarPregunta<PreguntaError> for PreguntaPorExamenMongo {
                 PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
             })?;
 
+        self.abrir_borrador(&examen_id).await?;
+
         info!("Questions added successfully to exam {}", examen_id);
         Ok(())
     }
 }
 
+#[async_trait]
+impl RepositorioVincularPreguntaBanco<PreguntaError> for PreguntaPorExamenMongo {
+    async fn vincular(
+        &self,
+        examen_id: ExamenID,
+        pregunta_ids: Vec<PreguntaID>,
+    ) -> Result<(), PreguntaError> {
+        let pregunta_ids: Vec<String> = pregunta_ids.iter().map(ToString::to_string).collect();
+
+        let resultado = self
+            .get_collection()
+            .update_one(
+                doc! {
+                    "_id": examen_id.to_string()
+                },
+                doc! {
+                    "$addToSet": {
+                        "preguntas_banco": {
+                            "$each": pregunta_ids
+                        }
+                    }
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("Error linking bank questions to exam: {}", e);
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })?;
+
+        if resultado.matched_count == 0 {
+            return Err(PreguntaError::PreguntaRepositorioError(ExamenNoExiste));
+        }
+
+        if resultado.modified_count > 0 {
+            self.abrir_borrador(&examen_id).await?;
+        }
+
+        info!("Bank questions linked successfully to exam {}", examen_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RepositorioPreguntaExamen<PreguntaError> for PreguntaPorExamenMongo {
+    async fn origen(
+        &self,
+        examen_id: &ExamenID,
+        pregunta_id: &PreguntaID,
+    ) -> Result<Option<OrigenPregunta>, PreguntaError> {
+        let pregunta_id = pregunta_id.to_string();
+        let examen = self
+            .get_collection()
+            .find_one(doc! { "_id": examen_id.to_string() })
+            .projection(doc! { "preguntas._id": 1, "preguntas_banco": 1 })
+            .await
+            .map_err(|e| {
+                error!("Error reading exam {}: {}", examen_id, e);
+                PreguntaError::PreguntaRepositorioError(LecturaNoFinalizada)
+            })?
+            .ok_or(PreguntaError::PreguntaRepositorioError(ExamenNoExiste))?;
+
+        let propia = examen.get_array("preguntas").is_ok_and(|preguntas| {
+            preguntas.iter().any(|pregunta| {
+                pregunta
+                    .as_document()
+                    .and_then(|pregunta| pregunta.get_str("_id").ok())
+                    == Some(pregunta_id.as_str())
+            })
+        });
+        if propia {
+            return Ok(Some(OrigenPregunta::Propia));
+        }
+
+        let banco = examen.get_array("preguntas_banco").is_ok_and(|ids| {
+            ids.iter()
+                .any(|id| id.as_str() == Some(pregunta_id.as_str()))
+        });
+        Ok(banco.then_some(OrigenPregunta::Banco))
+    }
+
+    async fn reemplazar(
+        &self,
+        examen_id: ExamenID,
+        pregunta: PreguntaEntity,
+    ) -> Result<(), PreguntaError> {
+        let resultado = self
+            .get_collection()
+            .update_one(
+                doc! {
+                    "_id": examen_id.to_string(),
+                    "preguntas._id": pregunta.id.to_string(),
+                },
+                doc! {
+                    "$set": { "preguntas.$": pregunta_to_document(&pregunta) }
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("Error replacing question {}: {}", pregunta.id, e);
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })?;
+
+        if resultado.modified_count > 0 {
+            self.abrir_borrador(&examen_id).await?;
+        }
+
+        info!("Question {} updated in exam {}", pregunta.id, examen_id);
+        Ok(())
+    }
+
+    async fn quitar(
+        &self,
+        examen_id: ExamenID,
+        pregunta_id: PreguntaID,
+    ) -> Result<(), PreguntaError> {
+        let resultado = self
+            .get_collection()
+            .update_one(
+                doc! { "_id": examen_id.to_string() },
+                doc! {
+                    "$pull": {
+                        "preguntas": { "_id": pregunta_id.to_string() },
+                        "preguntas_banco": pregunta_id.to_string(),
+                    }
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("Error removing question {}: {}", pregunta_id, e);
+                PreguntaError::PreguntaRepositorioError(ActualizacionNoFinalizada)
+            })?;
+
+        if resultado.modified_count > 0 {
+            self.abrir_borrador(&examen_id).await?;
+        }
+
+        info!("Question {} removed from exam {}", pregunta_id, examen_id);
+        Ok(())
+    }
+}
+
 fn preguntas_to_bson(preguntas: &[PreguntaEntity]) -> Vec<Bson> {
     preguntas
         .iter()
-        .map(|pregunta| {
-            let mut document = doc! {
-                "_id": pregunta.id.to_string(),
-                "contenido": pregunta.contenido.clone(),
-                "etiqueta": pregunta.etiqueta.to_string(),
-                "tipo_de_pregunta": pregunta.tipo_de_pregunta.to_string()
-            };
-
-            if let Some(ref imagen) = pregunta.imagen_ref {
-                document.insert("imagen_ref", imagen.clone());
-            }
+        .map(|pregunta| Bson::Document(pregunta_to_document(pregunta)))
+        .collect()
+}
 
-            let alternativas = &pregunta.alternativas;
-            let alternativas_doc = alternativas
-                .iter()
-                .map(|(key, value)| (key.to_string(), Bson::String(value.clone())))
-                .collect::<Document>();
+pub(crate) fn pregunta_to_document(pregunta: &PreguntaEntity) -> Document {
+    let mut document = doc! {
+        "_id": pregunta.id.to_string(),
+        "contenido": pregunta.contenido.clone(),
+        "etiqueta": pregunta.etiqueta.to_string(),
+        "tipo_de_pregunta": pregunta.tipo_de_pregunta.to_string(),
+        "inversa": pregunta.inversa,
+    };
 
-            document.insert("alternativas", alternativas_doc);
+    if let Some(ref imagen) = pregunta.imagen_ref {
+        document.insert("imagen_ref", imagen.clone());
+    }
 
-            let puntaje = &pregunta.puntaje;
-            let puntaje_doc = puntaje
-                .iter()
-                .map(|(key, value)| (key.to_string(), Bson::Int32(*value as i32)))
-                .collect::<Document>();
+    let alternativas = &pregunta.alternativas;
+    let alternativas_doc = alternativas
+        .iter()
+        .map(|(key, value)| (key.to_string(), Bson::String(value.clone())))
+        .collect::<Document>();
 
-            document.insert("puntaje", puntaje_doc);
+    document.insert("alternativas", alternativas_doc);
 
-            Bson::Document(document)
-        })
-        .collect()
+    let puntaje = &pregunta.puntaje;
+    let puntaje_doc = puntaje
+        .iter()
+        .map(|(key, value)| (key.to_string(), Bson::Int32(*value as i32)))
+        .collect::<Document>();
+
+    document.insert("puntaje", puntaje_doc);
+
+    if let Some(ref seleccion) = pregunta.seleccion {
+        document.insert("seleccion", SeleccionDTO::from(seleccion).to_document());
+    }
+
+    if let Some(ordenamiento) = pregunta.ordenamiento {
+        document.insert("ordenamiento", ordenamiento.to_string());
+    }
+
+    if let Some(ref numerica) = pregunta.numerica {
+        document.insert("numerica", NumericaDTO::from(numerica).to_document());
+    }
+
+    document
 }
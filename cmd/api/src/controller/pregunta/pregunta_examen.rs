@@ -0,0 +1,63 @@
+use crate::controller::pregunta::agregar_pregunta::respuesta_error;
+use crate::controller::pregunta::dto::PreguntaDetailDto;
+use crate::controller::pregunta::mongo::write::PreguntaPorExamenMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::info;
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::pregunta::use_case::actualizar_pregunta_examen::{self, ActualizarPreguntaExamen};
+use quizz_core::pregunta::use_case::eliminar_pregunta_examen::{self, EliminarPreguntaExamen};
+
+pub struct PreguntaExamenController;
+
+impl PreguntaExamenController {
+    pub async fn update(
+        req: HttpRequest,
+        body: web::Json<PreguntaDetailDto>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let Some((examen_id, pregunta_id)) = ids(&req) else {
+            return HttpResponse::BadRequest()
+                .json("se debe enviar el ID del examen y de la pregunta");
+        };
+
+        info!("PUT /examenes/{}/preguntas/{}", examen_id, pregunta_id);
+
+        let actualizar = ActualizarPreguntaExamen::new(Box::new(PreguntaPorExamenMongo::new(pool)));
+        let input = actualizar_pregunta_examen::InputData {
+            examen_id,
+            pregunta_id,
+            pregunta: body.into_inner().into(),
+        };
+
+        match actualizar.ejecutar(input).await {
+            Ok(_) => HttpResponse::Ok().finish(),
+            Err(err) => respuesta_error(err),
+        }
+    }
+
+    pub async fn delete(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
+        let Some((examen_id, pregunta_id)) = ids(&req) else {
+            return HttpResponse::BadRequest()
+                .json("se debe enviar el ID del examen y de la pregunta");
+        };
+
+        info!("DELETE /examenes/{}/preguntas/{}", examen_id, pregunta_id);
+
+        let eliminar = EliminarPreguntaExamen::new(Box::new(PreguntaPorExamenMongo::new(pool)));
+        let input = eliminar_pregunta_examen::InputData {
+            examen_id,
+            pregunta_id,
+        };
+
+        match eliminar.ejecutar(input).await {
+            Ok(_) => HttpResponse::NoContent().finish(),
+            Err(err) => respuesta_error(err),
+        }
+    }
+}
+
+fn ids(req: &HttpRequest) -> Option<(String, String)> {
+    let examen_id = req.match_info().get("id")?;
+    let pregunta_id = req.match_info().get("pregunta_id")?;
+    Some((examen_id.to_string(), pregunta_id.to_string()))
+}
@@ -0,0 +1,16 @@
+use crate::controller::pregunta::banco_preguntas::BancoPreguntasController;
+use actix_web::web;
+
+pub fn pregunta(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/preguntas")
+            .service(web::resource("").route(web::get().to(BancoPreguntasController::list)))
+            .service(
+                web::resource("/{id}")
+                    .route(web::get().to(BancoPreguntasController::get))
+                    .route(web::post().to(BancoPreguntasController::create))
+                    .route(web::put().to(BancoPreguntasController::update))
+                    .route(web::delete().to(BancoPreguntasController::delete)),
+            ),
+    );
+}
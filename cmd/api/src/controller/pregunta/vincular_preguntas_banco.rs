@@ -0,0 +1,41 @@
+use crate::controller::pregunta::agregar_pregunta::respuesta_error;
+use crate::controller::pregunta::dto::VincularPreguntasBancoDTO;
+use crate::controller::pregunta::mongo::banco::BancoPreguntasMongo;
+use crate::controller::pregunta::mongo::write::PreguntaPorExamenMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::info;
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::pregunta::use_case::vincular_preguntas_banco::{InputData, VincularPreguntasBanco};
+
+pub struct VincularPreguntasBancoController;
+
+impl VincularPreguntasBancoController {
+    pub async fn update(
+        req: HttpRequest,
+        body: web::Json<VincularPreguntasBancoDTO>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let examen_id = match req.match_info().get("id") {
+            Some(id) => id.to_string(),
+            None => {
+                return HttpResponse::BadRequest().json("se debe enviar el ID del examen");
+            }
+        };
+
+        info!("PUT /examenes/{}/banco", examen_id);
+
+        let vincular = VincularPreguntasBanco::new(
+            Box::new(PreguntaPorExamenMongo::new(pool.clone())),
+            Box::new(BancoPreguntasMongo::new(pool)),
+        );
+        let input = InputData {
+            examen_id,
+            pregunta_ids: body.into_inner().pregunta_ids,
+        };
+
+        match vincular.ejecutar(input).await {
+            Ok(_) => HttpResponse::Ok().finish(),
+            Err(err) => respuesta_error(err),
+        }
+    }
+}
@@ -1,23 +1,58 @@
+use crate::configuration::LoginSettings;
+use crate::controller::auth::redis::codigo_acceso::CodigoAccesoRedis;
 use crate::controller::respuesta::dto::{
-    CrearRespuestaDTO, RespuestaCreatedDTO, build_respuesta_links,
+    AsignarLoteDTO, CodigoAccesoDTO, CrearRespuestaDTO, ReemitirCodigoAccesoDTO,
+    ReporteAsignacionDTO, RespuestaCreatedDTO, build_respuesta_links,
 };
+use crate::controller::respuesta::mongo::read::ListarAsignacionesMongo;
 use crate::controller::respuesta::mongo::write::RespuestaEvaluacionMongo;
 use actix_web::{HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
+use quizz_auth::universal::domain::error::login_universal::LoginUniversalError;
+use quizz_auth::universal::use_case::emitir_codigo_acceso::{self, EmitirCodigoAcceso};
 use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::entity::respuesta::Estado;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
+use quizz_core::respuesta::use_case::asignar_masivo::{self, AsignarEvaluacionMasiva};
 use quizz_core::respuesta::use_case::asignar_postulante::{
     AsignarEvaluacionAPostulante, InputData,
 };
+use quizz_core::respuesta::use_case::listar_asignaciones::{self, ListarAsignaciones};
 use serde_json::json;
 
 pub struct AsignarEvaluacionPostulanteController;
 
+async fn emitir_codigo(
+    redis_client: &web::Data<redis::Client>,
+    login_settings: &LoginSettings,
+    postulante_id: String,
+    respuesta_id: String,
+) -> Result<CodigoAccesoDTO, LoginUniversalError> {
+    let emitir = EmitirCodigoAcceso::new(
+        Box::new(CodigoAccesoRedis::new(redis_client.clone())),
+        login_settings.codigo_acceso_segundos,
+    );
+    let codigo = emitir
+        .ejecutar(emitir_codigo_acceso::InputData {
+            postulante_id,
+            respuesta_id,
+        })
+        .await?;
+
+    Ok(CodigoAccesoDTO {
+        codigo: codigo.codigo,
+        expires_in: codigo.expiration,
+    })
+}
+
 impl AsignarEvaluacionPostulanteController {
     pub async fn create(
         req: HttpRequest,
         body: web::Json<CrearRespuestaDTO>,
         pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        login_settings: web::Data<LoginSettings>,
     ) -> HttpResponse {
         let evaluacion_id = match req.match_info().get("evaluacion_id") {
             Some(id) => id.to_string(),
@@ -44,23 +79,57 @@ impl AsignarEvaluacionPostulanteController {
         };
 
         match asociar.ejecutar(input).await {
-            Ok(()) => {
+            Ok(respuesta_id) => {
+                let respuesta_id = respuesta_id.to_string();
                 info!(
-                    "POST /evaluaciones/{}/respuestas - asignacion exitosa",
-                    evaluacion_id
+                    "POST /evaluaciones/{}/respuestas - asignacion exitosa, respuesta={}",
+                    evaluacion_id, respuesta_id
                 );
+
+                let codigo_acceso = if dto.codigo_acceso {
+                    match emitir_codigo(
+                        &redis_client,
+                        &login_settings,
+                        dto.postulante_id.clone(),
+                        respuesta_id.clone(),
+                    )
+                    .await
+                    {
+                        Ok(codigo) => Some(codigo),
+                        Err(err) => {
+                            error!(
+                                "POST /evaluaciones/{}/respuestas - error al emitir el codigo de acceso de {}: {:?}",
+                                evaluacion_id, respuesta_id, err
+                            );
+                            return HttpResponse::InternalServerError().json(json!({
+                                "error": format!(
+                                    "La respuesta {} fue creada pero no se pudo emitir su codigo de acceso",
+                                    respuesta_id
+                                )
+                            }));
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 let links = build_respuesta_links(
-                    "",
+                    &respuesta_id,
                     &dto.postulante_id,
                     "Creado",
                     &Rol::Psicologo.to_string(),
                 );
                 HttpResponse::Created().json(RespuestaCreatedDTO {
-                    id: String::new(),
+                    id: respuesta_id,
                     estado: "Creado".to_string(),
+                    codigo_acceso,
                     links,
                 })
             }
+            Err(err @ RespuestaError::EvaluacionNoDisponible(_)) => {
+                warn!("POST /evaluaciones/{}/respuestas - {}", evaluacion_id, err);
+                HttpResponse::Conflict().json(json!({"error": err.to_string()}))
+            }
             Err(err) => {
                 error!(
                     "POST /evaluaciones/{}/respuestas - error: {}",
@@ -70,4 +139,188 @@ impl AsignarEvaluacionPostulanteController {
             }
         }
     }
+
+    pub async fn create_batch(
+        req: HttpRequest,
+        body: web::Json<AsignarLoteDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        login_settings: web::Data<LoginSettings>,
+    ) -> HttpResponse {
+        let evaluacion_id = match req.match_info().get("evaluacion_id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!("POST /evaluaciones/.../respuestas/lote - evaluacion_id no proporcionado");
+                return HttpResponse::BadRequest()
+                    .json(json!({"error": "Se debe enviar el ID de la evaluacion"}));
+            }
+        };
+
+        let dto = body.into_inner();
+        if dto.postulante_ids.is_empty() && dto.documentos.is_empty() {
+            warn!(
+                "POST /evaluaciones/{}/respuestas/lote - sin postulantes",
+                evaluacion_id
+            );
+            return HttpResponse::BadRequest()
+                .json(json!({"error": "Se debe enviar al menos un postulante"}));
+        }
+
+        info!(
+            "POST /evaluaciones/{}/respuestas/lote - {} ids, {} documentos",
+            evaluacion_id,
+            dto.postulante_ids.len(),
+            dto.documentos.len()
+        );
+
+        let codigo_acceso = dto.codigo_acceso;
+        let asignar = AsignarEvaluacionMasiva::new(Box::new(RespuestaEvaluacionMongo::new(pool)));
+        let input = asignar_masivo::InputData {
+            evaluacion_id: evaluacion_id.clone(),
+            postulante_ids: dto.postulante_ids,
+            documentos: dto.documentos,
+        };
+
+        match asignar.ejecutar(input).await {
+            Ok(resultados) => {
+                let mut reporte = ReporteAsignacionDTO::from(resultados);
+                if codigo_acceso {
+                    for resultado in reporte.resultados.iter_mut() {
+                        let (Some(postulante_id), Some(respuesta_id)) =
+                            (&resultado.postulante_id, &resultado.respuesta_id)
+                        else {
+                            continue;
+                        };
+                        // Un codigo que falla no deshace la asignacion: se puede reemitir
+                        match emitir_codigo(
+                            &redis_client,
+                            &login_settings,
+                            postulante_id.clone(),
+                            respuesta_id.clone(),
+                        )
+                        .await
+                        {
+                            Ok(codigo) => resultado.codigo_acceso = Some(codigo),
+                            Err(err) => error!(
+                                "POST /evaluaciones/{}/respuestas/lote - error al emitir el codigo de acceso de {}: {:?}",
+                                evaluacion_id, respuesta_id, err
+                            ),
+                        }
+                    }
+                }
+                info!(
+                    "POST /evaluaciones/{}/respuestas/lote - asignados: {}, ya asignados: {}, desconocidos: {}",
+                    evaluacion_id, reporte.asignados, reporte.ya_asignados, reporte.desconocidos
+                );
+                HttpResponse::Ok().json(reporte)
+            }
+            Err(err @ RespuestaError::EvaluacionRespuestaNotFound) => {
+                warn!(
+                    "POST /evaluaciones/{}/respuestas/lote - {}",
+                    evaluacion_id, err
+                );
+                HttpResponse::NotFound().json(json!({"error": err.to_string()}))
+            }
+            Err(err @ RespuestaError::EvaluacionNoDisponible(_)) => {
+                warn!(
+                    "POST /evaluaciones/{}/respuestas/lote - {}",
+                    evaluacion_id, err
+                );
+                HttpResponse::Conflict().json(json!({"error": err.to_string()}))
+            }
+            Err(err) => {
+                error!(
+                    "POST /evaluaciones/{}/respuestas/lote - error: {}",
+                    evaluacion_id, err
+                );
+                HttpResponse::InternalServerError().json(json!({"error": err.to_string()}))
+            }
+        }
+    }
+
+    pub async fn reemitir_codigo(
+        req: HttpRequest,
+        body: web::Json<ReemitirCodigoAccesoDTO>,
+        pool: web::Data<mongodb::Client>,
+        redis_client: web::Data<redis::Client>,
+        login_settings: web::Data<LoginSettings>,
+    ) -> HttpResponse {
+        let evaluacion_id = match req.match_info().get("evaluacion_id") {
+            Some(id) => id.to_string(),
+            None => {
+                warn!(
+                    "POST /evaluaciones/.../respuestas/codigo-acceso - evaluacion_id no proporcionado"
+                );
+                return HttpResponse::BadRequest()
+                    .json(json!({"error": "Se debe enviar el ID de la evaluacion"}));
+            }
+        };
+
+        let dto = body.into_inner();
+        info!(
+            "POST /evaluaciones/{}/respuestas/codigo-acceso - postulante={}",
+            evaluacion_id, dto.postulante_id
+        );
+
+        let listar = ListarAsignaciones::new(Box::new(ListarAsignacionesMongo::new(pool)));
+        let input = listar_asignaciones::InputData {
+            postulante_id: Some(dto.postulante_id.clone()),
+            evaluacion_id: Some(evaluacion_id.clone()),
+        };
+        let asignacion = match listar.ejecutar(input).await {
+            Ok(asignaciones) => match asignaciones.into_iter().next() {
+                Some(asignacion) => asignacion,
+                None => {
+                    warn!(
+                        "POST /evaluaciones/{}/respuestas/codigo-acceso - {} no tiene la evaluacion asignada",
+                        evaluacion_id, dto.postulante_id
+                    );
+                    return HttpResponse::NotFound().json(json!({
+                        "error": "El postulante no tiene asignada esta evaluacion"
+                    }));
+                }
+            },
+            Err(err) => {
+                error!(
+                    "POST /evaluaciones/{}/respuestas/codigo-acceso - error: {}",
+                    evaluacion_id, err
+                );
+                return HttpResponse::InternalServerError().json(json!({"error": err.to_string()}));
+            }
+        };
+
+        if asignacion.estado == Estado::Finalizado.to_string() {
+            warn!(
+                "POST /evaluaciones/{}/respuestas/codigo-acceso - respuesta {} finalizada",
+                evaluacion_id, asignacion.respuesta_id
+            );
+            return HttpResponse::Conflict()
+                .json(json!({"error": RespuestaError::EvaluacionYaFinalizada.to_string()}));
+        }
+
+        match emitir_codigo(
+            &redis_client,
+            &login_settings,
+            dto.postulante_id,
+            asignacion.respuesta_id.clone(),
+        )
+        .await
+        {
+            Ok(codigo) => {
+                info!(
+                    "POST /evaluaciones/{}/respuestas/codigo-acceso - codigo reemitido para {}",
+                    evaluacion_id, asignacion.respuesta_id
+                );
+                HttpResponse::Created().json(codigo)
+            }
+            Err(err) => {
+                error!(
+                    "POST /evaluaciones/{}/respuestas/codigo-acceso - error al emitir el codigo de acceso de {}: {:?}",
+                    evaluacion_id, asignacion.respuesta_id, err
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "No se pudo emitir el codigo de acceso"}))
+            }
+        }
+    }
 }
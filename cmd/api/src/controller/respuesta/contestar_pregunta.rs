@@ -1,9 +1,12 @@
 use crate::controller::auth::jwt::Claims;
 use crate::controller::respuesta::dto::ContestacionDTO;
-use crate::controller::respuesta::mongo::write::RespuestaEvaluacionMongo;
+use crate::controller::respuesta::mongo::write::{
+    RepositorioEmpezarExamenMongo, RespositorioFinalizarEvaluacionMongo, RespuestaEvaluacionMongo,
+};
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
-use log::{error, info};
+use log::{error, info, warn};
 use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
 use quizz_core::respuesta::use_case::responder_evaluacion::{InputData, ResponderEvaluacion};
 use serde_json::json;
 
@@ -63,8 +66,11 @@ impl ContestarPreguntaController {
             respuestas: dto.respuestas,
         };
 
-        let respuesta_questionario =
-            ResponderEvaluacion::new(Box::new(RespuestaEvaluacionMongo::new(pool)));
+        let respuesta_questionario = ResponderEvaluacion::new(
+            Box::new(RespuestaEvaluacionMongo::new(pool.clone())),
+            Box::new(RepositorioEmpezarExamenMongo::new(pool.clone())),
+            Box::new(RespositorioFinalizarEvaluacionMongo::new(pool)),
+        );
 
         match respuesta_questionario.ejecutar(input).await {
             Ok(()) => {
@@ -86,6 +92,58 @@ impl ContestarPreguntaController {
                     }
                 }))
             }
+            Err(RespuestaError::TiempoAgotado) => {
+                warn!(
+                    "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - tiempo agotado",
+                    respuesta_id, examen_id, pregunta_id
+                );
+                HttpResponse::Conflict().json(json!({
+                    "error": "El tiempo para rendir la evaluacion se agoto",
+                    "estado": "Finalizado",
+                    "_links": {
+                        "respuesta": {
+                            "href": format!("/respuestas/{}", respuesta_id),
+                            "method": "GET"
+                        }
+                    }
+                }))
+            }
+            Err(
+                err @ (RespuestaError::EvaluacionNoIniciada
+                | RespuestaError::EvaluacionYaFinalizada
+                | RespuestaError::TiempoExamenAgotado),
+            ) => {
+                warn!(
+                    "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - {}",
+                    respuesta_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::Conflict().json(json!({"error": err.to_string()}))
+            }
+            Err(RespuestaError::RespuestaDeOtroPostulante) => {
+                warn!(
+                    "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - postulante no autorizado",
+                    respuesta_id, examen_id, pregunta_id
+                );
+                HttpResponse::Forbidden()
+                    .json(json!({"error": "La respuesta no pertenece al postulante"}))
+            }
+            Err(
+                err @ (RespuestaError::SeleccionFueraDeRango { .. }
+                | RespuestaError::OrdenIncompleto),
+            ) => {
+                warn!(
+                    "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - {}",
+                    respuesta_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::UnprocessableEntity().json(json!({"error": err.to_string()}))
+            }
+            Err(RespuestaError::RespuestaNoEncontrada) => {
+                warn!(
+                    "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - no encontrada",
+                    respuesta_id, examen_id, pregunta_id
+                );
+                HttpResponse::NotFound().json(json!({"error": "Respuesta no encontrada"}))
+            }
             Err(err) => {
                 error!(
                     "POST /respuestas/{}/examenes/{}/preguntas/{}/contestaciones - error: {:?}",
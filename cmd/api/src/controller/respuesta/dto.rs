@@ -1,13 +1,47 @@
+use crate::controller::evaluacion::dto::CriterioResultadoDTO;
+use crate::controller::examen::dto::{
+    AleatorizacionDTO, PoliticaPuntajeDTO, ReglaMuestreoDTO, VersionDTO,
+};
 use crate::controller::hateoas::{Link, Links};
+use crate::controller::pregunta::dto::{NumericaDTO, SeleccionDTO};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
+use quizz_core::examen::domain::value_object::muestreo::{Muestreo, ReglaMuestreo};
+use quizz_core::pregunta::domain::value_object::etiqueta::Etiqueta;
+use quizz_core::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use quizz_core::respuesta::domain::value_object::barajado::{
+    OrdenAlternativas, barajar_preguntas, sortear_preguntas,
+};
+use quizz_core::respuesta::use_case::asignar_masivo::{EstadoAsignacion, ResultadoAsignacion};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 
 // --- Request DTOs ---
 
 #[derive(Deserialize)]
 pub struct CrearRespuestaDTO {
     pub postulante_id: String,
+    /// Emite un codigo de un solo uso para entrar a esta respuesta sin password
+    #[serde(default)]
+    pub codigo_acceso: bool,
+}
+
+/// Postulantes a los que se asigna la evaluacion, por id, por documento o
+/// ambos
+#[derive(Deserialize)]
+pub struct AsignarLoteDTO {
+    #[serde(default)]
+    pub postulante_ids: Vec<String>,
+    #[serde(default)]
+    pub documentos: Vec<String>,
+    /// Emite un codigo de acceso para cada respuesta creada
+    #[serde(default)]
+    pub codigo_acceso: bool,
+}
+
+#[derive(Deserialize)]
+pub struct ReemitirCodigoAccesoDTO {
+    pub postulante_id: String,
 }
 
 #[derive(Deserialize)]
@@ -39,10 +73,73 @@ pub struct AsignacionesQueryParams {
 pub struct RespuestaCreatedDTO {
     pub id: String,
     pub estado: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codigo_acceso: Option<CodigoAccesoDTO>,
     #[serde(rename = "_links")]
     pub links: Links,
 }
 
+#[derive(Serialize)]
+pub struct CodigoAccesoDTO {
+    pub codigo: String,
+    pub expires_in: u64,
+}
+
+#[derive(Serialize)]
+pub struct ResultadoAsignacionDTO {
+    pub postulante: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postulante_id: Option<String>,
+    pub estado: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub respuesta_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codigo_acceso: Option<CodigoAccesoDTO>,
+}
+
+impl From<ResultadoAsignacion> for ResultadoAsignacionDTO {
+    fn from(resultado: ResultadoAsignacion) -> Self {
+        let (estado, respuesta_id) = match resultado.estado {
+            EstadoAsignacion::Asignado { respuesta_id } => ("asignado", Some(respuesta_id)),
+            EstadoAsignacion::YaAsignado => ("ya_asignado", None),
+            EstadoAsignacion::PostulanteDesconocido => ("postulante_desconocido", None),
+        };
+
+        Self {
+            postulante: resultado.postulante,
+            postulante_id: resultado.postulante_id,
+            estado,
+            respuesta_id,
+            codigo_acceso: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ReporteAsignacionDTO {
+    pub asignados: usize,
+    pub ya_asignados: usize,
+    pub desconocidos: usize,
+    pub resultados: Vec<ResultadoAsignacionDTO>,
+}
+
+impl From<Vec<ResultadoAsignacion>> for ReporteAsignacionDTO {
+    fn from(resultados: Vec<ResultadoAsignacion>) -> Self {
+        let resultados: Vec<ResultadoAsignacionDTO> = resultados
+            .into_iter()
+            .map(ResultadoAsignacionDTO::from)
+            .collect();
+        let contar = |estado: &str| resultados.iter().filter(|r| r.estado == estado).count();
+
+        Self {
+            asignados: contar("asignado"),
+            ya_asignados: contar("ya_asignado"),
+            desconocidos: contar("postulante_desconocido"),
+            resultados,
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct RespuestaListItemDTO {
     pub id: String,
@@ -79,6 +176,8 @@ pub struct RespuestaDetailDTO {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fecha_tiempo_transcurrido: Option<i64>,
     pub fecha_tiempo_fin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fecha_tiempo_limite: Option<String>,
     pub estado: String,
     pub evaluacion: EvaluacionResponseDTO,
     pub revision: String,
@@ -93,6 +192,8 @@ pub struct EvaluacionResponseDTO {
     pub id: String,
     pub nombre: String,
     pub descripcion: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duracion_minutos: Option<u32>,
     pub examenes: Vec<ExamenResponseDTO>,
 }
 
@@ -102,6 +203,8 @@ pub struct ExamenResponseDTO {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duracion_minutos: Option<u32>,
     pub preguntas: Vec<PreguntaResponseDTO>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub puntos_obtenidos: Option<i64>,
@@ -122,32 +225,62 @@ pub struct PreguntaResponseDTO {
     pub respuestas: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub puntos: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seleccion: Option<SeleccionDTO>,
     #[serde(rename = "_links")]
     pub links: Links,
 }
 
 // --- Mongo DTOs (internal) ---
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct EvaluacionMongoDTO {
     #[serde(rename = "_id")]
     pub id: String,
     pub nombre: String,
     pub descripcion: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
+    #[serde(default, skip_serializing_if = "CriterioResultadoDTO::esta_vacio")]
+    pub criterio_resultado: CriterioResultadoDTO,
     pub examenes: Vec<ExamenMongoDTO>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ExamenMongoDTO {
     #[serde(rename = "_id")]
     pub id: String,
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
     pub preguntas: Vec<PreguntaMongoDTO>,
+    #[serde(default)]
+    pub politica_puntaje: PoliticaPuntajeDTO,
+    // se aplica al asignar; la respuesta guarda el orden resultante
+    #[serde(default, skip_serializing)]
+    pub aleatorizacion: AleatorizacionDTO,
+    #[serde(default, skip_serializing)]
+    pub muestreo: Vec<ReglaMuestreoDTO>,
+    // registro de las reglas aplicadas y las preguntas sorteadas
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sorteo: Vec<SorteoDTO>,
+    // version del examen con la que se armo la respuesta
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<VersionDTO>,
 }
 
-#[derive(Serialize, Deserialize)]
+/// Auditoria de una regla de muestreo aplicada al asignar la evaluacion.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SorteoDTO {
+    pub etiqueta: String,
+    pub cantidad: u32,
+    pub disponibles: u32,
+    pub preguntas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 pub struct PreguntaMongoDTO {
     #[serde(rename = "_id")]
     pub id: String,
@@ -159,6 +292,95 @@ pub struct PreguntaMongoDTO {
     pub alternativas: HashMap<String, String>,
     pub puntaje: HashMap<String, u32>,
     pub respuestas: Option<Vec<String>>,
+    #[serde(default)]
+    pub inversa: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seleccion: Option<SeleccionDTO>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ordenamiento: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numerica: Option<NumericaDTO>,
+    // claves originales en el orden en que el postulante ve las alternativas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orden_alternativas: Option<Vec<String>>,
+}
+
+impl EvaluacionMongoDTO {
+    /// Sortea las preguntas de cada examen con muestreo y registra el sorteo.
+    /// Un muestreo que ya no es valido se ignora y se entregan todas.
+    pub fn sortear(&mut self, respuesta_id: &str) {
+        for examen in &mut self.examenes {
+            let muestreo = examen
+                .muestreo
+                .iter()
+                .cloned()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<ReglaMuestreo>, _>>()
+                .and_then(Muestreo::new);
+            let Ok(muestreo) = muestreo else {
+                continue;
+            };
+            if muestreo.reglas.is_empty() {
+                continue;
+            }
+
+            let etiquetas: Vec<Etiqueta> = examen
+                .preguntas
+                .iter()
+                .map(|pregunta| pregunta.etiqueta.parse().unwrap_or(Etiqueta::No))
+                .collect();
+            let sorteo = sortear_preguntas(respuesta_id, &examen.id, &muestreo, &etiquetas);
+
+            examen.sorteo = sorteo
+                .reglas
+                .iter()
+                .map(|regla| SorteoDTO {
+                    etiqueta: regla.etiqueta.to_string(),
+                    cantidad: regla.cantidad,
+                    disponibles: regla.disponibles,
+                    preguntas: regla
+                        .extraidas
+                        .iter()
+                        .map(|i| examen.preguntas[*i].id.clone())
+                        .collect(),
+                })
+                .collect();
+
+            let preguntas = std::mem::take(&mut examen.preguntas);
+            examen.preguntas = preguntas
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| sorteo.seleccionadas.binary_search(i).is_ok())
+                .map(|(_, pregunta)| pregunta)
+                .collect();
+        }
+    }
+
+    /// Aplica la aleatorizacion de cada examen con la respuesta como semilla
+    pub fn barajar(&mut self, respuesta_id: &str) {
+        for examen in &mut self.examenes {
+            if examen.aleatorizacion.preguntas {
+                barajar_preguntas(respuesta_id, &examen.id, &mut examen.preguntas);
+            }
+            if !examen.aleatorizacion.alternativas {
+                continue;
+            }
+            for pregunta in &mut examen.preguntas {
+                let aplica = TipoPregunta::from_str(&pregunta.tipo_de_pregunta)
+                    .is_ok_and(|tipo| OrdenAlternativas::aplica(&tipo));
+                if aplica {
+                    pregunta.orden_alternativas = Some(
+                        OrdenAlternativas::generar(
+                            respuesta_id,
+                            &pregunta.id,
+                            &pregunta.alternativas,
+                        )
+                        .claves,
+                    );
+                }
+            }
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1,7 +1,8 @@
 use crate::controller::auth::jwt::Claims;
-use crate::controller::hateoas::{Link, ListResponse, Links};
+use crate::controller::hateoas::{Link, Links, ListResponse};
 use crate::controller::respuesta::dto::{AsignacionListItemDTO, AsignacionesQueryParams};
 use crate::controller::respuesta::mongo::read::ListarAsignacionesMongo;
+use crate::controller::respuesta::plazo::finalizar_vencidas;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
@@ -21,17 +22,13 @@ impl ListarAsignacionesController {
             Some(c) => c,
             None => {
                 warn!("GET /respuestas/asignaciones - claims no encontrados");
-                return HttpResponse::Unauthorized()
-                    .json(json!({"error": "Token no encontrado"}));
+                return HttpResponse::Unauthorized().json(json!({"error": "Token no encontrado"}));
             }
         };
 
         let rol = claims.rol.as_deref().unwrap_or("");
         if rol != Rol::Admin.to_string() && rol != Rol::Psicologo.to_string() {
-            warn!(
-                "GET /respuestas/asignaciones - rol no autorizado: {}",
-                rol
-            );
+            warn!("GET /respuestas/asignaciones - rol no autorizado: {}", rol);
             return HttpResponse::Forbidden().json(json!({"error": "Acceso denegado"}));
         }
 
@@ -40,6 +37,14 @@ impl ListarAsignacionesController {
             query.postulante_id, query.evaluacion_id
         );
 
+        if let Err(e) = finalizar_vencidas(&pool, query.postulante_id.clone()).await {
+            error!(
+                "GET /respuestas/asignaciones - error al finalizar por tiempo: {}",
+                e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
         let listar = ListarAsignaciones::new(Box::new(ListarAsignacionesMongo::new(pool)));
         let input = InputData {
             postulante_id: query.postulante_id.clone(),
@@ -101,8 +106,7 @@ impl ListarAsignacionesController {
                     .collect();
 
                 let mut collection_links = Links::new();
-                collection_links
-                    .insert("self".into(), Link::get("/respuestas/asignaciones"));
+                collection_links.insert("self".into(), Link::get("/respuestas/asignaciones"));
 
                 HttpResponse::Ok().json(ListResponse {
                     links: collection_links,
@@ -4,6 +4,7 @@ use crate::controller::respuesta::dto::{
     RespuestaListItemDTO, RespuestaQueryParams, build_respuesta_list_item_links,
 };
 use crate::controller::respuesta::mongo::read::ListaRespuestaPostulanteMongo;
+use crate::controller::respuesta::plazo::finalizar_vencidas;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
@@ -48,6 +49,14 @@ impl ListarRespuestasController {
 
         info!("GET /respuestas?postulante_id={}", postulante_id);
 
+        if let Err(e) = finalizar_vencidas(&pool, Some(postulante_id.clone())).await {
+            error!(
+                "GET /respuestas?postulante_id={} - error al finalizar por tiempo: {}",
+                postulante_id, e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
         let lista_respuesta =
             ListaRespuestaPostulante::new(Box::new(ListaRespuestaPostulanteMongo::new(pool)));
         let input = InputData {
@@ -5,5 +5,6 @@ mod listar_asignaciones;
 mod listar_respuestas;
 pub mod mongo;
 mod obtener_respuesta;
+pub mod plazo;
 pub mod route;
 mod transicion_estado;
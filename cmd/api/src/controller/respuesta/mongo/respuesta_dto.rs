@@ -1,3 +1,6 @@
+use crate::controller::evaluacion::dto::CriterioResultadoDTO;
+use crate::controller::pregunta::dto::SeleccionDTO;
+use crate::controller::revision::dto::CalificacionDTO;
 use quizz_core::evaluacion::value_object::id::EvaluacionID;
 use quizz_core::examen::domain::value_object::id::ExamenID;
 use quizz_core::postulante::domain::value_object::id::PostulanteID;
@@ -7,7 +10,9 @@ use quizz_core::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
 use quizz_core::respuesta::domain::entity::evaluacion::Evaluacion;
 use quizz_core::respuesta::domain::entity::examen::Examen;
 use quizz_core::respuesta::domain::entity::pregunta::Pregunta;
+use quizz_core::respuesta::domain::entity::rasgo::PuntuacionRasgo;
 use quizz_core::respuesta::domain::entity::respuesta::{Respuesta, Revision};
+use quizz_core::respuesta::domain::value_object::barajado::OrdenAlternativas;
 use quizz_core::respuesta::domain::value_object::id::RespuestaID;
 use quizz_core::respuesta::use_case::respuesta_postulante::{
     OutputData, OutputEvaluacion, OutputExamen, OutputPregunta,
@@ -31,19 +36,38 @@ pub struct RespuestaDTO {
     pub evaluacion: EvaluacionDTO,
     pub revision: String,
     pub resultado: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resultado_sugerido: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub justificacion: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rasgos: Vec<RasgoDTO>,
 }
 
 impl From<RespuestaDTO> for Respuesta {
     fn from(respuesta: RespuestaDTO) -> Self {
         let revision = Revision::from_str(respuesta.revision.as_str()).unwrap_or(Revision::Default);
+        // Los dictamenes en texto libre anteriores al resultado tipado se conservan
+        let (resultado, resultado_anterior) = match respuesta.resultado {
+            Some(texto) => match texto.parse() {
+                Ok(resultado) => (Some(resultado), None),
+                Err(_) if texto.trim().is_empty() => (None, None),
+                Err(_) => (None, Some(texto)),
+            },
+            None => (None, None),
+        };
         Self {
             id: RespuestaID::new(respuesta.id.as_str()).unwrap(),
             fecha_tiempo_inicio: respuesta.fecha_tiempo_inicio,
             fecha_tiempo_fin: respuesta.fecha_tiempo_fin,
             evaluacion: respuesta.evaluacion.into(),
-            resultado: respuesta.resultado.unwrap_or_default(),
+            resultado,
+            resultado_anterior,
+            resultado_sugerido: respuesta.resultado_sugerido.and_then(|r| r.parse().ok()),
+            justificacion: respuesta.justificacion,
             postulante: PostulanteID::new(respuesta.postulante_id.as_str()).unwrap(),
             revision,
+            rasgos: respuesta.rasgos.into_iter().map(|r| r.into()).collect(),
         }
     }
 }
@@ -59,6 +83,39 @@ impl From<OutputData> for RespuestaDTO {
             evaluacion: respuesta.evaluacion.into(),
             revision: "".to_string(),
             resultado: Option::from(respuesta.resultado),
+            resultado_sugerido: None,
+            justificacion: String::new(),
+            rasgos: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RasgoDTO {
+    pub etiqueta: String,
+    pub puntos_brutos: i64,
+    pub cantidad_items: u32,
+    pub promedio: f64,
+}
+
+impl From<RasgoDTO> for PuntuacionRasgo {
+    fn from(rasgo: RasgoDTO) -> Self {
+        Self {
+            etiqueta: Etiqueta::from_str(&rasgo.etiqueta).unwrap_or(Etiqueta::No),
+            puntos_brutos: rasgo.puntos_brutos,
+            cantidad_items: rasgo.cantidad_items,
+            promedio: rasgo.promedio,
+        }
+    }
+}
+
+impl From<&PuntuacionRasgo> for RasgoDTO {
+    fn from(rasgo: &PuntuacionRasgo) -> Self {
+        Self {
+            etiqueta: rasgo.etiqueta.to_string(),
+            puntos_brutos: rasgo.puntos_brutos,
+            cantidad_items: rasgo.cantidad_items,
+            promedio: rasgo.promedio,
         }
     }
 }
@@ -69,6 +126,10 @@ pub struct EvaluacionDTO {
     pub id: String,
     pub nombre: String,
     pub descripcion: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
+    #[serde(default, skip_serializing_if = "CriterioResultadoDTO::esta_vacio")]
+    pub criterio_resultado: CriterioResultadoDTO,
     pub examenes: Vec<ExamenDTO>,
 }
 
@@ -78,6 +139,8 @@ impl From<EvaluacionDTO> for Evaluacion {
             id: EvaluacionID::new(evaluacion.id.as_str()).unwrap(),
             nombre: evaluacion.nombre,
             descripcion: evaluacion.descripcion,
+            duracion_minutos: evaluacion.duracion_minutos,
+            criterio: evaluacion.criterio_resultado.into(),
             examenes: evaluacion
                 .examenes
                 .into_iter()
@@ -93,6 +156,8 @@ impl From<OutputEvaluacion> for EvaluacionDTO {
             id: evaluacion.id.to_string(),
             nombre: evaluacion.nombre,
             descripcion: evaluacion.descripcion,
+            duracion_minutos: evaluacion.duracion_minutos,
+            criterio_resultado: CriterioResultadoDTO::default(),
             examenes: evaluacion
                 .examenes
                 .into_iter()
@@ -109,6 +174,8 @@ pub struct ExamenDTO {
     pub titulo: String,
     pub descripcion: String,
     pub instrucciones: String,
+    #[serde(default)]
+    pub duracion_minutos: Option<u32>,
     pub preguntas: Vec<PreguntaDTO>,
     pub puntos_obtenidos: Option<i64>,
     pub observacion: Option<String>,
@@ -121,6 +188,7 @@ impl From<ExamenDTO> for Examen {
             titulo: examen.titulo,
             descripcion: examen.descripcion,
             instrucciones: examen.instrucciones,
+            duracion_minutos: examen.duracion_minutos,
             observaciones: "".to_owned(),
             preguntas: examen
                 .preguntas
@@ -140,6 +208,7 @@ impl From<OutputExamen> for ExamenDTO {
             titulo: examen.titulo,
             descripcion: examen.descripcion,
             instrucciones: examen.instrucciones,
+            duracion_minutos: examen.duracion_minutos,
             preguntas: examen
                 .preguntas
                 .into_iter()
@@ -164,6 +233,16 @@ pub struct PreguntaDTO {
     #[serde(default)]
     pub respuestas: Option<Vec<String>>,
     pub puntos: Option<i64>,
+    #[serde(default, skip_serializing)]
+    pub puntaje: HashMap<String, u32>,
+    #[serde(default, skip_serializing)]
+    pub inversa: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seleccion: Option<SeleccionDTO>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calificacion: Option<CalificacionDTO>,
+    #[serde(default, skip_serializing)]
+    pub orden_alternativas: Option<Vec<String>>,
 }
 
 impl From<PreguntaDTO> for Pregunta {
@@ -176,9 +255,17 @@ impl From<PreguntaDTO> for Pregunta {
             tipo_de_pregunta: TipoPregunta::from_str(&pregunta.tipo_de_pregunta).unwrap(),
             imagen_ref: pregunta.imagen_ref.unwrap_or_default(),
             alternativas: pregunta.alternativas,
-            puntaje: Default::default(),
+            puntaje: pregunta.puntaje,
             respuestas: Option::from(pregunta.respuestas.unwrap_or_default()),
             puntos: pregunta.puntos.unwrap_or_default(),
+            inversa: pregunta.inversa,
+            seleccion: pregunta
+                .seleccion
+                .and_then(|seleccion| seleccion.try_into().ok()),
+            calificacion: pregunta.calificacion.map(Into::into),
+            orden_alternativas: pregunta
+                .orden_alternativas
+                .map(|claves| OrdenAlternativas { claves }),
         }
     }
 }
@@ -198,6 +285,11 @@ impl From<OutputPregunta> for PreguntaDTO {
             alternativas: pregunta.alternativas,
             respuestas: pregunta.respuestas,
             puntos: Option::from(pregunta.puntos),
+            puntaje: HashMap::new(),
+            inversa: false,
+            seleccion: pregunta.seleccion.as_ref().map(SeleccionDTO::from),
+            calificacion: None,
+            orden_alternativas: None,
         }
     }
 }
@@ -1,21 +1,40 @@
 use crate::controller::evaluacion::mongo::write::EvaluacionMongo;
+use crate::controller::examen::dto::PoliticaPuntajeDTO;
 use crate::controller::mongo_repository::MongoRepository;
 use crate::controller::postulante::mongo::write::PostulanteMongo;
+use crate::controller::pregunta::dto::{NumericaDTO, SeleccionDTO};
 use crate::controller::respuesta::dto::{EvaluacionMongoDTO, RespuestaMongoDTO};
 use crate::controller::respuesta::mongo::constantes::RESPUESTA_COLLECTION_NAME;
+use crate::controller::respuesta::mongo::respuesta_dto::{RasgoDTO, RespuestaDTO};
 use actix_web::web;
 use async_trait::async_trait;
+use futures::TryStreamExt;
 use mongodb::bson;
 use mongodb::bson::doc;
+use quizz_core::evaluacion::domain::value_object::evaluacion_estado::EvaluacionEstado;
+use quizz_core::evaluacion::domain::value_object::resultado::Resultado;
 use quizz_core::evaluacion::value_object::id::EvaluacionID;
+use quizz_core::examen::domain::value_object::politica_puntaje::PoliticaPuntaje;
 use quizz_core::postulante::domain::value_object::id::PostulanteID;
+use quizz_core::pregunta::domain::value_object::ordenamiento::ModoOrdenamiento;
+use quizz_core::pregunta::domain::value_object::tipo_pregunta::TipoPregunta;
+use quizz_core::respuesta::domain::entity::correccion::Correccion;
+use quizz_core::respuesta::domain::entity::evaluacion::Evaluacion;
 use quizz_core::respuesta::domain::entity::pregunta::Puntaje;
-use quizz_core::respuesta::domain::entity::respuesta::{Estado, RespuestaEvaluacion, Revision};
+use quizz_core::respuesta::domain::entity::rasgo::PuntuacionRasgo;
+use quizz_core::respuesta::domain::entity::respuesta::{
+    Estado, Respuesta, RespuestaEvaluacion, Revision,
+};
 use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
+use quizz_core::respuesta::domain::value_object::barajado::OrdenAlternativas;
 use quizz_core::respuesta::domain::value_object::id::RespuestaID;
+use quizz_core::respuesta::domain::value_object::plazo::Plazo;
 use quizz_core::respuesta::provider::repositorio::{
-    RepositorioEmpezarExamen, RepositorioRespuestaEscritura, RespositorioFinalizarEvaluacion,
+    RepositorioAsignacionMasiva, RepositorioEmpezarExamen, RepositorioRespuestaEscritura,
+    RespositorioFinalizarEvaluacion,
 };
+use quizz_core::respuesta::use_case::asignar_masivo::PostulanteConocido;
+use std::collections::HashSet;
 use std::str::FromStr;
 
 pub struct RespuestaEvaluacionMongo {
@@ -36,6 +55,59 @@ impl RespuestaEvaluacionMongo {
     }
 }
 
+impl RespuestaEvaluacionMongo {
+    /// Evaluacion lista para copiarse en una respuesta. Solo una evaluacion
+    /// publicada admite nuevas asignaciones; las ya asignadas de una
+    /// evaluacion archivada se siguen rindiendo.
+    async fn evaluacion_asignable(
+        &self,
+        evaluacion_id: &EvaluacionID,
+    ) -> Result<EvaluacionMongoDTO, RespuestaError> {
+        let evaluacion_doc = self
+            .repositorio_evaluacion
+            .get_collection()
+            .find_one(doc! { "_id": evaluacion_id.to_string() })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        let evaluacion_document =
+            evaluacion_doc.ok_or(RespuestaError::EvaluacionRespuestaNotFound)?;
+
+        let estado = evaluacion_document.get_str("estado").unwrap_or_default();
+        if estado != EvaluacionEstado::Publicado.to_string() {
+            return Err(RespuestaError::EvaluacionNoDisponible(estado.to_string()));
+        }
+
+        bson::from_document(evaluacion_document).map_err(|_| RespuestaError::DatabaseError)
+    }
+}
+
+/// Respuesta nueva para el postulante. Las preguntas se sortean y barajan con
+/// el id de la respuesta, asi cada postulante recibe su propio orden.
+fn nueva_respuesta(
+    evaluacion: &EvaluacionMongoDTO,
+    postulante_id: String,
+) -> Result<(String, bson::Document), RespuestaError> {
+    let respuesta_id = RespuestaID::new_v4().to_string();
+    let mut evaluacion = evaluacion.clone();
+    evaluacion.sortear(&respuesta_id);
+    evaluacion.barajar(&respuesta_id);
+
+    let respuesta_dto = RespuestaMongoDTO {
+        id: respuesta_id.clone(),
+        evaluacion,
+        postulante_id,
+        fecha_tiempo_inicio: String::new(),
+        fecha_tiempo_fin: String::new(),
+        estado: Estado::Creado.to_string(),
+        revision: Revision::SinIniciar.to_string(),
+    };
+
+    let respuesta_doc =
+        bson::to_document(&respuesta_dto).map_err(|_| RespuestaError::DatabaseError)?;
+    Ok((respuesta_id, respuesta_doc))
+}
+
 impl MongoRepository for RespuestaEvaluacionMongo {
     fn get_collection_name(&self) -> &str {
         RESPUESTA_COLLECTION_NAME
@@ -52,7 +124,7 @@ impl RepositorioRespuestaEscritura<RespuestaError> for RespuestaEvaluacionMongo
         &self,
         evaluacion_id: EvaluacionID,
         postulante_id: PostulanteID,
-    ) -> Result<(), RespuestaError> {
+    ) -> Result<RespuestaID, RespuestaError> {
         let existing_respuesta = self
             .get_collection()
             .find_one(doc! {
@@ -77,38 +149,16 @@ impl RepositorioRespuestaEscritura<RespuestaError> for RespuestaEvaluacionMongo
             return Err(RespuestaError::PostulanteRespuestaNotFound);
         }
 
-        let evaluacion_doc = self
-            .repositorio_evaluacion
-            .get_collection()
-            .find_one(doc! { "_id": evaluacion_id.to_string() })
-            .await
-            .map_err(|_| RespuestaError::DatabaseError)?;
-
-        let evaluacion_document =
-            evaluacion_doc.ok_or(RespuestaError::EvaluacionRespuestaNotFound)?;
-
-        let evaluacion: EvaluacionMongoDTO =
-            bson::from_document(evaluacion_document).map_err(|_| RespuestaError::DatabaseError)?;
-
-        let respuesta_dto = RespuestaMongoDTO {
-            id: RespuestaID::new_v4().to_string(),
-            evaluacion,
-            postulante_id: postulante_id.to_string(),
-            fecha_tiempo_inicio: String::new(),
-            fecha_tiempo_fin: String::new(),
-            estado: Estado::Creado.to_string(),
-            revision: Revision::SinIniciar.to_string(),
-        };
-
-        let respuesta_doc =
-            bson::to_document(&respuesta_dto).map_err(|_| RespuestaError::DatabaseError)?;
+        let evaluacion = self.evaluacion_asignable(&evaluacion_id).await?;
+        let (respuesta_id, respuesta_doc) =
+            nueva_respuesta(&evaluacion, postulante_id.to_string())?;
 
         self.get_collection()
             .insert_one(respuesta_doc)
             .await
             .map_err(|_| RespuestaError::DatabaseError)?;
 
-        Ok(())
+        Ok(RespuestaID::new(&respuesta_id)?)
     }
 
     async fn responder_evaluacion(
@@ -147,10 +197,28 @@ impl RepositorioRespuestaEscritura<RespuestaError> for RespuestaEvaluacionMongo
         Ok(())
     }
 
-    async fn obtener_puntaje(
+    async fn obtener_postulante(
+        &self,
+        respuesta_id: &RespuestaID,
+    ) -> Result<PostulanteID, RespuestaError> {
+        let result = self
+            .get_collection()
+            .find_one(doc! { "_id": respuesta_id.to_string() })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        let postulante_id = result
+            .get_str("postulante_id")
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(PostulanteID::new(postulante_id)?)
+    }
+
+    async fn obtener_correccion(
         &self,
         respuesta_evaluacion: &RespuestaEvaluacion,
-    ) -> Result<Puntaje, RespuestaError> {
+    ) -> Result<Correccion, RespuestaError> {
         use std::collections::HashMap;
 
         let filter = doc! {
@@ -199,6 +267,31 @@ impl RepositorioRespuestaEscritura<RespuestaError> for RespuestaEvaluacionMongo
             })
             .ok_or(RespuestaError::DatabaseError)?;
 
+        let tipo_de_pregunta = pregunta_doc
+            .get_str("tipo_de_pregunta")
+            .ok()
+            .and_then(|tipo| TipoPregunta::from_str(tipo).ok())
+            .ok_or(RespuestaError::DatabaseError)?;
+
+        let seleccion = match pregunta_doc.get_document("seleccion") {
+            Ok(seleccion) => bson::from_document::<SeleccionDTO>(seleccion.clone())
+                .ok()
+                .and_then(|seleccion| seleccion.try_into().ok()),
+            Err(_) => None,
+        };
+
+        let ordenamiento = pregunta_doc
+            .get_str("ordenamiento")
+            .ok()
+            .and_then(|modo| ModoOrdenamiento::from_str(modo).ok());
+
+        let numerica = match pregunta_doc.get_document("numerica") {
+            Ok(numerica) => bson::from_document::<NumericaDTO>(numerica.clone())
+                .ok()
+                .and_then(|numerica| numerica.try_into().ok()),
+            Err(_) => None,
+        };
+
         let puntaje_doc = pregunta_doc
             .get_document("puntaje")
             .map_err(|_| RespuestaError::DatabaseError)?;
@@ -224,7 +317,26 @@ impl RepositorioRespuestaEscritura<RespuestaError> for RespuestaEvaluacionMongo
             puntaje.insert(key.to_string(), puntos);
         }
 
-        Ok(puntaje)
+        let orden_alternativas = pregunta_doc
+            .get_array("orden_alternativas")
+            .ok()
+            .map(|claves| OrdenAlternativas {
+                claves: claves
+                    .iter()
+                    .filter_map(|clave| clave.as_str())
+                    .map(ToString::to_string)
+                    .collect(),
+            });
+
+        Ok(Correccion {
+            tipo_de_pregunta,
+            puntaje,
+            politica: politica_puntaje(examen_doc)?,
+            seleccion,
+            ordenamiento,
+            numerica,
+            orden_alternativas,
+        })
     }
 }
 
@@ -277,27 +389,35 @@ impl RespositorioFinalizarEvaluacion<RespuestaError> for RespositorioFinalizarEv
                     .get_str("_id")
                     .map_err(|_| RespuestaError::DatabaseError)?;
 
-                let mut examen_total_puntos: u32 = 0;
+                let politica = politica_puntaje(examen_doc)?;
+                let mut puntos_preguntas: Vec<i64> = Vec::new();
 
                 if let Ok(preguntas) = examen_doc.get_array("preguntas") {
-                    for pregunta_bson in preguntas.iter() {
-                        if let bson::Bson::Document(pregunta_doc) = pregunta_bson
-                            && let Some(puntos_value) = pregunta_doc.get("puntos")
-                        {
-                            let puntos = match puntos_value {
-                                bson::Bson::Int32(n) => *n as u32,
-                                bson::Bson::Int64(n) => *n as u32,
-                                bson::Bson::Double(n) => *n as u32,
-                                _ => 0,
-                            };
-                            examen_total_puntos += puntos;
-                        }
+                    for pregunta_doc in preguntas.iter().filter_map(|p| p.as_document()) {
+                        let puntos = pregunta_doc.get("puntos").map(|puntos| match puntos {
+                            bson::Bson::Int32(n) => i64::from(*n),
+                            bson::Bson::Int64(n) => *n,
+                            bson::Bson::Double(n) => *n as i64,
+                            _ => 0,
+                        });
+                        let tipo = pregunta_doc
+                            .get_str("tipo_de_pregunta")
+                            .ok()
+                            .and_then(|tipo| TipoPregunta::from_str(tipo).ok());
+                        // Las preguntas sin contestar se califican como en blanco
+                        puntos_preguntas.push(match tipo {
+                            Some(tipo) => politica.puntos_pregunta(&tipo, puntos),
+                            None => puntos.unwrap_or_default(),
+                        });
                     }
                 }
 
+                // La politica del examen decide si el total puede quedar negativo
+                let examen_total_puntos = politica.totalizar(puntos_preguntas);
+
                 let update = doc! {
                     "$set": {
-                        "evaluacion.examenes.$[examen].puntos_obtenidos": examen_total_puntos as i64,
+                        "evaluacion.examenes.$[examen].puntos_obtenidos": examen_total_puntos,
                     }
                 };
 
@@ -324,7 +444,7 @@ impl RespositorioFinalizarEvaluacion<RespuestaError> for RespositorioFinalizarEv
             .find_one(filter)
             .await
             .map_err(|_| RespuestaError::DatabaseError)?
-            .ok_or(RespuestaError::DatabaseError)?;
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
 
         let estado_str = result
             .get_str("estado")
@@ -358,6 +478,189 @@ impl RespositorioFinalizarEvaluacion<RespuestaError> for RespositorioFinalizarEv
 
         Ok(())
     }
+
+    async fn obtener_plazo(&self, evaluacion_id: String) -> Result<Plazo, RespuestaError> {
+        let filter = doc! {
+            "_id": &evaluacion_id,
+        };
+
+        let result = self
+            .get_collection()
+            .find_one(filter)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        let fecha_tiempo_inicio = result
+            .get_str("fecha_tiempo_inicio")
+            .unwrap_or_default()
+            .to_string();
+
+        let evaluacion = result
+            .get_document("evaluacion")
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        let duracion_evaluacion = duracion_minutos(evaluacion);
+        let duracion_examenes: Vec<Option<u32>> = evaluacion
+            .get_array("examenes")
+            .map(|examenes| {
+                examenes
+                    .iter()
+                    .filter_map(|examen| examen.as_document())
+                    .map(duracion_minutos)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Plazo::new(
+            fecha_tiempo_inicio,
+            duracion_evaluacion,
+            &duracion_examenes,
+        ))
+    }
+
+    async fn iniciar_plazo_examen(
+        &self,
+        evaluacion_id: String,
+        examen_id: String,
+    ) -> Result<Plazo, RespuestaError> {
+        let fecha_actual = quizz_common::domain::value_objects::zona_horaria::formatear_rfc3339(
+            &quizz_common::domain::value_objects::zona_horaria::ahora_lima(),
+        );
+
+        // Solo la primera respuesta del examen fija su inicio
+        let filter = doc! {
+            "_id": &evaluacion_id,
+            "evaluacion.examenes": {
+                "$elemMatch": {
+                    "_id": &examen_id,
+                    "fecha_tiempo_inicio": { "$exists": false },
+                }
+            },
+        };
+        let update = doc! {
+            "$set": { "evaluacion.examenes.$.fecha_tiempo_inicio": fecha_actual }
+        };
+        self.get_collection()
+            .update_one(filter, update)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        let result = self
+            .get_collection()
+            .find_one(doc! { "_id": &evaluacion_id })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        let examen = result
+            .get_document("evaluacion")
+            .and_then(|evaluacion| evaluacion.get_array("examenes"))
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .iter()
+            .filter_map(|examen| examen.as_document())
+            .find(|examen| examen.get_str("_id").ok() == Some(examen_id.as_str()))
+            .ok_or(RespuestaError::ExamenNotFound)?;
+
+        Ok(Plazo::new(
+            examen
+                .get_str("fecha_tiempo_inicio")
+                .unwrap_or_default()
+                .to_string(),
+            duracion_minutos(examen),
+            &[],
+        ))
+    }
+
+    async fn respuestas_en_proceso(
+        &self,
+        postulante_id: Option<String>,
+    ) -> Result<Vec<String>, RespuestaError> {
+        let mut filter = doc! { "estado": Estado::EnProceso.to_string() };
+        if let Some(postulante_id) = postulante_id {
+            filter.insert("postulante_id", postulante_id);
+        }
+
+        let documentos: Vec<bson::Document> = self
+            .get_collection()
+            .find(filter)
+            .projection(doc! { "_id": 1 })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .try_collect()
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(documentos
+            .iter()
+            .filter_map(|documento| documento.get_str("_id").ok())
+            .map(str::to_string)
+            .collect())
+    }
+
+    async fn obtener_evaluacion(
+        &self,
+        evaluacion_id: String,
+    ) -> Result<Evaluacion, RespuestaError> {
+        let result = self
+            .get_collection()
+            .find_one(doc! { "_id": &evaluacion_id })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        let respuesta_dto: RespuestaDTO =
+            bson::from_document(result).map_err(|_| RespuestaError::DatabaseError)?;
+        let respuesta: Respuesta = respuesta_dto.into();
+
+        Ok(respuesta.evaluacion)
+    }
+
+    async fn guardar_puntuacion(
+        &self,
+        evaluacion_id: String,
+        rasgos: Vec<PuntuacionRasgo>,
+        resultado_sugerido: Option<Resultado>,
+    ) -> Result<(), RespuestaError> {
+        let rasgos_docs = rasgos
+            .iter()
+            .map(|rasgo| bson::to_document(&RasgoDTO::from(rasgo)))
+            .collect::<Result<Vec<bson::Document>, _>>()
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        let update = doc! {
+            "$set": {
+                "rasgos": rasgos_docs,
+                "resultado_sugerido": resultado_sugerido.map(|r| r.to_string()),
+            }
+        };
+
+        self.get_collection()
+            .update_one(doc! { "_id": &evaluacion_id }, update)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(())
+    }
+}
+
+// Los examenes publicados antes de existir la politica usan la politica por defecto
+fn politica_puntaje(examen: &bson::Document) -> Result<PoliticaPuntaje, RespuestaError> {
+    match examen.get_document("politica_puntaje") {
+        Ok(politica) => bson::from_document::<PoliticaPuntajeDTO>(politica.clone())
+            .ok()
+            .and_then(|politica| PoliticaPuntaje::try_from(politica).ok())
+            .ok_or(RespuestaError::DatabaseError),
+        Err(_) => Ok(PoliticaPuntaje::default()),
+    }
+}
+
+fn duracion_minutos(documento: &bson::Document) -> Option<u32> {
+    match documento.get("duracion_minutos") {
+        Some(bson::Bson::Int32(n)) => Some(*n as u32),
+        Some(bson::Bson::Int64(n)) => Some(*n as u32),
+        _ => None,
+    }
 }
 
 pub struct RepositorioEmpezarExamenMongo {
@@ -427,3 +730,100 @@ impl RepositorioEmpezarExamen<RespuestaError> for RepositorioEmpezarExamenMongo
         Ok(())
     }
 }
+
+#[async_trait]
+impl RepositorioAsignacionMasiva<RespuestaError> for RespuestaEvaluacionMongo {
+    async fn resolver_postulantes(
+        &self,
+        postulante_ids: &[String],
+        documentos: &[String],
+    ) -> Result<Vec<PostulanteConocido>, RespuestaError> {
+        if postulante_ids.is_empty() && documentos.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let documentos: Vec<&str> = documentos
+            .iter()
+            .map(|documento| documento.trim())
+            .collect();
+        let postulantes: Vec<bson::Document> = self
+            .reposiorio_postulante
+            .get_collection()
+            .find(doc! {
+                "$or": [
+                    { "_id": { "$in": postulante_ids } },
+                    { "documento": { "$in": documentos } },
+                ]
+            })
+            .projection(doc! { "documento": 1 })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .try_collect()
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(postulantes
+            .iter()
+            .filter_map(|postulante| {
+                Some(PostulanteConocido {
+                    id: postulante.get_str("_id").ok()?.to_string(),
+                    documento: postulante.get_str("documento").ok()?.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    async fn postulantes_asignados(
+        &self,
+        evaluacion_id: &EvaluacionID,
+        postulante_ids: &[String],
+    ) -> Result<HashSet<String>, RespuestaError> {
+        if postulante_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let respuestas: Vec<bson::Document> = self
+            .get_collection()
+            .find(doc! {
+                "evaluacion._id": evaluacion_id.to_string(),
+                "postulante_id": { "$in": postulante_ids },
+            })
+            .projection(doc! { "postulante_id": 1 })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .try_collect()
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(respuestas
+            .iter()
+            .filter_map(|respuesta| respuesta.get_str("postulante_id").ok())
+            .map(ToString::to_string)
+            .collect())
+    }
+
+    async fn asignar_a_postulantes(
+        &self,
+        evaluacion_id: &EvaluacionID,
+        postulante_ids: &[String],
+    ) -> Result<Vec<String>, RespuestaError> {
+        let evaluacion = self.evaluacion_asignable(evaluacion_id).await?;
+        if postulante_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (respuesta_ids, respuestas): (Vec<String>, Vec<bson::Document>) = postulante_ids
+            .iter()
+            .map(|postulante_id| nueva_respuesta(&evaluacion, postulante_id.clone()))
+            .collect::<Result<Vec<_>, RespuestaError>>()?
+            .into_iter()
+            .unzip();
+
+        self.get_collection()
+            .insert_many(respuestas)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(respuesta_ids)
+    }
+}
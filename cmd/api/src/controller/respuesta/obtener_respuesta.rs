@@ -1,11 +1,13 @@
 use crate::controller::auth::jwt::Claims;
+use crate::controller::pregunta::dto::SeleccionDTO;
 use crate::controller::respuesta::dto::{
     EvaluacionResponseDTO, ExamenResponseDTO, PreguntaResponseDTO, RespuestaDetailDTO,
     build_pregunta_links, build_respuesta_links,
 };
 use crate::controller::respuesta::mongo::read::RespuestaPorPostulanteMongo;
+use crate::controller::respuesta::plazo::finalizar_si_vencida;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
-use log::{info, warn};
+use log::{error, info, warn};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
 use quizz_common::use_case::CasoDeUso;
 use quizz_core::respuesta::use_case::respuesta_postulante::{InputData, RespuestaPorPostulante};
@@ -43,6 +45,14 @@ impl ObtenerRespuestaController {
 
         info!("GET /respuestas/{}", respuesta_id);
 
+        if let Err(e) = finalizar_si_vencida(&pool, &respuesta_id).await {
+            error!(
+                "GET /respuestas/{} - error al finalizar por tiempo: {}",
+                respuesta_id, e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
         let resp_post =
             RespuestaPorPostulante::new(Box::new(RespuestaPorPostulanteMongo::new(pool)));
         let input = InputData {
@@ -61,6 +71,7 @@ impl ObtenerRespuestaController {
                     id: r.evaluacion.id.to_string(),
                     nombre: r.evaluacion.nombre,
                     descripcion: r.evaluacion.descripcion,
+                    duracion_minutos: r.evaluacion.duracion_minutos,
                     examenes: r
                         .evaluacion
                         .examenes
@@ -70,6 +81,7 @@ impl ObtenerRespuestaController {
                             titulo: ex.titulo,
                             descripcion: ex.descripcion,
                             instrucciones: ex.instrucciones,
+                            duracion_minutos: ex.duracion_minutos,
                             preguntas: ex
                                 .preguntas
                                 .into_iter()
@@ -94,6 +106,7 @@ impl ObtenerRespuestaController {
                                         alternativas: p.alternativas,
                                         respuestas: p.respuestas,
                                         puntos: Option::from(p.puntos),
+                                        seleccion: p.seleccion.as_ref().map(SeleccionDTO::from),
                                         links: pregunta_links,
                                     }
                                 })
@@ -115,6 +128,7 @@ impl ObtenerRespuestaController {
                     fecha_tiempo_inicio: r.fecha_tiempo_inicio,
                     fecha_tiempo_transcurrido,
                     fecha_tiempo_fin: r.fecha_tiempo_fin,
+                    fecha_tiempo_limite: r.fecha_tiempo_limite,
                     estado: estado.to_string(),
                     evaluacion,
                     revision: r.revision,
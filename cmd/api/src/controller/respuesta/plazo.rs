@@ -0,0 +1,47 @@
+use crate::controller::respuesta::mongo::write::RespositorioFinalizarEvaluacionMongo;
+use actix_web::web;
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
+use quizz_core::respuesta::use_case::finalizar_por_tiempo::{
+    FinalizarPorTiempo, FinalizarVencidas, InputData, InputDataVencidas,
+};
+
+/// Finaliza la respuesta si su plazo vencio, antes de leerla o revisarla.
+/// Una respuesta que no existe se deja pasar para que la lectura responda `404`.
+pub async fn finalizar_si_vencida(
+    pool: &web::Data<mongodb::Client>,
+    respuesta_id: &str,
+) -> Result<(), RespuestaError> {
+    let finalizar = FinalizarPorTiempo::new(Box::new(RespositorioFinalizarEvaluacionMongo::new(
+        pool.clone(),
+    )));
+
+    match finalizar
+        .ejecutar(InputData {
+            id: respuesta_id.to_string(),
+        })
+        .await
+    {
+        Ok(_) | Err(RespuestaError::RespuestaNoEncontrada) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Finaliza las respuestas vencidas, de todos o de un postulante, antes de listarlas
+pub async fn finalizar_vencidas(
+    pool: &web::Data<mongodb::Client>,
+    postulante_id: Option<String>,
+) -> Result<(), RespuestaError> {
+    let finalizar = FinalizarVencidas::new(Box::new(RespositorioFinalizarEvaluacionMongo::new(
+        pool.clone(),
+    )));
+
+    let finalizadas = finalizar
+        .ejecutar(InputDataVencidas { postulante_id })
+        .await?;
+    if finalizadas > 0 {
+        log::info!("{} respuestas finalizadas por tiempo", finalizadas);
+    }
+
+    Ok(())
+}
@@ -7,12 +7,16 @@ use crate::controller::respuesta::mongo::write::{
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
 use log::{error, info, warn};
 use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
 use quizz_core::respuesta::use_case::empezar_examen::{
     EmpezarExamen, InputData as EmpezarInputData,
 };
 use quizz_core::respuesta::use_case::finalizar_evaluacion::{
     FinalizarEvaluacion, InputData as FinalizarInputData,
 };
+use quizz_core::respuesta::use_case::finalizar_por_tiempo::{
+    FinalizarPorTiempo, InputData as FinalizarPorTiempoInputData,
+};
 use serde_json::json;
 
 pub struct TransicionEstadoController;
@@ -65,6 +69,45 @@ impl TransicionEstadoController {
         respuesta_id: &str,
         _rol: &str,
     ) -> HttpResponse {
+        let finalizar_por_tiempo = FinalizarPorTiempo::new(Box::new(
+            RespositorioFinalizarEvaluacionMongo::new(pool.clone()),
+        ));
+        let input = FinalizarPorTiempoInputData {
+            id: respuesta_id.to_string(),
+        };
+
+        match finalizar_por_tiempo.ejecutar(input).await {
+            Ok(output) if output.finalizada => {
+                warn!(
+                    "PATCH /respuestas/{}/estado - tiempo agotado, finalizado automaticamente",
+                    respuesta_id
+                );
+                let mut links = crate::controller::hateoas::Links::new();
+                links.insert(
+                    "self".into(),
+                    Link::get(format!("/respuestas/{}", respuesta_id)),
+                );
+
+                return HttpResponse::Conflict().json(json!({
+                    "error": "El tiempo para rendir la evaluacion se agoto",
+                    "estado": "Finalizado",
+                    "_links": links
+                }));
+            }
+            Ok(_) => {}
+            Err(e @ RespuestaError::RespuestaNoEncontrada) => {
+                warn!("PATCH /respuestas/{}/estado empezar - {}", respuesta_id, e);
+                return HttpResponse::NotFound().json(json!({"error": e.to_string()}));
+            }
+            Err(e) => {
+                error!(
+                    "PATCH /respuestas/{}/estado empezar - error al verificar el plazo: {}",
+                    respuesta_id, e
+                );
+                return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+            }
+        }
+
         let empezar_examen = EmpezarExamen::new(Box::new(RepositorioEmpezarExamenMongo::new(pool)));
         let input = EmpezarInputData {
             id: respuesta_id.to_string(),
@@ -92,13 +135,21 @@ impl TransicionEstadoController {
                     "_links": links
                 }))
             }
+            Err(e @ RespuestaError::EvaluacionYaIniciada) => {
+                warn!("PATCH /respuestas/{}/estado empezar - {}", respuesta_id, e);
+                HttpResponse::Conflict()
+                    .json(json!({"error": "No se puede iniciar el examen en el estado actual"}))
+            }
+            Err(e @ RespuestaError::RespuestaNoEncontrada) => {
+                warn!("PATCH /respuestas/{}/estado empezar - {}", respuesta_id, e);
+                HttpResponse::NotFound().json(json!({"error": e.to_string()}))
+            }
             Err(e) => {
                 error!(
                     "PATCH /respuestas/{}/estado empezar - error: {}",
                     respuesta_id, e
                 );
-                HttpResponse::Conflict()
-                    .json(json!({"error": "No se puede iniciar el examen en el estado actual"}))
+                HttpResponse::InternalServerError().json(json!({"error": e.to_string()}))
             }
         }
     }
@@ -129,13 +180,27 @@ impl TransicionEstadoController {
                     "_links": links
                 }))
             }
+            Err(e @ RespuestaError::EvaluacionNoEstaEnProceso) => {
+                warn!(
+                    "PATCH /respuestas/{}/estado finalizar - {}",
+                    respuesta_id, e
+                );
+                HttpResponse::Conflict()
+                    .json(json!({"error": "No se puede finalizar el examen en el estado actual"}))
+            }
+            Err(e @ RespuestaError::RespuestaNoEncontrada) => {
+                warn!(
+                    "PATCH /respuestas/{}/estado finalizar - {}",
+                    respuesta_id, e
+                );
+                HttpResponse::NotFound().json(json!({"error": e.to_string()}))
+            }
             Err(e) => {
                 error!(
                     "PATCH /respuestas/{}/estado finalizar - error: {}",
                     respuesta_id, e
                 );
-                HttpResponse::Conflict()
-                    .json(json!({"error": "No se puede finalizar el examen en el estado actual"}))
+                HttpResponse::InternalServerError().json(json!({"error": e.to_string()}))
             }
         }
     }
@@ -1,4 +1,5 @@
-mod dto;
+mod calificar_respuesta_libre;
+pub mod dto;
 mod listar_revisiones;
 mod mongo;
 mod obtener_revision;
@@ -0,0 +1,124 @@
+use crate::controller::respuesta::mongo::write::RespositorioFinalizarEvaluacionMongo;
+use crate::controller::respuesta::plazo::finalizar_si_vencida;
+use crate::controller::revision::dto::CalificacionDTO;
+use crate::controller::revision::mongo::write::RevisionEvaluacionMongo;
+use actix_web::{HttpRequest, HttpResponse, web};
+use log::{error, info, warn};
+use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
+use quizz_core::respuesta::use_case::calificar_respuesta_libre::{
+    CalificarRespuestaLibre, InputData,
+};
+use serde_json::json;
+
+pub struct CalificarRespuestaLibreController;
+
+impl CalificarRespuestaLibreController {
+    pub async fn grade(
+        req: HttpRequest,
+        body: web::Json<CalificacionDTO>,
+        pool: web::Data<mongodb::Client>,
+    ) -> HttpResponse {
+        let (revision_id, examen_id, pregunta_id) = match (
+            req.match_info().get("revision_id"),
+            req.match_info().get("examen_id"),
+            req.match_info().get("pregunta_id"),
+        ) {
+            (Some(revision_id), Some(examen_id), Some(pregunta_id)) => (
+                revision_id.to_string(),
+                examen_id.to_string(),
+                pregunta_id.to_string(),
+            ),
+            _ => {
+                return HttpResponse::BadRequest().json(
+                    json!({"error": "Se deben enviar los IDs de la revision, el examen y la pregunta"}),
+                );
+            }
+        };
+
+        info!(
+            "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion",
+            revision_id, examen_id, pregunta_id
+        );
+
+        let dto = body.into_inner();
+        let input = InputData {
+            revision_id: revision_id.clone(),
+            examen_id: examen_id.clone(),
+            pregunta_id: pregunta_id.clone(),
+            puntos: dto.puntos,
+            comentario: dto.comentario,
+        };
+
+        if let Err(e) = finalizar_si_vencida(&pool, &revision_id).await {
+            error!(
+                "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - error al finalizar por tiempo: {}",
+                revision_id, examen_id, pregunta_id, e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
+        let calificar = CalificarRespuestaLibre::new(
+            Box::new(RevisionEvaluacionMongo::new(pool.clone())),
+            Box::new(RespositorioFinalizarEvaluacionMongo::new(pool)),
+        );
+
+        match calificar.ejecutar(input).await {
+            Ok(()) => {
+                info!(
+                    "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - guardada",
+                    revision_id, examen_id, pregunta_id
+                );
+                HttpResponse::Ok().json(json!({
+                    "mensaje": "Calificacion guardada correctamente",
+                    "_links": {
+                        "revision": {
+                            "href": format!("/revisiones/{}", revision_id),
+                            "method": "GET"
+                        },
+                        "finalizar": {
+                            "href": format!("/revisiones/{}", revision_id),
+                            "method": "POST"
+                        }
+                    }
+                }))
+            }
+            Err(
+                err @ (RespuestaError::RespuestaNoEncontrada
+                | RespuestaError::ExamenNotFound
+                | RespuestaError::PreguntaNotFound),
+            ) => {
+                warn!(
+                    "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - {}",
+                    revision_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::NotFound().json(json!({"error": err.to_string()}))
+            }
+            Err(
+                err @ (RespuestaError::PreguntaNoEsLibre
+                | RespuestaError::PuntosFueraDeRango { .. }),
+            ) => {
+                warn!(
+                    "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - {}",
+                    revision_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::UnprocessableEntity().json(json!({"error": err.to_string()}))
+            }
+            Err(err @ RespuestaError::RevisionYaFinalizada) => {
+                warn!(
+                    "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - {}",
+                    revision_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::Conflict().json(json!({"error": err.to_string()}))
+            }
+            Err(err) => {
+                error!(
+                    "PUT /revisiones/{}/examenes/{}/preguntas/{}/calificacion - error: {}",
+                    revision_id, examen_id, pregunta_id, err
+                );
+                HttpResponse::InternalServerError()
+                    .json(json!({"error": "Error al guardar la calificacion"}))
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::controller::hateoas::Links;
+use quizz_core::respuesta::domain::entity::revision::CalificacionManual;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,6 +10,8 @@ pub struct CrearRevisionDTO {
     pub evaluacion_id: String,
     pub examenes: Vec<ExamenRevisionInputDTO>,
     pub resultado: String,
+    #[serde(default)]
+    pub justificacion: String,
 }
 
 #[derive(Deserialize)]
@@ -17,6 +20,33 @@ pub struct ExamenRevisionInputDTO {
     pub observacion: String,
 }
 
+/// Calificacion manual de una respuesta libre. Se recibe al calificar y se
+/// guarda igual en la pregunta de la respuesta.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CalificacionDTO {
+    pub puntos: u32,
+    #[serde(default)]
+    pub comentario: String,
+}
+
+impl From<&CalificacionManual> for CalificacionDTO {
+    fn from(calificacion: &CalificacionManual) -> Self {
+        Self {
+            puntos: calificacion.puntos,
+            comentario: calificacion.comentario.clone(),
+        }
+    }
+}
+
+impl From<CalificacionDTO> for CalificacionManual {
+    fn from(calificacion: CalificacionDTO) -> Self {
+        Self {
+            puntos: calificacion.puntos,
+            comentario: calificacion.comentario,
+        }
+    }
+}
+
 // --- Response DTOs ---
 
 #[derive(Serialize)]
@@ -44,16 +74,39 @@ pub struct RevisionDetalleDTO {
     pub id: String,
     pub postulante_id: String,
     pub resultado: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resultado_sugerido: Option<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub justificacion: String,
     pub revision: String,
     pub fecha_tiempo_inicio: String,
     pub fecha_tiempo_fin: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub psicologo: Option<RevisionPsicologoDTO>,
     pub evaluacion: RevisionEvaluacionDTO,
+    pub rasgos: Vec<RevisionRasgoDTO>,
     #[serde(rename = "_links")]
     pub links: Links,
 }
 
+#[derive(Serialize)]
+pub struct RevisionRasgoDTO {
+    pub etiqueta: String,
+    pub puntos_brutos: i64,
+    pub cantidad_items: u32,
+    pub promedio: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normativa: Option<RevisionNormativaDTO>,
+}
+
+#[derive(Serialize)]
+pub struct RevisionNormativaDTO {
+    pub baremo_id: String,
+    pub percentil: f64,
+    pub puntaje_t: f64,
+    pub eneatipo: u8,
+}
+
 #[derive(Serialize)]
 pub struct RevisionPsicologoDTO {
     pub nombre_completo: String,
@@ -77,6 +130,8 @@ pub struct RevisionExamenDTO {
     pub preguntas: Vec<RevisionPreguntaDTO>,
     pub puntos_obtenidos: i64,
     pub observacion: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normativa: Option<RevisionNormativaDTO>,
 }
 
 #[derive(Serialize)]
@@ -89,4 +144,6 @@ pub struct RevisionPreguntaDTO {
     pub alternativas: HashMap<String, String>,
     pub respuestas: Option<Vec<String>>,
     pub puntos: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calificacion: Option<CalificacionDTO>,
 }
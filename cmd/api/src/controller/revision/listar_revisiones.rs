@@ -1,5 +1,6 @@
 use crate::controller::hateoas::{Link, Links, ListResponse};
 use crate::controller::respuesta::mongo::read::RespuestaRevisionMongo;
+use crate::controller::respuesta::plazo::finalizar_vencidas;
 use crate::controller::revision::dto::RevisionListItemDTO;
 use actix_web::{HttpResponse, web};
 use log::{error, info};
@@ -13,6 +14,11 @@ impl ListarRevisionesController {
     pub async fn list(pool: web::Data<mongodb::Client>) -> HttpResponse {
         info!("GET /revisiones");
 
+        if let Err(e) = finalizar_vencidas(&pool, None).await {
+            error!("GET /revisiones - error al finalizar por tiempo: {}", e);
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
         let revision = RespuestaRevision::new(Box::new(RespuestaRevisionMongo::new(pool)));
 
         match revision.ejecutar(()).await {
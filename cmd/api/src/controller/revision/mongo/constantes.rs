@@ -1 +1,2 @@
 pub const RESPUESTA_COLLECTION_NAME: &str = "respuesta";
+pub const POSTULANTE_COLLECTION_NAME: &str = "postulante";
@@ -1,14 +1,21 @@
 use crate::controller::mongo_repository::MongoRepository;
 use crate::controller::respuesta::mongo::respuesta_dto::RespuestaDTO;
-use crate::controller::revision::mongo::constantes::RESPUESTA_COLLECTION_NAME;
+use crate::controller::revision::mongo::constantes::{
+    POSTULANTE_COLLECTION_NAME, RESPUESTA_COLLECTION_NAME,
+};
 use actix_web::web;
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use mongodb::bson;
-use mongodb::bson::doc;
+use mongodb::bson::{Document, doc};
+use quizz_common::domain::value_objects::fecha_nacimiento::FechaNacimiento;
+use quizz_core::baremo::domain::value_object::grupo_normativo::PerfilNormativo;
+use quizz_core::postulante::domain::value_object::genero::Genero;
+use quizz_core::postulante::domain::value_object::grado_instruccion::GradoInstruccion;
 use quizz_core::respuesta::domain::entity::respuesta::{Estado, Respuesta};
 use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
 use quizz_core::respuesta::provider::repositorio::RepositorioObtenerRevisionPorId;
-use tracing::error;
+use tracing::{error, warn};
 
 pub struct RevisionReadMongo {
     client: web::Data<mongodb::Client>,
@@ -57,4 +64,54 @@ impl RepositorioObtenerRevisionPorId<RespuestaError> for RevisionReadMongo {
             None => Err(RespuestaError::RespuestaNoEncontrada),
         }
     }
+
+    async fn obtener_perfil_normativo(
+        &self,
+        postulante_id: String,
+        fecha_referencia: NaiveDate,
+    ) -> Result<Option<PerfilNormativo>, RespuestaError> {
+        let collection = self
+            .get_client()
+            .database(self.get_database_name())
+            .collection::<Document>(POSTULANTE_COLLECTION_NAME);
+
+        let doc = collection
+            .find_one(doc! { "_id": &postulante_id })
+            .await
+            .map_err(|e| {
+                error!("Error finding postulante {}: {}", postulante_id, e);
+                RespuestaError::RepositorioError
+            })?;
+
+        let Some(doc) = doc else {
+            return Ok(None);
+        };
+
+        let genero = doc
+            .get_str("genero")
+            .ok()
+            .and_then(|g| g.parse::<Genero>().ok());
+        let grado_instruccion = doc
+            .get_str("grado_instruccion")
+            .ok()
+            .and_then(|g| g.parse::<GradoInstruccion>().ok());
+        let fecha_nacimiento = doc
+            .get_str("fecha_nacimiento")
+            .ok()
+            .and_then(|f| FechaNacimiento::new(f).ok());
+
+        match (genero, fecha_nacimiento, grado_instruccion) {
+            (Some(genero), Some(fecha_nacimiento), Some(grado_instruccion)) => {
+                Ok(Some(PerfilNormativo {
+                    genero,
+                    edad: fecha_nacimiento.edad_al(fecha_referencia),
+                    grado_instruccion,
+                }))
+            }
+            _ => {
+                warn!("Postulante {} sin datos normativos validos", postulante_id);
+                Ok(None)
+            }
+        }
+    }
 }
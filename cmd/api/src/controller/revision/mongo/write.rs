@@ -1,11 +1,17 @@
 use crate::controller::mongo_repository::MongoRepository;
+use crate::controller::respuesta::mongo::respuesta_dto::RespuestaDTO;
 use crate::controller::revision::mongo::constantes::RESPUESTA_COLLECTION_NAME;
 use actix_web::web;
 use async_trait::async_trait;
-use quizz_core::respuesta::domain::entity::respuesta::Revision;
-use quizz_core::respuesta::domain::entity::revision::ExamenRevision;
+use mongodb::bson;
+use quizz_core::evaluacion::domain::value_object::resultado::Resultado;
+use quizz_core::respuesta::domain::entity::evaluacion::Evaluacion;
+use quizz_core::respuesta::domain::entity::respuesta::{Estado, Respuesta, Revision};
+use quizz_core::respuesta::domain::entity::revision::{CalificacionManual, ExamenRevision};
 use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
-use quizz_core::respuesta::provider::repositorio::RespositorioRealizarRevision;
+use quizz_core::respuesta::provider::repositorio::{
+    RepositorioCalificarRespuestaLibre, RespositorioRealizarRevision,
+};
 
 pub struct RevisionEvaluacionMongo {
     client: web::Data<mongodb::Client>,
@@ -35,7 +41,8 @@ impl RespositorioRealizarRevision<RespuestaError> for RevisionEvaluacionMongo {
         evaluacion_id: String,
         examenes: Vec<ExamenRevision>,
         estado: Revision,
-        resultado: String,
+        resultado: Resultado,
+        justificacion: String,
     ) -> Result<(), RespuestaError> {
         use mongodb::bson::doc;
 
@@ -48,7 +55,8 @@ impl RespositorioRealizarRevision<RespuestaError> for RevisionEvaluacionMongo {
         let update = doc! {
             "$set": {
                 "revision": estado.to_string(),
-                "resultado": resultado,
+                "resultado": resultado.to_string(),
+                "justificacion": justificacion,
             }
         };
 
@@ -92,4 +100,95 @@ impl RespositorioRealizarRevision<RespuestaError> for RevisionEvaluacionMongo {
 
         Ok(())
     }
+
+    async fn obtener_resultado_sugerido(
+        &self,
+        revision_id: String,
+    ) -> Result<Option<Resultado>, RespuestaError> {
+        use mongodb::bson::doc;
+
+        let documento = self
+            .get_collection()
+            .find_one(doc! { "_id": &revision_id })
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        Ok(documento
+            .get_str("resultado_sugerido")
+            .ok()
+            .and_then(|resultado| resultado.parse().ok()))
+    }
+
+    async fn obtener_evaluacion(&self, revision_id: String) -> Result<Evaluacion, RespuestaError> {
+        Ok(self.obtener_respuesta(revision_id).await?.evaluacion)
+    }
+}
+
+#[async_trait]
+impl RepositorioCalificarRespuestaLibre<RespuestaError> for RevisionEvaluacionMongo {
+    async fn obtener_respuesta(&self, revision_id: String) -> Result<Respuesta, RespuestaError> {
+        use mongodb::bson::doc;
+
+        let filter = doc! {
+            "_id": &revision_id,
+            "estado": Estado::Finalizado.to_string(),
+        };
+
+        let documento = self
+            .get_collection()
+            .find_one(filter)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?
+            .ok_or(RespuestaError::RespuestaNoEncontrada)?;
+
+        let respuesta_dto: RespuestaDTO =
+            bson::from_document(documento).map_err(|_| RespuestaError::DatabaseError)?;
+
+        Ok(respuesta_dto.into())
+    }
+
+    async fn guardar_calificacion(
+        &self,
+        revision_id: String,
+        examen_id: String,
+        pregunta_id: String,
+        calificacion: &CalificacionManual,
+    ) -> Result<(), RespuestaError> {
+        use mongodb::bson::doc;
+
+        let filter = doc! {
+            "_id": &revision_id,
+            "evaluacion.examenes._id": &examen_id,
+            "evaluacion.examenes.preguntas._id": &pregunta_id,
+        };
+
+        let update = doc! {
+            "$set": {
+                "evaluacion.examenes.$[examen].preguntas.$[pregunta].puntos": i64::from(calificacion.puntos),
+                "evaluacion.examenes.$[examen].preguntas.$[pregunta].calificacion": {
+                    "puntos": i64::from(calificacion.puntos),
+                    "comentario": &calificacion.comentario,
+                },
+            }
+        };
+
+        let array_filters = vec![
+            doc! { "examen._id": &examen_id },
+            doc! { "pregunta._id": &pregunta_id },
+        ];
+
+        let result = self
+            .get_collection()
+            .update_one(filter, update)
+            .array_filters(array_filters)
+            .await
+            .map_err(|_| RespuestaError::DatabaseError)?;
+
+        if result.matched_count == 0 {
+            return Err(RespuestaError::PreguntaNotFound);
+        }
+
+        Ok(())
+    }
 }
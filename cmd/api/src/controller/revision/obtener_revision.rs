@@ -1,9 +1,11 @@
 use crate::controller::auth::jwt::Claims;
+use crate::controller::baremo::mongo::read::BaremoReadMongo;
 use crate::controller::hateoas::{Link, Links};
 use crate::controller::psicologo::mongo::read::PsicologoReadMongo;
+use crate::controller::respuesta::plazo::finalizar_si_vencida;
 use crate::controller::revision::dto::{
-    RevisionDetalleDTO, RevisionEvaluacionDTO, RevisionExamenDTO, RevisionPreguntaDTO,
-    RevisionPsicologoDTO,
+    CalificacionDTO, RevisionDetalleDTO, RevisionEvaluacionDTO, RevisionExamenDTO,
+    RevisionNormativaDTO, RevisionPreguntaDTO, RevisionPsicologoDTO, RevisionRasgoDTO,
 };
 use crate::controller::revision::mongo::read::RevisionReadMongo;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
@@ -11,11 +13,22 @@ use log::{error, info, warn};
 use quizz_auth::autorizacion::domain::value_object::rol::Rol;
 use quizz_common::use_case::CasoDeUso;
 use quizz_core::psicologo::provider::repositorio::RepositorioPsicologoLectura;
-use quizz_core::respuesta::use_case::obtener_revision::{InputData, ObtenerRevisionPorId};
+use quizz_core::respuesta::use_case::obtener_revision::{
+    InputData, ObtenerRevisionPorId, OutputNormativa,
+};
 use serde_json::json;
 
 pub struct ObtenerRevisionController;
 
+fn normativa_dto(normativa: OutputNormativa) -> RevisionNormativaDTO {
+    RevisionNormativaDTO {
+        baremo_id: normativa.baremo_id,
+        percentil: normativa.percentil,
+        puntaje_t: normativa.puntaje_t,
+        eneatipo: normativa.eneatipo,
+    }
+}
+
 impl ObtenerRevisionController {
     pub async fn get(req: HttpRequest, pool: web::Data<mongodb::Client>) -> HttpResponse {
         let revision_id = match req.match_info().get("revision_id") {
@@ -28,8 +41,18 @@ impl ObtenerRevisionController {
 
         info!("GET /revisiones/{}", revision_id);
 
-        let obtener_revision =
-            ObtenerRevisionPorId::new(Box::new(RevisionReadMongo::new(pool.clone())));
+        if let Err(e) = finalizar_si_vencida(&pool, &revision_id).await {
+            error!(
+                "GET /revisiones/{} - error al finalizar por tiempo: {}",
+                revision_id, e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
+        let obtener_revision = ObtenerRevisionPorId::new(
+            Box::new(RevisionReadMongo::new(pool.clone())),
+            Box::new(BaremoReadMongo::new(pool.clone())),
+        );
 
         match obtener_revision
             .ejecutar(InputData {
@@ -80,6 +103,8 @@ impl ObtenerRevisionController {
                     id: output.id,
                     postulante_id: output.postulante_id,
                     resultado: output.resultado,
+                    resultado_sugerido: output.resultado_sugerido,
+                    justificacion: output.justificacion,
                     revision: output.revision,
                     fecha_tiempo_inicio: output.fecha_tiempo_inicio,
                     fecha_tiempo_fin: output.fecha_tiempo_fin,
@@ -112,13 +137,29 @@ impl ObtenerRevisionController {
                                         alternativas: p.alternativas,
                                         respuestas: p.respuestas,
                                         puntos: p.puntos,
+                                        calificacion: p
+                                            .calificacion
+                                            .as_ref()
+                                            .map(CalificacionDTO::from),
                                     })
                                     .collect(),
                                 puntos_obtenidos: ex.puntos_obtenidos,
                                 observacion: ex.observacion,
+                                normativa: ex.normativa.map(normativa_dto),
                             })
                             .collect(),
                     },
+                    rasgos: output
+                        .rasgos
+                        .into_iter()
+                        .map(|r| RevisionRasgoDTO {
+                            etiqueta: r.etiqueta,
+                            puntos_brutos: r.puntos_brutos,
+                            cantidad_items: r.cantidad_items,
+                            promedio: r.promedio,
+                            normativa: r.normativa.map(normativa_dto),
+                        })
+                        .collect(),
                     links,
                 })
             }
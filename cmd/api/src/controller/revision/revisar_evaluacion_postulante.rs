@@ -1,9 +1,11 @@
 use crate::controller::hateoas::Link;
+use crate::controller::respuesta::plazo::finalizar_si_vencida;
 use crate::controller::revision::dto::{CrearRevisionDTO, RevisionCreatedDTO};
 use crate::controller::revision::mongo::write::RevisionEvaluacionMongo;
 use actix_web::{HttpRequest, HttpResponse, web};
-use log::{error, info};
+use log::{error, info, warn};
 use quizz_common::use_case::CasoDeUso;
+use quizz_core::respuesta::domain::error::respuesta::RespuestaError;
 use quizz_core::respuesta::use_case::realizar_revision::{
     InputData, InputDataExamen, RealizarRevision,
 };
@@ -36,6 +38,7 @@ impl RevisarEvaluacionPostulanteController {
             respuesta_id: respuesta_id.clone(),
             evaluacion_id: body.evaluacion_id,
             resultado: body.resultado,
+            justificacion: body.justificacion,
             examenes: body
                 .examenes
                 .into_iter()
@@ -46,6 +49,14 @@ impl RevisarEvaluacionPostulanteController {
                 .collect(),
         };
 
+        if let Err(e) = finalizar_si_vencida(&pool, &respuesta_id).await {
+            error!(
+                "POST /revisiones/{} - error al finalizar por tiempo: {}",
+                respuesta_id, e
+            );
+            return HttpResponse::InternalServerError().json(json!({"error": e.to_string()}));
+        }
+
         let revisar = RealizarRevision::new(Box::new(RevisionEvaluacionMongo::new(pool)));
         match revisar.ejecutar(input).await {
             Ok(_) => {
@@ -67,6 +78,17 @@ impl RevisarEvaluacionPostulanteController {
                     links,
                 })
             }
+            Err(
+                err @ (RespuestaError::ResultadoNoValido(_)
+                | RespuestaError::JustificacionRequerida(_)
+                | RespuestaError::CalificacionPendiente(_)),
+            ) => {
+                warn!("POST /revisiones/{} - {}", respuesta_id, err);
+                HttpResponse::UnprocessableEntity().json(json!({"error": err.to_string()}))
+            }
+            Err(RespuestaError::RespuestaNoEncontrada) => {
+                HttpResponse::NotFound().json(json!({"error": "La respuesta a revisar no existe"}))
+            }
             Err(err) => {
                 error!("POST /revisiones/{} - error: {}", respuesta_id, err);
                 HttpResponse::InternalServerError()
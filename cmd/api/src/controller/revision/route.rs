@@ -1,3 +1,4 @@
+use crate::controller::revision::calificar_respuesta_libre::CalificarRespuestaLibreController;
 use crate::controller::revision::listar_revisiones::ListarRevisionesController;
 use crate::controller::revision::obtener_revision::ObtenerRevisionController;
 use crate::controller::revision::revisar_evaluacion_postulante::RevisarEvaluacionPostulanteController;
@@ -12,6 +13,12 @@ pub fn revision(cfg: &mut web::ServiceConfig) {
                     .route(web::get().to(ObtenerRevisionController::get))
                     .route(web::post().to(RevisarEvaluacionPostulanteController::review))
                     .route(web::patch().to(RevisarEvaluacionPostulanteController::review)),
+            )
+            .service(
+                web::resource(
+                    "/{revision_id}/examenes/{examen_id}/preguntas/{pregunta_id}/calificacion",
+                )
+                .route(web::put().to(CalificarRespuestaLibreController::grade)),
             ),
     );
 }
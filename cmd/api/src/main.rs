@@ -38,6 +38,7 @@ async fn main() -> Result<(), std::io::Error> {
         connection_pool,
         redis_pool,
         configuration.jwt,
+        configuration.login,
         enforcer,
     )?
     .await?;
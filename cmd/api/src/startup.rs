@@ -1,11 +1,13 @@
-use crate::configuration::JwtSettings;
+use crate::configuration::{JwtSettings, LoginSettings};
 use crate::controller::admin::route::admin;
 use crate::controller::auth::middleware::AuthMiddleware;
-use crate::controller::auth::route::login_routes;
+use crate::controller::auth::route::{bloqueo, cuenta, login_routes, sesion};
+use crate::controller::baremo::route::baremo;
 use crate::controller::evaluacion::route::evaluacion;
 use crate::controller::examen::route::examen;
 use crate::controller::healthcheck::route::health_check;
 use crate::controller::postulante::route::postulante;
+use crate::controller::pregunta::route::pregunta;
 use crate::controller::psicologo::route::psicologo;
 use crate::controller::respuesta::route::respuesta;
 use crate::controller::revision::route::revision;
@@ -32,13 +34,20 @@ pub fn run(
     mongo_client: MongoClient,
     redis_client: RedisClient,
     jwt_settings: JwtSettings,
+    login_settings: LoginSettings,
     enforcer: Arc<RwLock<casbin::Enforcer>>,
 ) -> Result<Server, std::io::Error> {
     let db_connection_pool = web::Data::new(mongo_client);
     let redis_connection_pool = web::Data::new(redis_client);
     let jwt_settings_data = web::Data::new(jwt_settings.clone());
+    let login_settings_data = web::Data::new(login_settings);
     let server = HttpServer::new(move || {
-        let auth_middleware = AuthMiddleware::new(jwt_settings.secret.clone(), enforcer.clone());
+        let auth_middleware = AuthMiddleware::new(
+            jwt_settings.secret.clone(),
+            enforcer.clone(),
+            redis_connection_pool.clone(),
+            db_connection_pool.clone(),
+        );
         App::new()
             .wrap(set_cors())
             .configure(health_check)
@@ -47,16 +56,22 @@ pub fn run(
                 web::scope("")
                     .wrap(auth_middleware)
                     .configure(examen)
+                    .configure(pregunta)
                     .configure(evaluacion)
                     .configure(respuesta)
                     .configure(revision)
                     .configure(postulante)
                     .configure(psicologo)
-                    .configure(admin),
+                    .configure(admin)
+                    .configure(sesion)
+                    .configure(bloqueo)
+                    .configure(cuenta)
+                    .configure(baremo),
             )
             .app_data(db_connection_pool.clone())
             .app_data(redis_connection_pool.clone())
             .app_data(jwt_settings_data.clone())
+            .app_data(login_settings_data.clone())
     })
     .listen(tcp_listener)?
     .run();